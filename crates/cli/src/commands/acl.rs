@@ -0,0 +1,266 @@
+//! acl command - Manage bucket and object ACLs
+//!
+//! Show the access control list of a bucket or object, or apply a canned ACL.
+
+use clap::{Args, Subcommand};
+use rc_core::{Acl, AliasManager, CannedAcl, ObjectStore as _, RemotePath};
+use rc_s3::S3Client;
+use serde::Serialize;
+
+use crate::commands::resolve_alias;
+use crate::exit_code::ExitCode;
+use crate::output::{Formatter, OutputConfig};
+
+/// ACL subcommands
+#[derive(Subcommand, Debug)]
+pub enum AclCommands {
+    /// Show the ACL of a bucket or object
+    Get(AclPathArg),
+
+    /// Apply a canned ACL to a bucket or object
+    SetCanned(SetCannedArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AclPathArg {
+    /// Bucket or object path (alias/bucket[/key])
+    pub path: String,
+}
+
+#[derive(Args, Debug)]
+pub struct SetCannedArgs {
+    /// Bucket or object path (alias/bucket[/key])
+    pub path: String,
+
+    /// Canned ACL: private, public-read, public-read-write, authenticated-read,
+    /// bucket-owner-read or bucket-owner-full-control
+    pub acl: CannedAcl,
+}
+
+#[derive(Debug, Serialize)]
+struct AclOutput {
+    path: String,
+    #[serde(flatten)]
+    acl: Acl,
+}
+
+#[derive(Debug, Serialize)]
+struct SetCannedOutput {
+    path: String,
+    acl: String,
+    status: &'static str,
+}
+
+/// Execute an acl subcommand
+pub async fn execute(cmd: AclCommands, output_config: OutputConfig) -> ExitCode {
+    match cmd {
+        AclCommands::Get(args) => execute_get(args, output_config).await,
+        AclCommands::SetCanned(args) => execute_set_canned(args, output_config).await,
+    }
+}
+
+async fn execute_get(args: AclPathArg, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let (alias_name, bucket, key) = match parse_acl_path(&args.path) {
+        Ok(p) => p,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let client = match setup_client(&alias_name, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let result = match &key {
+        Some(key) => {
+            client
+                .get_object_acl(&RemotePath::new(&alias_name, &bucket, key))
+                .await
+        }
+        None => client.get_bucket_acl(&bucket).await,
+    };
+
+    match result {
+        Ok(acl) => {
+            if formatter.is_json() {
+                let output = AclOutput {
+                    path: args.path.clone(),
+                    acl,
+                };
+                formatter.json(&output);
+            } else {
+                print_acl(&args.path, &acl, &formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => acl_error(&args.path, "get ACL", e, &formatter),
+    }
+}
+
+async fn execute_set_canned(args: SetCannedArgs, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let (alias_name, bucket, key) = match parse_acl_path(&args.path) {
+        Ok(p) => p,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    if key.is_none() && !args.acl.applies_to_bucket() {
+        formatter.error(&format!(
+            "Canned ACL '{}' only applies to objects",
+            args.acl
+        ));
+        return ExitCode::UsageError;
+    }
+
+    let client = match setup_client(&alias_name, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let result = match &key {
+        Some(key) => {
+            client
+                .set_object_acl(&RemotePath::new(&alias_name, &bucket, key), args.acl)
+                .await
+        }
+        None => client.set_bucket_acl(&bucket, args.acl).await,
+    };
+
+    match result {
+        Ok(()) => {
+            if formatter.is_json() {
+                let output = SetCannedOutput {
+                    path: args.path.clone(),
+                    acl: args.acl.to_string(),
+                    status: "applied",
+                };
+                formatter.json(&output);
+            } else {
+                formatter.success(&format!("Applied ACL '{}' to '{}'", args.acl, args.path));
+            }
+            ExitCode::Success
+        }
+        Err(e) => acl_error(&args.path, "set ACL", e, &formatter),
+    }
+}
+
+fn print_acl(path: &str, acl: &Acl, formatter: &Formatter) {
+    formatter.println(&format!("ACL for '{path}':"));
+    if let Some(owner) = &acl.owner {
+        let name = owner
+            .display_name
+            .as_deref()
+            .or(owner.id.as_deref())
+            .unwrap_or("-");
+        formatter.println(&format!("  Owner: {}", formatter.style_name(name)));
+    }
+    if acl.grants.is_empty() {
+        formatter.println("  No grants.");
+    }
+    for grant in &acl.grants {
+        formatter.println(&format!(
+            "  {:<12} {} ({})",
+            grant.permission, grant.grantee, grant.grantee_type
+        ));
+    }
+}
+
+fn acl_error(path: &str, action: &str, error: rc_core::Error, formatter: &Formatter) -> ExitCode {
+    match error {
+        rc_core::Error::NotFound(_) => {
+            formatter.error(&format!("Not found: {path}"));
+            ExitCode::NotFound
+        }
+        rc_core::Error::Auth(e) => {
+            formatter.error(&format!("Access denied: {e}"));
+            ExitCode::AuthError
+        }
+        e => {
+            formatter.error(&format!("Failed to {action}: {e}"));
+            ExitCode::GeneralError
+        }
+    }
+}
+
+async fn setup_client(alias_name: &str, formatter: &Formatter) -> Result<S3Client, ExitCode> {
+    let alias_manager = match AliasManager::new() {
+        Ok(am) => am,
+        Err(e) => {
+            formatter.error(&format!("Failed to load aliases: {e}"));
+            return Err(ExitCode::GeneralError);
+        }
+    };
+
+    let alias = match resolve_alias(&alias_manager, alias_name) {
+        Ok(a) => a,
+        Err(_) => {
+            formatter.error(&format!("Alias '{alias_name}' not found"));
+            return Err(ExitCode::NotFound);
+        }
+    };
+
+    S3Client::new(alias).await.map_err(|e| {
+        formatter.error(&format!("Failed to create S3 client: {e}"));
+        ExitCode::NetworkError
+    })
+}
+
+/// Parse an ACL path into (alias, bucket, optional key)
+fn parse_acl_path(path: &str) -> Result<(String, String, Option<String>), String> {
+    if path.is_empty() {
+        return Err("Path cannot be empty".to_string());
+    }
+
+    let parts: Vec<&str> = path.splitn(3, '/').collect();
+
+    if parts.len() < 2 || parts[1].is_empty() {
+        return Err("Bucket is required (alias/bucket[/key])".to_string());
+    }
+
+    let key = parts
+        .get(2)
+        .filter(|k| !k.is_empty())
+        .map(|k| k.to_string());
+
+    Ok((parts[0].to_string(), parts[1].to_string(), key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_acl_path() {
+        let (alias, bucket, key) = parse_acl_path("myalias/mybucket/dir/file.txt").unwrap();
+        assert_eq!(alias, "myalias");
+        assert_eq!(bucket, "mybucket");
+        assert_eq!(key.as_deref(), Some("dir/file.txt"));
+
+        let (_, bucket, key) = parse_acl_path("myalias/mybucket/").unwrap();
+        assert_eq!(bucket, "mybucket");
+        assert!(key.is_none());
+
+        assert!(parse_acl_path("").is_err());
+        assert!(parse_acl_path("myalias").is_err());
+    }
+
+    #[test]
+    fn test_acl_output_flattens_grants() {
+        let output = AclOutput {
+            path: "local/bucket".to_string(),
+            acl: Acl::default(),
+        };
+        let value = serde_json::to_value(&output).expect("serialize acl output");
+        assert_eq!(value["path"], "local/bucket");
+        assert!(value["grants"].as_array().is_some());
+        assert!(value.get("owner").is_none());
+    }
+}
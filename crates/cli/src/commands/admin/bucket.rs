@@ -0,0 +1,573 @@
+//! Bucket admin commands
+//!
+//! Commands for registering the remote targets (endpoint, bucket and
+//! credentials) that bucket replication rules name by ARN, and for auditing
+//! a bucket's configuration in one report.
+
+use std::collections::{BTreeMap, HashMap};
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::{get_admin_client, get_s3_client};
+use crate::exit_code::ExitCode;
+use crate::output::Formatter;
+use rc_core::admin::{AdminApi, BucketQuota, BucketTarget, TargetCredentials};
+use rc_core::{ObjectLockConfig, ObjectStore as _};
+
+/// Bucket admin subcommands
+#[derive(Subcommand, Debug)]
+pub enum BucketCommands {
+    /// Summarize a bucket's versioning, object lock, quota, lifecycle,
+    /// replication, tags and policy
+    Info(InfoArgs),
+
+    /// Manage remote targets for bucket replication
+    #[command(subcommand)]
+    Remote(RemoteCommands),
+}
+
+/// Remote target subcommands
+#[derive(Subcommand, Debug)]
+pub enum RemoteCommands {
+    /// Register a remote target for a bucket and print its ARN
+    Add(AddArgs),
+
+    /// List remote targets (secret keys are redacted)
+    #[command(name = "ls", alias = "list")]
+    List(ListArgs),
+
+    /// Remove a remote target by ARN
+    #[command(name = "rm", alias = "remove")]
+    Remove(RemoveArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct InfoArgs {
+    /// Bucket (alias/bucket)
+    pub path: String,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct AddArgs {
+    /// Source bucket (alias/bucket)
+    pub path: String,
+
+    /// Remote endpoint URL (e.g. https://backup.example.com:9000)
+    #[arg(long)]
+    pub endpoint: String,
+
+    /// Bucket on the remote endpoint
+    #[arg(long)]
+    pub target_bucket: String,
+
+    /// Access key on the remote endpoint
+    #[arg(long)]
+    pub access_key: String,
+
+    /// Secret key on the remote endpoint
+    #[arg(long)]
+    pub secret_key: String,
+
+    /// Region of the remote bucket
+    #[arg(long)]
+    pub region: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ListArgs {
+    /// Alias, or alias/bucket to list one bucket's targets
+    pub path: String,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct RemoveArgs {
+    /// Source bucket (alias/bucket)
+    pub path: String,
+
+    /// ARN of the target to remove
+    pub arn: String,
+}
+
+/// JSON output for remote ls
+#[derive(Serialize)]
+struct RemoteListOutput {
+    targets: Vec<BucketTarget>,
+}
+
+/// JSON output for remote add and rm
+#[derive(Serialize)]
+struct RemoteOperationOutput {
+    success: bool,
+    bucket: String,
+    arn: String,
+    message: String,
+}
+
+/// Object lock state in bucket info output
+#[derive(Debug, PartialEq, Serialize)]
+struct ObjectLockOutput {
+    enabled: bool,
+    #[serde(flatten)]
+    retention: ObjectLockConfig,
+}
+
+/// JSON output for bucket info
+///
+/// A `null` field could not be read; `errors` says why.
+#[derive(Debug, Serialize)]
+struct BucketInfoOutput {
+    bucket: String,
+    versioning: Option<&'static str>,
+    object_lock: Option<ObjectLockOutput>,
+    quota: Option<BucketQuota>,
+    lifecycle_rules: Option<usize>,
+    replication_rules: Option<usize>,
+    tags: Option<BTreeMap<String, String>>,
+    policy: Option<bool>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    errors: BTreeMap<&'static str, String>,
+}
+
+/// Results of the individual configuration reads behind bucket info
+struct BucketChecks {
+    versioning: rc_core::Result<Option<bool>>,
+    object_lock: rc_core::Result<Option<ObjectLockConfig>>,
+    quota: rc_core::Result<Option<BucketQuota>>,
+    lifecycle_rules: rc_core::Result<usize>,
+    replication_rules: rc_core::Result<usize>,
+    tags: rc_core::Result<HashMap<String, String>>,
+    policy: rc_core::Result<Option<String>>,
+}
+
+impl BucketInfoOutput {
+    fn new(bucket: &str, checks: BucketChecks) -> Self {
+        let mut errors = BTreeMap::new();
+
+        let versioning =
+            record(&mut errors, "versioning", checks.versioning).map(|status| match status {
+                Some(true) => "Enabled",
+                Some(false) => "Suspended",
+                None => "Not configured",
+            });
+        let object_lock =
+            record(&mut errors, "object_lock", checks.object_lock).map(|config| ObjectLockOutput {
+                enabled: config.is_some(),
+                retention: config.unwrap_or_default(),
+            });
+        // An unset quota is reported as 0 bytes so `null` always means unknown
+        let quota = record(&mut errors, "quota", checks.quota).map(Option::unwrap_or_default);
+        let lifecycle_rules = record(&mut errors, "lifecycle_rules", checks.lifecycle_rules);
+        let replication_rules = record(&mut errors, "replication_rules", checks.replication_rules);
+        let tags = record(&mut errors, "tags", checks.tags).map(|tags| tags.into_iter().collect());
+        let policy = record(&mut errors, "policy", checks.policy).map(|policy| policy.is_some());
+
+        Self {
+            bucket: bucket.to_string(),
+            versioning,
+            object_lock,
+            quota,
+            lifecycle_rules,
+            replication_rules,
+            tags,
+            policy,
+            errors,
+        }
+    }
+}
+
+/// Keep a successful check's value, or note why it failed
+fn record<T>(
+    errors: &mut BTreeMap<&'static str, String>,
+    name: &'static str,
+    result: rc_core::Result<T>,
+) -> Option<T> {
+    result
+        .map_err(|e| {
+            errors.insert(name, e.to_string());
+        })
+        .ok()
+}
+
+/// Execute a bucket subcommand
+pub async fn execute(cmd: BucketCommands, formatter: &Formatter) -> ExitCode {
+    match cmd {
+        BucketCommands::Info(args) => execute_info(args, formatter).await,
+        BucketCommands::Remote(RemoteCommands::Add(args)) => execute_add(args, formatter).await,
+        BucketCommands::Remote(RemoteCommands::List(args)) => execute_list(args, formatter).await,
+        BucketCommands::Remote(RemoteCommands::Remove(args)) => {
+            execute_remove(args, formatter).await
+        }
+    }
+}
+
+/// Split `alias[/bucket]` into its parts
+fn parse_target_path(path: &str) -> Result<(String, Option<String>), String> {
+    let (alias, bucket) = match path.split_once('/') {
+        Some((alias, bucket)) => (alias, bucket.trim_end_matches('/')),
+        None => (path, ""),
+    };
+    if alias.is_empty() {
+        return Err(format!("Invalid path '{path}': expected alias[/bucket]"));
+    }
+    if bucket.contains('/') {
+        return Err(format!("Invalid path '{path}': expected alias[/bucket]"));
+    }
+    let bucket = (!bucket.is_empty()).then(|| bucket.to_string());
+    Ok((alias.to_string(), bucket))
+}
+
+/// Parse `alias/bucket`, reporting a usage error when the bucket is missing
+fn bucket_path(path: &str, formatter: &Formatter) -> Result<(String, String), ExitCode> {
+    match parse_target_path(path) {
+        Ok((alias, Some(bucket))) => Ok((alias, bucket)),
+        Ok((_, None)) => {
+            formatter.error("Bucket name is required (alias/bucket)");
+            Err(ExitCode::UsageError)
+        }
+        Err(e) => {
+            formatter.error(&e);
+            Err(ExitCode::UsageError)
+        }
+    }
+}
+
+async fn execute_info(args: InfoArgs, formatter: &Formatter) -> ExitCode {
+    let (alias, bucket) = match bucket_path(&args.path, formatter) {
+        Ok(p) => p,
+        Err(code) => return code,
+    };
+
+    let admin = match get_admin_client(&alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+    let s3 = match get_s3_client(&alias, formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    // The individual reads cannot tell a missing bucket from a missing setting
+    match s3.bucket_exists(&bucket).await {
+        Ok(true) => {}
+        Ok(false) => {
+            formatter.error(&format!("Bucket '{alias}/{bucket}' not found"));
+            return ExitCode::NotFound;
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to check bucket: {e}"));
+            return ExitCode::from(&e);
+        }
+    }
+
+    let (versioning, object_lock, quota, lifecycle_rules, replication_rules, tags, policy) = tokio::join!(
+        s3.get_versioning(&bucket),
+        s3.get_object_lock_config(&bucket),
+        admin.get_bucket_quota(&bucket),
+        s3.lifecycle_rule_count(&bucket),
+        s3.replication_rule_count(&bucket),
+        s3.get_bucket_tags(&bucket),
+        s3.get_bucket_policy(&bucket),
+    );
+    let info = BucketInfoOutput::new(
+        &bucket,
+        BucketChecks {
+            versioning,
+            object_lock,
+            quota,
+            lifecycle_rules,
+            replication_rules,
+            tags,
+            policy,
+        },
+    );
+
+    if formatter.is_json() {
+        formatter.json(&info);
+    } else {
+        print_bucket_info(&alias, &info, formatter);
+    }
+    for (name, error) in &info.errors {
+        formatter.warning(&format!(
+            "Could not read {}: {error}",
+            name.replace('_', " ")
+        ));
+    }
+    ExitCode::Success
+}
+
+fn print_bucket_info(alias: &str, info: &BucketInfoOutput, formatter: &Formatter) {
+    let unknown = || formatter.style_date("unknown");
+    let rules = |count: usize| match count {
+        0 => "none".to_string(),
+        1 => "1 rule".to_string(),
+        n => format!("{n} rules"),
+    };
+    let line = |key: &str, value: String| {
+        formatter.println(&format!(
+            "{} : {value}",
+            formatter.style_key(&format!("{key:<11}"))
+        ));
+    };
+
+    line(
+        "Bucket",
+        formatter.style_name(&format!("{alias}/{}", info.bucket)),
+    );
+    line(
+        "Versioning",
+        info.versioning.map_or_else(unknown, str::to_string),
+    );
+    line(
+        "Object lock",
+        info.object_lock
+            .as_ref()
+            .map_or_else(unknown, object_lock_summary),
+    );
+    line(
+        "Quota",
+        info.quota.as_ref().map_or_else(unknown, |quota| {
+            if quota.is_set() {
+                let size = humansize::format_size(quota.quota, humansize::BINARY);
+                format!("{} ({})", formatter.style_size(&size), quota.quota_type)
+            } else {
+                "none".to_string()
+            }
+        }),
+    );
+    line(
+        "Lifecycle",
+        info.lifecycle_rules.map_or_else(unknown, rules),
+    );
+    line(
+        "Replication",
+        info.replication_rules.map_or_else(unknown, rules),
+    );
+    line(
+        "Tags",
+        info.tags.as_ref().map_or_else(unknown, |tags| {
+            if tags.is_empty() {
+                "none".to_string()
+            } else {
+                tags.iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        }),
+    );
+    line(
+        "Policy",
+        info.policy
+            .map_or_else(unknown, |set| if set { "set" } else { "none" }.to_string()),
+    );
+}
+
+/// One-line description of a bucket's object lock state
+fn object_lock_summary(lock: &ObjectLockOutput) -> String {
+    if !lock.enabled {
+        return "disabled".to_string();
+    }
+    let retention = &lock.retention;
+    let period = match (retention.days, retention.years) {
+        (Some(days), _) => Some(format!("{days}d")),
+        (None, Some(years)) => Some(format!("{years}y")),
+        (None, None) => None,
+    };
+    match (&retention.mode, period) {
+        (Some(mode), Some(period)) => format!("enabled ({mode}, {period})"),
+        (Some(mode), None) => format!("enabled ({mode})"),
+        _ => "enabled".to_string(),
+    }
+}
+
+async fn execute_add(args: AddArgs, formatter: &Formatter) -> ExitCode {
+    let (alias, bucket) = match bucket_path(&args.path, formatter) {
+        Ok(p) => p,
+        Err(code) => return code,
+    };
+
+    let credentials = TargetCredentials {
+        access_key: args.access_key,
+        secret_key: args.secret_key,
+    };
+    let mut target = match BucketTarget::replication(
+        &bucket,
+        &args.endpoint,
+        &args.target_bucket,
+        credentials,
+    ) {
+        Ok(t) => t,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+    if let Some(region) = args.region {
+        target.region = region;
+    }
+
+    let client = match get_admin_client(&alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.set_remote_target(&target).await {
+        Ok(arn) => {
+            if formatter.is_json() {
+                formatter.json(&RemoteOperationOutput {
+                    success: true,
+                    bucket,
+                    arn,
+                    message: "Remote target added".to_string(),
+                });
+            } else {
+                formatter.success(&format!(
+                    "Remote target {} added for bucket '{bucket}'.",
+                    target.endpoint_url()
+                ));
+                formatter.println(&format!("ARN: {}", formatter.style_name(&arn)));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to add remote target: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_list(args: ListArgs, formatter: &Formatter) -> ExitCode {
+    let (alias, bucket) = match parse_target_path(&args.path) {
+        Ok(p) => p,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let client = match get_admin_client(&alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let targets: Vec<BucketTarget> = match client.list_remote_targets(bucket.as_deref()).await {
+        Ok(targets) => targets.iter().map(BucketTarget::redacted).collect(),
+        Err(e) => {
+            formatter.error(&format!("Failed to list remote targets: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    if formatter.is_json() {
+        formatter.json(&RemoteListOutput { targets });
+    } else if targets.is_empty() {
+        formatter.println("No remote targets configured.");
+    } else {
+        for target in &targets {
+            formatter.println(&formatter.style_name(&target.arn));
+            formatter.println(&format!(
+                "  {} -> {}/{}",
+                target.source_bucket,
+                target.endpoint_url(),
+                target.target_bucket
+            ));
+            if let Some(credentials) = &target.credentials {
+                formatter.println(&format!(
+                    "  access key: {}, secret key: {}",
+                    credentials.access_key, credentials.secret_key
+                ));
+            }
+        }
+    }
+    ExitCode::Success
+}
+
+async fn execute_remove(args: RemoveArgs, formatter: &Formatter) -> ExitCode {
+    let (alias, bucket) = match bucket_path(&args.path, formatter) {
+        Ok(p) => p,
+        Err(code) => return code,
+    };
+
+    let client = match get_admin_client(&alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.remove_remote_target(&bucket, &args.arn).await {
+        Ok(()) => {
+            if formatter.is_json() {
+                formatter.json(&RemoteOperationOutput {
+                    success: true,
+                    bucket,
+                    arn: args.arn,
+                    message: "Remote target removed".to_string(),
+                });
+            } else {
+                formatter.success(&format!(
+                    "Remote target '{}' removed from bucket '{bucket}'.",
+                    args.arn
+                ));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to remove remote target: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target_path() {
+        assert_eq!(
+            parse_target_path("local").unwrap(),
+            ("local".to_string(), None)
+        );
+        assert_eq!(
+            parse_target_path("local/photos/").unwrap(),
+            ("local".to_string(), Some("photos".to_string()))
+        );
+        assert!(parse_target_path("/photos").is_err());
+        assert!(parse_target_path("local/photos/2024").is_err());
+    }
+
+    #[test]
+    fn test_bucket_info_output() {
+        let info = BucketInfoOutput::new(
+            "photos",
+            BucketChecks {
+                versioning: Ok(Some(true)),
+                object_lock: Ok(Some(ObjectLockConfig {
+                    mode: Some("GOVERNANCE".to_string()),
+                    days: Some(30),
+                    years: None,
+                })),
+                quota: Ok(None),
+                lifecycle_rules: Ok(2),
+                replication_rules: Err(rc_core::Error::Auth("Access Denied".to_string())),
+                tags: Ok(HashMap::from([("env".to_string(), "prod".to_string())])),
+                policy: Ok(None),
+            },
+        );
+
+        assert_eq!(info.versioning, Some("Enabled"));
+        let lock = info.object_lock.as_ref().expect("object lock");
+        assert_eq!(object_lock_summary(lock), "enabled (GOVERNANCE, 30d)");
+        assert_eq!(info.quota.as_ref().map(BucketQuota::is_set), Some(false));
+        assert_eq!(info.lifecycle_rules, Some(2));
+        assert_eq!(info.replication_rules, None);
+        assert!(info.errors["replication_rules"].contains("Access Denied"));
+        assert_eq!(info.policy, Some(false));
+
+        let json = serde_json::to_value(&info).expect("serialize");
+        assert_eq!(json["object_lock"]["enabled"], true);
+        assert_eq!(json["object_lock"]["days"], 30);
+        assert_eq!(json["replication_rules"], serde_json::Value::Null);
+        assert_eq!(json["tags"]["env"], "prod");
+    }
+}
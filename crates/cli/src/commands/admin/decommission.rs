@@ -0,0 +1,248 @@
+//! Decommission command for storage pool removal
+//!
+//! Commands for starting, checking and canceling the decommission of a pool.
+//! Pools are named by their command line (endpoint pattern) as shown by
+//! `rc admin decommission status`.
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::output::{Formatter, format_bytes};
+use rc_core::admin::{AdminApi, PoolDecommissionInfo, PoolStatus};
+
+/// Decommission subcommands
+#[derive(Subcommand, Debug)]
+pub enum DecommissionCommands {
+    /// Start decommissioning a pool
+    Start(PoolArgs),
+
+    /// Display decommission progress for every pool
+    Status(StatusArgs),
+
+    /// Cancel a running pool decommission
+    Cancel(PoolArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct PoolArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Pool to act on, as listed by `decommission status` (e.g. "http://node{1...4}/data{1...4}")
+    pub pool: String,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct StatusArgs {
+    /// Alias name of the server
+    pub alias: String,
+}
+
+/// JSON output for decommission start and cancel
+#[derive(Serialize)]
+struct DecommissionOperationOutput {
+    success: bool,
+    pool: String,
+    message: String,
+}
+
+/// JSON output for decommission status
+#[derive(Serialize)]
+struct DecommissionStatusOutput {
+    pools: Vec<PoolStatus>,
+}
+
+/// Execute a decommission subcommand
+pub async fn execute(cmd: DecommissionCommands, formatter: &Formatter) -> ExitCode {
+    match cmd {
+        DecommissionCommands::Start(args) => execute_start(args, formatter).await,
+        DecommissionCommands::Status(args) => execute_status(args, formatter).await,
+        DecommissionCommands::Cancel(args) => execute_cancel(args, formatter).await,
+    }
+}
+
+async fn execute_start(args: PoolArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.decommission_start(&args.pool).await {
+        Ok(()) => {
+            if formatter.is_json() {
+                formatter.json(&DecommissionOperationOutput {
+                    success: true,
+                    pool: args.pool,
+                    message: "Decommission started".to_string(),
+                });
+            } else {
+                formatter.success(&format!("Decommission of pool '{}' started.", args.pool));
+                formatter.println(&format!(
+                    "Track progress with: rc admin decommission status {}",
+                    args.alias
+                ));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to start decommission: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_status(args: StatusArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.list_pools().await {
+        Ok(pools) => {
+            if formatter.is_json() {
+                formatter.json(&DecommissionStatusOutput { pools });
+            } else {
+                print_pools(&pools, formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to get decommission status: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_cancel(args: PoolArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.decommission_cancel(&args.pool).await {
+        Ok(()) => {
+            if formatter.is_json() {
+                formatter.json(&DecommissionOperationOutput {
+                    success: true,
+                    pool: args.pool,
+                    message: "Decommission canceled".to_string(),
+                });
+            } else {
+                formatter.success(&format!("Decommission of pool '{}' canceled.", args.pool));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to cancel decommission: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+fn print_pools(pools: &[PoolStatus], formatter: &Formatter) {
+    if pools.is_empty() {
+        formatter.println("No pools reported by the server.");
+        return;
+    }
+
+    for (i, pool) in pools.iter().enumerate() {
+        if i > 0 {
+            formatter.println("");
+        }
+        formatter.println(&format!(
+            "{} {}",
+            formatter.style_name(&format!("Pool {}:", pool.id)),
+            pool.cmdline
+        ));
+
+        let Some(info) = &pool.decommission_info else {
+            formatter.println(&format!(
+                "  State:         {}",
+                formatter.style_date("Active")
+            ));
+            continue;
+        };
+
+        formatter.println(&format!("  State:         {}", decommission_state(info)));
+        formatter.println(&format!(
+            "  Objects:       {} moved, {} failed",
+            info.objects_decommissioned, info.objects_decommissioned_failed
+        ));
+        formatter.println(&format!(
+            "  Data:          {} moved, {} failed",
+            format_bytes(info.bytes_decommissioned),
+            format_bytes(info.bytes_decommissioned_failed)
+        ));
+        formatter.println(&format!(
+            "  Remaining:     {} of {} at start",
+            format_bytes(info.current_size),
+            format_bytes(info.start_size)
+        ));
+        if let Some(ref started) = info.start_time {
+            formatter.println(&format!("  Started:       {}", started));
+        }
+        if let Some(ref last_update) = pool.last_update {
+            formatter.println(&format!("  Last Update:   {}", last_update));
+        }
+    }
+}
+
+/// Human-readable decommission state
+fn decommission_state(info: &PoolDecommissionInfo) -> &'static str {
+    if info.complete {
+        "Complete"
+    } else if info.failed {
+        "Failed"
+    } else if info.canceled {
+        "Canceled"
+    } else {
+        "Decommissioning"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decommission_state() {
+        let mut info = PoolDecommissionInfo::default();
+        assert_eq!(decommission_state(&info), "Decommissioning");
+        assert!(info.is_active());
+
+        info.canceled = true;
+        assert_eq!(decommission_state(&info), "Canceled");
+        assert!(!info.is_active());
+
+        info.complete = true;
+        assert_eq!(decommission_state(&info), "Complete");
+    }
+
+    #[test]
+    fn test_pool_status_deserialization() {
+        let body = r#"[{"id":1,"cmdline":"http://node{1...4}/data{1...4}",
+            "lastUpdate":"2026-01-01T10:30:00Z",
+            "decommissionInfo":{"startTime":"2026-01-01T10:00:00Z","startSize":1048576,
+            "totalSize":4194304,"currentSize":524288,"complete":false,"failed":false,
+            "canceled":false,"objectsDecommissioned":120,"objectsDecommissionedFailed":2,
+            "bytesDecommissioned":524288,"bytesDecommissionedFailed":1024}},
+            {"id":0,"cmdline":"http://node{1...4}/disk{1...4}"}]"#;
+
+        let pools: Vec<PoolStatus> = serde_json::from_str(body).expect("parse pools");
+        assert_eq!(pools.len(), 2);
+        let info = pools[0]
+            .decommission_info
+            .as_ref()
+            .expect("decommission info");
+        assert_eq!(info.objects_decommissioned, 120);
+        assert_eq!(info.bytes_decommissioned_failed, 1024);
+        assert!(info.is_active());
+        assert!(pools[1].decommission_info.is_none());
+
+        let value = serde_json::to_value(DecommissionStatusOutput { pools }).expect("serialize");
+        assert!(value["pools"][0]["decommissionInfo"]["bytesDecommissioned"].is_u64());
+        assert!(value["pools"][1].get("decommissionInfo").is_none());
+    }
+}
@@ -134,6 +134,7 @@ struct GroupInfo {
     name: String,
     status: String,
     policies: Vec<String>,
+    member_count: usize,
     members: Vec<String>,
 }
 
@@ -144,6 +145,7 @@ impl From<Group> for GroupInfo {
             name: group.name,
             status: group.status.to_string(),
             policies,
+            member_count: group.members.len(),
             members: group.members,
         }
     }
@@ -268,7 +270,12 @@ async fn execute_info(args: InfoArgs, formatter: &Formatter) -> ExitCode {
                 if group.members.is_empty() {
                     formatter.println("Members:  (none)");
                 } else {
-                    formatter.println(&format!("Members:  {}", group.members.join(", ")));
+                    formatter.println(&format!("Members:  {}", group.members.len()));
+                    let mut members = group.members.clone();
+                    members.sort();
+                    for member in &members {
+                        formatter.println(&format!("  - {}", formatter.style_name(member)));
+                    }
                 }
             }
             ExitCode::Success
@@ -479,6 +486,22 @@ mod tests {
         assert_eq!(info.name, "developers");
         assert_eq!(info.status, "enabled");
         assert_eq!(info.policies, vec!["readonly", "writeonly"]);
+        assert_eq!(info.member_count, 2);
         assert_eq!(info.members, vec!["user1", "user2"]);
     }
+
+    #[test]
+    fn test_group_info_serialization() {
+        let group = Group {
+            name: "empty".to_string(),
+            policy: None,
+            members: vec![],
+            status: GroupStatus::Disabled,
+        };
+
+        let value = serde_json::to_value(GroupInfo::from(group)).expect("serialize group info");
+        assert_eq!(value["memberCount"], 0);
+        assert_eq!(value["status"], "disabled");
+        assert!(value["policies"].as_array().expect("array").is_empty());
+    }
 }
@@ -7,7 +7,7 @@ use serde::Serialize;
 
 use super::get_admin_client;
 use crate::exit_code::ExitCode;
-use crate::output::Formatter;
+use crate::output::{Formatter, format_bytes};
 use rc_core::admin::{AdminApi, HealScanMode, HealStartRequest, HealStatus};
 
 /// Heal subcommands
@@ -57,6 +57,10 @@ pub struct StartArgs {
     /// Dry run mode - show what would be healed without actually healing
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Heal only this drive, given by endpoint or UUID (e.g. a replaced disk)
+    #[arg(long, conflicts_with_all = ["bucket", "prefix"])]
+    pub disk: Option<String>,
 }
 
 #[derive(clap::Args, Debug)]
@@ -137,7 +141,7 @@ async fn execute_status(args: StatusArgs, formatter: &Formatter) -> ExitCode {
         }
         Err(e) => {
             formatter.error(&format!("Failed to get heal status: {e}"));
-            ExitCode::GeneralError
+            ExitCode::from(&e)
         }
     }
 }
@@ -205,6 +209,28 @@ async fn execute_start(args: StartArgs, formatter: &Formatter) -> ExitCode {
         }
     };
 
+    // Resolve the drive against the cluster so typos fail before healing starts
+    let disk = match &args.disk {
+        Some(id) => match client.cluster_info().await {
+            Ok(info) => match info.find_disk(id) {
+                Some(disk) if disk.endpoint.is_empty() => Some(disk.uuid.clone()),
+                Some(disk) => Some(disk.endpoint.clone()),
+                None => {
+                    formatter.error(&format!(
+                        "Disk '{id}' not found; use `rc admin info disk {}` to list drives",
+                        args.alias
+                    ));
+                    return ExitCode::UsageError;
+                }
+            },
+            Err(e) => {
+                formatter.error(&format!("Failed to get cluster info: {e}"));
+                return ExitCode::from(&e);
+            }
+        },
+        None => None,
+    };
+
     let request = HealStartRequest {
         bucket: args.bucket,
         prefix: args.prefix,
@@ -212,6 +238,7 @@ async fn execute_start(args: StartArgs, formatter: &Formatter) -> ExitCode {
         remove: args.remove,
         recreate: args.recreate,
         dry_run: args.dry_run,
+        disk,
     };
 
     match client.heal_start(request).await {
@@ -236,7 +263,7 @@ async fn execute_start(args: StartArgs, formatter: &Formatter) -> ExitCode {
         }
         Err(e) => {
             formatter.error(&format!("Failed to start heal operation: {e}"));
-            ExitCode::GeneralError
+            ExitCode::from(&e)
         }
     }
 }
@@ -263,43 +290,15 @@ async fn execute_stop(args: StopArgs, formatter: &Formatter) -> ExitCode {
         }
         Err(e) => {
             formatter.error(&format!("Failed to stop heal operation: {e}"));
-            ExitCode::GeneralError
+            ExitCode::from(&e)
         }
     }
 }
 
-/// Format bytes into human-readable form
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-    const TB: u64 = GB * 1024;
-
-    if bytes >= TB {
-        format!("{:.2} TiB", bytes as f64 / TB as f64)
-    } else if bytes >= GB {
-        format!("{:.2} GiB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MiB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KiB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} B", bytes)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_format_bytes() {
-        assert_eq!(format_bytes(0), "0 B");
-        assert_eq!(format_bytes(1024), "1.00 KiB");
-        assert_eq!(format_bytes(1024 * 1024), "1.00 MiB");
-        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.00 GiB");
-    }
-
     #[test]
     fn test_heal_operation_output_serialization() {
         let status = HealStatus {
@@ -0,0 +1,109 @@
+//! Shared reporting for bulk import commands
+//!
+//! `admin user import` and `admin policy import` both process a list of
+//! entries, continue past failures and report per-entry results.
+
+use serde::Serialize;
+
+use crate::exit_code::ExitCode;
+use crate::output::Formatter;
+
+/// Result of importing a single entry
+#[derive(Debug, Serialize)]
+pub(super) struct ImportEntryResult {
+    pub name: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ImportEntryResult {
+    pub fn ok(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            success: true,
+            error: None,
+        }
+    }
+
+    pub fn failed(name: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            success: false,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// JSON output for bulk imports
+#[derive(Debug, Serialize)]
+pub(super) struct ImportOutput {
+    total: usize,
+    succeeded: usize,
+    failed: usize,
+    results: Vec<ImportEntryResult>,
+}
+
+impl ImportOutput {
+    pub fn new(results: Vec<ImportEntryResult>) -> Self {
+        let succeeded = results.iter().filter(|r| r.success).count();
+        Self {
+            total: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
+    }
+}
+
+/// Print the import summary and return the exit code for the run
+///
+/// `kind` is the singular noun used in messages ("user", "policy").
+pub(super) fn report(output: &ImportOutput, kind: &str, formatter: &Formatter) -> ExitCode {
+    if formatter.is_json() {
+        formatter.json(output);
+    } else {
+        for result in &output.results {
+            let styled_name = formatter.style_name(&result.name);
+            match &result.error {
+                None => formatter.success(&format!("Imported {kind} '{styled_name}'")),
+                Some(e) => {
+                    formatter.error(&format!("Failed to import {kind} '{styled_name}': {e}"))
+                }
+            }
+        }
+        formatter.println("");
+        formatter.println(&format!(
+            "Import complete: {} succeeded, {} failed",
+            output.succeeded, output.failed
+        ));
+    }
+
+    if output.failed > 0 {
+        ExitCode::GeneralError
+    } else {
+        ExitCode::Success
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_output_counts() {
+        let output = ImportOutput::new(vec![
+            ImportEntryResult::ok("alice"),
+            ImportEntryResult::failed("bob", "conflict"),
+            ImportEntryResult::ok("carol"),
+        ]);
+
+        assert_eq!(output.total, 3);
+        assert_eq!(output.succeeded, 2);
+        assert_eq!(output.failed, 1);
+
+        let value = serde_json::to_value(&output).expect("serialize import output");
+        assert!(value["results"][0].get("error").is_none());
+        assert_eq!(value["results"][1]["error"], "conflict");
+    }
+}
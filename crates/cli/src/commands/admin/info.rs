@@ -1,18 +1,24 @@
 //! Info command for cluster information
 //!
-//! Commands for displaying cluster status, disk info, and server information.
+//! Commands for displaying cluster status, disk info, and server information,
+//! plus a one-screen overview of all three.
 
 use clap::Subcommand;
 use serde::Serialize;
 
-use super::get_admin_client;
+use super::{ColumnArgs, get_admin_client, print_table};
 use crate::exit_code::ExitCode;
-use crate::output::Formatter;
+use crate::output::table::Column;
+use crate::output::{Formatter, format_bytes, format_duration};
 use rc_core::admin::{AdminApi, ClusterInfo, DiskInfo, ServerInfo};
 
 /// Info subcommands
 #[derive(Subcommand, Debug)]
 pub enum InfoCommands {
+    /// Display a compact overview of servers, disks, usage and erasure coding
+    #[command(name = "all")]
+    All(ClusterArgs),
+
     /// Display cluster overview information
     #[command(name = "cluster")]
     Cluster(ClusterArgs),
@@ -36,6 +42,9 @@ pub struct ClusterArgs {
 pub struct ServerArgs {
     /// Alias name of the server
     pub alias: String,
+
+    #[command(flatten)]
+    pub table: ColumnArgs,
 }
 
 #[derive(clap::Args, Debug)]
@@ -50,8 +59,109 @@ pub struct DiskArgs {
     /// Show only healing disks
     #[arg(long)]
     pub healing: bool,
+
+    #[command(flatten)]
+    pub table: ColumnArgs,
 }
 
+/// Columns for `info server` with `--wide` or `--columns`
+const SERVER_COLUMNS: &[Column<ServerInfo>] = &[
+    Column {
+        name: "endpoint",
+        wide: false,
+        value: |s| s.endpoint.clone(),
+    },
+    Column {
+        name: "state",
+        wide: false,
+        value: |s| s.state.clone(),
+    },
+    Column {
+        name: "version",
+        wide: false,
+        value: |s| s.version.clone(),
+    },
+    Column {
+        name: "uptime",
+        wide: false,
+        value: |s| format_duration(s.uptime),
+    },
+    Column {
+        name: "disks",
+        wide: false,
+        value: |s| s.disks.len().to_string(),
+    },
+    Column {
+        name: "offline",
+        wide: true,
+        value: |s| ServerOutput::from(s).offline_disks.to_string(),
+    },
+    Column {
+        name: "pool",
+        wide: true,
+        value: |s| s.pool_number.to_string(),
+    },
+    Column {
+        name: "commit",
+        wide: true,
+        value: |s| s.commit_id.clone(),
+    },
+];
+
+/// Columns for `info disk` with `--wide` or `--columns`
+const DISK_COLUMNS: &[Column<DiskInfo>] = &[
+    Column {
+        name: "path",
+        wide: false,
+        value: |d| d.drive_path.clone(),
+    },
+    Column {
+        name: "state",
+        wide: false,
+        value: |d| d.state.clone(),
+    },
+    Column {
+        name: "used",
+        wide: false,
+        value: |d| format_bytes(d.used_space),
+    },
+    Column {
+        name: "total",
+        wide: false,
+        value: |d| format_bytes(d.total_space),
+    },
+    Column {
+        name: "healing",
+        wide: false,
+        value: |d| d.healing.to_string(),
+    },
+    Column {
+        name: "endpoint",
+        wide: true,
+        value: |d| d.endpoint.clone(),
+    },
+    Column {
+        name: "pool",
+        wide: true,
+        value: |d| d.pool_index.to_string(),
+    },
+    Column {
+        name: "set",
+        wide: true,
+        value: |d| d.set_index.to_string(),
+    },
+    Column {
+        name: "disk",
+        wide: true,
+        value: |d| d.disk_index.to_string(),
+    },
+    Column {
+        name: "uuid",
+        wide: true,
+        value: |d| d.uuid.clone(),
+    },
+];
+
 /// JSON output for cluster info
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -68,6 +178,81 @@ struct ClusterOutput {
     objects: u64,
 }
 
+/// Overview shown by `info all`, assembled from a single cluster info call
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DashboardOutput {
+    mode: String,
+    deployment_id: String,
+    region: String,
+    servers: usize,
+    online_servers: usize,
+    offline_servers: usize,
+    disks: usize,
+    online_disks: usize,
+    offline_disks: usize,
+    healing_disks: usize,
+    total_capacity: u64,
+    used_capacity: u64,
+    /// Used share of the total capacity, rounded to one decimal; absent when the capacity is unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage_percent: Option<f64>,
+    buckets: u64,
+    objects: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    standard_parity: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rr_parity: Option<usize>,
+    erasure_sets: usize,
+    drives_per_set: Vec<usize>,
+}
+
+impl From<&ClusterInfo> for DashboardOutput {
+    fn from(info: &ClusterInfo) -> Self {
+        let servers = info.servers.as_deref().unwrap_or_default();
+        let online_servers = servers.iter().filter(|s| s.state == "online").count();
+        let disks: Vec<&DiskInfo> = servers.iter().flat_map(|s| &s.disks).collect();
+        let total_capacity = info.total_capacity();
+        let used_capacity = info.used_capacity();
+        let usage_percent = (total_capacity > 0)
+            .then(|| (used_capacity as f64 / total_capacity as f64 * 1000.0).round() / 10.0);
+        let backend = info.backend.as_ref();
+
+        Self {
+            mode: info
+                .mode
+                .clone()
+                .unwrap_or_else(|| "standalone".to_string()),
+            deployment_id: info.deployment_id.clone().unwrap_or_default(),
+            region: info
+                .region
+                .clone()
+                .unwrap_or_else(|| "us-east-1".to_string()),
+            servers: servers.len(),
+            online_servers,
+            offline_servers: servers.len() - online_servers,
+            disks: disks.len(),
+            online_disks: info.online_disks(),
+            offline_disks: info.offline_disks(),
+            healing_disks: disks.iter().filter(|d| d.healing).count(),
+            total_capacity,
+            used_capacity,
+            usage_percent,
+            buckets: info.buckets.as_ref().map(|b| b.count).unwrap_or(0),
+            objects: info.objects.as_ref().map(|o| o.count).unwrap_or(0),
+            backend: backend.map(|b| b.backend_type.to_string()),
+            standard_parity: backend.and_then(|b| b.standard_sc_parity),
+            rr_parity: backend.and_then(|b| b.rr_sc_parity),
+            erasure_sets: backend.map(|b| b.total_sets.iter().sum()).unwrap_or(0),
+            drives_per_set: backend
+                .map(|b| b.drives_per_set.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
 /// JSON output for server list
 #[derive(Serialize)]
 struct ServerListOutput {
@@ -152,12 +337,106 @@ impl From<&DiskInfo> for DiskOutput {
 /// Execute an info subcommand
 pub async fn execute(cmd: InfoCommands, formatter: &Formatter) -> ExitCode {
     match cmd {
+        InfoCommands::All(args) => execute_all(args, formatter).await,
         InfoCommands::Cluster(args) => execute_cluster(args, formatter).await,
         InfoCommands::Server(args) => execute_server(args, formatter).await,
         InfoCommands::Disk(args) => execute_disk(args, formatter).await,
     }
 }
 
+async fn execute_all(args: ClusterArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.cluster_info().await {
+        Ok(info) => {
+            let dashboard = DashboardOutput::from(&info);
+            if formatter.is_json() {
+                formatter.json(&dashboard);
+            } else {
+                print_dashboard(&dashboard, formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to get cluster info: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+fn print_dashboard(d: &DashboardOutput, formatter: &Formatter) {
+    let deployment = if d.deployment_id.is_empty() {
+        "unknown"
+    } else {
+        &d.deployment_id
+    };
+    formatter.println(&format!(
+        "{}  {}  {}",
+        formatter.style_name(&format!("{} cluster", d.mode)),
+        formatter.style_date(&format!("deployment {deployment}")),
+        formatter.style_date(&format!("region {}", d.region))
+    ));
+
+    // Counts that need attention are highlighted
+    let problem = |count: usize, label: &str| {
+        (count > 0).then(|| formatter.style_date(&format!("{count} {label}")))
+    };
+
+    let mut servers = vec![formatter.style_size(&format!("{} online", d.online_servers))];
+    servers.extend(problem(d.offline_servers, "offline"));
+    formatter.println(&format!(
+        "  Servers:  {} ({})",
+        d.servers,
+        servers.join(", ")
+    ));
+
+    let mut disks = vec![formatter.style_size(&format!("{} online", d.online_disks))];
+    disks.extend(problem(d.offline_disks, "offline"));
+    disks.extend(problem(d.healing_disks, "healing"));
+    formatter.println(&format!("  Disks:    {} ({})", d.disks, disks.join(", ")));
+
+    match d.usage_percent {
+        Some(percent) => formatter.println(&format!(
+            "  Usage:    {} / {} ({percent:.1}%)",
+            format_bytes(d.used_capacity),
+            format_bytes(d.total_capacity)
+        )),
+        None => formatter.println("  Usage:    unknown"),
+    }
+    formatter.println(&format!(
+        "  Content:  {} buckets, {} objects",
+        d.buckets, d.objects
+    ));
+
+    if let Some(backend) = &d.backend {
+        let mut parts = vec![formatter.style_name(backend)];
+        if d.erasure_sets > 0 {
+            let drives = match d.drives_per_set.as_slice() {
+                [] => String::new(),
+                [n, rest @ ..] if rest.iter().all(|m| m == n) => format!(" x {n} drives"),
+                many => format!(
+                    " x {} drives",
+                    many.iter()
+                        .map(usize::to_string)
+                        .collect::<Vec<_>>()
+                        .join("/")
+                ),
+            };
+            parts.push(format!("{} sets{drives}", d.erasure_sets));
+        }
+        if let Some(parity) = d.standard_parity {
+            parts.push(format!("parity EC:{parity}"));
+        }
+        if let Some(parity) = d.rr_parity {
+            parts.push(format!("RRS EC:{parity}"));
+        }
+        formatter.println(&format!("  Backend:  {}", parts.join(", ")));
+    }
+}
+
 async fn execute_cluster(args: ClusterArgs, formatter: &Formatter) -> ExitCode {
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
@@ -193,7 +472,7 @@ async fn execute_cluster(args: ClusterArgs, formatter: &Formatter) -> ExitCode {
         }
         Err(e) => {
             formatter.error(&format!("Failed to get cluster info: {e}"));
-            ExitCode::GeneralError
+            ExitCode::from(&e)
         }
     }
 }
@@ -275,6 +554,10 @@ fn print_cluster_info(info: &ClusterInfo, formatter: &Formatter) {
 }
 
 async fn execute_server(args: ServerArgs, formatter: &Formatter) -> ExitCode {
+    let columns = match args.table.table(SERVER_COLUMNS, formatter) {
+        Ok(columns) => columns,
+        Err(code) => return code,
+    };
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
         Err(code) => return code,
@@ -291,6 +574,8 @@ async fn execute_server(args: ServerArgs, formatter: &Formatter) -> ExitCode {
                 formatter.json(&output);
             } else if servers.is_empty() {
                 formatter.println("No servers found.");
+            } else if let Some(columns) = &columns {
+                print_table(formatter, columns, &servers);
             } else {
                 formatter.println(&format!(
                     "{} ({} servers)",
@@ -321,12 +606,16 @@ async fn execute_server(args: ServerArgs, formatter: &Formatter) -> ExitCode {
         }
         Err(e) => {
             formatter.error(&format!("Failed to get server info: {e}"));
-            ExitCode::GeneralError
+            ExitCode::from(&e)
         }
     }
 }
 
 async fn execute_disk(args: DiskArgs, formatter: &Formatter) -> ExitCode {
+    let columns = match args.table.table(DISK_COLUMNS, formatter) {
+        Ok(columns) => columns,
+        Err(code) => return code,
+    };
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
         Err(code) => return code,
@@ -355,6 +644,8 @@ async fn execute_disk(args: DiskArgs, formatter: &Formatter) -> ExitCode {
                 formatter.json(&output);
             } else if disks.is_empty() {
                 formatter.println("No disks found matching criteria.");
+            } else if let Some(columns) = &columns {
+                print_table(formatter, columns, disks.iter().copied());
             } else {
                 formatter.println(&format!(
                     "{} ({} disks)",
@@ -403,49 +694,11 @@ async fn execute_disk(args: DiskArgs, formatter: &Formatter) -> ExitCode {
         }
         Err(e) => {
             formatter.error(&format!("Failed to get disk info: {e}"));
-            ExitCode::GeneralError
+            ExitCode::from(&e)
         }
     }
 }
 
-/// Format bytes into human-readable form
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-    const TB: u64 = GB * 1024;
-    const PB: u64 = TB * 1024;
-
-    if bytes >= PB {
-        format!("{:.2} PiB", bytes as f64 / PB as f64)
-    } else if bytes >= TB {
-        format!("{:.2} TiB", bytes as f64 / TB as f64)
-    } else if bytes >= GB {
-        format!("{:.2} GiB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MiB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KiB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} B", bytes)
-    }
-}
-
-/// Format duration in seconds to human-readable form
-fn format_duration(seconds: u64) -> String {
-    let days = seconds / 86400;
-    let hours = (seconds % 86400) / 3600;
-    let minutes = (seconds % 3600) / 60;
-
-    if days > 0 {
-        format!("{}d {}h {}m", days, hours, minutes)
-    } else if hours > 0 {
-        format!("{}h {}m", hours, minutes)
-    } else {
-        format!("{}m", minutes)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -472,23 +725,61 @@ mod tests {
     }
 
     #[test]
-    fn test_format_bytes() {
-        assert_eq!(format_bytes(0), "0 B");
-        assert_eq!(format_bytes(512), "512 B");
-        assert_eq!(format_bytes(1024), "1.00 KiB");
-        assert_eq!(format_bytes(1024 * 1024), "1.00 MiB");
-        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.00 GiB");
-        assert_eq!(format_bytes(1024 * 1024 * 1024 * 1024), "1.00 TiB");
-    }
+    fn test_dashboard_output_from_cluster_info() {
+        use rc_core::admin::{BackendInfo, BackendType, BucketsInfo};
+
+        let disk = |state: &str, healing: bool| DiskInfo {
+            state: state.to_string(),
+            healing,
+            total_space: 1000,
+            used_space: 250,
+            ..Default::default()
+        };
+        let info = ClusterInfo {
+            mode: Some("distributed".to_string()),
+            buckets: Some(BucketsInfo {
+                count: 3,
+                error: None,
+            }),
+            backend: Some(BackendInfo {
+                backend_type: BackendType::Erasure,
+                standard_sc_parity: Some(2),
+                total_sets: vec![1, 1],
+                drives_per_set: vec![2, 2],
+                ..Default::default()
+            }),
+            servers: Some(vec![
+                ServerInfo {
+                    state: "online".to_string(),
+                    disks: vec![disk("ok", false), disk("ok", true)],
+                    ..Default::default()
+                },
+                ServerInfo {
+                    state: "offline".to_string(),
+                    disks: vec![disk("offline", false), disk("ok", false)],
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
 
-    #[test]
-    fn test_format_duration() {
-        assert_eq!(format_duration(0), "0m");
-        assert_eq!(format_duration(60), "1m");
-        assert_eq!(format_duration(3600), "1h 0m");
-        assert_eq!(format_duration(3661), "1h 1m");
-        assert_eq!(format_duration(86400), "1d 0h 0m");
-        assert_eq!(format_duration(90061), "1d 1h 1m");
+        let d = DashboardOutput::from(&info);
+        assert_eq!((d.servers, d.online_servers, d.offline_servers), (2, 1, 1));
+        assert_eq!(
+            (d.disks, d.online_disks, d.offline_disks, d.healing_disks),
+            (4, 3, 1, 1)
+        );
+        assert_eq!(d.usage_percent, Some(25.0));
+        assert_eq!((d.buckets, d.objects), (3, 0));
+        assert_eq!(d.backend.as_deref(), Some("Erasure"));
+        assert_eq!(d.standard_parity, Some(2));
+        assert_eq!(d.erasure_sets, 2);
+
+        let empty = DashboardOutput::from(&ClusterInfo::default());
+        assert_eq!(empty.usage_percent, None);
+        let value = serde_json::to_value(&empty).expect("serialize dashboard");
+        assert!(value.get("usagePercent").is_none());
+        assert!(value.get("healingDisks").is_some());
     }
 
     #[test]
@@ -544,4 +835,30 @@ mod tests {
         assert_eq!(output.set_index, 1);
         assert_eq!(output.disk_index, 2);
     }
+
+    #[test]
+    fn test_disk_wide_columns() {
+        let disk = DiskInfo {
+            drive_path: "/data/disk1".to_string(),
+            state: "ok".to_string(),
+            uuid: "4f1c".to_string(),
+            set_index: 3,
+            ..Default::default()
+        };
+
+        let requested = vec!["path".to_string(), "set".to_string(), "uuid".to_string()];
+        let columns = crate::output::table::select_columns(DISK_COLUMNS, false, Some(&requested))
+            .expect("known columns");
+        let lines = crate::output::table::render(&columns, [&disk]);
+        assert_eq!(lines, ["PATH         SET  UUID", "/data/disk1  3    4f1c"]);
+
+        assert!(
+            crate::output::table::select_columns(
+                DISK_COLUMNS,
+                false,
+                Some(&["commit".to_string()])
+            )
+            .is_err()
+        );
+    }
 }
@@ -0,0 +1,204 @@
+//! Logs command for tailing server logs
+//!
+//! Streams log entries from every node (or one node) until interrupted,
+//! reconnecting when the connection drops.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::interrupt;
+use crate::output::Formatter;
+use rc_core::Error;
+use rc_core::admin::{AdminApi, LogEntry, LogKind, LogOpts};
+
+/// First delay before reconnecting a dropped stream
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Upper bound for the reconnect delay
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Kind of log entries to show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogType {
+    /// Storage server errors
+    Minio,
+    /// Application errors
+    Application,
+    /// Every entry
+    All,
+}
+
+impl From<LogType> for LogKind {
+    fn from(value: LogType) -> Self {
+        match value {
+            LogType::Minio => LogKind::Minio,
+            LogType::Application => LogKind::Application,
+            LogType::All => LogKind::All,
+        }
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LogsArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Only show entries from this node (host:port)
+    #[arg(long)]
+    pub node: Option<String>,
+
+    /// Kind of entries to show
+    #[arg(long = "type", value_enum, default_value = "all")]
+    pub log_type: LogType,
+
+    /// Number of recent entries to show before following new ones
+    #[arg(long, default_value = "10")]
+    pub last: usize,
+}
+
+/// Execute the logs command
+pub async fn execute(args: LogsArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let mut opts = LogOpts {
+        node: args.node,
+        kind: args.log_type.into(),
+        last: args.last,
+    };
+
+    // Network errors are only retried once the stream has worked, so a wrong
+    // endpoint or a down server fails fast instead of retrying forever.
+    let received = AtomicBool::new(false);
+    let on_entry = |entry: &LogEntry| {
+        received.store(true, Ordering::Relaxed);
+        print_entry(entry, formatter);
+    };
+
+    let mut streaming = false;
+    let mut delay = INITIAL_RETRY_DELAY;
+
+    // Ctrl-C is how the user stops following, so it ends the stream successfully
+    let mut guard = interrupt::Guard::new();
+
+    loop {
+        let result = tokio::select! {
+            result = client.stream_logs(&opts, &on_entry) => result,
+            _ = guard.interrupted() => return ExitCode::Success,
+        };
+
+        if received.swap(false, Ordering::Relaxed) {
+            streaming = true;
+            delay = INITIAL_RETRY_DELAY;
+        }
+
+        match result {
+            Ok(()) => {
+                streaming = true;
+                formatter.warning("Server closed the log stream; reconnecting");
+            }
+            Err(Error::Network(msg)) if streaming => {
+                formatter.warning(&format!(
+                    "Log stream interrupted: {msg}; reconnecting in {}s",
+                    delay.as_secs()
+                ));
+            }
+            Err(e) => {
+                formatter.error(&format!("Failed to stream logs: {e}"));
+                return ExitCode::from(&e);
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = guard.interrupted() => return ExitCode::Success,
+        }
+        delay = (delay * 2).min(MAX_RETRY_DELAY);
+
+        // Entries replayed by the server were already shown
+        opts.last = 0;
+    }
+}
+
+fn print_entry(entry: &LogEntry, formatter: &Formatter) {
+    if formatter.is_json() {
+        formatter.json_lines(std::slice::from_ref(entry));
+        return;
+    }
+
+    formatter.println(&format_entry(entry, formatter));
+    if let Some(error) = &entry.error {
+        for source in &error.source {
+            formatter.println(&format!("    at {source}"));
+        }
+    }
+}
+
+/// Render the first line of an entry: time, node, level, request and text
+fn format_entry(entry: &LogEntry, formatter: &Formatter) -> String {
+    let mut line = String::new();
+    if !entry.time.is_empty() {
+        line.push_str(&formatter.style_date(&entry.time));
+        line.push(' ');
+    }
+    if !entry.node.is_empty() {
+        line.push_str(&formatter.style_name(&entry.node));
+        line.push(' ');
+    }
+    if !entry.level.is_empty() {
+        line.push_str(&entry.level.to_uppercase());
+        line.push(' ');
+    }
+    if let Some(request_id) = entry.request_id.as_deref().filter(|r| !r.is_empty()) {
+        line.push_str(&format!("[{request_id}] "));
+    }
+    line.push_str(entry.text());
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rc_core::admin::LogErrorDetail;
+
+    #[test]
+    fn test_format_entry() {
+        let formatter = Formatter::default();
+        let entry = LogEntry {
+            node: "n1:9000".to_string(),
+            level: "error".to_string(),
+            time: "2026-01-02T03:04:05Z".to_string(),
+            request_id: Some("17A".to_string()),
+            error: Some(LogErrorDetail {
+                message: "signature mismatch".to_string(),
+                source: vec!["auth.rs:12".to_string()],
+            }),
+            ..Default::default()
+        };
+
+        let line = format_entry(&entry, &formatter);
+        assert!(line.contains("n1:9000"));
+        assert!(line.contains("ERROR [17A] signature mismatch"));
+    }
+
+    #[test]
+    fn test_format_entry_without_metadata() {
+        let formatter = Formatter::default();
+        let entry = LogEntry {
+            console_msg: Some("server started\n".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(format_entry(&entry, &formatter), "server started");
+    }
+
+    #[test]
+    fn test_log_type_maps_to_kind() {
+        assert_eq!(LogKind::from(LogType::Minio).to_string(), "MINIO");
+        assert_eq!(LogKind::from(LogType::All).to_string(), "ALL");
+    }
+}
@@ -3,19 +3,32 @@
 //! This module provides commands for managing users, policies, groups,
 //! service accounts, and cluster operations on RustFS/MinIO-compatible servers.
 
+mod bucket;
+mod decommission;
 mod group;
 mod heal;
+mod import;
 mod info;
+mod logs;
+mod notify;
 mod policy;
+mod profile;
+mod prometheus;
+mod rebalance;
+mod replicate;
+mod service;
 mod service_account;
+mod speedtest;
 mod user;
 
 use clap::Subcommand;
 
+use super::resolve_alias;
 use crate::exit_code::ExitCode;
+use crate::output::table::{self, Column};
 use crate::output::{Formatter, OutputConfig};
-use rc_core::AliasManager;
-use rc_s3::AdminClient;
+use rc_core::{Alias, AliasManager};
+use rc_s3::{AdminClient, S3Client};
 
 /// Admin subcommands for IAM and cluster management
 #[derive(Subcommand, Debug)]
@@ -28,6 +41,26 @@ pub enum AdminCommands {
     #[command(subcommand)]
     Heal(heal::HealCommands),
 
+    /// Decommission storage pools before removing them
+    #[command(subcommand)]
+    Decommission(decommission::DecommissionCommands),
+
+    /// Rebalance data across pools after adding one
+    #[command(subcommand)]
+    Rebalance(rebalance::RebalanceCommands),
+
+    /// Show site replication sites and health
+    #[command(subcommand)]
+    Replicate(replicate::ReplicateCommands),
+
+    /// Manage notification targets (webhook, Kafka, AMQP, ...)
+    #[command(subcommand)]
+    Notify(notify::NotifyCommands),
+
+    /// Inspect bucket configuration and manage replication remote targets
+    #[command(subcommand)]
+    Bucket(bucket::BucketCommands),
+
     /// Manage IAM users
     #[command(subcommand)]
     User(user::UserCommands),
@@ -43,6 +76,22 @@ pub enum AdminCommands {
     /// Manage service accounts
     #[command(name = "service-account", subcommand)]
     ServiceAccount(service_account::ServiceAccountCommands),
+
+    /// Benchmark cluster PUT/GET throughput
+    Speedtest(speedtest::SpeedtestArgs),
+
+    /// Capture CPU/memory profiles from all nodes
+    Profile(profile::ProfileArgs),
+
+    /// Tail server logs
+    Logs(logs::LogsArgs),
+
+    /// Print a Prometheus metrics snapshot
+    Prometheus(prometheus::PrometheusArgs),
+
+    /// Restart or stop the cluster
+    #[command(subcommand)]
+    Service(service::ServiceCommands),
 }
 
 /// Execute an admin subcommand
@@ -52,15 +101,102 @@ pub async fn execute(cmd: AdminCommands, output_config: OutputConfig) -> ExitCod
     match cmd {
         AdminCommands::Info(info_cmd) => info::execute(info_cmd, &formatter).await,
         AdminCommands::Heal(heal_cmd) => heal::execute(heal_cmd, &formatter).await,
+        AdminCommands::Decommission(cmd) => decommission::execute(cmd, &formatter).await,
+        AdminCommands::Rebalance(cmd) => rebalance::execute(cmd, &formatter).await,
+        AdminCommands::Replicate(cmd) => replicate::execute(cmd, &formatter).await,
+        AdminCommands::Notify(cmd) => notify::execute(cmd, &formatter).await,
+        AdminCommands::Bucket(cmd) => bucket::execute(cmd, &formatter).await,
         AdminCommands::User(user_cmd) => user::execute(user_cmd, &formatter).await,
         AdminCommands::Policy(policy_cmd) => policy::execute(policy_cmd, &formatter).await,
         AdminCommands::Group(group_cmd) => group::execute(group_cmd, &formatter).await,
         AdminCommands::ServiceAccount(sa_cmd) => service_account::execute(sa_cmd, &formatter).await,
+        AdminCommands::Speedtest(args) => speedtest::execute(args, &formatter).await,
+        AdminCommands::Profile(args) => profile::execute(args, &formatter).await,
+        AdminCommands::Logs(args) => logs::execute(args, &formatter).await,
+        AdminCommands::Prometheus(args) => prometheus::execute(args, &formatter).await,
+        AdminCommands::Service(service_cmd) => service::execute(service_cmd, &formatter).await,
+    }
+}
+
+/// Table options shared by admin listings
+#[derive(clap::Args, Debug, Default)]
+pub struct ColumnArgs {
+    /// Show a table with extra columns (IDs, indices, commit)
+    #[arg(long)]
+    pub wide: bool,
+
+    /// Show a table with only these comma-separated columns
+    #[arg(long, value_delimiter = ',', value_name = "NAMES")]
+    pub columns: Option<Vec<String>>,
+}
+
+impl ColumnArgs {
+    /// Columns to render, or `None` to keep the listing's usual layout
+    ///
+    /// Unknown column names are reported as a usage error.
+    fn table<'a, T>(
+        &self,
+        columns: &'a [Column<T>],
+        formatter: &Formatter,
+    ) -> Result<Option<Vec<&'a Column<T>>>, ExitCode> {
+        if !self.wide && self.columns.is_none() {
+            return Ok(None);
+        }
+        match table::select_columns(columns, self.wide, self.columns.as_deref()) {
+            Ok(selected) => Ok(Some(selected)),
+            Err(msg) => {
+                formatter.error(&msg);
+                Err(ExitCode::UsageError)
+            }
+        }
+    }
+}
+
+/// Print rows as a table with a styled header
+fn print_table<'r, T: 'r>(
+    formatter: &Formatter,
+    columns: &[&Column<T>],
+    rows: impl IntoIterator<Item = &'r T>,
+) {
+    let mut lines = table::render(columns, rows).into_iter();
+    if let Some(header) = lines.next() {
+        formatter.println(&formatter.style_key(&header));
+    }
+    for line in lines {
+        formatter.println(&line);
     }
 }
 
 /// Helper to get AdminClient from an alias name
 pub fn get_admin_client(alias_name: &str, formatter: &Formatter) -> Result<AdminClient, ExitCode> {
+    let alias = load_alias(alias_name, formatter)?;
+
+    match AdminClient::new(&alias) {
+        Ok(client) => Ok(client),
+        Err(e) => {
+            // An anonymous alias is a configuration error: exit code 2
+            formatter.error(&format!("Failed to create admin client: {e}"));
+            Err(ExitCode::from(&e))
+        }
+    }
+}
+
+/// Helper to get an S3 client from an alias name, for admin commands that
+/// also read bucket configuration through the S3 API
+pub async fn get_s3_client(alias_name: &str, formatter: &Formatter) -> Result<S3Client, ExitCode> {
+    let alias = load_alias(alias_name, formatter)?;
+
+    match S3Client::new(alias).await {
+        Ok(client) => Ok(client),
+        Err(e) => {
+            formatter.error(&format!("Failed to create S3 client: {e}"));
+            Err(ExitCode::NetworkError)
+        }
+    }
+}
+
+/// Load and resolve an alias, reporting failures through the formatter
+fn load_alias(alias_name: &str, formatter: &Formatter) -> Result<Alias, ExitCode> {
     let alias_manager = match AliasManager::new() {
         Ok(am) => am,
         Err(e) => {
@@ -69,22 +205,14 @@ pub fn get_admin_client(alias_name: &str, formatter: &Formatter) -> Result<Admin
         }
     };
 
-    let alias = match alias_manager.get(alias_name) {
-        Ok(a) => a,
+    match resolve_alias(&alias_manager, alias_name) {
+        Ok(alias) => Ok(alias),
         Err(rc_core::Error::AliasNotFound(_)) => {
             formatter.error(&format!("Alias '{}' not found", alias_name));
-            return Err(ExitCode::NotFound);
+            Err(ExitCode::NotFound)
         }
         Err(e) => {
             formatter.error(&format!("Failed to get alias: {e}"));
-            return Err(ExitCode::GeneralError);
-        }
-    };
-
-    match AdminClient::new(&alias) {
-        Ok(client) => Ok(client),
-        Err(e) => {
-            formatter.error(&format!("Failed to create admin client: {e}"));
             Err(ExitCode::GeneralError)
         }
     }
@@ -101,6 +229,18 @@ mod tests {
         command: AdminCommands,
     }
 
+    #[test]
+    fn test_parse_admin_info_all() {
+        let cli = TestCli::parse_from(["rc", "info", "all", "local"]);
+
+        match cli.command {
+            AdminCommands::Info(info::InfoCommands::All(args)) => {
+                assert_eq!(args.alias, "local");
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
     #[test]
     fn test_parse_admin_info_disk_options() {
         let cli = TestCli::parse_from(["rc", "info", "disk", "local", "--offline", "--healing"]);
@@ -115,6 +255,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_admin_column_options() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "info",
+            "disk",
+            "local",
+            "--wide",
+            "--columns",
+            "path,uuid",
+        ]);
+
+        match cli.command {
+            AdminCommands::Info(info::InfoCommands::Disk(args)) => {
+                assert!(args.table.wide);
+                assert_eq!(
+                    args.table.columns,
+                    Some(vec!["path".to_string(), "uuid".to_string()])
+                );
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+
+        let cli = TestCli::parse_from(["rc", "user", "ls", "local"]);
+        match cli.command {
+            AdminCommands::User(user::UserCommands::List(args)) => {
+                assert!(!args.table.wide);
+                assert!(args.table.columns.is_none());
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
     #[test]
     fn test_parse_admin_heal_start_options() {
         let cli = TestCli::parse_from([
@@ -142,8 +315,299 @@ mod tests {
                 assert!(args.remove);
                 assert!(args.recreate);
                 assert!(args.dry_run);
+                assert!(args.disk.is_none());
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_heal_start_disk() {
+        let cli = TestCli::parse_from(["rc", "heal", "start", "local", "--disk", "uuid-1"]);
+        match cli.command {
+            AdminCommands::Heal(heal::HealCommands::Start(args)) => {
+                assert_eq!(args.disk.as_deref(), Some("uuid-1"));
             }
             _ => panic!("Unexpected command parsing result"),
         }
+
+        let conflict =
+            TestCli::try_parse_from(["rc", "heal", "start", "local", "--disk", "d", "-b", "b"]);
+        assert!(conflict.is_err());
+    }
+
+    #[test]
+    fn test_parse_admin_bucket_info() {
+        let cli = TestCli::parse_from(["rc", "bucket", "info", "local/photos"]);
+        match cli.command {
+            AdminCommands::Bucket(bucket::BucketCommands::Info(args)) => {
+                assert_eq!(args.path, "local/photos");
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_bucket_remote_add() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "bucket",
+            "remote",
+            "add",
+            "local/photos",
+            "--endpoint",
+            "https://backup.example.com:9000",
+            "--target-bucket",
+            "photos-copy",
+            "--access-key",
+            "replicator",
+            "--secret-key",
+            "s3cr3t-key",
+        ]);
+
+        match cli.command {
+            AdminCommands::Bucket(bucket::BucketCommands::Remote(bucket::RemoteCommands::Add(
+                args,
+            ))) => {
+                assert_eq!(args.path, "local/photos");
+                assert_eq!(args.endpoint, "https://backup.example.com:9000");
+                assert_eq!(args.target_bucket, "photos-copy");
+                assert!(args.region.is_none());
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_decommission_start() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "decommission",
+            "start",
+            "local",
+            "http://node{1...4}/data{1...4}",
+        ]);
+
+        match cli.command {
+            AdminCommands::Decommission(decommission::DecommissionCommands::Start(args)) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.pool, "http://node{1...4}/data{1...4}");
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_notify_add() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "notify",
+            "add",
+            "local",
+            "webhook",
+            "audit",
+            "--endpoint",
+            "https://hook.example.com",
+            "--kv",
+            "auth_token=abc",
+        ]);
+
+        match cli.command {
+            AdminCommands::Notify(notify::NotifyCommands::Add(args)) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.target_type, "webhook");
+                assert_eq!(args.name, "audit");
+                assert_eq!(args.endpoint.as_deref(), Some("https://hook.example.com"));
+                assert_eq!(args.settings, vec!["auth_token=abc"]);
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_speedtest_options() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "speedtest",
+            "local",
+            "--size",
+            "4MiB",
+            "--duration",
+            "30s",
+            "--concurrent",
+            "8",
+        ]);
+
+        match cli.command {
+            AdminCommands::Speedtest(args) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.size, "4MiB");
+                assert_eq!(args.duration, "30s");
+                assert_eq!(args.concurrent, 8);
+                assert!(!args.autotune);
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_logs_options() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "logs",
+            "local",
+            "--node",
+            "node1:9000",
+            "--type",
+            "application",
+            "--last",
+            "50",
+        ]);
+
+        match cli.command {
+            AdminCommands::Logs(args) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.node.as_deref(), Some("node1:9000"));
+                assert_eq!(args.log_type, logs::LogType::Application);
+                assert_eq!(args.last, 50);
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_prometheus_options() {
+        let cli = TestCli::parse_from(["rc", "prometheus", "local", "--metric", "bucket"]);
+
+        match cli.command {
+            AdminCommands::Prometheus(args) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.metric, prometheus::Metric::Bucket);
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_profile_options() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "profile",
+            "local",
+            "--type",
+            "cpu,goroutines",
+            "--duration",
+            "1m",
+            "-o",
+            "out.zip",
+        ]);
+
+        match cli.command {
+            AdminCommands::Profile(args) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.profilers, vec!["cpu", "goroutines"]);
+                assert_eq!(args.duration, "1m");
+                assert_eq!(args.output, std::path::PathBuf::from("out.zip"));
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_service_restart() {
+        let cli = TestCli::parse_from(["rc", "service", "restart", "local", "--yes"]);
+
+        match cli.command {
+            AdminCommands::Service(service::ServiceCommands::Restart(args)) => {
+                assert_eq!(args.alias, "local");
+                assert!(args.yes);
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_service_account_edit() {
+        let cli = TestCli::parse_from([
+            "rc",
+            "service-account",
+            "edit",
+            "local",
+            "AKEXAMPLE",
+            "--secret",
+            "rotated-secret",
+            "--policy",
+            "policy.json",
+        ]);
+
+        match cli.command {
+            AdminCommands::ServiceAccount(service_account::ServiceAccountCommands::Edit(args)) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.access_key, "AKEXAMPLE");
+                assert_eq!(args.secret.as_deref(), Some("rotated-secret"));
+                assert_eq!(args.policy.as_deref(), Some("policy.json"));
+                assert!(args.expiry.is_none());
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+
+        let cli = TestCli::parse_from(["rc", "service-account", "disable", "local", "AKEXAMPLE"]);
+        assert!(matches!(
+            cli.command,
+            AdminCommands::ServiceAccount(service_account::ServiceAccountCommands::Disable(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_admin_user_import() {
+        let cli = TestCli::parse_from(["rc", "user", "import", "local", "users.json"]);
+
+        match cli.command {
+            AdminCommands::User(user::UserCommands::Import(args)) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.file, "users.json");
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+    }
+
+    #[test]
+    fn test_parse_admin_user_sts() {
+        let cli =
+            TestCli::parse_from(["rc", "user", "sts", "local", "--duration", "1h", "--export"]);
+
+        match cli.command {
+            AdminCommands::User(user::UserCommands::Sts(args)) => {
+                assert_eq!(args.alias, "local");
+                assert_eq!(args.duration, Some(3600));
+                assert!(args.export);
+                assert!(args.web_identity_token_file.is_none());
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+
+        let cli = TestCli::parse_from([
+            "rc",
+            "user",
+            "sts",
+            "local",
+            "--web-identity-token-file",
+            "/run/token",
+            "--role-arn",
+            "arn",
+        ]);
+        match cli.command {
+            AdminCommands::User(user::UserCommands::Sts(args)) => {
+                assert_eq!(args.web_identity_token_file.as_deref(), Some("/run/token"));
+                assert_eq!(args.role_arn.as_deref(), Some("arn"));
+            }
+            _ => panic!("Unexpected command parsing result"),
+        }
+
+        // The token itself is never accepted on the command line
+        assert!(
+            TestCli::try_parse_from(["rc", "user", "sts", "local", "--web-identity-token", "eyJ"])
+                .is_err()
+        );
     }
 }
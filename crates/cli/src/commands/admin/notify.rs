@@ -0,0 +1,308 @@
+//! Notify command for notification target management
+//!
+//! Commands for listing, adding and removing the server-side notification
+//! targets (webhook, Kafka, AMQP, ...) that bucket event rules deliver to.
+
+use std::collections::BTreeMap;
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::output::Formatter;
+use rc_core::admin::{AdminApi, NotifyTarget, NotifyTargetType};
+use rc_s3::{REDACTED, is_secret_field};
+
+/// Notify subcommands
+#[derive(Subcommand, Debug)]
+pub enum NotifyCommands {
+    /// List notification targets
+    List(ListArgs),
+
+    /// Add or replace a notification target
+    Add(AddArgs),
+
+    /// Remove a notification target
+    #[command(name = "rm", alias = "remove")]
+    Remove(RemoveArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ListArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Only list targets of this type
+    #[arg(long = "type", value_name = "TYPE")]
+    pub target_type: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct AddArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Target type: webhook, kafka, amqp, mqtt, nats, nsq, redis, mysql, postgresql, elasticsearch
+    pub target_type: String,
+
+    /// Target name, unique per type
+    pub name: String,
+
+    /// Address events are delivered to (the type's endpoint setting, e.g. Kafka brokers)
+    #[arg(long)]
+    pub endpoint: Option<String>,
+
+    /// Additional setting (repeatable), e.g. --kv topic=events --kv auth_token=...
+    #[arg(long = "kv", value_name = "KEY=VALUE")]
+    pub settings: Vec<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct RemoveArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Target type
+    pub target_type: String,
+
+    /// Target name
+    pub name: String,
+}
+
+/// JSON output for notify list
+#[derive(Serialize)]
+struct NotifyListOutput {
+    targets: Vec<NotifyTarget>,
+}
+
+/// JSON output for notify add and rm
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NotifyOperationOutput {
+    success: bool,
+    target_type: String,
+    name: String,
+    message: String,
+}
+
+/// Execute a notify subcommand
+pub async fn execute(cmd: NotifyCommands, formatter: &Formatter) -> ExitCode {
+    match cmd {
+        NotifyCommands::List(args) => execute_list(args, formatter).await,
+        NotifyCommands::Add(args) => execute_add(args, formatter).await,
+        NotifyCommands::Remove(args) => execute_remove(args, formatter).await,
+    }
+}
+
+fn parse_target_type(value: &str, formatter: &Formatter) -> Result<NotifyTargetType, ExitCode> {
+    value.parse().map_err(|e: String| {
+        formatter.error(&e);
+        ExitCode::UsageError
+    })
+}
+
+/// Build a target from the add arguments, validating type and required settings
+fn build_target(args: &AddArgs) -> Result<NotifyTarget, String> {
+    let target_type: NotifyTargetType = args.target_type.parse()?;
+
+    let mut config = BTreeMap::new();
+    for setting in &args.settings {
+        let (key, value) = setting
+            .split_once('=')
+            .filter(|(key, _)| !key.is_empty() && !key.contains(char::is_whitespace))
+            .ok_or_else(|| format!("Invalid setting '{setting}', expected KEY=VALUE"))?;
+        config.insert(key.to_string(), value.to_string());
+    }
+    if let Some(endpoint) = &args.endpoint {
+        config.insert(target_type.endpoint_key().to_string(), endpoint.clone());
+    }
+    config
+        .entry("enable".to_string())
+        .or_insert_with(|| "on".to_string());
+
+    NotifyTarget::new(target_type, args.name.clone(), config)
+}
+
+/// Copy of a target with secret settings replaced
+fn redacted(target: &NotifyTarget) -> NotifyTarget {
+    let mut target = target.clone();
+    for (key, value) in target.config.iter_mut() {
+        if is_secret_field(key) && !value.is_empty() {
+            *value = REDACTED.to_string();
+        }
+    }
+    target
+}
+
+async fn execute_list(args: ListArgs, formatter: &Formatter) -> ExitCode {
+    let types = match &args.target_type {
+        Some(value) => match parse_target_type(value, formatter) {
+            Ok(t) => vec![t],
+            Err(code) => return code,
+        },
+        None => NotifyTargetType::ALL.to_vec(),
+    };
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let mut targets = Vec::new();
+    for target_type in types {
+        match client.list_notify_targets(target_type).await {
+            Ok(found) => targets.extend(found.iter().map(redacted)),
+            // A server without this target type reports the subsystem as unknown
+            Err(rc_core::Error::NotFound(_)) if args.target_type.is_none() => {}
+            Err(e) => {
+                formatter.error(&format!("Failed to list {target_type} targets: {e}"));
+                return ExitCode::from(&e);
+            }
+        }
+    }
+
+    if formatter.is_json() {
+        formatter.json(&NotifyListOutput { targets });
+    } else if targets.is_empty() {
+        formatter.println("No notification targets configured.");
+    } else {
+        for target in &targets {
+            formatter.println(&format!(
+                "{}:{}",
+                formatter.style_key(&target.target_type.to_string()),
+                formatter.style_name(&target.name)
+            ));
+            for (key, value) in &target.config {
+                formatter.println(&format!("  {key} = {value}"));
+            }
+        }
+    }
+    ExitCode::Success
+}
+
+async fn execute_add(args: AddArgs, formatter: &Formatter) -> ExitCode {
+    let target = match build_target(&args) {
+        Ok(t) => t,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.set_notify_target(&target).await {
+        Ok(()) => {
+            if formatter.is_json() {
+                formatter.json(&NotifyOperationOutput {
+                    success: true,
+                    target_type: target.target_type.to_string(),
+                    name: target.name,
+                    message: "Notification target configured".to_string(),
+                });
+            } else {
+                formatter.success(&format!(
+                    "Notification target '{}:{}' configured.",
+                    target.target_type, target.name
+                ));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to add notification target: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_remove(args: RemoveArgs, formatter: &Formatter) -> ExitCode {
+    let target_type = match parse_target_type(&args.target_type, formatter) {
+        Ok(t) => t,
+        Err(code) => return code,
+    };
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.remove_notify_target(target_type, &args.name).await {
+        Ok(()) => {
+            if formatter.is_json() {
+                formatter.json(&NotifyOperationOutput {
+                    success: true,
+                    target_type: target_type.to_string(),
+                    name: args.name,
+                    message: "Notification target removed".to_string(),
+                });
+            } else {
+                formatter.success(&format!(
+                    "Notification target '{target_type}:{}' removed.",
+                    args.name
+                ));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to remove notification target: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_args(target_type: &str, endpoint: Option<&str>, settings: &[&str]) -> AddArgs {
+        AddArgs {
+            alias: "local".to_string(),
+            target_type: target_type.to_string(),
+            name: "events".to_string(),
+            endpoint: endpoint.map(str::to_string),
+            settings: settings.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_build_target_maps_endpoint_to_type_key() {
+        let target = build_target(&add_args(
+            "kafka",
+            Some("k1:9092"),
+            &["topic=bucket-events"],
+        ))
+        .unwrap();
+        assert_eq!(target.config["brokers"], "k1:9092");
+        assert_eq!(target.config["topic"], "bucket-events");
+        assert_eq!(target.config["enable"], "on");
+    }
+
+    #[test]
+    fn test_build_target_validates() {
+        assert!(build_target(&add_args("smtp", Some("x"), &[])).is_err());
+        assert!(
+            build_target(&add_args("kafka", Some("k1:9092"), &[]))
+                .unwrap_err()
+                .contains("topic")
+        );
+        assert!(build_target(&add_args("webhook", None, &[])).is_err());
+        assert!(build_target(&add_args("webhook", Some("http://h"), &["novalue"])).is_err());
+    }
+
+    #[test]
+    fn test_list_redacts_secrets() {
+        let target = build_target(&add_args(
+            "webhook",
+            Some("http://h"),
+            &["auth_token=Bearer abc"],
+        ))
+        .unwrap();
+        let shown = redacted(&target);
+        assert_eq!(shown.config["auth_token"], "REDACTED");
+        assert_eq!(shown.config["endpoint"], "http://h");
+    }
+}
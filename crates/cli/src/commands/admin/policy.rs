@@ -1,15 +1,18 @@
 //! Policy management commands
 //!
-//! Commands for managing IAM policies: list, create, info, remove, attach.
+//! Commands for managing IAM policies: list, create, info, remove, attach, validate,
+//! import.
 
 use clap::Subcommand;
 use serde::Serialize;
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use super::get_admin_client;
+use super::import::{self, ImportEntryResult, ImportOutput};
 use crate::exit_code::ExitCode;
 use crate::output::Formatter;
-use rc_core::admin::{AdminApi, PolicyEntity};
+use rc_core::admin::{AdminApi, Policy, PolicyEntity, PolicyValidation};
 
 /// Policy management subcommands
 #[derive(Subcommand, Debug)]
@@ -30,6 +33,12 @@ pub enum PolicyCommands {
 
     /// Attach policy to a user or group
     Attach(AttachArgs),
+
+    /// Check a policy document for errors without contacting a server
+    Validate(ValidateArgs),
+
+    /// Create policies in bulk from a directory of JSON documents
+    Import(ImportArgs),
 }
 
 #[derive(clap::Args, Debug)]
@@ -48,6 +57,16 @@ pub struct CreateArgs {
 
     /// Path to policy JSON file
     pub policy_file: String,
+
+    /// Send the document without checking Version, Statement and Effect
+    #[arg(long)]
+    pub no_validate: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ValidateArgs {
+    /// Path to policy JSON file
+    pub file: String,
 }
 
 #[derive(clap::Args, Debug)]
@@ -85,6 +104,15 @@ pub struct AttachArgs {
     pub group: Option<String>,
 }
 
+#[derive(clap::Args, Debug)]
+pub struct ImportArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Directory of policy JSON files; each file name (without .json) is the policy name
+    pub dir: String,
+}
+
 /// JSON output for policy list
 #[derive(Serialize)]
 struct PolicyListOutput {
@@ -98,6 +126,15 @@ struct PolicyInfoOutput {
     policy: serde_json::Value,
 }
 
+/// JSON output for policy validate
+#[derive(Serialize)]
+struct PolicyValidateOutput {
+    file: String,
+    valid: bool,
+    #[serde(flatten)]
+    result: PolicyValidation,
+}
+
 /// JSON output for policy operations
 #[derive(Serialize)]
 struct PolicyOperationOutput {
@@ -114,6 +151,8 @@ pub async fn execute(cmd: PolicyCommands, formatter: &Formatter) -> ExitCode {
         PolicyCommands::Info(args) => execute_info(args, formatter).await,
         PolicyCommands::Remove(args) => execute_remove(args, formatter).await,
         PolicyCommands::Attach(args) => execute_attach(args, formatter).await,
+        PolicyCommands::Validate(args) => execute_validate(args, formatter),
+        PolicyCommands::Import(args) => execute_import(args, formatter).await,
     }
 }
 
@@ -170,10 +209,24 @@ async fn execute_create(args: CreateArgs, formatter: &Formatter) -> ExitCode {
         }
     };
 
-    // Validate JSON
-    if serde_json::from_str::<serde_json::Value>(&policy_content).is_err() {
-        formatter.error("Policy file is not valid JSON");
-        return ExitCode::UsageError;
+    if args.no_validate {
+        if serde_json::from_str::<serde_json::Value>(&policy_content).is_err() {
+            formatter.error("Policy file is not valid JSON");
+            return ExitCode::UsageError;
+        }
+    } else {
+        let result = Policy::new(&args.name, &policy_content).validate();
+        for warning in &result.warnings {
+            formatter.warning(warning);
+        }
+        if !result.is_valid() {
+            formatter.error(&format!(
+                "Policy file '{}' is invalid: {}",
+                args.policy_file,
+                result.errors.join("; ")
+            ));
+            return ExitCode::UsageError;
+        }
     }
 
     match client.create_policy(&args.name, &policy_content).await {
@@ -198,6 +251,43 @@ async fn execute_create(args: CreateArgs, formatter: &Formatter) -> ExitCode {
     }
 }
 
+fn execute_validate(args: ValidateArgs, formatter: &Formatter) -> ExitCode {
+    let content = match fs::read_to_string(&args.file) {
+        Ok(content) => content,
+        Err(e) => {
+            formatter.error(&format!("Failed to read policy file '{}': {e}", args.file));
+            return ExitCode::UsageError;
+        }
+    };
+
+    let result = Policy::new("", content).validate();
+    let valid = result.is_valid();
+
+    if formatter.is_json() {
+        formatter.json(&PolicyValidateOutput {
+            file: args.file,
+            valid,
+            result,
+        });
+    } else {
+        for warning in &result.warnings {
+            formatter.warning(warning);
+        }
+        for error in &result.errors {
+            formatter.println(&format!("{}: {error}", args.file));
+        }
+        if valid {
+            formatter.success(&format!("Policy file '{}' is valid.", args.file));
+        }
+    }
+
+    if valid {
+        ExitCode::Success
+    } else {
+        ExitCode::UsageError
+    }
+}
+
 async fn execute_info(args: InfoArgs, formatter: &Formatter) -> ExitCode {
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
@@ -328,6 +418,65 @@ async fn execute_attach(args: AttachArgs, formatter: &Formatter) -> ExitCode {
     }
 }
 
+async fn execute_import(args: ImportArgs, formatter: &Formatter) -> ExitCode {
+    let files = match collect_policy_files(Path::new(&args.dir)) {
+        Ok(f) => f,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    if files.is_empty() {
+        formatter.error(&format!("No .json policy files found in '{}'", args.dir));
+        return ExitCode::UsageError;
+    }
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let mut results = Vec::with_capacity(files.len());
+    for (name, path) in files {
+        let result = match fs::read_to_string(&path) {
+            Err(e) => ImportEntryResult::failed(name, format!("Failed to read file: {e}")),
+            Ok(content) if serde_json::from_str::<serde_json::Value>(&content).is_err() => {
+                ImportEntryResult::failed(name, "Policy file is not valid JSON")
+            }
+            Ok(content) => match client.create_policy(&name, &content).await {
+                Ok(()) => ImportEntryResult::ok(name),
+                Err(e) => ImportEntryResult::failed(name, e.to_string()),
+            },
+        };
+        results.push(result);
+    }
+
+    import::report(&ImportOutput::new(results), "policy", formatter)
+}
+
+/// List `(policy name, path)` for every `.json` file in `dir`, sorted by name
+fn collect_policy_files(dir: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory '{}': {e}", dir.display()))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("Failed to read directory '{}': {e}", dir.display()))?
+            .path();
+        if !path.is_file() || path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+            files.push((name.to_string(), path.clone()));
+        }
+    }
+
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,4 +490,37 @@ mod tests {
         assert!(json.contains("readonly"));
         assert!(json.contains("admin"));
     }
+
+    #[test]
+    fn test_policy_validate_output_serialization() {
+        let result =
+            Policy::new("", r#"{"Version":"2012-10-17","Statement":[],"Extra":1}"#).validate();
+        let output = PolicyValidateOutput {
+            file: "policy.json".to_string(),
+            valid: result.is_valid(),
+            result,
+        };
+        let value = serde_json::to_value(&output).unwrap();
+        assert_eq!(value["valid"], true);
+        assert_eq!(value["errors"].as_array().map(Vec::len), Some(0));
+        assert_eq!(value["warnings"][0], "Unknown top-level key: Extra");
+    }
+
+    #[test]
+    fn test_collect_policy_files() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        fs::write(dir.path().join("readwrite.json"), "{}").expect("write policy");
+        fs::write(dir.path().join("readonly.json"), "{}").expect("write policy");
+        fs::write(dir.path().join("notes.txt"), "ignored").expect("write file");
+        fs::create_dir(dir.path().join("nested.json")).expect("create dir");
+
+        let files = collect_policy_files(dir.path()).expect("collect files");
+        let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["readonly", "readwrite"]);
+    }
+
+    #[test]
+    fn test_collect_policy_files_missing_dir() {
+        assert!(collect_policy_files(Path::new("/nonexistent/policies")).is_err());
+    }
 }
@@ -0,0 +1,199 @@
+//! Profile command for capturing server CPU/memory profiles
+//!
+//! Starts profiling on every node, waits for the requested duration and
+//! downloads the collected profiles as a zip archive.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::filter::parse_duration;
+use crate::output::{Formatter, ProgressBar};
+use rc_core::admin::{AdminApi, ProfilerType};
+
+#[derive(clap::Args, Debug)]
+pub struct ProfileArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Profiles to capture (cpu, mem, block, mutex, trace, threads, goroutines)
+    #[arg(long = "type", value_delimiter = ',', default_value = "cpu,mem")]
+    pub profilers: Vec<String>,
+
+    /// How long to profile before downloading (e.g., 10s, 1m)
+    #[arg(long, default_value = "10s")]
+    pub duration: String,
+
+    /// Path of the zip archive to write
+    #[arg(short, long, default_value = "profile.zip")]
+    pub output: PathBuf,
+}
+
+/// JSON output for the profile command
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProfileOutput {
+    success: bool,
+    profilers: Vec<String>,
+    nodes: Vec<NodeOutput>,
+    output: String,
+    size_bytes: u64,
+}
+
+/// JSON output for a node's profiling start result
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NodeOutput {
+    node_name: String,
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Execute the profile command
+pub async fn execute(args: ProfileArgs, formatter: &Formatter) -> ExitCode {
+    let profilers = match parse_profilers(&args.profilers) {
+        Ok(p) => p,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let duration = match parse_duration(&args.duration) {
+        Ok(d) if !d.is_zero() => d,
+        Ok(_) => {
+            formatter.error("Duration must be greater than zero");
+            return ExitCode::UsageError;
+        }
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let nodes = match client.start_profiling(&profilers).await {
+        Ok(n) => n,
+        Err(e) => {
+            formatter.error(&format!("Failed to start profiling: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    for node in nodes.iter().filter(|n| !n.success) {
+        formatter.warning(&format!(
+            "Profiling failed to start on {}: {}",
+            node.node_name, node.error
+        ));
+    }
+
+    if !nodes.is_empty() && nodes.iter().all(|n| !n.success) {
+        formatter.error("Profiling failed to start on every node");
+        return ExitCode::GeneralError;
+    }
+
+    let spinner = ProgressBar::spinner(
+        formatter.output_config().clone(),
+        &format!("Profiling for {}...", args.duration),
+    );
+    tokio::time::sleep(duration).await;
+    spinner.set_message("Downloading profiles...");
+
+    let result = client.download_profiling_data(&args.output).await;
+    spinner.finish_and_clear();
+
+    let size_bytes = match result {
+        Ok(size) => size,
+        Err(e) => {
+            // Don't leave a truncated archive behind
+            let _ = std::fs::remove_file(&args.output);
+            formatter.error(&format!("Failed to download profiling data: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    let output_path = args.output.display().to_string();
+    if formatter.is_json() {
+        let output = ProfileOutput {
+            success: true,
+            profilers: profilers.iter().map(|p| p.to_string()).collect(),
+            nodes: nodes
+                .into_iter()
+                .map(|n| NodeOutput {
+                    node_name: n.node_name,
+                    success: n.success,
+                    error: Some(n.error).filter(|e| !e.is_empty()),
+                })
+                .collect(),
+            output: output_path,
+            size_bytes,
+        };
+        formatter.json(&output);
+    } else {
+        formatter.success(&format!(
+            "Profiles written to {} ({} bytes)",
+            formatter.style_file(&output_path),
+            size_bytes
+        ));
+    }
+
+    ExitCode::Success
+}
+
+/// Parse and de-duplicate the requested profile kinds
+fn parse_profilers(values: &[String]) -> Result<Vec<ProfilerType>, String> {
+    let mut profilers = Vec::new();
+    for value in values {
+        let kind = value.trim().parse::<ProfilerType>()?;
+        if !profilers.contains(&kind) {
+            profilers.push(kind);
+        }
+    }
+
+    if profilers.is_empty() {
+        return Err("At least one profiler type is required".to_string());
+    }
+    Ok(profilers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profilers() {
+        let profilers =
+            parse_profilers(&["cpu".to_string(), "mem".to_string(), "cpu".to_string()]).unwrap();
+        assert_eq!(profilers, vec![ProfilerType::Cpu, ProfilerType::Mem]);
+
+        assert!(parse_profilers(&["bogus".to_string()]).is_err());
+        assert!(parse_profilers(&[]).is_err());
+    }
+
+    #[test]
+    fn test_profile_output_serialization() {
+        let output = ProfileOutput {
+            success: true,
+            profilers: vec!["cpu".to_string()],
+            nodes: vec![NodeOutput {
+                node_name: "node1:9000".to_string(),
+                success: true,
+                error: None,
+            }],
+            output: "profile.zip".to_string(),
+            size_bytes: 2048,
+        };
+
+        let value = serde_json::to_value(&output).expect("serialize profile output");
+        assert_eq!(value["sizeBytes"], 2048);
+        assert_eq!(value["nodes"][0]["nodeName"], "node1:9000");
+        assert!(value["nodes"][0].get("error").is_none());
+    }
+}
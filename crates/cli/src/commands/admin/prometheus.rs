@@ -0,0 +1,197 @@
+//! Prometheus command for fetching a metrics snapshot
+//!
+//! Fetches one scrape target and prints it in Prometheus text format, or as
+//! parsed samples with `--json`.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::output::Formatter;
+use rc_core::admin::{AdminApi, MetricsTarget};
+
+/// Scrape target to fetch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Metric {
+    /// Cluster-wide metrics
+    Cluster,
+    /// Per-node metrics
+    Node,
+    /// Per-bucket metrics
+    Bucket,
+    /// Host resource metrics
+    Resource,
+}
+
+impl From<Metric> for MetricsTarget {
+    fn from(value: Metric) -> Self {
+        match value {
+            Metric::Cluster => MetricsTarget::Cluster,
+            Metric::Node => MetricsTarget::Node,
+            Metric::Bucket => MetricsTarget::Bucket,
+            Metric::Resource => MetricsTarget::Resource,
+        }
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct PrometheusArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Scrape target to fetch
+    #[arg(long, value_enum, default_value = "cluster")]
+    pub metric: Metric,
+}
+
+/// JSON output for the prometheus command
+#[derive(Serialize)]
+struct PrometheusOutput {
+    target: String,
+    samples: Vec<Sample>,
+}
+
+/// One parsed sample line
+#[derive(Debug, Serialize, PartialEq)]
+struct Sample {
+    name: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    labels: BTreeMap<String, String>,
+    /// Number, or a string for `NaN` and infinities, which JSON cannot hold
+    value: serde_json::Value,
+}
+
+/// Execute the prometheus command
+pub async fn execute(args: PrometheusArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let target = MetricsTarget::from(args.metric);
+    let text = match client.prometheus_metrics(target).await {
+        Ok(text) => text,
+        Err(e) => {
+            formatter.error(&format!("Failed to fetch {target} metrics: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    if formatter.is_json() {
+        formatter.json(&PrometheusOutput {
+            target: target.to_string(),
+            samples: text.lines().filter_map(parse_sample).collect(),
+        });
+    } else {
+        formatter.println(text.trim_end());
+    }
+
+    ExitCode::Success
+}
+
+/// Parse one line of the Prometheus text format
+///
+/// Comments, blank lines and malformed lines yield `None`.
+fn parse_sample(line: &str) -> Option<Sample> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let name_end = line.find(|c: char| c == '{' || c.is_whitespace())?;
+    let name = &line[..name_end];
+    let mut rest = &line[name_end..];
+
+    let mut labels = BTreeMap::new();
+    if let Some(body) = rest.strip_prefix('{') {
+        let (parsed, after) = parse_labels(body)?;
+        labels = parsed;
+        rest = after;
+    }
+
+    // A trailing timestamp, if present, is dropped
+    let raw = rest.split_whitespace().next()?;
+    let value = match raw.parse::<f64>() {
+        Ok(v) if v.is_finite() => serde_json::Number::from_f64(v)?.into(),
+        Ok(_) => raw.into(),
+        Err(_) => return None,
+    };
+
+    Some(Sample {
+        name: name.to_string(),
+        labels,
+        value,
+    })
+}
+
+/// Parse `key="value",...}` and return the labels and the text after `}`
+fn parse_labels(mut s: &str) -> Option<(BTreeMap<String, String>, &str)> {
+    let mut labels = BTreeMap::new();
+    loop {
+        s = s.trim_start().trim_start_matches(',').trim_start();
+        if let Some(after) = s.strip_prefix('}') {
+            return Some((labels, after));
+        }
+
+        let eq = s.find('=')?;
+        let key = s[..eq].trim().to_string();
+        let quoted = s[eq + 1..].trim_start().strip_prefix('"')?;
+        let mut chars = quoted.char_indices();
+
+        let mut value = String::new();
+        let mut end = None;
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    other => value.push(other),
+                },
+                '"' => {
+                    end = Some(i + 1);
+                    break;
+                }
+                other => value.push(other),
+            }
+        }
+
+        labels.insert(key, value);
+        s = &quoted[end?..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sample() {
+        assert!(parse_sample("# HELP up Whether the node is up").is_none());
+        assert!(parse_sample("").is_none());
+        assert!(parse_sample("garbage").is_none());
+
+        let sample = parse_sample("up 1").expect("plain sample");
+        assert_eq!(sample.name, "up");
+        assert!(sample.labels.is_empty());
+        assert_eq!(sample.value, serde_json::json!(1.0));
+
+        let sample = parse_sample(
+            r#"bucket_usage_bytes{bucket="photos",server="n1:9000"} 4096 1700000000000"#,
+        )
+        .expect("labelled sample");
+        assert_eq!(sample.labels["bucket"], "photos");
+        assert_eq!(sample.labels["server"], "n1:9000");
+        assert_eq!(sample.value, serde_json::json!(4096.0));
+    }
+
+    #[test]
+    fn test_parse_sample_escapes_and_special_values() {
+        let sample = parse_sample(r#"err{msg="a \"b\",c\\d"} NaN"#).expect("escaped sample");
+        assert_eq!(sample.labels["msg"], r#"a "b",c\d"#);
+        assert_eq!(sample.value, serde_json::json!("NaN"));
+
+        let sample = parse_sample("latency_seconds +Inf").expect("infinite sample");
+        assert_eq!(sample.value, serde_json::json!("+Inf"));
+    }
+}
@@ -0,0 +1,288 @@
+//! Rebalance command for evening out data across pools
+//!
+//! Commands for starting, checking and stopping a cluster rebalance, usually
+//! run after adding a pool.
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::output::{Formatter, format_bytes, format_duration};
+use rc_core::admin::{AdminApi, RebalancePoolStatus, RebalanceStatus};
+
+/// Nanoseconds per second, for the durations the server reports
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Rebalance subcommands
+#[derive(Subcommand, Debug)]
+pub enum RebalanceCommands {
+    /// Start rebalancing data across pools
+    Start(RebalanceArgs),
+
+    /// Display per-pool rebalance progress
+    Status(RebalanceArgs),
+
+    /// Stop a running rebalance
+    Stop(RebalanceArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct RebalanceArgs {
+    /// Alias name of the server
+    pub alias: String,
+}
+
+/// JSON output for rebalance status
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RebalanceStatusOutput {
+    id: String,
+    running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stopped_at: Option<String>,
+    pools: Vec<RebalancePoolOutput>,
+}
+
+/// JSON output for one pool, with durations in seconds
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RebalancePoolOutput {
+    id: usize,
+    status: String,
+    used: f64,
+    objects: u64,
+    versions: u64,
+    bytes: u64,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    bucket: String,
+    elapsed_secs: u64,
+    eta_secs: u64,
+}
+
+impl From<&RebalanceStatus> for RebalanceStatusOutput {
+    fn from(status: &RebalanceStatus) -> Self {
+        Self {
+            id: status.id.clone(),
+            running: status.is_running(),
+            stopped_at: status.stopped_at.clone(),
+            pools: status.pools.iter().map(RebalancePoolOutput::from).collect(),
+        }
+    }
+}
+
+impl From<&RebalancePoolStatus> for RebalancePoolOutput {
+    fn from(pool: &RebalancePoolStatus) -> Self {
+        let progress = pool.progress.clone().unwrap_or_default();
+        Self {
+            id: pool.id,
+            status: pool.status.clone(),
+            used: pool.used,
+            objects: progress.objects,
+            versions: progress.versions,
+            bytes: progress.bytes,
+            bucket: progress.bucket,
+            elapsed_secs: progress.elapsed / NANOS_PER_SEC,
+            eta_secs: progress.eta / NANOS_PER_SEC,
+        }
+    }
+}
+
+/// JSON output for rebalance start and stop
+#[derive(Serialize)]
+struct RebalanceOperationOutput {
+    success: bool,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+}
+
+/// Execute a rebalance subcommand
+pub async fn execute(cmd: RebalanceCommands, formatter: &Formatter) -> ExitCode {
+    match cmd {
+        RebalanceCommands::Start(args) => execute_start(args, formatter).await,
+        RebalanceCommands::Status(args) => execute_status(args, formatter).await,
+        RebalanceCommands::Stop(args) => execute_stop(args, formatter).await,
+    }
+}
+
+async fn execute_start(args: RebalanceArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.rebalance_start().await {
+        Ok(id) => {
+            if formatter.is_json() {
+                formatter.json(&RebalanceOperationOutput {
+                    success: true,
+                    message: "Rebalance started".to_string(),
+                    id: Some(id),
+                });
+            } else {
+                formatter.success(&format!("Rebalance started (ID: {id})."));
+                formatter.println(&format!(
+                    "Track progress with: rc admin rebalance status {}",
+                    args.alias
+                ));
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to start rebalance: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_status(args: RebalanceArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.rebalance_status().await {
+        Ok(status) => {
+            if formatter.is_json() {
+                formatter.json(&RebalanceStatusOutput::from(&status));
+            } else {
+                print_rebalance_status(&status, formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to get rebalance status: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_stop(args: RebalanceArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.rebalance_stop().await {
+        Ok(()) => {
+            if formatter.is_json() {
+                formatter.json(&RebalanceOperationOutput {
+                    success: true,
+                    message: "Rebalance stopped".to_string(),
+                    id: None,
+                });
+            } else {
+                formatter.success("Rebalance stopped.");
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to stop rebalance: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+fn print_rebalance_status(status: &RebalanceStatus, formatter: &Formatter) {
+    let state = if status.is_running() {
+        formatter.style_size("In Progress")
+    } else {
+        formatter.style_date("Idle")
+    };
+
+    formatter.println(&format!(
+        "{} {}",
+        formatter.style_name("Rebalance Status:"),
+        state
+    ));
+    formatter.println("");
+
+    if !status.id.is_empty() {
+        formatter.println(&format!("  Rebalance ID:  {}", status.id));
+    }
+    if let Some(ref stopped_at) = status.stopped_at {
+        formatter.println(&format!("  Stopped:       {}", stopped_at));
+    }
+    if status.pools.is_empty() {
+        formatter.println("  No rebalance has been run.");
+        return;
+    }
+
+    for pool in &status.pools {
+        formatter.println("");
+        formatter.println(&format!(
+            "  {} {} ({:.1}% used)",
+            formatter.style_name(&format!("Pool {}:", pool.id)),
+            pool.status,
+            pool.used * 100.0
+        ));
+
+        let Some(progress) = &pool.progress else {
+            continue;
+        };
+        formatter.println(&format!(
+            "    Moved:       {} objects, {} versions, {}",
+            progress.objects,
+            progress.versions,
+            format_bytes(progress.bytes)
+        ));
+        if !progress.bucket.is_empty() {
+            formatter.println(&format!(
+                "    Current:     {}/{}",
+                progress.bucket, progress.object
+            ));
+        }
+        formatter.println(&format!(
+            "    Elapsed:     {}",
+            format_duration(progress.elapsed / NANOS_PER_SEC)
+        ));
+        if pool.status == "Started" {
+            formatter.println(&format!(
+                "    ETA:         {}",
+                format_duration(progress.eta / NANOS_PER_SEC)
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebalance_status_output() {
+        let body = r#"{"ID":"reb-1","pools":[
+            {"id":0,"status":"Started","used":0.82,"progress":{"objects":1200,
+             "versions":1300,"bytes":1073741824,"bucket":"logs","object":"2026/01/a.gz",
+             "elapsed":90000000000,"eta":300000000000}},
+            {"id":1,"status":"None","used":0.05}]}"#;
+
+        let status: RebalanceStatus = serde_json::from_str(body).expect("parse rebalance status");
+        assert!(status.is_running());
+
+        let output = RebalanceStatusOutput::from(&status);
+        assert_eq!(output.pools[0].objects, 1200);
+        assert_eq!(output.pools[0].elapsed_secs, 90);
+        assert_eq!(output.pools[0].eta_secs, 300);
+        assert_eq!(output.pools[1].bytes, 0);
+
+        let value = serde_json::to_value(&output).expect("serialize rebalance status");
+        assert_eq!(value["id"], "reb-1");
+        assert!(value.get("stoppedAt").is_none());
+        assert!(value["pools"][0].get("etaSecs").is_some());
+    }
+
+    #[test]
+    fn test_stopped_rebalance_is_not_running() {
+        let status = RebalanceStatus {
+            id: "reb-1".to_string(),
+            stopped_at: Some("2026-01-01T10:00:00Z".to_string()),
+            pools: vec![RebalancePoolStatus {
+                status: "Started".to_string(),
+                ..Default::default()
+            }],
+        };
+        assert!(!status.is_running());
+    }
+}
@@ -0,0 +1,326 @@
+//! Replicate command for site replication visibility
+//!
+//! Commands for showing the configured replication sites and the replication
+//! health of each site and bucket.
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::output::{Formatter, format_bytes};
+use rc_core::admin::{AdminApi, SiteReplicationInfo, SiteReplicationStatus};
+
+/// Nanoseconds per millisecond, for the latencies the server reports
+const NANOS_PER_MILLI: u64 = 1_000_000;
+
+/// Site replication subcommands
+#[derive(Subcommand, Debug)]
+pub enum ReplicateCommands {
+    /// Show the configured replication sites
+    Info(ReplicateArgs),
+
+    /// Show replication health per site and per bucket
+    Status(ReplicateArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ReplicateArgs {
+    /// Alias name of the server
+    pub alias: String,
+}
+
+/// JSON output for replicate status
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReplicateStatusOutput {
+    enabled: bool,
+    sites: Vec<SiteOutput>,
+    buckets: Vec<BucketOutput>,
+}
+
+/// Health of one site
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SiteOutput {
+    name: String,
+    endpoint: String,
+    deployment_id: String,
+    /// `None` for the local site, which has no metrics of its own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    online: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_online: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lag_ms: Option<u64>,
+    replicated_count: u64,
+    replicated_size: u64,
+}
+
+/// Health of one bucket across sites
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BucketOutput {
+    bucket: String,
+    healthy: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_on: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mismatched_on: Vec<String>,
+}
+
+impl From<&SiteReplicationStatus> for ReplicateStatusOutput {
+    fn from(status: &SiteReplicationStatus) -> Self {
+        let site_name = |id: &str| {
+            status
+                .sites
+                .get(id)
+                .map_or_else(|| id.to_string(), |site| site.name.clone())
+        };
+
+        let sites = status
+            .sites
+            .iter()
+            .map(|(id, site)| {
+                let metric = status.metrics.metrics.get(id);
+                SiteOutput {
+                    name: site.name.clone(),
+                    endpoint: site.endpoint.clone(),
+                    deployment_id: id.clone(),
+                    online: metric.map(|m| m.online),
+                    last_online: metric.and_then(|m| m.last_online.clone()),
+                    lag_ms: metric.map(|m| m.latency.avg / NANOS_PER_MILLI),
+                    replicated_count: metric.map_or(0, |m| m.replicated_count),
+                    replicated_size: metric.map_or(0, |m| m.replicated_size),
+                }
+            })
+            .collect();
+
+        let buckets = status
+            .bucket_stats
+            .iter()
+            .map(|(bucket, per_site)| {
+                let missing_on: Vec<String> = per_site
+                    .iter()
+                    .filter(|(_, s)| !s.has_bucket)
+                    .map(|(id, _)| site_name(id))
+                    .collect();
+                let mismatched_on: Vec<String> = per_site
+                    .iter()
+                    .filter(|(_, s)| s.has_bucket && !s.is_healthy())
+                    .map(|(id, _)| site_name(id))
+                    .collect();
+                BucketOutput {
+                    bucket: bucket.clone(),
+                    healthy: missing_on.is_empty() && mismatched_on.is_empty(),
+                    missing_on,
+                    mismatched_on,
+                }
+            })
+            .collect();
+
+        Self {
+            enabled: status.enabled,
+            sites,
+            buckets,
+        }
+    }
+}
+
+/// Execute a replicate subcommand
+pub async fn execute(cmd: ReplicateCommands, formatter: &Formatter) -> ExitCode {
+    match cmd {
+        ReplicateCommands::Info(args) => execute_info(args, formatter).await,
+        ReplicateCommands::Status(args) => execute_status(args, formatter).await,
+    }
+}
+
+async fn execute_info(args: ReplicateArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.site_replication_info().await {
+        Ok(info) => {
+            if formatter.is_json() {
+                formatter.json(&info);
+            } else {
+                print_info(&info, formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to get site replication info: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+async fn execute_status(args: ReplicateArgs, formatter: &Formatter) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.site_replication_status().await {
+        Ok(status) => {
+            let output = ReplicateStatusOutput::from(&status);
+            if formatter.is_json() {
+                formatter.json(&output);
+            } else {
+                print_status(&output, formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to get site replication status: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+fn print_info(info: &SiteReplicationInfo, formatter: &Formatter) {
+    if !info.enabled {
+        formatter.println("Site replication is not configured.");
+        return;
+    }
+
+    formatter.println(&format!(
+        "{} {} sites (this site: {})",
+        formatter.style_name("Site Replication:"),
+        info.sites.len(),
+        info.name
+    ));
+    for site in &info.sites {
+        formatter.println("");
+        formatter.println(&format!("  {}", formatter.style_name(&site.name)));
+        formatter.println(&format!(
+            "    Endpoint:      {}",
+            formatter.style_url(&site.endpoint)
+        ));
+        formatter.println(&format!("    Deployment ID: {}", site.deployment_id));
+    }
+}
+
+fn print_status(status: &ReplicateStatusOutput, formatter: &Formatter) {
+    if !status.enabled {
+        formatter.println("Site replication is not configured.");
+        return;
+    }
+
+    formatter.println(&formatter.style_name("Sites:"));
+    for site in &status.sites {
+        let state = match site.online {
+            Some(true) => formatter.style_date("online"),
+            Some(false) => formatter.style_size("OFFLINE"),
+            None => "local".to_string(),
+        };
+        formatter.println(&format!(
+            "  {:<16} {} [{}]",
+            site.name,
+            formatter.style_url(&site.endpoint),
+            state
+        ));
+        if let Some(lag) = site.lag_ms {
+            formatter.println(&format!(
+                "    Lag: {lag} ms, replicated {} objects ({})",
+                site.replicated_count,
+                format_bytes(site.replicated_size)
+            ));
+        }
+        if site.online == Some(false)
+            && let Some(ref last_online) = site.last_online
+        {
+            formatter.println(&format!("    Last online: {last_online}"));
+        }
+    }
+
+    formatter.println("");
+    formatter.println(&formatter.style_name("Buckets:"));
+    if status.buckets.is_empty() {
+        formatter.println("  No buckets.");
+    }
+    for bucket in &status.buckets {
+        if bucket.healthy {
+            formatter.println(&format!("  {:<32} in sync", bucket.bucket));
+            continue;
+        }
+        let mut issues = Vec::new();
+        if !bucket.missing_on.is_empty() {
+            issues.push(format!("missing on {}", bucket.missing_on.join(", ")));
+        }
+        if !bucket.mismatched_on.is_empty() {
+            issues.push(format!(
+                "config differs on {}",
+                bucket.mismatched_on.join(", ")
+            ));
+        }
+        formatter.println(&format!(
+            "  {:<32} {}",
+            bucket.bucket,
+            formatter.style_size(&issues.join("; "))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS_BODY: &str = r#"{
+        "Enabled": true,
+        "Sites": {
+            "dep-a": {"name": "site-a", "endpoint": "https://a.example.com", "deploymentID": "dep-a"},
+            "dep-b": {"name": "site-b", "endpoint": "https://b.example.com", "deploymentID": "dep-b"}
+        },
+        "BucketStats": {
+            "logs": {
+                "dep-a": {"HasBucket": true},
+                "dep-b": {"HasBucket": true}
+            },
+            "media": {
+                "dep-a": {"HasBucket": true, "PolicyMismatch": true},
+                "dep-b": {"HasBucket": false}
+            }
+        },
+        "Metrics": {
+            "Metrics": {
+                "dep-b": {"DeploymentID": "dep-b", "Endpoint": "https://b.example.com",
+                          "Online": true, "Latency": {"curr": 3000000, "avg": 12000000, "max": 90000000},
+                          "ReplicatedCount": 42, "ReplicatedSize": 1048576}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_replicate_status_output() {
+        let status: SiteReplicationStatus =
+            serde_json::from_str(STATUS_BODY).expect("parse site replication status");
+        let output = ReplicateStatusOutput::from(&status);
+
+        assert_eq!(output.sites.len(), 2);
+        assert_eq!(output.sites[0].online, None);
+        assert_eq!(output.sites[1].lag_ms, Some(12));
+        assert_eq!(output.sites[1].replicated_count, 42);
+
+        assert!(output.buckets[0].healthy);
+        let media = &output.buckets[1];
+        assert!(!media.healthy);
+        assert_eq!(media.missing_on, vec!["site-b"]);
+        assert_eq!(media.mismatched_on, vec!["site-a"]);
+    }
+
+    #[test]
+    fn test_replicate_status_json_keys() {
+        let status: SiteReplicationStatus =
+            serde_json::from_str(STATUS_BODY).expect("parse site replication status");
+        let value = serde_json::to_value(ReplicateStatusOutput::from(&status))
+            .expect("serialize replicate status");
+
+        assert_eq!(value["sites"][1]["deploymentId"], "dep-b");
+        assert_eq!(value["sites"][1]["lagMs"], 12);
+        assert!(value["sites"][0].get("online").is_none());
+        assert!(value["buckets"][0].get("missingOn").is_none());
+    }
+}
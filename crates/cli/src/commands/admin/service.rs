@@ -0,0 +1,127 @@
+//! Service control commands
+//!
+//! Commands for restarting or stopping all nodes of a cluster.
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::exit_code::ExitCode;
+use crate::output::Formatter;
+use rc_core::admin::{AdminApi, ServiceAction};
+
+/// Service control subcommands
+#[derive(Subcommand, Debug)]
+pub enum ServiceCommands {
+    /// Restart all nodes of the cluster
+    Restart(ServiceArgs),
+
+    /// Stop all nodes of the cluster
+    Stop(ServiceArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ServiceArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Confirm the operation (required)
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+/// JSON output for service operations
+#[derive(Serialize)]
+struct ServiceOperationOutput {
+    success: bool,
+    action: String,
+    message: String,
+}
+
+/// Execute a service subcommand
+pub async fn execute(cmd: ServiceCommands, formatter: &Formatter) -> ExitCode {
+    match cmd {
+        ServiceCommands::Restart(args) => {
+            execute_action(args, ServiceAction::Restart, formatter).await
+        }
+        ServiceCommands::Stop(args) => execute_action(args, ServiceAction::Stop, formatter).await,
+    }
+}
+
+async fn execute_action(
+    args: ServiceArgs,
+    action: ServiceAction,
+    formatter: &Formatter,
+) -> ExitCode {
+    if !args.yes {
+        formatter.error(&format!(
+            "This will {action} every node behind '{}'. Re-run with --yes to confirm.",
+            args.alias
+        ));
+        return ExitCode::UsageError;
+    }
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    match client.service_action(action).await {
+        Ok(()) => {
+            let message = signal_message(action);
+            if formatter.is_json() {
+                let output = ServiceOperationOutput {
+                    success: true,
+                    action: action.to_string(),
+                    message: message.to_string(),
+                };
+                formatter.json(&output);
+            } else {
+                formatter.success(&format!(
+                    "{message} for '{}'.",
+                    formatter.style_name(&args.alias)
+                ));
+            }
+            ExitCode::Success
+        }
+        Err(rc_core::Error::Auth(e)) => {
+            formatter.error(&format!("Failed to {action} service: {e}"));
+            ExitCode::AuthError
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to {action} service: {e}"));
+            ExitCode::GeneralError
+        }
+    }
+}
+
+fn signal_message(action: ServiceAction) -> &'static str {
+    match action {
+        ServiceAction::Restart => "Restart signaled",
+        ServiceAction::Stop => "Stop signaled",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_message() {
+        assert_eq!(signal_message(ServiceAction::Restart), "Restart signaled");
+        assert_eq!(signal_message(ServiceAction::Stop), "Stop signaled");
+    }
+
+    #[test]
+    fn test_service_operation_output_serialization() {
+        let output = ServiceOperationOutput {
+            success: true,
+            action: ServiceAction::Restart.to_string(),
+            message: "Restart signaled".to_string(),
+        };
+
+        let json = serde_json::to_string(&output).expect("serialize service output");
+        assert!(json.contains("\"action\":\"restart\""));
+        assert!(json.contains("\"success\":true"));
+    }
+}
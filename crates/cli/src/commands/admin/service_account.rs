@@ -1,14 +1,18 @@
 //! Service account management commands
 //!
-//! Commands for managing service accounts: list, create, info, remove.
+//! Commands for managing service accounts: list, create, info, edit, enable,
+//! disable, remove.
 
 use clap::Subcommand;
 use serde::Serialize;
 
 use super::get_admin_client;
 use crate::exit_code::ExitCode;
+use crate::filter::{parse_absolute_time, parse_duration_ago};
 use crate::output::Formatter;
-use rc_core::admin::{AdminApi, CreateServiceAccountRequest, ServiceAccount};
+use rc_core::admin::{
+    AdminApi, CreateServiceAccountRequest, ServiceAccount, UpdateServiceAccountRequest, UserStatus,
+};
 
 /// Service account management subcommands
 #[derive(Subcommand, Debug)]
@@ -23,6 +27,15 @@ pub enum ServiceAccountCommands {
     /// Get service account information
     Info(InfoArgs),
 
+    /// Change a service account's secret key, policy, name, description or expiry
+    Edit(EditArgs),
+
+    /// Enable a service account
+    Enable(StatusArgs),
+
+    /// Disable a service account
+    Disable(StatusArgs),
+
     /// Remove a service account
     #[command(name = "rm", alias = "remove")]
     Remove(RemoveArgs),
@@ -55,8 +68,8 @@ pub struct CreateArgs {
     #[arg(long)]
     pub policy: Option<String>,
 
-    /// Optional expiration time (ISO 8601 format)
-    #[arg(long)]
+    /// Expire the account after a duration (e.g. 12h, 30d) or at a date/time (RFC3339 or YYYY-MM-DD, UTC)
+    #[arg(long, value_name = "DURATION|DATE")]
     pub expiry: Option<String>,
 }
 
@@ -69,6 +82,44 @@ pub struct InfoArgs {
     pub access_key: String,
 }
 
+#[derive(clap::Args, Debug)]
+pub struct EditArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Access key of the service account
+    pub access_key: String,
+
+    /// New secret key (8-40 characters)
+    #[arg(long)]
+    pub secret: Option<String>,
+
+    /// New policy document (JSON file path)
+    #[arg(long)]
+    pub policy: Option<String>,
+
+    /// New name
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// New description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// New expiry: a duration from now (e.g. 30d) or a date/time (RFC3339 or YYYY-MM-DD, UTC)
+    #[arg(long, value_name = "DURATION|DATE")]
+    pub expiry: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct StatusArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Access key of the service account
+    pub access_key: String,
+}
+
 #[derive(clap::Args, Debug)]
 pub struct RemoveArgs {
     /// Alias name of the server
@@ -118,6 +169,18 @@ struct ServiceAccountCreateOutput {
     access_key: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     secret_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration: Option<String>,
+    message: String,
+}
+
+/// JSON output for service account edit, enable and disable
+#[derive(Serialize)]
+struct ServiceAccountUpdateOutput {
+    success: bool,
+    account: ServiceAccountInfo,
     message: String,
 }
 
@@ -135,6 +198,13 @@ pub async fn execute(cmd: ServiceAccountCommands, formatter: &Formatter) -> Exit
         ServiceAccountCommands::List(args) => execute_list(args, formatter).await,
         ServiceAccountCommands::Create(args) => execute_create(args, formatter).await,
         ServiceAccountCommands::Info(args) => execute_info(args, formatter).await,
+        ServiceAccountCommands::Edit(args) => execute_edit(args, formatter).await,
+        ServiceAccountCommands::Enable(args) => {
+            execute_set_status(args, UserStatus::Enabled, formatter).await
+        }
+        ServiceAccountCommands::Disable(args) => {
+            execute_set_status(args, UserStatus::Disabled, formatter).await
+        }
         ServiceAccountCommands::Remove(args) => execute_remove(args, formatter).await,
     }
 }
@@ -165,7 +235,11 @@ async fn execute_list(args: ListArgs, formatter: &Formatter) -> ExitCode {
                         .account_status
                         .map(|s| format!(" [{}]", s))
                         .unwrap_or_default();
-                    formatter.println(&format!("  {styled_key}{parent}{status}"));
+                    let expiry = sa
+                        .expiration
+                        .map(|e| format!(" expires {}", formatter.style_date(&e)))
+                        .unwrap_or_default();
+                    formatter.println(&format!("  {styled_key}{parent}{status}{expiry}"));
                 }
             }
             ExitCode::Success
@@ -199,9 +273,22 @@ async fn execute_create(args: CreateArgs, formatter: &Formatter) -> ExitCode {
         None
     };
 
+    let expiry = match args
+        .expiry
+        .as_deref()
+        .map(|s| parse_expiry(s, jiff::Timestamp::now()))
+    {
+        Some(Ok(ts)) => Some(ts),
+        Some(Err(e)) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+        None => None,
+    };
+
     let request = CreateServiceAccountRequest {
         policy,
-        expiry: args.expiry,
+        expiry,
         name: args.name,
         description: args.description,
     };
@@ -213,6 +300,8 @@ async fn execute_create(args: CreateArgs, formatter: &Formatter) -> ExitCode {
                     success: true,
                     access_key: sa.access_key.clone(),
                     secret_key: sa.secret_key.clone(),
+                    parent_user: sa.parent_user.clone(),
+                    expiration: sa.expiration.clone(),
                     message: "Service account created successfully".to_string(),
                 };
                 formatter.json(&output);
@@ -220,6 +309,12 @@ async fn execute_create(args: CreateArgs, formatter: &Formatter) -> ExitCode {
                 let styled_key = formatter.style_name(&sa.access_key);
                 formatter.success("Service account created successfully.");
                 formatter.println(&format!("Access Key: {styled_key}"));
+                if let Some(parent) = &sa.parent_user {
+                    formatter.println(&format!("Parent:     {parent}"));
+                }
+                if let Some(expiry) = &sa.expiration {
+                    formatter.println(&format!("Expiration: {expiry}"));
+                }
                 if let Some(secret) = &sa.secret_key {
                     formatter.println(&format!("Secret Key: {secret}"));
                     formatter.println("");
@@ -236,6 +331,32 @@ async fn execute_create(args: CreateArgs, formatter: &Formatter) -> ExitCode {
     }
 }
 
+/// Resolve `--expiry` to an RFC3339 time in the future
+///
+/// Accepts a duration from `now` (`30d`, `12h`, plain seconds) or an absolute
+/// RFC3339 timestamp, date-time or date (UTC).
+fn parse_expiry(s: &str, now: jiff::Timestamp) -> Result<String, String> {
+    let s = s.trim();
+    let expiry = match parse_absolute_time(s)? {
+        Some(ts) => ts,
+        None => {
+            let ago = parse_duration_ago(s, now).map_err(|e| {
+                format!(
+                    "Invalid expiry '{s}': expected a duration like 30d, RFC3339 or YYYY-MM-DD ({e})"
+                )
+            })?;
+            now.checked_add(now.duration_since(ago))
+                .map_err(|e| format!("Invalid expiry '{s}': {e}"))?
+        }
+    };
+    if expiry <= now {
+        return Err(format!("Expiry must be in the future (got {expiry})"));
+    }
+    jiff::Timestamp::from_second(expiry.as_second())
+        .map(|ts| ts.to_string())
+        .map_err(|e| format!("Invalid expiry '{s}': {e}"))
+}
+
 async fn execute_info(args: InfoArgs, formatter: &Formatter) -> ExitCode {
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
@@ -281,6 +402,157 @@ async fn execute_info(args: InfoArgs, formatter: &Formatter) -> ExitCode {
     }
 }
 
+async fn execute_edit(args: EditArgs, formatter: &Formatter) -> ExitCode {
+    if let Some(secret) = &args.secret
+        && !(8..=40).contains(&secret.len())
+    {
+        formatter.error("Secret key must be 8 to 40 characters long");
+        return ExitCode::UsageError;
+    }
+
+    let new_policy = match &args.policy {
+        Some(policy_path) => match std::fs::read_to_string(policy_path) {
+            Ok(content) => Some(content),
+            Err(e) => {
+                formatter.error(&format!("Failed to read policy file '{policy_path}': {e}"));
+                return ExitCode::UsageError;
+            }
+        },
+        None => None,
+    };
+
+    let new_expiration = match args
+        .expiry
+        .as_deref()
+        .map(|s| parse_expiry(s, jiff::Timestamp::now()))
+    {
+        Some(Ok(ts)) => Some(ts),
+        Some(Err(e)) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+        None => None,
+    };
+
+    let request = UpdateServiceAccountRequest {
+        new_policy,
+        new_secret_key: args.secret,
+        new_name: args.name,
+        new_description: args.description,
+        new_expiration,
+        new_status: None,
+    };
+    if request.is_empty() {
+        formatter.error(
+            "Nothing to change. Give at least one of --secret, --policy, --name, \
+             --description or --expiry",
+        );
+        return ExitCode::UsageError;
+    }
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    if let Err(e) = client
+        .update_service_account(&args.access_key, request)
+        .await
+    {
+        report_update_error(&args.access_key, "update", &e, formatter);
+        return ExitCode::from(&e);
+    }
+
+    let sa = match client.get_service_account(&args.access_key).await {
+        Ok(sa) => sa,
+        Err(e) => {
+            formatter.error(&format!("Failed to read back service account: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+    print_updated(sa, "updated", formatter);
+    ExitCode::Success
+}
+
+/// Enable or disable a service account and read it back to confirm the change
+async fn execute_set_status(
+    args: StatusArgs,
+    status: UserStatus,
+    formatter: &Formatter,
+) -> ExitCode {
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+    let action = match status {
+        UserStatus::Enabled => "enable",
+        UserStatus::Disabled => "disable",
+    };
+
+    if let Err(e) = client
+        .set_service_account_status(&args.access_key, status)
+        .await
+    {
+        report_update_error(&args.access_key, action, &e, formatter);
+        return ExitCode::from(&e);
+    }
+
+    let sa = match client.get_service_account(&args.access_key).await {
+        Ok(sa) => sa,
+        Err(e) => {
+            formatter.error(&format!("Failed to confirm service account status: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+    if sa.status().is_some_and(|reported| reported != status) {
+        formatter.error(&format!(
+            "Server reports service account '{}' as {} after the {action} request",
+            args.access_key,
+            sa.account_status.as_deref().unwrap_or_default()
+        ));
+        return ExitCode::GeneralError;
+    }
+
+    print_updated(sa, &format!("{action}d"), formatter);
+    ExitCode::Success
+}
+
+fn report_update_error(access_key: &str, action: &str, e: &rc_core::Error, formatter: &Formatter) {
+    if matches!(e, rc_core::Error::NotFound(_)) {
+        formatter.error(&format!("Service account '{access_key}' not found"));
+    } else {
+        formatter.error(&format!("Failed to {action} service account: {e}"));
+    }
+}
+
+/// Print a changed service account; the secret key is never echoed
+fn print_updated(mut sa: ServiceAccount, done: &str, formatter: &Formatter) {
+    sa.secret_key = None;
+    let message = format!("Service account '{}' {done} successfully", sa.access_key);
+    if formatter.is_json() {
+        formatter.json(&ServiceAccountUpdateOutput {
+            success: true,
+            account: ServiceAccountInfo::from(sa),
+            message,
+        });
+        return;
+    }
+
+    let styled_key = formatter.style_name(&sa.access_key);
+    formatter.success(&format!(
+        "Service account '{styled_key}' {done} successfully."
+    ));
+    if let Some(parent) = &sa.parent_user {
+        formatter.println(&format!("Parent User: {parent}"));
+    }
+    if let Some(status) = &sa.account_status {
+        formatter.println(&format!("Status:      {status}"));
+    }
+    if let Some(expiry) = &sa.expiration {
+        formatter.println(&format!("Expiration:  {expiry}"));
+    }
+}
+
 async fn execute_remove(args: RemoveArgs, formatter: &Formatter) -> ExitCode {
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
@@ -319,6 +591,26 @@ async fn execute_remove(args: RemoveArgs, formatter: &Formatter) -> ExitCode {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_expiry() {
+        let now: jiff::Timestamp = "2026-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(parse_expiry("30d", now).unwrap(), "2026-01-31T00:00:00Z");
+        assert_eq!(parse_expiry("12h", now).unwrap(), "2026-01-01T12:00:00Z");
+        assert_eq!(
+            parse_expiry("2026-06-01", now).unwrap(),
+            "2026-06-01T00:00:00Z"
+        );
+        assert_eq!(
+            parse_expiry("2026-02-01T10:30:00.5Z", now).unwrap(),
+            "2026-02-01T10:30:00Z"
+        );
+
+        assert!(parse_expiry("2025-12-31", now).is_err());
+        assert!(parse_expiry("0s", now).is_err());
+        assert!(parse_expiry("-1d", now).is_err());
+        assert!(parse_expiry("soon", now).is_err());
+    }
+
     #[test]
     fn test_service_account_info_from() {
         let sa = ServiceAccount {
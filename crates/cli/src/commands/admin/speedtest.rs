@@ -0,0 +1,242 @@
+//! Speedtest command for benchmarking cluster throughput
+//!
+//! Runs a server-side PUT/GET benchmark and reports aggregate and per-node numbers.
+
+use serde::Serialize;
+
+use super::get_admin_client;
+use crate::commands::parse_size;
+use crate::exit_code::ExitCode;
+use crate::filter::parse_duration;
+use crate::output::{Formatter, ProgressBar, format_bytes};
+use rc_core::admin::{AdminApi, SpeedTestOpts, SpeedTestResult, SpeedTestStats};
+
+#[derive(clap::Args, Debug)]
+pub struct SpeedtestArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Object size used for each request (e.g., 4MiB, 64MiB, 1GiB)
+    #[arg(long, default_value = "64MiB")]
+    pub size: String,
+
+    /// Duration of the test (e.g., 10s, 1m)
+    #[arg(long, default_value = "10s")]
+    pub duration: String,
+
+    /// Number of concurrent requests per server
+    #[arg(long, default_value = "32")]
+    pub concurrent: usize,
+
+    /// Increase concurrency until throughput stops improving
+    #[arg(long)]
+    pub autotune: bool,
+}
+
+/// JSON output for per-server numbers
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerStatsOutput {
+    endpoint: String,
+    throughput_per_sec: u64,
+    objects_per_sec: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// JSON output for one direction (PUT or GET)
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsOutput {
+    throughput_per_sec: u64,
+    objects_per_sec: u64,
+    servers: Vec<ServerStatsOutput>,
+}
+
+impl From<&SpeedTestStats> for StatsOutput {
+    fn from(stats: &SpeedTestStats) -> Self {
+        Self {
+            throughput_per_sec: stats.throughput_per_sec,
+            objects_per_sec: stats.objects_per_sec,
+            servers: stats
+                .servers
+                .iter()
+                .map(|s| ServerStatsOutput {
+                    endpoint: s.endpoint.clone(),
+                    throughput_per_sec: s.throughput_per_sec,
+                    objects_per_sec: s.objects_per_sec,
+                    error: s.err.clone().filter(|e| !e.is_empty()),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// JSON output for the speedtest result
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpeedtestOutput {
+    servers: usize,
+    disks: usize,
+    size: u64,
+    concurrent: usize,
+    put: StatsOutput,
+    get: StatsOutput,
+}
+
+impl From<&SpeedTestResult> for SpeedtestOutput {
+    fn from(result: &SpeedTestResult) -> Self {
+        Self {
+            servers: result.servers,
+            disks: result.disks,
+            size: result.size,
+            concurrent: result.concurrent,
+            put: StatsOutput::from(&result.put_stats),
+            get: StatsOutput::from(&result.get_stats),
+        }
+    }
+}
+
+/// Execute the speedtest command
+pub async fn execute(args: SpeedtestArgs, formatter: &Formatter) -> ExitCode {
+    let size = match parse_size(&args.size) {
+        Ok(s) => s,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let duration = match parse_duration(&args.duration) {
+        Ok(d) if !d.is_zero() => d,
+        Ok(_) => {
+            formatter.error("Duration must be greater than zero");
+            return ExitCode::UsageError;
+        }
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    if args.concurrent == 0 {
+        formatter.error("Concurrency must be at least 1");
+        return ExitCode::UsageError;
+    }
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let opts = SpeedTestOpts {
+        size,
+        concurrent: args.concurrent,
+        duration: format!("{}s", duration.as_secs()),
+        autotune: args.autotune,
+    };
+
+    let spinner = ProgressBar::spinner(formatter.output_config().clone(), "Running speedtest...");
+    let progress = |result: &SpeedTestResult| {
+        spinner.set_message(&format!(
+            "Running speedtest... PUT {}/s, GET {}/s",
+            format_bytes(result.put_stats.throughput_per_sec),
+            format_bytes(result.get_stats.throughput_per_sec)
+        ));
+    };
+
+    let result = client.speedtest(opts, &progress).await;
+    spinner.finish_and_clear();
+
+    match result {
+        Ok(result) => {
+            if formatter.is_json() {
+                formatter.json(&SpeedtestOutput::from(&result));
+            } else {
+                print_result(&result, formatter);
+            }
+            ExitCode::Success
+        }
+        Err(e) => {
+            formatter.error(&format!("Speedtest failed: {e}"));
+            ExitCode::GeneralError
+        }
+    }
+}
+
+fn print_result(result: &SpeedTestResult, formatter: &Formatter) {
+    formatter.println(&format!(
+        "{} {} server(s), {} disk(s), {} objects, concurrency {}",
+        formatter.style_name("Speedtest:"),
+        result.servers,
+        result.disks,
+        format_bytes(result.size),
+        result.concurrent
+    ));
+    formatter.println("");
+
+    print_stats("PUT", &result.put_stats, formatter);
+    print_stats("GET", &result.get_stats, formatter);
+}
+
+fn print_stats(label: &str, stats: &SpeedTestStats, formatter: &Formatter) {
+    formatter.println(&format!(
+        "  {label}: {}/s, {} objs/s",
+        formatter.style_size(&format_bytes(stats.throughput_per_sec)),
+        stats.objects_per_sec
+    ));
+
+    for server in &stats.servers {
+        match server.err.as_deref().filter(|e| !e.is_empty()) {
+            Some(err) => formatter.println(&format!("    {:<30} error: {err}", server.endpoint)),
+            None => formatter.println(&format!(
+                "    {:<30} {}/s, {} objs/s",
+                server.endpoint,
+                format_bytes(server.throughput_per_sec),
+                server.objects_per_sec
+            )),
+        }
+    }
+    formatter.println("");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rc_core::admin::SpeedTestServerStats;
+
+    #[test]
+    fn test_speedtest_output_from_result() {
+        let result = SpeedTestResult {
+            servers: 2,
+            disks: 8,
+            size: 64 * 1024 * 1024,
+            concurrent: 32,
+            put_stats: SpeedTestStats {
+                throughput_per_sec: 1000,
+                objects_per_sec: 10,
+                servers: vec![
+                    SpeedTestServerStats {
+                        endpoint: "node1:9000".to_string(),
+                        throughput_per_sec: 500,
+                        objects_per_sec: 5,
+                        err: Some(String::new()),
+                    },
+                    SpeedTestServerStats {
+                        endpoint: "node2:9000".to_string(),
+                        err: Some("timeout".to_string()),
+                        ..Default::default()
+                    },
+                ],
+            },
+            ..Default::default()
+        };
+
+        let value = serde_json::to_value(SpeedtestOutput::from(&result)).expect("serialize");
+        assert_eq!(value["servers"], 2);
+        assert_eq!(value["put"]["throughputPerSec"], 1000);
+        assert!(value["put"]["servers"][0].get("error").is_none());
+        assert_eq!(value["put"]["servers"][1]["error"], "timeout");
+        assert_eq!(value["get"]["objectsPerSec"], 0);
+    }
+}
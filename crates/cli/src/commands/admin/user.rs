@@ -1,14 +1,24 @@
 //! User management commands
 //!
-//! Commands for managing IAM users: list, add, info, remove, enable, disable.
+//! Commands for managing IAM users: list, add, info, remove, enable, disable,
+//! attach-policy, detach-policy, import, and sts for temporary credentials.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
 
 use clap::Subcommand;
-use serde::Serialize;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
 
-use super::get_admin_client;
+use super::import::{self, ImportEntryResult, ImportOutput};
+use super::{ColumnArgs, get_admin_client, print_table};
 use crate::exit_code::ExitCode;
-use crate::output::Formatter;
-use rc_core::admin::{AdminApi, User, UserStatus};
+use crate::filter::parse_duration;
+use crate::output::table::Column;
+use crate::output::{Formatter, format_duration};
+use rc_core::admin::{
+    AdminApi, AssumeRoleRequest, PolicyEntity, TemporaryCredentials, User, UserStatus,
+};
 
 /// User management subcommands
 #[derive(Subcommand, Debug)]
@@ -32,14 +42,104 @@ pub enum UserCommands {
 
     /// Disable a user
     Disable(DisableArgs),
+
+    /// Attach one or more existing policies to a user
+    AttachPolicy(UserPolicyArgs),
+
+    /// Detach one or more policies from a user
+    DetachPolicy(UserPolicyArgs),
+
+    /// Create users in bulk from a JSON file
+    Import(ImportArgs),
+
+    /// Request temporary credentials from the server's STS endpoint
+    Sts(StsArgs),
 }
 
 #[derive(clap::Args, Debug)]
 pub struct ListArgs {
     /// Alias name of the server
     pub alias: String,
+
+    /// Look up each user's groups and show the policies inherited through them
+    #[arg(long)]
+    pub with_groups: bool,
+
+    /// Also fetch the documents of every attached policy (implies --with-groups)
+    #[arg(long)]
+    pub with_policies: bool,
+
+    #[command(flatten)]
+    pub table: ColumnArgs,
 }
 
+/// Group and policy lookups in flight at once for `user ls --with-groups`
+const LOOKUP_CONCURRENCY: usize = 8;
+
+/// Columns for `user ls` with `--wide` or `--columns`
+const USER_COLUMNS: &[Column<User>] = &[
+    Column {
+        name: "access-key",
+        wide: false,
+        value: |u| u.access_key.clone(),
+    },
+    Column {
+        name: "status",
+        wide: false,
+        value: |u| u.status.to_string(),
+    },
+    Column {
+        name: "policies",
+        wide: false,
+        value: |u| u.policies().join(","),
+    },
+    Column {
+        name: "groups",
+        wide: true,
+        value: |u| u.member_of.join(","),
+    },
+];
+
+/// Columns for `user ls --with-groups`
+const USER_GROUP_COLUMNS: &[Column<UserDetailOutput>] = &[
+    Column {
+        name: "access-key",
+        wide: false,
+        value: |u| u.user.access_key.clone(),
+    },
+    Column {
+        name: "status",
+        wide: false,
+        value: |u| u.user.status.clone(),
+    },
+    Column {
+        name: "groups",
+        wide: false,
+        value: |u| u.user.member_of.join(","),
+    },
+    Column {
+        name: "policies",
+        wide: false,
+        value: |u| u.user.policies.join(","),
+    },
+    Column {
+        name: "effective",
+        wide: false,
+        value: |u| u.effective_policies.join(","),
+    },
+    Column {
+        name: "group-policies",
+        wide: true,
+        value: |u| {
+            u.group_policies
+                .iter()
+                .map(|g| format!("{}={}", g.group, g.policies.join("|")))
+                .collect::<Vec<_>>()
+                .join(" ")
+        },
+    },
+];
+
 #[derive(clap::Args, Debug)]
 pub struct AddArgs {
     /// Alias name of the server
@@ -88,6 +188,97 @@ pub struct DisableArgs {
     pub access_key: String,
 }
 
+#[derive(clap::Args, Debug)]
+pub struct UserPolicyArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Access key of the user
+    pub access_key: String,
+
+    /// Policy names
+    #[arg(required = true)]
+    pub policies: Vec<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ImportArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// JSON or YAML file with a list of users ({"accessKey", "secretKey", "policy"})
+    pub file: String,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct StsArgs {
+    /// Alias name of the server
+    pub alias: String,
+
+    /// Lifetime of the credentials (e.g. 900, 30m, 12h, 7d; at least 15 minutes)
+    #[arg(long, value_name = "DURATION", value_parser = parse_sts_duration)]
+    pub duration: Option<u32>,
+
+    /// Session policy document (JSON file path) narrowing the permissions
+    #[arg(long, value_name = "FILE")]
+    pub policy: Option<String>,
+
+    /// File holding an OIDC token to exchange (AssumeRoleWithWebIdentity) instead
+    /// of the alias's keys; the token can also come from RC_WEB_IDENTITY_TOKEN
+    #[arg(long, value_name = "FILE")]
+    pub web_identity_token_file: Option<String>,
+
+    /// Role ARN to assume, for servers that require one with web identity
+    #[arg(long, value_name = "ARN")]
+    pub role_arn: Option<String>,
+
+    /// Print `export AWS_...=` lines for a POSIX shell instead of a summary
+    #[arg(long)]
+    pub export: bool,
+}
+
+/// Shortest lifetime STS grants
+const STS_MIN_DURATION_SECS: u32 = 900;
+
+/// Longest lifetime STS grants (365 days)
+const STS_MAX_DURATION_SECS: u32 = 31_536_000;
+
+/// Environment variable holding an OIDC token for `user sts`
+const WEB_IDENTITY_TOKEN_ENV: &str = "RC_WEB_IDENTITY_TOKEN";
+
+/// A user entry in an import file
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserImportEntry {
+    access_key: String,
+    secret_key: String,
+    /// Comma-separated policy names to attach after creation
+    #[serde(default)]
+    policy: Option<String>,
+}
+
+/// Import file layout: either a bare list or `{"users": [...]}`
+///
+/// Entries are kept as raw values so that one malformed entry is reported on
+/// its own instead of rejecting the whole file.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum UserImportFile {
+    List(Vec<serde_json::Value>),
+    Wrapped { users: Vec<serde_json::Value> },
+}
+
+impl UserImportFile {
+    fn into_entries(self) -> Vec<serde_json::Value> {
+        match self {
+            UserImportFile::List(users) | UserImportFile::Wrapped { users } => users,
+        }
+    }
+}
+
+/// An import file entry, or the failure to report for it
+type ParsedImportEntry = Result<UserImportEntry, ImportEntryResult>;
+
 /// JSON output for user list
 #[derive(Serialize)]
 struct UserListOutput {
@@ -116,6 +307,51 @@ impl From<User> for UserInfo {
     }
 }
 
+/// JSON output for `user ls --with-groups`
+#[derive(Serialize)]
+struct UserGroupListOutput {
+    users: Vec<UserDetailOutput>,
+    /// Documents of the attached policies, keyed by name (`--with-policies`)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    policies: BTreeMap<String, serde_json::Value>,
+}
+
+/// JSON output for user info, including policies inherited through groups
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserDetailOutput {
+    #[serde(flatten)]
+    user: UserInfo,
+    group_policies: Vec<GroupPolicies>,
+    effective_policies: Vec<String>,
+}
+
+/// Policies attached to one of the user's groups
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GroupPolicies {
+    group: String,
+    policies: Vec<String>,
+}
+
+impl UserDetailOutput {
+    fn new(user: User, group_policies: Vec<GroupPolicies>) -> Self {
+        let user = UserInfo::from(user);
+        let mut effective_policies = user.policies.clone();
+        for policy in group_policies.iter().flat_map(|g| &g.policies) {
+            if !effective_policies.contains(policy) {
+                effective_policies.push(policy.clone());
+            }
+        }
+
+        Self {
+            user,
+            group_policies,
+            effective_policies,
+        }
+    }
+}
+
 /// JSON output for user operations
 #[derive(Serialize)]
 struct UserOperationOutput {
@@ -126,6 +362,35 @@ struct UserOperationOutput {
     secret_key: Option<String>,
 }
 
+/// JSON output for `user sts`
+#[derive(Serialize)]
+struct StsOutput {
+    #[serde(flatten)]
+    credentials: TemporaryCredentials,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_in_seconds: Option<i64>,
+}
+
+/// JSON output for enable and disable, with the status read back from the server
+#[derive(Serialize)]
+struct UserStatusOutput {
+    success: bool,
+    access_key: String,
+    status: String,
+    message: String,
+}
+
+/// JSON output for attach-policy and detach-policy
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserPolicyOutput {
+    access_key: String,
+    /// Policies named on the command line
+    changed: Vec<String>,
+    /// Policies attached to the user after the change
+    policies: Vec<String>,
+}
+
 /// Execute a user subcommand
 pub async fn execute(cmd: UserCommands, formatter: &Formatter) -> ExitCode {
     match cmd {
@@ -135,10 +400,22 @@ pub async fn execute(cmd: UserCommands, formatter: &Formatter) -> ExitCode {
         UserCommands::Remove(args) => execute_remove(args, formatter).await,
         UserCommands::Enable(args) => execute_enable(args, formatter).await,
         UserCommands::Disable(args) => execute_disable(args, formatter).await,
+        UserCommands::AttachPolicy(args) => execute_policy_change(args, true, formatter).await,
+        UserCommands::DetachPolicy(args) => execute_policy_change(args, false, formatter).await,
+        UserCommands::Import(args) => execute_import(args, formatter).await,
+        UserCommands::Sts(args) => execute_sts(args, formatter).await,
     }
 }
 
 async fn execute_list(args: ListArgs, formatter: &Formatter) -> ExitCode {
+    if args.with_groups || args.with_policies {
+        return execute_list_with_groups(args, formatter).await;
+    }
+
+    let columns = match args.table.table(USER_COLUMNS, formatter) {
+        Ok(columns) => columns,
+        Err(code) => return code,
+    };
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
         Err(code) => return code,
@@ -153,6 +430,8 @@ async fn execute_list(args: ListArgs, formatter: &Formatter) -> ExitCode {
                 formatter.json(&output);
             } else if users.is_empty() {
                 formatter.println("No users found.");
+            } else if let Some(columns) = &columns {
+                print_table(formatter, columns, &users);
             } else {
                 for user in users {
                     let status_icon = match user.status {
@@ -180,6 +459,127 @@ async fn execute_list(args: ListArgs, formatter: &Formatter) -> ExitCode {
     }
 }
 
+/// List users with their groups' policies and, optionally, policy documents
+///
+/// Each group and policy is fetched once however many users share it, with
+/// up to `LOOKUP_CONCURRENCY` requests in flight. Lookups are best-effort: a
+/// failed one is reported as a warning and left out.
+async fn execute_list_with_groups(args: ListArgs, formatter: &Formatter) -> ExitCode {
+    let columns = match args.table.table(USER_GROUP_COLUMNS, formatter) {
+        Ok(Some(columns)) => columns,
+        Ok(None) => USER_GROUP_COLUMNS.iter().filter(|c| !c.wide).collect(),
+        Err(code) => return code,
+    };
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+    let client = &client;
+
+    let users = match client.list_users().await {
+        Ok(users) => users,
+        Err(e) => {
+            formatter.error(&format!("Failed to list users: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    let group_names: BTreeSet<String> = users.iter().flat_map(|u| u.member_of.clone()).collect();
+    let mut groups = BTreeMap::new();
+    for (name, result) in lookup_all(
+        group_names,
+        |name| async move { client.get_group(&name).await },
+    )
+    .await
+    {
+        match result {
+            Ok(group) => {
+                groups.insert(name, group.policies());
+            }
+            Err(e) => formatter.warning(&format!("Failed to get group '{name}': {e}")),
+        }
+    }
+
+    let users: Vec<UserDetailOutput> = users
+        .into_iter()
+        .map(|user| {
+            let group_policies = user
+                .member_of
+                .iter()
+                .filter_map(|group| {
+                    groups.get(group).map(|policies| GroupPolicies {
+                        group: group.clone(),
+                        policies: policies.clone(),
+                    })
+                })
+                .collect();
+            UserDetailOutput::new(user, group_policies)
+        })
+        .collect();
+
+    let mut policies = BTreeMap::new();
+    if args.with_policies {
+        let policy_names: BTreeSet<String> = users
+            .iter()
+            .flat_map(|u| u.effective_policies.clone())
+            .collect();
+        for (name, result) in lookup_all(policy_names, |name| async move {
+            client.get_policy(&name).await
+        })
+        .await
+        {
+            match result {
+                Ok(policy) => {
+                    let document = policy
+                        .parse_document()
+                        .unwrap_or_else(|_| serde_json::Value::String(policy.policy.clone()));
+                    policies.insert(name, document);
+                }
+                Err(e) => formatter.warning(&format!("Failed to get policy '{name}': {e}")),
+            }
+        }
+    }
+
+    if formatter.is_json() {
+        formatter.json(&UserGroupListOutput { users, policies });
+        return ExitCode::Success;
+    }
+
+    if users.is_empty() {
+        formatter.println("No users found.");
+    } else {
+        print_table(formatter, &columns, &users);
+    }
+    for (name, document) in &policies {
+        formatter.println("");
+        formatter.println(&format!("Policy {}:", formatter.style_name(name)));
+        let pretty = serde_json::to_string_pretty(document).unwrap_or_default();
+        for line in pretty.lines() {
+            formatter.println(&format!("  {line}"));
+        }
+    }
+    ExitCode::Success
+}
+
+/// Run one lookup per name, `LOOKUP_CONCURRENCY` at a time
+async fn lookup_all<T, F, Fut>(
+    names: BTreeSet<String>,
+    lookup: F,
+) -> Vec<(String, rc_core::Result<T>)>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = rc_core::Result<T>>,
+{
+    stream::iter(names)
+        .map(|name| {
+            let request = lookup(name.clone());
+            async move { (name, request.await) }
+        })
+        .buffer_unordered(LOOKUP_CONCURRENCY)
+        .collect()
+        .await
+}
+
 async fn execute_add(args: AddArgs, formatter: &Formatter) -> ExitCode {
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
@@ -225,43 +625,84 @@ async fn execute_info(args: InfoArgs, formatter: &Formatter) -> ExitCode {
         Err(code) => return code,
     };
 
-    match client.get_user(&args.access_key).await {
-        Ok(user) => {
-            if formatter.is_json() {
-                formatter.json(&UserInfo::from(user));
-            } else {
-                let styled_key = formatter.style_name(&user.access_key);
-                let status = match user.status {
-                    UserStatus::Enabled => formatter.style_size("enabled"),
-                    UserStatus::Disabled => formatter.style_date("disabled"),
-                };
-                formatter.println(&format!("Access Key: {styled_key}"));
-                formatter.println(&format!("Status:     {status}"));
-
-                let policies = user.policies();
-                if policies.is_empty() {
-                    formatter.println("Policies:   (none)");
-                } else {
-                    formatter.println(&format!("Policies:   {}", policies.join(", ")));
-                }
-
-                if user.member_of.is_empty() {
-                    formatter.println("Groups:     (none)");
-                } else {
-                    formatter.println(&format!("Groups:     {}", user.member_of.join(", ")));
-                }
-            }
-            ExitCode::Success
-        }
+    let user = match client.get_user(&args.access_key).await {
+        Ok(user) => user,
         Err(rc_core::Error::NotFound(_)) => {
             formatter.error(&format!("User '{}' not found", args.access_key));
-            ExitCode::NotFound
+            return ExitCode::NotFound;
         }
         Err(e) => {
             formatter.error(&format!("Failed to get user info: {e}"));
-            ExitCode::GeneralError
+            return ExitCode::GeneralError;
+        }
+    };
+
+    // Group lookups are best-effort: the user's own details are still useful
+    // when a group can't be read (e.g. it was removed concurrently).
+    let mut group_policies = Vec::new();
+    for group in &user.member_of {
+        match client.get_group(group).await {
+            Ok(g) => group_policies.push(GroupPolicies {
+                group: group.clone(),
+                policies: g.policies(),
+            }),
+            Err(e) => formatter.warning(&format!("Failed to get group '{group}': {e}")),
+        }
+    }
+
+    let output = UserDetailOutput::new(user, group_policies);
+
+    if formatter.is_json() {
+        formatter.json(&output);
+    } else {
+        print_user_detail(&output, formatter);
+    }
+    ExitCode::Success
+}
+
+fn print_user_detail(output: &UserDetailOutput, formatter: &Formatter) {
+    let user = &output.user;
+    let styled_key = formatter.style_name(&user.access_key);
+    let status = if user.status == UserStatus::Disabled.to_string() {
+        formatter.style_date(&user.status)
+    } else {
+        formatter.style_size(&user.status)
+    };
+    formatter.println(&format!("Access Key: {styled_key}"));
+    formatter.println(&format!("Status:     {status}"));
+
+    if user.policies.is_empty() {
+        formatter.println("Policies:   (none)");
+    } else {
+        formatter.println(&format!("Policies:   {}", user.policies.join(", ")));
+    }
+
+    if user.member_of.is_empty() {
+        formatter.println("Groups:     (none)");
+    } else {
+        formatter.println(&format!("Groups:     {}", user.member_of.join(", ")));
+        for group in &output.group_policies {
+            let policies = if group.policies.is_empty() {
+                "(no policies)".to_string()
+            } else {
+                group.policies.join(", ")
+            };
+            formatter.println(&format!(
+                "  {} {}",
+                formatter.style_name(&format!("{}:", group.group)),
+                formatter.style_date(&policies)
+            ));
         }
     }
+
+    if output.effective_policies.is_empty() {
+        formatter.println("Effective:  (none)");
+    } else {
+        formatter.println(&format!(
+            "Effective:  {}",
+            output.effective_policies.join(", ")
+        ));
+    }
 }
 
 async fn execute_remove(args: RemoveArgs, formatter: &Formatter) -> ExitCode {
@@ -298,72 +739,546 @@ async fn execute_remove(args: RemoveArgs, formatter: &Formatter) -> ExitCode {
 }
 
 async fn execute_enable(args: EnableArgs, formatter: &Formatter) -> ExitCode {
-    let client = match get_admin_client(&args.alias, formatter) {
+    execute_set_status(
+        &args.alias,
+        &args.access_key,
+        UserStatus::Enabled,
+        formatter,
+    )
+    .await
+}
+
+async fn execute_disable(args: DisableArgs, formatter: &Formatter) -> ExitCode {
+    execute_set_status(
+        &args.alias,
+        &args.access_key,
+        UserStatus::Disabled,
+        formatter,
+    )
+    .await
+}
+
+/// The admin calls enable and disable make; implemented for every
+/// [`AdminApi`] client and faked in tests
+trait UserStatusApi {
+    async fn set_status(&self, access_key: &str, status: UserStatus) -> rc_core::Result<()>;
+    async fn user(&self, access_key: &str) -> rc_core::Result<User>;
+}
+
+impl<T: AdminApi> UserStatusApi for T {
+    async fn set_status(&self, access_key: &str, status: UserStatus) -> rc_core::Result<()> {
+        self.set_user_status(access_key, status).await
+    }
+
+    async fn user(&self, access_key: &str) -> rc_core::Result<User> {
+        self.get_user(access_key).await
+    }
+}
+
+/// Which step of a status change failed
+enum StatusError {
+    /// Setting the status
+    Set(rc_core::Error),
+    /// Reading the user back
+    Confirm(rc_core::Error),
+}
+
+/// Set a user's status and return the user as the server reports it afterwards
+async fn apply_user_status(
+    client: &impl UserStatusApi,
+    access_key: &str,
+    status: UserStatus,
+) -> Result<User, StatusError> {
+    client
+        .set_status(access_key, status)
+        .await
+        .map_err(StatusError::Set)?;
+    client.user(access_key).await.map_err(StatusError::Confirm)
+}
+
+/// Set a user's status and read it back to confirm the change
+async fn execute_set_status(
+    alias: &str,
+    access_key: &str,
+    status: UserStatus,
+    formatter: &Formatter,
+) -> ExitCode {
+    let client = match get_admin_client(alias, formatter) {
         Ok(c) => c,
         Err(code) => return code,
     };
+    let action = match status {
+        UserStatus::Enabled => "enable",
+        UserStatus::Disabled => "disable",
+    };
 
-    match client
-        .set_user_status(&args.access_key, UserStatus::Enabled)
-        .await
-    {
-        Ok(()) => {
-            if formatter.is_json() {
-                let output = UserOperationOutput {
-                    success: true,
-                    access_key: args.access_key.clone(),
-                    message: format!("User '{}' enabled successfully", args.access_key),
-                    secret_key: None,
-                };
-                formatter.json(&output);
+    let user = match apply_user_status(&client, access_key, status).await {
+        Ok(u) => u,
+        Err(StatusError::Set(e)) => {
+            if matches!(e, rc_core::Error::NotFound(_)) {
+                formatter.error(&format!("User '{access_key}' not found"));
             } else {
-                let styled_key = formatter.style_name(&args.access_key);
-                formatter.success(&format!("User '{styled_key}' enabled successfully."));
+                formatter.error(&format!("Failed to {action} user: {e}"));
             }
-            ExitCode::Success
+            return ExitCode::from(&e);
+        }
+        Err(StatusError::Confirm(e)) => {
+            formatter.error(&format!("Failed to confirm user status: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+    if user.status != status {
+        formatter.error(&format!(
+            "Server reports user '{access_key}' as {} after the {action} request",
+            user.status
+        ));
+        return ExitCode::GeneralError;
+    }
+
+    if formatter.is_json() {
+        formatter.json(&UserStatusOutput {
+            success: true,
+            access_key: access_key.to_string(),
+            status: user.status.to_string(),
+            message: format!("User '{access_key}' {action}d successfully"),
+        });
+    } else {
+        let styled_key = formatter.style_name(access_key);
+        formatter.success(&format!("User '{styled_key}' is now {}.", user.status));
+    }
+    ExitCode::Success
+}
+
+async fn execute_policy_change(
+    args: UserPolicyArgs,
+    attach: bool,
+    formatter: &Formatter,
+) -> ExitCode {
+    let requested: Vec<String> = args
+        .policies
+        .iter()
+        .flat_map(|p| p.split(','))
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if requested.is_empty() {
+        formatter.error("At least one policy name is required");
+        return ExitCode::UsageError;
+    }
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    // Validate up front so a typo is reported by name rather than as an
+    // opaque server error (or not at all).
+    if attach {
+        let available: Vec<String> = match client.list_policies().await {
+            Ok(policies) => policies.into_iter().map(|p| p.name).collect(),
+            Err(e) => {
+                formatter.error(&format!("Failed to list policies: {e}"));
+                return ExitCode::from(&e);
+            }
+        };
+        let missing = missing_policies(&requested, &available);
+        if !missing.is_empty() {
+            formatter.error(&format!("Policy not found: {}", missing.join(", ")));
+            return ExitCode::NotFound;
+        }
+    }
+
+    let user = match client.get_user(&args.access_key).await {
+        Ok(user) => user,
+        Err(rc_core::Error::NotFound(_)) => {
+            formatter.error(&format!("User '{}' not found", args.access_key));
+            return ExitCode::NotFound;
         }
         Err(e) => {
-            formatter.error(&format!("Failed to enable user: {e}"));
-            ExitCode::GeneralError
+            formatter.error(&format!("Failed to get user info: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    let current = user.policies();
+    if !attach {
+        let missing = missing_policies(&requested, &current);
+        if !missing.is_empty() {
+            formatter.error(&format!(
+                "Policy not attached to user '{}': {}",
+                args.access_key,
+                missing.join(", ")
+            ));
+            return ExitCode::NotFound;
+        }
+    }
+
+    let result = if attach {
+        client
+            .attach_policy(&requested, PolicyEntity::User, &args.access_key)
+            .await
+    } else {
+        client
+            .detach_policy(&requested, PolicyEntity::User, &args.access_key)
+            .await
+    };
+    if let Err(e) = result {
+        let verb = if attach { "attach" } else { "detach" };
+        formatter.error(&format!("Failed to {verb} policy: {e}"));
+        return ExitCode::from(&e);
+    }
+
+    let output = UserPolicyOutput {
+        access_key: args.access_key,
+        policies: resulting_policies(&current, &requested, attach),
+        changed: requested,
+    };
+
+    if formatter.is_json() {
+        formatter.json(&output);
+    } else {
+        let styled_key = formatter.style_name(&output.access_key);
+        let styled_policies = formatter.style_name(&output.changed.join(", "));
+        let message = if attach {
+            format!("Policy '{styled_policies}' attached to user '{styled_key}'.")
+        } else {
+            format!("Policy '{styled_policies}' detached from user '{styled_key}'.")
+        };
+        formatter.success(&message);
+        if output.policies.is_empty() {
+            formatter.println("Policies: (none)");
+        } else {
+            formatter.println(&format!("Policies: {}", output.policies.join(", ")));
         }
     }
+    ExitCode::Success
 }
 
-async fn execute_disable(args: DisableArgs, formatter: &Formatter) -> ExitCode {
+/// Requested policy names that are not in `available`
+fn missing_policies(requested: &[String], available: &[String]) -> Vec<String> {
+    requested
+        .iter()
+        .filter(|p| !available.contains(p))
+        .cloned()
+        .collect()
+}
+
+/// The user's policy set after attaching or detaching `requested`
+fn resulting_policies(current: &[String], requested: &[String], attach: bool) -> Vec<String> {
+    let mut policies: Vec<String> = current
+        .iter()
+        .filter(|p| attach || !requested.contains(p))
+        .cloned()
+        .collect();
+    if attach {
+        for policy in requested {
+            if !policies.contains(policy) {
+                policies.push(policy.clone());
+            }
+        }
+    }
+    policies
+}
+
+async fn execute_import(args: ImportArgs, formatter: &Formatter) -> ExitCode {
+    let entries = match parse_import_file(&args.file) {
+        Ok(e) => e,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
     let client = match get_admin_client(&args.alias, formatter) {
         Ok(c) => c,
         Err(code) => return code,
     };
 
-    match client
-        .set_user_status(&args.access_key, UserStatus::Disabled)
+    let mut results = Vec::with_capacity(entries.len());
+    for entry in entries {
+        results.push(match entry {
+            Ok(entry) => import_user(&client, entry).await,
+            Err(failed) => failed,
+        });
+    }
+
+    import::report(&ImportOutput::new(results), "user", formatter)
+}
+
+async fn import_user(client: &impl AdminApi, entry: UserImportEntry) -> ImportEntryResult {
+    if entry.access_key.is_empty() {
+        return ImportEntryResult::failed("(empty)", "Access key cannot be empty");
+    }
+    if entry.secret_key.len() < 8 {
+        return ImportEntryResult::failed(
+            entry.access_key,
+            "Secret key must be at least 8 characters long",
+        );
+    }
+
+    if let Err(e) = client
+        .create_user(&entry.access_key, &entry.secret_key)
         .await
     {
-        Ok(()) => {
-            if formatter.is_json() {
-                let output = UserOperationOutput {
-                    success: true,
-                    access_key: args.access_key.clone(),
-                    message: format!("User '{}' disabled successfully", args.access_key),
-                    secret_key: None,
-                };
-                formatter.json(&output);
-            } else {
-                let styled_key = formatter.style_name(&args.access_key);
-                formatter.success(&format!("User '{styled_key}' disabled successfully."));
+        return ImportEntryResult::failed(entry.access_key, e.to_string());
+    }
+
+    let policies: Vec<String> = entry
+        .policy
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    if !policies.is_empty()
+        && let Err(e) = client
+            .attach_policy(&policies, PolicyEntity::User, &entry.access_key)
+            .await
+    {
+        return ImportEntryResult::failed(
+            entry.access_key,
+            format!("User created but policy attach failed: {e}"),
+        );
+    }
+
+    ImportEntryResult::ok(entry.access_key)
+}
+
+async fn execute_sts(args: StsArgs, formatter: &Formatter) -> ExitCode {
+    let web_identity_token =
+        match web_identity_token(&args, std::env::var(WEB_IDENTITY_TOKEN_ENV).ok()) {
+            Ok(token) => token,
+            Err(e) => {
+                formatter.error(&e);
+                return ExitCode::UsageError;
             }
-            ExitCode::Success
-        }
+        };
+
+    let policy = match &args.policy {
+        Some(policy_path) => match std::fs::read_to_string(policy_path) {
+            Ok(content) => Some(content),
+            Err(e) => {
+                formatter.error(&format!("Failed to read policy file '{policy_path}': {e}"));
+                return ExitCode::UsageError;
+            }
+        },
+        None => None,
+    };
+
+    let client = match get_admin_client(&args.alias, formatter) {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let request = AssumeRoleRequest {
+        duration_seconds: args.duration,
+        policy,
+        role_arn: args.role_arn,
+        web_identity_token,
+    };
+    let credentials = match client.assume_role(&request).await {
+        Ok(credentials) => credentials,
         Err(e) => {
-            formatter.error(&format!("Failed to disable user: {e}"));
-            ExitCode::GeneralError
+            formatter.error(&format!("Failed to get temporary credentials: {e}"));
+            return ExitCode::from(&e);
         }
+    };
+
+    let expires_in_seconds = credentials.seconds_left(jiff::Timestamp::now());
+    if args.export {
+        formatter.plain_lines(&export_lines(&credentials));
+    } else if formatter.is_json() {
+        formatter.json(&StsOutput {
+            credentials,
+            expires_in_seconds,
+        });
+    } else {
+        let expiry = match (credentials.expiration, expires_in_seconds) {
+            (Some(exp), Some(secs)) => {
+                format!("{exp} (in {})", format_duration(secs.max(0) as u64))
+            }
+            _ => "not reported by the server".to_string(),
+        };
+        formatter.println(&format!("Expires:       {}", formatter.style_date(&expiry)));
+        formatter.println(&format!(
+            "Access Key:    {}",
+            formatter.style_name(&credentials.access_key)
+        ));
+        formatter.println(&format!("Secret Key:    {}", credentials.secret_key));
+        formatter.println(&format!("Session Token: {}", credentials.session_token));
+    }
+    ExitCode::Success
+}
+
+/// OIDC token from `--web-identity-token-file`, else from `RC_WEB_IDENTITY_TOKEN`
+///
+/// The token is never taken from the command line, where other local users
+/// could read it from the process list.
+fn web_identity_token(args: &StsArgs, env_token: Option<String>) -> Result<Option<String>, String> {
+    let token = match &args.web_identity_token_file {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read web identity token file '{path}': {e}"))?;
+            let token = content.trim();
+            if token.is_empty() {
+                return Err(format!("Web identity token file '{path}' is empty"));
+            }
+            Some(token.to_string())
+        }
+        None => env_token
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty()),
+    };
+
+    if args.role_arn.is_some() && token.is_none() {
+        return Err(format!(
+            "--role-arn needs a web identity token from --web-identity-token-file or {WEB_IDENTITY_TOKEN_ENV}"
+        ));
+    }
+    Ok(token)
+}
+
+/// Parse `--duration` into whole seconds
+fn parse_sts_duration(s: &str) -> Result<u32, String> {
+    let secs = parse_duration(s)?.as_secs();
+    if secs < u64::from(STS_MIN_DURATION_SECS) {
+        return Err(format!(
+            "Duration must be at least {STS_MIN_DURATION_SECS} seconds (15m)"
+        ));
+    }
+    u32::try_from(secs)
+        .ok()
+        .filter(|&secs| secs <= STS_MAX_DURATION_SECS)
+        .ok_or_else(|| format!("Duration must be at most {STS_MAX_DURATION_SECS} seconds (365d)"))
+}
+
+/// Shell `export` lines for the credentials, with the expiry as a comment
+fn export_lines(credentials: &TemporaryCredentials) -> Vec<String> {
+    let mut lines = Vec::with_capacity(4);
+    if let Some(exp) = credentials.expiration {
+        lines.push(format!("# expires {exp}"));
+    }
+    for (name, value) in [
+        ("AWS_ACCESS_KEY_ID", &credentials.access_key),
+        ("AWS_SECRET_ACCESS_KEY", &credentials.secret_key),
+        ("AWS_SESSION_TOKEN", &credentials.session_token),
+    ] {
+        lines.push(format!("export {name}='{}'", value.replace('\'', "'\\''")));
+    }
+    lines
+}
+
+fn parse_import_file(path: &str) -> Result<Vec<ParsedImportEntry>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read import file '{path}': {e}"))?;
+    parse_import_entries(&content, is_yaml_file(path, &content))
+}
+
+/// Whether an import file is YAML: by extension, otherwise by its first character
+fn is_yaml_file(path: &str, content: &str) -> bool {
+    let extension = std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("yaml" | "yml") => true,
+        Some("json") => false,
+        _ => !matches!(content.trim_start().chars().next(), Some('[' | '{')),
+    }
+}
+
+/// Parse the entry list; entries that don't match the expected fields become failures
+fn parse_import_entries(content: &str, yaml: bool) -> Result<Vec<ParsedImportEntry>, String> {
+    let file = if yaml {
+        serde_yaml::from_str::<UserImportFile>(content).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str::<UserImportFile>(content).map_err(|e| e.to_string())
     }
+    .map_err(|e| format!("Invalid user import file: {e}"))?;
+
+    Ok(file
+        .into_entries()
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let name = value
+                .get("accessKey")
+                .and_then(|v| v.as_str())
+                .filter(|k| !k.is_empty())
+                .map_or_else(|| format!("(entry {})", index + 1), str::to_string);
+            serde_json::from_value::<UserImportEntry>(value)
+                .map_err(|e| ImportEntryResult::failed(name, format!("Invalid entry: {e}")))
+        })
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    /// In-memory user store for the status calls
+    struct FakeUsers {
+        status: Mutex<UserStatus>,
+        /// Whether set_status is applied; a server that ignores it keeps the old status
+        applies: bool,
+    }
+
+    impl UserStatusApi for FakeUsers {
+        async fn set_status(&self, access_key: &str, status: UserStatus) -> rc_core::Result<()> {
+            if access_key != "alice" {
+                return Err(rc_core::Error::NotFound(access_key.to_string()));
+            }
+            if self.applies {
+                *self.status.lock().unwrap() = status;
+            }
+            Ok(())
+        }
+
+        async fn user(&self, access_key: &str) -> rc_core::Result<User> {
+            Ok(User {
+                access_key: access_key.to_string(),
+                secret_key: None,
+                status: *self.status.lock().unwrap(),
+                policy_name: None,
+                member_of: Vec::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disable_then_get_user_reports_disabled() {
+        let users = FakeUsers {
+            status: Mutex::new(UserStatus::Enabled),
+            applies: true,
+        };
+        let Ok(user) = apply_user_status(&users, "alice", UserStatus::Disabled).await else {
+            panic!("status change failed");
+        };
+        assert_eq!(user.status, UserStatus::Disabled);
+
+        let Ok(user) = apply_user_status(&users, "alice", UserStatus::Enabled).await else {
+            panic!("status change failed");
+        };
+        assert_eq!(user.status, UserStatus::Enabled);
+
+        // The caller compares the read-back status, so an ignored change is caught
+        let stuck = FakeUsers {
+            status: Mutex::new(UserStatus::Enabled),
+            applies: false,
+        };
+        let Ok(user) = apply_user_status(&stuck, "alice", UserStatus::Disabled).await else {
+            panic!("status change failed");
+        };
+        assert_eq!(user.status, UserStatus::Enabled);
+
+        assert!(matches!(
+            apply_user_status(&users, "bob", UserStatus::Disabled).await,
+            Err(StatusError::Set(rc_core::Error::NotFound(_)))
+        ));
+    }
 
     #[test]
     fn test_user_info_from_user() {
@@ -381,4 +1296,283 @@ mod tests {
         assert_eq!(info.policies, vec!["policy1", "policy2"]);
         assert_eq!(info.member_of, vec!["group1"]);
     }
+
+    #[test]
+    fn test_user_detail_effective_policies() {
+        let user = User {
+            access_key: "testuser".to_string(),
+            secret_key: None,
+            status: UserStatus::Enabled,
+            policy_name: Some("readonly".to_string()),
+            member_of: vec!["devs".to_string(), "ops".to_string()],
+        };
+        let group_policies = vec![
+            GroupPolicies {
+                group: "devs".to_string(),
+                policies: vec!["readwrite".to_string(), "readonly".to_string()],
+            },
+            GroupPolicies {
+                group: "ops".to_string(),
+                policies: vec![],
+            },
+        ];
+
+        let output = UserDetailOutput::new(user, group_policies);
+        assert_eq!(output.effective_policies, vec!["readonly", "readwrite"]);
+
+        let value = serde_json::to_value(&output).expect("serialize user detail");
+        assert_eq!(value["accessKey"], "testuser");
+        assert_eq!(value["groupPolicies"][0]["group"], "devs");
+        assert_eq!(value["effectivePolicies"][1], "readwrite");
+    }
+
+    #[test]
+    fn test_user_group_columns() {
+        let user = User {
+            access_key: "alice".to_string(),
+            secret_key: None,
+            status: UserStatus::Enabled,
+            policy_name: Some("readonly".to_string()),
+            member_of: vec!["devs".to_string()],
+        };
+        let output = UserDetailOutput::new(
+            user,
+            vec![GroupPolicies {
+                group: "devs".to_string(),
+                policies: vec!["readwrite".to_string(), "diag".to_string()],
+            }],
+        );
+
+        let columns = crate::output::table::select_columns(USER_GROUP_COLUMNS, true, None)
+            .expect("default columns");
+        let lines = crate::output::table::render(&columns, [&output]);
+        assert_eq!(
+            lines[1],
+            "alice       enabled  devs    readonly  readonly,readwrite,diag  devs=readwrite|diag"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lookup_all_runs_each_name_once() {
+        let names: BTreeSet<String> = ["b", "a", "c"].iter().map(|n| n.to_string()).collect();
+        let mut results = lookup_all(names, |name| async move {
+            if name == "b" {
+                Err(rc_core::Error::NotFound(name))
+            } else {
+                Ok(name.len())
+            }
+        })
+        .await;
+        results.sort_by(|x, y| x.0.cmp(&y.0));
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], (ref n, Ok(1)) if n == "a"));
+        assert!(matches!(results[1].1, Err(rc_core::Error::NotFound(_))));
+        assert_eq!(results[2].0, "c");
+    }
+
+    #[test]
+    fn test_list_with_groups_args() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: ListArgs,
+        }
+
+        let cli = Cli::try_parse_from([
+            "ls",
+            "local",
+            "--with-policies",
+            "--columns",
+            "access-key,effective",
+        ])
+        .expect("parse");
+        assert!(cli.args.with_policies);
+        assert!(!cli.args.with_groups);
+        assert_eq!(
+            cli.args.table.columns.as_deref(),
+            Some(&["access-key".to_string(), "effective".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn test_missing_policies() {
+        let available = vec!["readonly".to_string(), "readwrite".to_string()];
+        let requested = vec!["readonly".to_string(), "typo".to_string()];
+        assert_eq!(missing_policies(&requested, &available), vec!["typo"]);
+    }
+
+    #[test]
+    fn test_resulting_policies() {
+        let current = vec!["readonly".to_string(), "diag".to_string()];
+
+        let attached = resulting_policies(
+            &current,
+            &["readwrite".to_string(), "readonly".to_string()],
+            true,
+        );
+        assert_eq!(attached, vec!["readonly", "diag", "readwrite"]);
+
+        let detached = resulting_policies(&current, &["readonly".to_string()], false);
+        assert_eq!(detached, vec!["diag"]);
+    }
+
+    #[test]
+    fn test_parse_import_entries_list() {
+        let entries = parse_import_entries(
+            r#"[{"accessKey": "alice", "secretKey": "alicesecret", "policy": "readonly"},
+                {"accessKey": "bob", "secretKey": "bobsecret1"}]"#,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        let alice = entries[0].as_ref().unwrap();
+        assert_eq!(alice.access_key, "alice");
+        assert_eq!(alice.policy.as_deref(), Some("readonly"));
+        assert!(entries[1].as_ref().unwrap().policy.is_none());
+    }
+
+    #[test]
+    fn test_parse_import_entries_wrapped() {
+        let entries = parse_import_entries(
+            r#"{"users": [{"accessKey": "alice", "secretKey": "alicesecret"}]}"#,
+            false,
+        )
+        .unwrap();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_import_entries_yaml() {
+        let yaml =
+            "users:\n  - accessKey: alice\n    secretKey: alicesecret\n    policy: readonly\n";
+        let entries = parse_import_entries(yaml, true).unwrap();
+        let alice = entries[0].as_ref().unwrap();
+        assert_eq!(alice.access_key, "alice");
+        assert_eq!(alice.policy.as_deref(), Some("readonly"));
+    }
+
+    #[test]
+    fn test_parse_import_entries_reports_bad_entries() {
+        let entries = parse_import_entries(
+            r#"[{"accessKey": "alice"}, {"secretKey": "nokey1234"},
+                {"accessKey": "bob", "secretKey": "bobsecret1"}]"#,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(entries.len(), 3);
+        let alice = entries[0].as_ref().unwrap_err();
+        assert_eq!(alice.name, "alice");
+        assert!(alice.error.as_deref().unwrap().contains("secretKey"));
+        assert_eq!(entries[1].as_ref().unwrap_err().name, "(entry 2)");
+        assert!(entries[2].is_ok());
+    }
+
+    #[test]
+    fn test_parse_import_entries_invalid() {
+        assert!(parse_import_entries("not json", false).is_err());
+        assert!(parse_import_entries("just a string", true).is_err());
+    }
+
+    #[test]
+    fn test_is_yaml_file() {
+        assert!(is_yaml_file("users.yaml", "[]"));
+        assert!(is_yaml_file("users.YML", "[]"));
+        assert!(!is_yaml_file("users.json", "users: []"));
+        assert!(!is_yaml_file("users", "  [{\"accessKey\": \"a\"}]"));
+        assert!(is_yaml_file("users", "- accessKey: a"));
+    }
+
+    fn sts_args(token_file: Option<String>, role_arn: Option<&str>) -> StsArgs {
+        StsArgs {
+            alias: "local".to_string(),
+            duration: None,
+            policy: None,
+            web_identity_token_file: token_file,
+            role_arn: role_arn.map(str::to_string),
+            export: false,
+        }
+    }
+
+    #[test]
+    fn test_web_identity_token_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        std::fs::write(&path, "eyJfile\n").unwrap();
+        let file = Some(path.to_string_lossy().into_owned());
+
+        // The file wins over the environment, and surrounding whitespace is dropped
+        let args = sts_args(file.clone(), None);
+        assert_eq!(
+            web_identity_token(&args, Some("eyJenv".to_string())).unwrap(),
+            Some("eyJfile".to_string())
+        );
+
+        let args = sts_args(None, Some("arn:role"));
+        assert_eq!(
+            web_identity_token(&args, Some("eyJenv".to_string())).unwrap(),
+            Some("eyJenv".to_string())
+        );
+
+        let args = sts_args(None, None);
+        assert_eq!(
+            web_identity_token(&args, Some(" ".to_string())).unwrap(),
+            None
+        );
+        assert_eq!(web_identity_token(&args, None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_web_identity_token_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let empty = dir.path().join("empty");
+        std::fs::write(&empty, "\n").unwrap();
+
+        let args = sts_args(Some(empty.to_string_lossy().into_owned()), None);
+        assert!(web_identity_token(&args, None).is_err());
+
+        let missing = dir.path().join("missing");
+        let args = sts_args(Some(missing.to_string_lossy().into_owned()), None);
+        assert!(web_identity_token(&args, None).is_err());
+
+        // A role ARN without any token
+        let args = sts_args(None, Some("arn:role"));
+        assert!(web_identity_token(&args, None).is_err());
+    }
+
+    #[test]
+    fn test_parse_sts_duration() {
+        assert_eq!(parse_sts_duration("900").unwrap(), 900);
+        assert_eq!(parse_sts_duration("30m").unwrap(), 1800);
+        assert_eq!(parse_sts_duration("12h").unwrap(), 43_200);
+        assert!(parse_sts_duration("5m").is_err());
+        assert!(parse_sts_duration("soon").is_err());
+        assert_eq!(parse_sts_duration("365d").unwrap(), 31_536_000);
+        assert!(parse_sts_duration("366d").is_err());
+        assert!(parse_sts_duration("99999999w").is_err());
+        assert!(parse_sts_duration("99999999999999999w").is_err());
+    }
+
+    #[test]
+    fn test_export_lines() {
+        let credentials = TemporaryCredentials {
+            access_key: "AKTEMP".to_string(),
+            secret_key: "se'cret".to_string(),
+            session_token: "TOKEN".to_string(),
+            expiration: Some("2026-01-15T11:00:00Z".parse().unwrap()),
+        };
+        assert_eq!(
+            export_lines(&credentials),
+            vec![
+                "# expires 2026-01-15T11:00:00Z",
+                "export AWS_ACCESS_KEY_ID='AKTEMP'",
+                "export AWS_SECRET_ACCESS_KEY='se'\\''cret'",
+                "export AWS_SESSION_TOKEN='TOKEN'",
+            ]
+        );
+    }
 }
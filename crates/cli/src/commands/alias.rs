@@ -6,21 +6,29 @@
 use clap::Subcommand;
 use serde::Serialize;
 
+use crate::commands::{cached_capabilities, resolve_alias};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
-use rc_core::{Alias, AliasManager};
+use rc_core::{Alias, AliasManager, Capabilities, ObjectStore, secret};
+use rc_s3::S3Client;
 
 /// Alias subcommands for managing storage service connections
 #[derive(Subcommand, Debug)]
 pub enum AliasCommands {
     /// Add or update an alias
-    Set(SetArgs),
+    Set(Box<SetArgs>),
 
     /// List all configured aliases
     List(ListArgs),
 
     /// Remove an alias
     Remove(RemoveArgs),
+
+    /// Set the current alias used by `:` paths (e.g. `rc ls :/bucket/`)
+    Use(UseArgs),
+
+    /// Show the features detected on an alias's backend
+    Capabilities(CapabilitiesArgs),
 }
 
 /// Arguments for the `alias set` command
@@ -32,11 +40,13 @@ pub struct SetArgs {
     /// S3 endpoint URL (e.g., `http://localhost:9000`, `https://s3.amazonaws.com`)
     pub endpoint: String,
 
-    /// Access key ID
-    pub access_key: String,
+    /// Access key ID (omit with --anonymous)
+    #[arg(required_unless_present = "anonymous")]
+    pub access_key: Option<String>,
 
-    /// Secret access key
-    pub secret_key: String,
+    /// Secret access key (omit with --anonymous)
+    #[arg(required_unless_present = "anonymous")]
+    pub secret_key: Option<String>,
 
     /// AWS region (default: us-east-1)
     #[arg(long, default_value = "us-east-1")]
@@ -53,6 +63,54 @@ pub struct SetArgs {
     /// Allow insecure TLS connections
     #[arg(long, default_value = "false")]
     pub insecure: bool,
+
+    /// Minimum TLS version to negotiate: 1.2 or 1.3
+    #[arg(long, value_name = "VERSION", value_parser = ["1.2", "1.3"])]
+    pub tls_min_version: Option<String>,
+
+    /// PEM client certificate for mutual TLS (requires --client-key)
+    #[arg(long, value_name = "PATH", requires = "client_key")]
+    pub client_cert: Option<String>,
+
+    /// PEM private key for --client-cert
+    #[arg(long, value_name = "PATH", requires = "client_cert")]
+    pub client_key: Option<String>,
+
+    /// Keep the secret key in the OS credential store; the config holds only a reference
+    #[arg(long)]
+    pub use_keyring: bool,
+
+    /// Connect to ADDR instead of resolving HOST (repeatable, e.g. once for IPv4 and once for IPv6)
+    #[arg(long, value_name = "HOST:ADDR")]
+    pub resolve: Vec<String>,
+
+    /// Send unsigned requests without credentials, for public buckets
+    #[arg(long, conflicts_with_all = ["access_key", "secret_key", "use_keyring"])]
+    pub anonymous: bool,
+
+    /// Storage class for cp/pipe uploads that don't pass --storage-class
+    #[arg(long, value_name = "CLASS")]
+    pub default_storage_class: Option<String>,
+
+    /// Content type for cp/pipe uploads with extension EXT that don't pass --content-type (repeatable)
+    #[arg(long, value_name = "EXT=TYPE", value_parser = parse_content_type_default)]
+    pub default_content_type: Vec<(String, String)>,
+}
+
+/// Parse an `EXT=TYPE` content-type default; the extension is lowercased and
+/// may be given with a leading dot
+fn parse_content_type_default(s: &str) -> Result<(String, String), String> {
+    let (extension, content_type) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid content-type default '{s}', expected EXT=TYPE"))?;
+    let extension = extension.trim().trim_start_matches('.');
+    let content_type = content_type.trim();
+    if extension.is_empty() || content_type.is_empty() {
+        return Err(format!(
+            "invalid content-type default '{s}', expected EXT=TYPE"
+        ));
+    }
+    Ok((extension.to_ascii_lowercase(), content_type.to_string()))
 }
 
 /// Arguments for the `alias list` command
@@ -70,6 +128,35 @@ pub struct RemoveArgs {
     pub name: String,
 }
 
+/// Arguments for the `alias use` command
+#[derive(clap::Args, Debug)]
+pub struct UseArgs {
+    /// Name of the alias to make current
+    pub name: String,
+}
+
+/// Arguments for the `alias capabilities` command
+#[derive(clap::Args, Debug)]
+pub struct CapabilitiesArgs {
+    /// Name of the alias to inspect
+    pub name: String,
+
+    /// Bucket to probe (default: the first bucket the alias can list)
+    #[arg(long)]
+    pub bucket: Option<String>,
+
+    /// Detect again instead of using cached results
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+/// JSON output for alias capabilities
+#[derive(Serialize)]
+struct CapabilitiesOutput {
+    alias: String,
+    capabilities: Capabilities,
+}
+
 /// JSON output for alias list
 #[derive(Serialize)]
 struct AliasListOutput {
@@ -116,12 +203,23 @@ pub async fn execute(cmd: AliasCommands, output_config: OutputConfig) -> ExitCod
     };
 
     match cmd {
-        AliasCommands::Set(args) => execute_set(args, &alias_manager, &formatter).await,
+        AliasCommands::Set(args) => execute_set(*args, &alias_manager, &formatter).await,
         AliasCommands::List(args) => execute_list(args, &alias_manager, &formatter).await,
         AliasCommands::Remove(args) => execute_remove(args, &alias_manager, &formatter).await,
+        AliasCommands::Use(args) => execute_use(args, &alias_manager, &formatter).await,
+        AliasCommands::Capabilities(args) => {
+            execute_capabilities(args, &alias_manager, &formatter).await
+        }
     }
 }
 
+/// Whether an endpoint uses a scheme the S3 client can talk to
+fn is_http_endpoint(endpoint: &str) -> bool {
+    endpoint.split_once("://").is_some_and(|(scheme, _)| {
+        scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+    })
+}
+
 async fn execute_set(args: SetArgs, manager: &AliasManager, formatter: &Formatter) -> ExitCode {
     // Validate inputs
     if args.name.is_empty() {
@@ -134,6 +232,14 @@ async fn execute_set(args: SetArgs, manager: &AliasManager, formatter: &Formatte
         return ExitCode::UsageError;
     }
 
+    if !is_http_endpoint(&args.endpoint) {
+        formatter.error(&format!(
+            "Endpoint '{}' is not an http:// or https:// URL",
+            args.endpoint
+        ));
+        return ExitCode::UsageError;
+    }
+
     // Validate signature version
     if args.signature != "v4" && args.signature != "v2" {
         formatter.error("Signature must be 'v4' or 'v2'");
@@ -146,17 +252,42 @@ async fn execute_set(args: SetArgs, manager: &AliasManager, formatter: &Formatte
         return ExitCode::UsageError;
     }
 
+    if let Some(err) = args
+        .resolve
+        .iter()
+        .find_map(|entry| rc_core::alias::parse_resolve(entry).err())
+    {
+        formatter.error(&err.to_string());
+        return ExitCode::UsageError;
+    }
+
+    let access_key = args.access_key.clone().unwrap_or_default();
+    let secret_key = args.secret_key.clone().unwrap_or_default();
+    let secret_key = if args.use_keyring {
+        match secret::store_in_keyring(&args.name, &secret_key) {
+            Ok(reference) => reference,
+            Err(e) => {
+                formatter.error(&e.to_string());
+                return ExitCode::from(&e);
+            }
+        }
+    } else {
+        secret_key
+    };
+
     // Create alias
-    let mut alias = Alias::new(
-        &args.name,
-        &args.endpoint,
-        &args.access_key,
-        &args.secret_key,
-    );
+    let mut alias = Alias::new(&args.name, &args.endpoint, access_key, secret_key);
     alias.region = args.region;
     alias.signature = args.signature;
     alias.bucket_lookup = args.bucket_lookup;
     alias.insecure = args.insecure;
+    alias.tls_min_version = args.tls_min_version;
+    alias.client_cert = args.client_cert;
+    alias.client_key = args.client_key;
+    alias.anonymous = args.anonymous;
+    alias.resolve = args.resolve;
+    alias.default_storage_class = args.default_storage_class;
+    alias.content_types = args.default_content_type.into_iter().collect();
 
     // Save alias
     match manager.set(alias) {
@@ -250,6 +381,110 @@ async fn execute_remove(
     }
 }
 
+async fn execute_use(args: UseArgs, manager: &AliasManager, formatter: &Formatter) -> ExitCode {
+    match manager.set_current(&args.name) {
+        Ok(()) => {
+            if formatter.is_json() {
+                let output = AliasOperationOutput {
+                    success: true,
+                    alias: args.name.clone(),
+                    message: format!("Switched to alias '{}'", args.name),
+                };
+                formatter.json(&output);
+            } else {
+                let styled_name = formatter.style_name(&args.name);
+                formatter.success(&format!("Switched to alias '{styled_name}'."));
+            }
+            ExitCode::Success
+        }
+        Err(rc_core::Error::AliasNotFound(_)) => {
+            formatter.error(&format!("Alias '{}' not found", args.name));
+            ExitCode::NotFound
+        }
+        Err(e) => {
+            formatter.error(&e.to_string());
+            ExitCode::GeneralError
+        }
+    }
+}
+
+async fn execute_capabilities(
+    args: CapabilitiesArgs,
+    manager: &AliasManager,
+    formatter: &Formatter,
+) -> ExitCode {
+    let alias = match resolve_alias(manager, &args.name) {
+        Ok(a) => a,
+        Err(_) => {
+            formatter.error(&format!("Alias '{}' not found", args.name));
+            return ExitCode::NotFound;
+        }
+    };
+
+    let client = match S3Client::new(alias).await {
+        Ok(c) => c,
+        Err(e) => {
+            formatter.error(&format!("Failed to create S3 client: {e}"));
+            return ExitCode::NetworkError;
+        }
+    };
+
+    let bucket = match &args.bucket {
+        Some(bucket) => bucket.clone(),
+        None => match client.list_buckets().await {
+            Ok(buckets) => match buckets.into_iter().next() {
+                Some(bucket) => bucket.key,
+                None => {
+                    formatter.error("No bucket to probe; pass --bucket <name>");
+                    return ExitCode::UsageError;
+                }
+            },
+            Err(e) => {
+                formatter.error(&format!("Failed to list buckets: {e}"));
+                return ExitCode::from(&e);
+            }
+        },
+    };
+
+    match cached_capabilities(&client, &bucket, args.refresh).await {
+        Ok(caps) => {
+            if formatter.is_json() {
+                let output = CapabilitiesOutput {
+                    alias: args.name.clone(),
+                    capabilities: caps,
+                };
+                formatter.json(&output);
+            } else {
+                for (feature, supported) in capability_rows(&caps) {
+                    let styled = formatter.style_name(&format!("{feature:<14}"));
+                    let mark = if supported { "yes" } else { "no" };
+                    formatter.println(&format!("{styled} {mark}"));
+                }
+            }
+            ExitCode::Success
+        }
+        Err(rc_core::Error::Auth(e)) => {
+            formatter.error(&format!("Access denied: {e}"));
+            ExitCode::AuthError
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to detect capabilities: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+/// Feature names and support flags, in display order
+fn capability_rows(caps: &Capabilities) -> [(&'static str, bool); 5] {
+    [
+        ("versioning", caps.versioning),
+        ("object_lock", caps.object_lock),
+        ("tagging", caps.tagging),
+        ("select", caps.select),
+        ("notifications", caps.notifications),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,12 +495,20 @@ mod tests {
         let args = SetArgs {
             name: "test".to_string(),
             endpoint: "http://localhost:9000".to_string(),
-            access_key: "accesskey".to_string(),
-            secret_key: "secretkey".to_string(),
+            access_key: Some("accesskey".to_string()),
+            secret_key: Some("secretkey".to_string()),
             region: "us-east-1".to_string(),
             signature: "v4".to_string(),
             bucket_lookup: "auto".to_string(),
             insecure: false,
+            tls_min_version: None,
+            client_cert: None,
+            client_key: None,
+            use_keyring: false,
+            resolve: Vec::new(),
+            anonymous: false,
+            default_storage_class: None,
+            default_content_type: Vec::new(),
         };
 
         assert_eq!(args.region, "us-east-1");
@@ -274,6 +517,28 @@ mod tests {
         assert!(!args.insecure);
     }
 
+    #[test]
+    fn test_is_http_endpoint() {
+        assert!(is_http_endpoint("http://localhost:9000"));
+        assert!(is_http_endpoint("HTTPS://s3.amazonaws.com"));
+        assert!(!is_http_endpoint("file:///tmp/rc-scratch"));
+        assert!(!is_http_endpoint("localhost:9000"));
+    }
+
+    #[test]
+    fn test_parse_content_type_default() {
+        assert_eq!(
+            parse_content_type_default(".PARQUET=application/vnd.apache.parquet"),
+            Ok((
+                "parquet".to_string(),
+                "application/vnd.apache.parquet".to_string()
+            ))
+        );
+        assert!(parse_content_type_default("parquet").is_err());
+        assert!(parse_content_type_default("=text/plain").is_err());
+        assert!(parse_content_type_default("txt=").is_err());
+    }
+
     #[test]
     fn test_alias_info_from_alias() {
         let alias = Alias::new("test", "http://localhost:9000", "key", "secret");
@@ -283,4 +548,17 @@ mod tests {
         assert_eq!(info.endpoint, "http://localhost:9000");
         assert_eq!(info.region, "us-east-1");
     }
+
+    #[test]
+    fn test_capability_rows() {
+        let caps = Capabilities {
+            versioning: true,
+            select: true,
+            ..Default::default()
+        };
+        let rows = capability_rows(&caps);
+        assert_eq!(rows[0], ("versioning", true));
+        assert_eq!(rows[1], ("object_lock", false));
+        assert_eq!(rows[3], ("select", true));
+    }
 }
@@ -0,0 +1,278 @@
+//! Shared target handling for mb and rb
+//!
+//! Both commands take several `alias/bucket` paths, run one operation per
+//! bucket and report every result, in text or as a single JSON document.
+
+use std::collections::HashMap;
+
+use futures::future::BoxFuture;
+use rc_core::{AliasManager, ObjectStore};
+use serde::Serialize;
+
+use crate::commands::{backend_registry, resolve_alias};
+use crate::exit_code::ExitCode;
+use crate::output::Formatter;
+
+/// JSON result for one bucket
+#[derive(Debug, Serialize)]
+pub(super) struct BucketResult {
+    pub(super) status: &'static str,
+    pub(super) bucket: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) message: Option<String>,
+}
+
+/// JSON output of mb and rb, whatever the number of targets
+#[derive(Debug, Serialize)]
+struct BucketResults {
+    results: Vec<BucketResult>,
+}
+
+/// Result of the operation on a single bucket
+pub(super) struct BucketOutcome {
+    pub(super) output: BucketResult,
+    pub(super) code: ExitCode,
+    /// Human-readable line printed for this bucket
+    pub(super) text: String,
+}
+
+impl BucketOutcome {
+    pub(super) fn success(
+        bucket: &str,
+        status: &'static str,
+        message: Option<&str>,
+        text: String,
+    ) -> Self {
+        Self {
+            output: BucketResult {
+                status,
+                bucket: bucket.to_string(),
+                message: message.map(str::to_string),
+            },
+            code: ExitCode::Success,
+            text,
+        }
+    }
+
+    pub(super) fn failure(bucket: &str, code: ExitCode, text: String) -> Self {
+        Self {
+            output: BucketResult {
+                status: "error",
+                bucket: bucket.to_string(),
+                message: Some(text.clone()),
+            },
+            code,
+            text,
+        }
+    }
+}
+
+/// Run `operation` on every `(alias, bucket)` target and report the results
+///
+/// Clients are created once per alias. A failure does not stop the remaining
+/// targets unless `stop_on_error` is set; the exit code is that of the first
+/// failure.
+pub(super) async fn run_targets<F>(
+    formatter: &Formatter,
+    targets: &[(String, String)],
+    stop_on_error: bool,
+    operation: F,
+) -> ExitCode
+where
+    F: for<'a> Fn(&'a dyn ObjectStore, &'a str, &'a str) -> BoxFuture<'a, BucketOutcome>,
+{
+    let alias_manager = match AliasManager::new() {
+        Ok(am) => am,
+        Err(e) => {
+            formatter.error(&format!("Failed to load aliases: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    let mut clients: HashMap<&str, Box<dyn ObjectStore>> = HashMap::new();
+    let mut results = Vec::with_capacity(targets.len());
+    let mut exit_code = ExitCode::Success;
+
+    for (alias_name, bucket) in targets {
+        let outcome = if let Some(client) = clients.get(alias_name.as_str()) {
+            operation(client.as_ref(), alias_name, bucket).await
+        } else {
+            match connect(&alias_manager, alias_name).await {
+                Ok(client) => {
+                    let outcome = operation(client.as_ref(), alias_name, bucket).await;
+                    clients.insert(alias_name, client);
+                    outcome
+                }
+                Err((code, message)) => BucketOutcome::failure(bucket, code, message),
+            }
+        };
+
+        if report(formatter, outcome, &mut results, &mut exit_code) && stop_on_error {
+            break;
+        }
+    }
+
+    if formatter.is_json() {
+        formatter.json(&BucketResults { results });
+    }
+    exit_code
+}
+
+/// Print or collect one bucket's outcome; returns true if it failed
+///
+/// The exit code of the first failure is kept as the command's exit code.
+fn report(
+    formatter: &Formatter,
+    outcome: BucketOutcome,
+    results: &mut Vec<BucketResult>,
+    exit_code: &mut ExitCode,
+) -> bool {
+    let failed = outcome.code != ExitCode::Success;
+    if failed && *exit_code == ExitCode::Success {
+        *exit_code = outcome.code;
+    }
+
+    if formatter.is_json() {
+        results.push(outcome.output);
+    } else if failed {
+        formatter.error(&outcome.text);
+    } else {
+        formatter.success(&outcome.text);
+    }
+    failed
+}
+
+/// Resolve an alias and create a client for its backend
+async fn connect(
+    alias_manager: &AliasManager,
+    alias_name: &str,
+) -> Result<Box<dyn ObjectStore>, (ExitCode, String)> {
+    let alias = resolve_alias(alias_manager, alias_name).map_err(|_| {
+        (
+            ExitCode::NotFound,
+            format!("Alias '{alias_name}' not found"),
+        )
+    })?;
+
+    backend_registry()
+        .backend_for(&alias)
+        .await
+        .map_err(|e| match e {
+            rc_core::Error::UnsupportedFeature(_) | rc_core::Error::Config(_) => {
+                (ExitCode::from(&e), e.to_string())
+            }
+            e => (
+                ExitCode::NetworkError,
+                format!("Failed to create S3 client: {e}"),
+            ),
+        })
+}
+
+/// Parse every target up front so a typo fails before any bucket is touched
+pub(super) fn parse_targets(
+    targets: &[String],
+    parse: impl Fn(&str) -> Result<(String, String), String>,
+) -> Result<Vec<(String, String)>, String> {
+    targets.iter().map(|target| parse(target)).collect()
+}
+
+/// Parse a bucket target path into (alias, bucket)
+pub(super) fn parse_bucket_path(path: &str) -> Result<(String, String), String> {
+    let path = path.trim_end_matches('/');
+
+    if path.is_empty() {
+        return Err("Path cannot be empty".to_string());
+    }
+
+    let parts: Vec<&str> = path.splitn(2, '/').collect();
+
+    if parts.len() != 2 {
+        return Err(format!(
+            "Invalid path format: '{path}'. Expected: alias/bucket"
+        ));
+    }
+
+    let alias = parts[0].to_string();
+    let bucket = parts[1].to_string();
+
+    if bucket.is_empty() {
+        return Err("Bucket name cannot be empty".to_string());
+    }
+
+    Ok((alias, bucket))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::OutputConfig;
+
+    #[test]
+    fn test_parse_bucket_path_valid() {
+        let (alias, bucket) = parse_bucket_path("myalias/mybucket").unwrap();
+        assert_eq!(alias, "myalias");
+        assert_eq!(bucket, "mybucket");
+    }
+
+    #[test]
+    fn test_parse_bucket_path_trailing_slash() {
+        let (alias, bucket) = parse_bucket_path("myalias/mybucket/").unwrap();
+        assert_eq!(alias, "myalias");
+        assert_eq!(bucket, "mybucket");
+    }
+
+    #[test]
+    fn test_parse_bucket_path_errors() {
+        assert!(parse_bucket_path("").is_err());
+        assert!(parse_bucket_path("myalias").is_err());
+        assert!(parse_bucket_path("myalias/").is_err());
+    }
+
+    #[test]
+    fn test_parse_targets_stops_at_first_bad_path() {
+        let targets = vec!["local/a-bucket".to_string(), "local".to_string()];
+        assert!(parse_targets(&targets, parse_bucket_path).is_err());
+
+        let targets = vec!["local/a-bucket".to_string(), "other/b-bucket".to_string()];
+        let parsed = parse_targets(&targets, parse_bucket_path).unwrap();
+        assert_eq!(parsed[1], ("other".to_string(), "b-bucket".to_string()));
+    }
+
+    #[test]
+    fn test_report_keeps_first_failure_code() {
+        let formatter = Formatter::new(OutputConfig {
+            json: true,
+            ..Default::default()
+        });
+        let mut results = Vec::new();
+        let mut exit_code = ExitCode::Success;
+
+        let ok = BucketOutcome::success("a-bucket", "success", None, "created".to_string());
+        assert!(!report(&formatter, ok, &mut results, &mut exit_code));
+
+        let conflict = BucketOutcome::failure("b-bucket", ExitCode::Conflict, "exists".to_string());
+        assert!(report(&formatter, conflict, &mut results, &mut exit_code));
+
+        let denied = BucketOutcome::failure("c-bucket", ExitCode::AuthError, "denied".to_string());
+        assert!(report(&formatter, denied, &mut results, &mut exit_code));
+
+        assert_eq!(exit_code, ExitCode::Conflict);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].status, "error");
+        assert_eq!(results[1].message.as_deref(), Some("exists"));
+    }
+
+    #[test]
+    fn test_results_serialize_as_object_with_array() {
+        let results = BucketResults {
+            results: vec![
+                BucketOutcome::success("a-bucket", "success", None, String::new()).output,
+            ],
+        };
+
+        let value = serde_json::to_value(&results).expect("serialize results");
+        assert_eq!(value["results"][0]["status"], "success");
+        assert_eq!(value["results"][0]["bucket"], "a-bucket");
+        assert!(value["results"][0].get("message").is_none());
+    }
+}
@@ -1,19 +1,22 @@
 //! cat command - Display object contents
 //!
-//! Outputs the entire content of an object to stdout.
+//! Outputs the content of an object to stdout, optionally limited to a byte
+//! range or a range of lines, and optionally inflating gzip data. A plain
+//! http(s) URL is fetched without signing.
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _, RemotePath};
-use rc_s3::S3Client;
-use std::io::{self, Write};
+use rc_core::{AliasManager, ObjectStore as _, ParsedPath, RemotePath, parse_path};
+use rc_s3::{S3Client, UrlBody};
+use std::io::{self, Read, Write};
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
 /// Display object contents
 #[derive(Args, Debug)]
 pub struct CatArgs {
-    /// Object path (alias/bucket/key)
+    /// Object path (alias/bucket/key) or http(s) URL
     pub path: String,
 
     /// Encrypt/decrypt with the given key (base64 encoded)
@@ -27,12 +30,49 @@ pub struct CatArgs {
     /// Specific version ID to retrieve
     #[arg(long)]
     pub version_id: Option<String>,
+
+    /// Start reading at this byte offset
+    #[arg(long, value_name = "BYTES", conflicts_with = "line_range")]
+    pub offset: Option<u64>,
+
+    /// Read at most this many bytes
+    #[arg(long, value_name = "BYTES", conflicts_with = "line_range")]
+    pub length: Option<u64>,
+
+    /// Print only lines START to END (1-based, inclusive; e.g. 10:20, 100:, :5)
+    #[arg(long, value_name = "START:END")]
+    pub line_range: Option<String>,
+
+    /// Inflate gzip-compressed content (default: raw passthrough)
+    #[arg(long, conflicts_with_all = ["offset", "length"])]
+    pub decompress: bool,
 }
 
+/// Magic bytes at the start of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
 /// Execute the cat command
 pub async fn execute(args: CatArgs, output_config: OutputConfig) -> ExitCode {
     let formatter = Formatter::new(output_config);
 
+    let line_range = match args.line_range.as_deref().map(parse_line_range) {
+        Some(Ok(range)) => Some(range),
+        Some(Err(e)) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+        None => None,
+    };
+
+    if args.length == Some(0) {
+        formatter.error("--length must be greater than zero");
+        return ExitCode::UsageError;
+    }
+
+    if let Ok(ParsedPath::Url(url)) = parse_path(&args.path) {
+        return cat_url(&url, &args, line_range, &formatter).await;
+    }
+
     // Parse the path
     let (alias_name, bucket, key) = match parse_cat_path(&args.path) {
         Ok(parsed) => parsed,
@@ -51,7 +91,7 @@ pub async fn execute(args: CatArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -70,30 +110,189 @@ pub async fn execute(args: CatArgs, output_config: OutputConfig) -> ExitCode {
 
     let path = RemotePath::new(&alias_name, &bucket, &key);
 
-    // Get object content
-    match client.get_object(&path).await {
-        Ok(data) => {
+    // Get object content, fetching only the requested bytes when a range is given
+    let result = if args.offset.is_some() || args.length.is_some() {
+        client
+            .get_object_range(&path, args.offset.unwrap_or(0), args.length)
+            .await
+    } else {
+        client.get_object(&path).await
+    };
+
+    match result {
+        Ok(mut data) => {
+            if args.decompress && data.starts_with(&GZIP_MAGIC) {
+                data = match gunzip(&data) {
+                    Ok(inflated) => inflated,
+                    Err(e) => {
+                        formatter.error(&format!("Failed to decompress {}: {e}", args.path));
+                        return ExitCode::GeneralError;
+                    }
+                };
+            }
+            let data = match line_range {
+                Some((start, end)) => select_lines(&data, start, end),
+                None => &data[..],
+            };
+
             // Write directly to stdout (not through formatter to preserve binary data)
-            if let Err(e) = io::stdout().write_all(&data) {
+            if let Err(e) = io::stdout().write_all(data) {
                 formatter.error(&format!("Failed to write to stdout: {e}"));
                 return ExitCode::GeneralError;
             }
             ExitCode::Success
         }
+        Err(rc_core::Error::NotFound(_)) => {
+            formatter.error(&format!("Object not found: {}", args.path));
+            ExitCode::NotFound
+        }
+        Err(rc_core::Error::Auth(_)) => {
+            formatter.error(&format!("Access denied: {}", args.path));
+            ExitCode::AuthError
+        }
+        Err(e @ rc_core::Error::InvalidArgument(_)) => {
+            formatter.error(&e.to_string());
+            ExitCode::UsageError
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to get object: {e}"));
+            ExitCode::NetworkError
+        }
+    }
+}
+
+/// Print a plain http(s) URL, streaming the body unless it has to be transformed
+async fn cat_url(
+    url: &url::Url,
+    args: &CatArgs,
+    line_range: Option<(usize, Option<usize>)>,
+    formatter: &Formatter,
+) -> ExitCode {
+    if args.version_id.is_some() || args.rewind.is_some() || args.enc_key.is_some() {
+        formatter.error("--version-id, --rewind and --enc-key cannot be used with a URL");
+        return ExitCode::UsageError;
+    }
+
+    let mut body = match UrlBody::get(url, args.offset.unwrap_or(0), args.length).await {
+        Ok(body) => body,
         Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                formatter.error(&format!("Object not found: {}", args.path));
-                ExitCode::NotFound
-            } else if err_str.contains("AccessDenied") {
-                formatter.error(&format!("Access denied: {}", args.path));
-                ExitCode::AuthError
-            } else {
-                formatter.error(&format!("Failed to get object: {e}"));
-                ExitCode::NetworkError
+            formatter.error(&format!("Failed to get {}: {e}", url_display(url)));
+            return ExitCode::from(&e);
+        }
+    };
+
+    let mut stdout = io::stdout().lock();
+    if args.decompress || line_range.is_some() {
+        let mut data = match body.bytes().await {
+            Ok(data) => data,
+            Err(e) => {
+                formatter.error(&format!("Failed to get {}: {e}", url_display(url)));
+                return ExitCode::from(&e);
+            }
+        };
+        if args.decompress && data.starts_with(&GZIP_MAGIC) {
+            data = match gunzip(&data) {
+                Ok(inflated) => inflated,
+                Err(e) => {
+                    formatter.error(&format!("Failed to decompress {}: {e}", url_display(url)));
+                    return ExitCode::GeneralError;
+                }
+            };
+        }
+        let data = match line_range {
+            Some((start, end)) => select_lines(&data, start, end),
+            None => &data[..],
+        };
+        if let Err(e) = stdout.write_all(data) {
+            formatter.error(&format!("Failed to write to stdout: {e}"));
+            return ExitCode::GeneralError;
+        }
+        return ExitCode::Success;
+    }
+
+    loop {
+        match body.chunk().await {
+            Ok(Some(chunk)) => {
+                if let Err(e) = stdout.write_all(&chunk) {
+                    formatter.error(&format!("Failed to write to stdout: {e}"));
+                    return ExitCode::GeneralError;
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                formatter.error(&format!("Failed to get {}: {e}", url_display(url)));
+                return ExitCode::from(&e);
             }
         }
     }
+    if let Err(e) = stdout.flush() {
+        formatter.error(&format!("Failed to write to stdout: {e}"));
+        return ExitCode::GeneralError;
+    }
+    ExitCode::Success
+}
+
+/// URL without its query, which holds the credentials of a presigned URL
+fn url_display(url: &url::Url) -> String {
+    let mut shown = url.clone();
+    shown.set_query(None);
+    shown.to_string()
+}
+
+/// Parse a `START:END` line range (1-based, inclusive, either side optional)
+fn parse_line_range(range: &str) -> Result<(usize, Option<usize>), String> {
+    let invalid = || format!("Invalid line range: '{range}' (expected START:END)");
+    let (start, end) = range.split_once(':').ok_or_else(invalid)?;
+
+    let start = match start.trim() {
+        "" => 1,
+        s => s.parse::<usize>().map_err(|_| invalid())?,
+    };
+    let end = match end.trim() {
+        "" => None,
+        e => Some(e.parse::<usize>().map_err(|_| invalid())?),
+    };
+
+    if start == 0 {
+        return Err("Line numbers start at 1".to_string());
+    }
+    if let Some(end) = end
+        && end < start
+    {
+        return Err(format!(
+            "Invalid line range: '{range}' (END is before START)"
+        ));
+    }
+
+    Ok((start, end))
+}
+
+/// Slice out lines `start..=end` (1-based), keeping their line endings
+fn select_lines(data: &[u8], start: usize, end: Option<usize>) -> &[u8] {
+    let mut line = 1;
+    let mut begin = if start == 1 { Some(0) } else { None };
+
+    for (i, byte) in data.iter().enumerate() {
+        if *byte != b'\n' {
+            continue;
+        }
+        if Some(line) == end {
+            return &data[begin.unwrap_or(data.len())..=i];
+        }
+        line += 1;
+        if line == start {
+            begin = Some(i + 1);
+        }
+    }
+
+    &data[begin.unwrap_or(data.len())..]
+}
+
+/// Inflate gzip data (including multi-member streams)
+fn gunzip(data: &[u8]) -> io::Result<Vec<u8>> {
+    let mut inflated = Vec::new();
+    flate2::read::MultiGzDecoder::new(data).read_to_end(&mut inflated)?;
+    Ok(inflated)
 }
 
 /// Parse cat path into (alias, bucket, key)
@@ -129,6 +328,12 @@ fn parse_cat_path(path: &str) -> Result<(String, String, String), String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_url_display_drops_query() {
+        let url = url::Url::parse("https://host/bucket/key?X-Amz-Signature=abc").unwrap();
+        assert_eq!(url_display(&url), "https://host/bucket/key");
+    }
+
     #[test]
     fn test_parse_cat_path_valid() {
         let (alias, bucket, key) = parse_cat_path("myalias/mybucket/file.txt").unwrap();
@@ -154,4 +359,35 @@ mod tests {
     fn test_parse_cat_path_empty() {
         assert!(parse_cat_path("").is_err());
     }
+
+    #[test]
+    fn test_parse_line_range() {
+        assert_eq!(parse_line_range("10:20").unwrap(), (10, Some(20)));
+        assert_eq!(parse_line_range("100:").unwrap(), (100, None));
+        assert_eq!(parse_line_range(":5").unwrap(), (1, Some(5)));
+        assert!(parse_line_range("0:5").is_err());
+        assert!(parse_line_range("20:10").is_err());
+        assert!(parse_line_range("10").is_err());
+    }
+
+    #[test]
+    fn test_select_lines() {
+        let data = b"one\ntwo\nthree\nfour";
+        assert_eq!(select_lines(data, 2, Some(3)), b"two\nthree\n");
+        assert_eq!(select_lines(data, 3, None), b"three\nfour");
+        assert_eq!(select_lines(data, 1, Some(1)), b"one\n");
+        assert_eq!(select_lines(data, 9, None), b"");
+    }
+
+    #[test]
+    fn test_gunzip() {
+        use flate2::{Compression, write::GzEncoder};
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"hello gzip").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert!(compressed.starts_with(&GZIP_MAGIC));
+        assert_eq!(gunzip(&compressed).unwrap(), b"hello gzip");
+    }
 }
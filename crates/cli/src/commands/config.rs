@@ -0,0 +1,364 @@
+//! Configuration file commands
+//!
+//! Locate, inspect, migrate and encrypt the rc configuration file. The location
+//! honours `RC_CONFIG_DIR`; secrets are never printed.
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use crate::exit_code::ExitCode;
+use crate::output::{Formatter, OutputConfig};
+use rc_core::alias::{RetryConfig, TimeoutConfig};
+use rc_core::secret::{self, PASSPHRASE_ENV};
+use rc_core::{Alias, AliasManager, Config, ConfigManager, Defaults};
+
+/// Configuration subcommands
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Print the configuration file location
+    Path,
+
+    /// Show the configuration without credentials
+    Show,
+
+    /// Rewrite the configuration file in the current format
+    Migrate,
+
+    /// Encrypt stored secret keys with the passphrase in RC_CONFIG_PASSPHRASE
+    Encrypt,
+
+    /// Store secret keys in plaintext again (needs RC_CONFIG_PASSPHRASE)
+    Decrypt,
+}
+
+/// JSON output for `config path`
+#[derive(Serialize)]
+struct ConfigPathOutput {
+    path: String,
+    dir: String,
+    exists: bool,
+    /// `RC_CONFIG_DIR` when the environment overrides the default location
+    source: &'static str,
+}
+
+/// JSON output for `config show`
+#[derive(Serialize)]
+struct ConfigShowOutput {
+    path: String,
+    schema_version: u32,
+    secrets_encrypted: bool,
+    defaults: Defaults,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_alias: Option<String>,
+    aliases: Vec<AliasSettings>,
+}
+
+/// Alias settings without the access and secret keys
+#[derive(Serialize)]
+struct AliasSettings {
+    name: String,
+    endpoint: String,
+    region: String,
+    signature: String,
+    bucket_lookup: String,
+    insecure: bool,
+    anonymous: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ca_bundle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tls_min_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_cert: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_key: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    resolve: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry: Option<RetryConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<TimeoutConfig>,
+}
+
+impl From<Alias> for AliasSettings {
+    fn from(alias: Alias) -> Self {
+        Self {
+            name: alias.name,
+            endpoint: alias.endpoint,
+            region: alias.region,
+            signature: alias.signature,
+            bucket_lookup: alias.bucket_lookup,
+            insecure: alias.insecure,
+            anonymous: alias.anonymous,
+            ca_bundle: alias.ca_bundle,
+            tls_min_version: alias.tls_min_version,
+            client_cert: alias.client_cert,
+            client_key: alias.client_key,
+            resolve: alias.resolve,
+            retry: alias.retry,
+            timeout: alias.timeout,
+        }
+    }
+}
+
+/// JSON output for `config migrate`
+#[derive(Serialize)]
+struct ConfigMigrateOutput {
+    path: String,
+    from_version: u32,
+    to_version: u32,
+    changes: Vec<String>,
+}
+
+/// JSON output for `config encrypt` and `config decrypt`
+#[derive(Serialize)]
+struct ConfigSecretsOutput {
+    path: String,
+    encrypted: bool,
+    changed: usize,
+}
+
+/// Execute a config subcommand
+pub fn execute(cmd: ConfigCommands, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+    let manager = match ConfigManager::new() {
+        Ok(m) => m,
+        Err(e) => {
+            formatter.error(&format!("Failed to locate configuration: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    match cmd {
+        ConfigCommands::Path => execute_path(&manager, &formatter),
+        ConfigCommands::Show => execute_show(&manager, &formatter),
+        ConfigCommands::Migrate => execute_migrate(&manager, &formatter),
+        ConfigCommands::Encrypt => execute_secrets(manager, true, &formatter),
+        ConfigCommands::Decrypt => execute_secrets(manager, false, &formatter),
+    }
+}
+
+fn execute_path(manager: &ConfigManager, formatter: &Formatter) -> ExitCode {
+    let path = manager.config_path();
+    let output = ConfigPathOutput {
+        path: path.display().to_string(),
+        dir: path
+            .parent()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default(),
+        exists: path.exists(),
+        source: if std::env::var_os("RC_CONFIG_DIR").is_some() {
+            "RC_CONFIG_DIR"
+        } else {
+            "default"
+        },
+    };
+
+    if formatter.is_json() {
+        formatter.json(&output);
+    } else {
+        formatter.println(&output.path);
+        if !output.exists {
+            formatter.warning("The file does not exist yet; it is created by `rc alias set`.");
+        }
+    }
+    ExitCode::Success
+}
+
+fn execute_show(manager: &ConfigManager, formatter: &Formatter) -> ExitCode {
+    let config = match manager.load() {
+        Ok(c) => c,
+        Err(e) => {
+            formatter.error(&format!("Failed to load configuration: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    let output = show_output(manager, config);
+    if formatter.is_json() {
+        formatter.json(&output);
+        return ExitCode::Success;
+    }
+
+    let kv = |key: &str, value: &str| {
+        formatter.println(&format!(
+            "{} : {value}",
+            formatter.style_key(&format!("{key:<14}"))
+        ));
+    };
+    kv("Path", &output.path);
+    kv("Schema", &output.schema_version.to_string());
+    kv(
+        "Secrets",
+        if output.secrets_encrypted {
+            "encrypted"
+        } else {
+            "plaintext"
+        },
+    );
+    kv("Output", &output.defaults.output);
+    kv("Color", &output.defaults.color);
+    kv("Progress", &output.defaults.progress.to_string());
+    kv(
+        "Current alias",
+        output.current_alias.as_deref().unwrap_or("(none)"),
+    );
+
+    for alias in &output.aliases {
+        formatter.println("");
+        formatter.println(&formatter.style_name(&alias.name));
+        kv("  Endpoint", &formatter.style_url(&alias.endpoint));
+        kv("  Region", &alias.region);
+        kv("  Signature", &alias.signature);
+        kv("  Bucket lookup", &alias.bucket_lookup);
+        if alias.insecure {
+            kv("  Insecure", "true");
+        }
+        if alias.anonymous {
+            kv("  Anonymous", "true");
+        }
+        if let Some(ca) = &alias.ca_bundle {
+            kv("  CA bundle", ca);
+        }
+        if let Some(version) = &alias.tls_min_version {
+            kv("  Min TLS version", version);
+        }
+        if let Some(cert) = &alias.client_cert {
+            kv("  Client cert", cert);
+        }
+        if let Some(key) = &alias.client_key {
+            kv("  Client key", key);
+        }
+        if !alias.resolve.is_empty() {
+            kv("  Resolve", &alias.resolve.join(", "));
+        }
+        if let Some(retry) = &alias.retry {
+            kv(
+                "  Retry",
+                &format!(
+                    "{} attempts, backoff {}-{} ms",
+                    retry.max_attempts, retry.initial_backoff_ms, retry.max_backoff_ms
+                ),
+            );
+        }
+        if let Some(timeout) = &alias.timeout {
+            kv(
+                "  Timeout",
+                &format!(
+                    "connect {} ms, read {} ms",
+                    timeout.connect_ms, timeout.read_ms
+                ),
+            );
+        }
+    }
+    ExitCode::Success
+}
+
+fn show_output(manager: &ConfigManager, config: Config) -> ConfigShowOutput {
+    ConfigShowOutput {
+        path: manager.config_path().display().to_string(),
+        schema_version: config.schema_version,
+        secrets_encrypted: config
+            .aliases
+            .iter()
+            .any(|a| secret::is_encrypted(&a.secret_key)),
+        defaults: config.defaults,
+        current_alias: config.current_alias,
+        aliases: config
+            .aliases
+            .into_iter()
+            .map(AliasSettings::from)
+            .collect(),
+    }
+}
+
+fn execute_migrate(manager: &ConfigManager, formatter: &Formatter) -> ExitCode {
+    let report = match manager.migrate_file() {
+        Ok(r) => r,
+        Err(e) => {
+            formatter.error(&format!("Failed to migrate configuration: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    let output = ConfigMigrateOutput {
+        path: manager.config_path().display().to_string(),
+        from_version: report.from_version,
+        to_version: report.to_version,
+        changes: report.changes,
+    };
+
+    if formatter.is_json() {
+        formatter.json(&output);
+    } else if output.changes.is_empty() {
+        formatter.success("Configuration is already up to date.");
+    } else {
+        for change in &output.changes {
+            formatter.println(&format!("  {change}"));
+        }
+        formatter.success(&format!("Migrated {}.", output.path));
+    }
+    ExitCode::Success
+}
+
+fn execute_secrets(manager: ConfigManager, encrypt: bool, formatter: &Formatter) -> ExitCode {
+    let Some(passphrase) = secret::passphrase_from_env() else {
+        formatter.error(&format!(
+            "Set {PASSPHRASE_ENV} to the passphrase; it is never read from the command line."
+        ));
+        return ExitCode::UsageError;
+    };
+
+    let path = manager.config_path().display().to_string();
+    let aliases = AliasManager::with_config_manager(manager);
+    let result = if encrypt {
+        aliases.encrypt_secrets(&passphrase)
+    } else {
+        aliases.decrypt_secrets(&passphrase)
+    };
+    let changed = match result {
+        Ok(n) => n,
+        Err(e) => {
+            let action = if encrypt { "encrypt" } else { "decrypt" };
+            formatter.error(&format!("Failed to {action} secrets: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    if formatter.is_json() {
+        formatter.json(&ConfigSecretsOutput {
+            path,
+            encrypted: encrypt,
+            changed,
+        });
+    } else if changed == 0 {
+        let state = if encrypt { "encrypted" } else { "in plaintext" };
+        formatter.success(&format!("All secret keys are already {state}."));
+    } else if encrypt {
+        formatter.success(&format!("Encrypted {changed} secret key(s) in {path}."));
+    } else {
+        formatter.success(&format!("Decrypted {changed} secret key(s) in {path}."));
+    }
+    ExitCode::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show_output_hides_credentials() {
+        let mut config = Config::default();
+        config.aliases.push(Alias::new(
+            "local",
+            "http://localhost:9000",
+            "AKIA",
+            "s3cr3t",
+        ));
+        let manager = ConfigManager::with_path("/tmp/rc/config.toml".into());
+
+        let json = serde_json::to_string(&show_output(&manager, config)).unwrap();
+        assert!(json.contains("\"name\":\"local\""));
+        assert!(!json.contains("AKIA"));
+        assert!(!json.contains("s3cr3t"));
+    }
+}
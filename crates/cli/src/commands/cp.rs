@@ -1,15 +1,30 @@
 //! cp command - Copy objects
 //!
 //! Copies objects between local filesystem and S3, or between S3 locations.
+//! A plain http(s) URL source is downloaded without signing.
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _, ParsedPath, RemotePath, parse_path};
-use rc_s3::S3Client;
+use futures::stream::{self, StreamExt};
+use rc_core::{
+    Acl, Alias, AliasManager, CannedAcl, ConfigManager, ObjectInfo, ObjectStore as _, Owner,
+    ParsedPath, PutOptions, RemotePath, parse_path,
+};
+use rc_s3::multipart::{MAX_PART_SIZE, MAX_PARTS, MIN_PART_SIZE};
+use rc_s3::multipart::{calculate_parts, part_byte_range};
+use rc_s3::{MultipartConfig, S3Client, UploadState, UrlBody};
 use serde::Serialize;
-use std::path::Path;
-
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::commands::{
+    page_size, parse_attr, parse_header_value, parse_size, read_path_list, resolve_alias,
+};
+use crate::compression::Compression;
 use crate::exit_code::ExitCode;
-use crate::output::{Formatter, OutputConfig};
+use crate::filter::PathFilter;
+use crate::interrupt;
+use crate::output::{Formatter, OutputConfig, ProgressBar};
 
 /// Copy objects
 #[derive(Args, Debug)]
@@ -24,7 +39,12 @@ pub struct CpArgs {
     #[arg(short, long)]
     pub recursive: bool,
 
-    /// Preserve file attributes
+    /// Copy only the paths listed in FILE (one per line, relative to SOURCE; `-` for stdin)
+    #[arg(long, value_name = "FILE")]
+    pub files_from: Option<String>,
+
+    /// Preserve attributes: mtime metadata on upload, and headers, metadata
+    /// and tags on S3-to-S3 copies
     #[arg(short, long)]
     pub preserve: bool,
 
@@ -44,9 +64,115 @@ pub struct CpArgs {
     #[arg(long)]
     pub storage_class: Option<String>,
 
-    /// Content type for uploaded files
+    /// Content type for uploaded files (overrides detection)
     #[arg(long)]
     pub content_type: Option<String>,
+
+    /// Send no content type and let the server choose its default
+    #[arg(long, conflicts_with = "content_type")]
+    pub no_guess_content_type: bool,
+
+    /// Cache-Control header for uploaded files (e.g. "public, max-age=3600")
+    #[arg(long, value_parser = parse_header_value)]
+    pub cache_control: Option<String>,
+
+    /// Content-Disposition header for uploaded files (e.g. "attachment")
+    #[arg(long, value_parser = parse_header_value)]
+    pub content_disposition: Option<String>,
+
+    /// Print a line for every file in recursive copies
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Discard partial downloads (`<file>.part`) instead of resuming them
+    #[arg(long)]
+    pub no_resume: bool,
+
+    /// Save interrupted or failed multipart uploads and continue saved ones instead of aborting them
+    #[arg(long, conflicts_with = "no_resume")]
+    pub resume: bool,
+
+    /// Compress uploads client-side and set Content-Encoding
+    #[arg(long, value_enum, value_name = "CODEC")]
+    pub compress: Option<Compression>,
+
+    /// Decompress downloads stored with a gzip or zstd Content-Encoding
+    #[arg(long)]
+    pub decompress: bool,
+
+    /// Download every multi-chunk object with concurrent ranged requests
+    /// (automatic for objects of 64 MiB and more)
+    #[arg(long)]
+    pub parallel_download: bool,
+
+    /// Ranged requests in flight per parallel download, and parts per multipart upload
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+
+    /// Part size for multipart uploads (e.g. 16MiB, 1G; 5 MiB to 5 GiB);
+    /// picked per file when unset
+    #[arg(long, value_name = "SIZE", value_parser = parse_part_size)]
+    pub part_size: Option<u64>,
+
+    /// Parts in flight per multipart upload (overrides --concurrency for uploads)
+    #[arg(long, value_name = "N")]
+    pub upload_concurrency: Option<usize>,
+
+    /// Check downloads against the object's SHA-256 checksum, when it has one
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Keys requested per listing page for recursive copies (1-1000, default 1000)
+    #[arg(long, value_name = "N")]
+    pub page_size: Option<i32>,
+
+    /// Only copy paths matching this glob in recursive copies (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Skip paths matching this glob in recursive copies (repeatable, wins over --include)
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Upload the targets of symlinks in recursive uploads (skipped by default)
+    #[arg(long)]
+    pub follow_symlinks: bool,
+
+    /// Keep empty directories as zero-byte `dir/` marker objects, and recreate them on download
+    #[arg(long)]
+    pub preserve_empty_dirs: bool,
+
+    /// User metadata to set, as KEY=VALUE (repeatable)
+    #[arg(long = "attr", value_name = "KEY=VALUE", value_parser = parse_attr)]
+    pub attr: Vec<(String, String)>,
+
+    /// Headers for S3-to-S3 copies: keep the source's (`copy`) or apply
+    /// --content-type, --cache-control, --content-disposition and --attr (`replace`)
+    #[arg(long, value_enum, value_name = "DIRECTIVE", default_value = "copy")]
+    pub metadata_directive: MetadataDirective,
+
+    /// Give S3-to-S3 copies the source object's ACL grants
+    #[arg(long, conflicts_with = "acl")]
+    pub preserve_acl: bool,
+
+    /// Canned ACL for uploaded and copied objects (e.g. private, public-read);
+    /// without it new objects get the bucket's default
+    #[arg(long, value_name = "CANNED")]
+    pub acl: Option<CannedAcl>,
+
+    /// Leave downloaded files with the current time instead of the object's
+    /// last-modified time
+    #[arg(long)]
+    pub no_preserve_mtime: bool,
+}
+
+/// How an S3-to-S3 copy treats the source's headers and user metadata
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MetadataDirective {
+    /// Keep the source's headers and metadata
+    Copy,
+    /// Replace them; copying an object onto itself edits it in place
+    Replace,
 }
 
 #[derive(Debug, Serialize)]
@@ -60,6 +186,36 @@ struct CpOutput {
     size_human: Option<String>,
 }
 
+/// A file that could not be copied
+#[derive(Debug, Serialize)]
+struct TransferFailure {
+    source: String,
+    target: String,
+    error: String,
+    #[serde(skip)]
+    code: ExitCode,
+}
+
+impl TransferFailure {
+    fn new(code: ExitCode, source: &str, target: &str, error: String) -> Self {
+        Self {
+            source: source.to_string(),
+            target: target.to_string(),
+            error,
+            code,
+        }
+    }
+}
+
+/// JSON summary of a recursive copy in which some files failed
+#[derive(Serialize)]
+struct CpFailureSummary {
+    status: &'static str,
+    succeeded: usize,
+    failed: usize,
+    failures: Vec<TransferFailure>,
+}
+
 /// Execute the cp command
 pub async fn execute(args: CpArgs, output_config: OutputConfig) -> ExitCode {
     let formatter = Formatter::new(output_config);
@@ -81,6 +237,53 @@ pub async fn execute(args: CpArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
+    if let Err(e) = PathFilter::new(&args.include, &args.exclude) {
+        formatter.error(&e);
+        return ExitCode::UsageError;
+    }
+
+    if args.metadata_directive == MetadataDirective::Replace {
+        if !matches!(
+            (&source, &target),
+            (ParsedPath::Remote(_), ParsedPath::Remote(_))
+        ) {
+            formatter.error("--metadata-directive replace only applies to S3-to-S3 copies");
+            return ExitCode::UsageError;
+        }
+        if replacement_options(&args).is_none() {
+            formatter.error(
+                "--metadata-directive replace needs --content-type, --cache-control, \
+                 --content-disposition or --attr",
+            );
+            return ExitCode::UsageError;
+        }
+    }
+
+    if args.preserve_acl
+        && !matches!(
+            (&source, &target),
+            (ParsedPath::Remote(_), ParsedPath::Remote(_))
+        )
+    {
+        formatter.error("--preserve-acl only applies to S3-to-S3 copies");
+        return ExitCode::UsageError;
+    }
+    if args.acl.is_some() && !target.is_remote() {
+        formatter.error("--acl only applies to uploads and S3-to-S3 copies");
+        return ExitCode::UsageError;
+    }
+
+    if let Some(list) = &args.files_from {
+        let entries = match read_file_list(list) {
+            Ok(entries) => entries,
+            Err(e) => {
+                formatter.error(&e);
+                return ExitCode::UsageError;
+            }
+        };
+        return copy_file_list(&source, &target, &entries, &args, &formatter).await;
+    }
+
     // Determine copy direction
     match (&source, &target) {
         (ParsedPath::Local(src), ParsedPath::Remote(dst)) => {
@@ -95,13 +298,147 @@ pub async fn execute(args: CpArgs, output_config: OutputConfig) -> ExitCode {
             // S3 to S3
             copy_s3_to_s3(src, dst, &args, &formatter).await
         }
+        (ParsedPath::Url(src), ParsedPath::Local(dst)) => {
+            copy_url_to_local(src, dst, &args, &formatter).await
+        }
         (ParsedPath::Local(_), ParsedPath::Local(_)) => {
             formatter.error("Cannot copy between two local paths. Use system cp command.");
             ExitCode::UsageError
         }
+        (_, ParsedPath::Url(_)) => {
+            formatter.error("Cannot copy to a URL. Use an alias path as the target.");
+            ExitCode::UsageError
+        }
+        (ParsedPath::Url(_), ParsedPath::Remote(_)) => {
+            formatter.error("URL sources can only be copied to a local path");
+            ExitCode::UsageError
+        }
+    }
+}
+
+/// Download a plain http(s) URL, such as a presigned or public object URL
+async fn copy_url_to_local(
+    src: &url::Url,
+    dst: &Path,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> ExitCode {
+    if args.recursive {
+        formatter.error("--recursive cannot be used with a URL source");
+        return ExitCode::UsageError;
+    }
+
+    let dst_path = if dst.is_dir() || dst.to_string_lossy().ends_with('/') {
+        match url_file_name(src) {
+            Some(name) => dst.join(name),
+            None => {
+                formatter.error(&format!(
+                    "Cannot derive a file name from {src}. Give a file path as the target."
+                ));
+                return ExitCode::UsageError;
+            }
+        }
+    } else {
+        dst.to_path_buf()
+    };
+
+    // Presigned URLs carry credentials in the query; keep them out of the output
+    let mut shown = src.clone();
+    shown.set_query(None);
+    let src_display = shown.to_string();
+    let dst_display = dst_path.display().to_string();
+
+    if args.dry_run {
+        let styled_src = formatter.style_file(&src_display);
+        let styled_dst = formatter.style_file(&dst_display);
+        formatter.println(&format!("Would copy: {styled_src} -> {styled_dst}"));
+        return ExitCode::Success;
+    }
+
+    if dst_path.exists() && !args.overwrite {
+        formatter.error(&format!(
+            "Destination exists: {dst_display}. Use --overwrite to replace."
+        ));
+        return ExitCode::Conflict;
+    }
+
+    let size = match fetch_url(src, &dst_path).await {
+        Ok(size) => size as i64,
+        Err(e) => {
+            let _ = std::fs::remove_file(&dst_path);
+            formatter.error(&format!("Failed to download {src_display}: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    if show_file_lines(args, formatter) {
+        if formatter.is_json() {
+            let output = CpOutput {
+                status: "success",
+                source: src_display,
+                target: dst_display,
+                size_bytes: Some(size),
+                size_human: Some(humansize::format_size(size as u64, humansize::BINARY)),
+            };
+            formatter.json(&output);
+        } else {
+            let styled_src = formatter.style_file(&src_display);
+            let styled_dst = formatter.style_file(&dst_display);
+            let styled_size =
+                formatter.style_size(&humansize::format_size(size as u64, humansize::BINARY));
+            formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+        }
+    }
+    ExitCode::Success
+}
+
+/// Last non-empty path segment of a URL, used as the local file name
+fn url_file_name(url: &url::Url) -> Option<String> {
+    url.path_segments()?
+        .rev()
+        .find(|segment| !segment.is_empty())
+        .map(str::to_string)
+}
+
+/// Stream a URL's body into `dst`, returning the number of bytes written
+///
+/// The body is written to `<dst>.part` and renamed over `dst` once complete,
+/// so a failed download never leaves a truncated file under the real name.
+async fn fetch_url(src: &url::Url, dst: &Path) -> rc_core::Result<u64> {
+    let mut body = UrlBody::get(src, 0, None).await?;
+    if let Some(parent) = dst.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let part = part_path(dst);
+    match write_body(&mut body, &part).await {
+        Ok(written) => {
+            std::fs::rename(&part, dst)?;
+            Ok(written)
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&part);
+            Err(e)
+        }
     }
 }
 
+/// Write the rest of `body` to a new file at `path`
+async fn write_body(body: &mut UrlBody, path: &Path) -> rc_core::Result<u64> {
+    use std::io::Write;
+
+    let mut file = std::fs::File::create(path)?;
+    let mut written = 0u64;
+    while let Some(chunk) = body.chunk().await? {
+        file.write_all(&chunk)?;
+        written += chunk.len() as u64;
+    }
+    file.flush()?;
+    Ok(written)
+}
+
 async fn copy_local_to_s3(
     src: &Path,
     dst: &RemotePath,
@@ -129,7 +466,7 @@ async fn copy_local_to_s3(
         }
     };
 
-    let alias = match alias_manager.get(&dst.alias) {
+    let alias = match resolve_alias(&alias_manager, &dst.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", dst.alias));
@@ -154,13 +491,179 @@ async fn copy_local_to_s3(
     }
 }
 
-async fn upload_file(
+/// Read a `--files-from` list into paths relative to the source
+fn read_file_list(list: &str) -> Result<Vec<String>, String> {
+    let lines = if list == "-" {
+        read_path_list(std::io::stdin().lock())
+            .map_err(|e| format!("Failed to read file list from stdin: {e}"))?
+    } else {
+        let file = std::fs::File::open(list)
+            .map_err(|e| format!("Failed to open file list {list}: {e}"))?;
+        read_path_list(std::io::BufReader::new(file))
+            .map_err(|e| format!("Failed to read file list {list}: {e}"))?
+    };
+    lines.iter().map(|line| list_entry(line)).collect()
+}
+
+/// Normalize one `--files-from` line into a relative `/`-separated path
+///
+/// Leading `./` and `/` are dropped; `..` is refused so nothing lands outside
+/// the destination.
+fn list_entry(line: &str) -> Result<String, String> {
+    let path = line.replace('\\', "/");
+    let parts: Vec<&str> = path
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+        .collect();
+
+    if parts.contains(&"..") {
+        return Err(format!("File list entry '{line}' must not contain '..'"));
+    }
+    if parts.is_empty() || path.ends_with('/') {
+        return Err(format!("File list entry '{line}' is not a file path"));
+    }
+    Ok(parts.join("/"))
+}
+
+/// Copy the files named by `--files-from`, keeping their relative paths
+///
+/// Uploads read each entry under the SOURCE directory; downloads read each
+/// entry as a key under the SOURCE prefix. Either way the entry's relative
+/// path is recreated under TARGET.
+async fn copy_file_list(
+    source: &ParsedPath,
+    target: &ParsedPath,
+    entries: &[String],
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> ExitCode {
+    let (alias_name, upload) = match (source, target) {
+        (ParsedPath::Local(src), ParsedPath::Remote(dst)) => {
+            if !src.is_dir() {
+                formatter.error(&format!(
+                    "--files-from needs a source directory: {}",
+                    src.display()
+                ));
+                return ExitCode::UsageError;
+            }
+            (&dst.alias, true)
+        }
+        (ParsedPath::Remote(src), ParsedPath::Local(_)) => (&src.alias, false),
+        _ => {
+            formatter.error("--files-from supports uploads and downloads only");
+            return ExitCode::UsageError;
+        }
+    };
+
+    let alias_manager = match AliasManager::new() {
+        Ok(am) => am,
+        Err(e) => {
+            formatter.error(&format!("Failed to load aliases: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    let alias = match resolve_alias(&alias_manager, alias_name) {
+        Ok(a) => a,
+        Err(_) => {
+            formatter.error(&format!("Alias '{alias_name}' not found"));
+            return ExitCode::NotFound;
+        }
+    };
+
+    let client = match S3Client::new(alias).await {
+        Ok(c) => c,
+        Err(e) => {
+            formatter.error(&format!("Failed to create S3 client: {e}"));
+            return ExitCode::NetworkError;
+        }
+    };
+
+    let filter = path_filter(args);
+    let progress = transfer_progress(args, formatter, None);
+    let mut success_count = 0;
+    let mut failures = Vec::new();
+
+    for relative in entries.iter().filter(|r| filter.matches(r)) {
+        progress.set_message(relative);
+        let (result, local) = match (source, target) {
+            (ParsedPath::Local(src), ParsedPath::Remote(dst)) => {
+                let local = src.join(relative);
+                let key = rebase_key("", relative, &dst.key);
+                let target = RemotePath::new(&dst.alias, &dst.bucket, key);
+                let result = try_upload_file(&client, &local, &target, args, formatter).await;
+                (result, local)
+            }
+            (ParsedPath::Remote(src), ParsedPath::Local(dst)) => {
+                let local = local_target(dst, "", relative);
+                let key = rebase_key("", relative, &src.key);
+                let object = RemotePath::new(&src.alias, &src.bucket, key);
+                let result =
+                    try_download_file(&client, &object, &local, None, args, formatter).await;
+                (result, local)
+            }
+            _ => continue,
+        };
+
+        match result {
+            Ok(()) => {
+                success_count += 1;
+                progress.inc(std::fs::metadata(&local).map_or(0, |m| m.len()));
+            }
+            Err(failure) => {
+                let code = record_failure(failure, &mut failures, formatter);
+                if !args.continue_on_error || code == ExitCode::Interrupted {
+                    progress.finish_and_clear();
+                    return code;
+                }
+            }
+        }
+    }
+    progress.finish_and_clear();
+
+    if !failures.is_empty() {
+        report_failures(success_count, failures, formatter)
+    } else {
+        if !formatter.is_json() {
+            let verb = if upload { "Uploaded" } else { "Downloaded" };
+            formatter.success(&format!("{verb} {success_count} file(s)."));
+        }
+        ExitCode::Success
+    }
+}
+
+pub(crate) async fn upload_file(
     client: &S3Client,
     src: &Path,
     dst: &RemotePath,
     args: &CpArgs,
     formatter: &Formatter,
 ) -> ExitCode {
+    exit_code(
+        try_upload_file(client, src, dst, args, formatter).await,
+        formatter,
+    )
+}
+
+/// Print a single transfer's failure, if any, and return its exit code
+fn exit_code(result: Result<(), TransferFailure>, formatter: &Formatter) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::Success,
+        Err(failure) => {
+            formatter.error(&failure.error);
+            failure.code
+        }
+    }
+}
+
+/// Upload one file, returning what failed instead of printing it
+async fn try_upload_file(
+    client: &S3Client,
+    src: &Path,
+    dst: &RemotePath,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> Result<(), TransferFailure> {
     // Determine destination key
     let dst_key = if dst.key.is_empty() || dst.key.ends_with('/') {
         // If destination is a directory, use source filename
@@ -178,53 +681,401 @@ async fn upload_file(
         let styled_src = formatter.style_file(&src_display);
         let styled_dst = formatter.style_file(&dst_display);
         formatter.println(&format!("Would copy: {styled_src} -> {styled_dst}"));
-        return ExitCode::Success;
+        return Ok(());
     }
+    let fail = |code, error| TransferFailure::new(code, &src_display, &dst_display, error);
 
     // Read file content
     let data = match std::fs::read(src) {
         Ok(d) => d,
         Err(e) => {
-            formatter.error(&format!("Failed to read {src_display}: {e}"));
-            return ExitCode::GeneralError;
+            return Err(fail(
+                ExitCode::GeneralError,
+                format!("Failed to read {src_display}: {e}"),
+            ));
         }
     };
 
+    let content_type = upload_content_type(args, client.alias(), src, &data);
+    let mut metadata = if args.preserve {
+        preserved_metadata(src)
+    } else {
+        HashMap::new()
+    };
+    metadata.extend(args.attr.iter().cloned());
+
+    // Compress the whole file up front so the stored size is the compressed size
+    let data = match args.compress {
+        Some(codec) => match codec.compress(&data) {
+            Ok(compressed) => compressed,
+            Err(e) => {
+                return Err(fail(
+                    ExitCode::GeneralError,
+                    format!("Failed to compress {src_display}: {e}"),
+                ));
+            }
+        },
+        None => data,
+    };
     let size = data.len() as i64;
 
-    // Determine content type
-    let guessed_type: Option<String> = mime_guess::from_path(src)
-        .first()
-        .map(|m| m.essence_str().to_string());
-    let content_type = args.content_type.as_deref().or(guessed_type.as_deref());
+    let options = PutOptions {
+        content_type,
+        content_encoding: args.compress.map(|c| c.encoding().to_string()),
+        cache_control: args.cache_control.clone(),
+        content_disposition: args.content_disposition.clone(),
+        metadata,
+        storage_class: args
+            .storage_class
+            .clone()
+            .or_else(|| client.alias().default_storage_class.clone()),
+    };
 
     // Upload
-    match client.put_object(&target, data, content_type).await {
+    let uploaded = if size as u64 > args.part_size.unwrap_or(MULTIPART_THRESHOLD) {
+        upload_multipart(client, &target, src, data, &options, args).await
+    } else {
+        client
+            .put_object_with_options(&target, data, &options)
+            .await
+            .map_err(|e| (ExitCode::NetworkError, e.to_string()))
+    };
+    match uploaded {
         Ok(info) => {
-            if formatter.is_json() {
-                let output = CpOutput {
-                    status: "success",
-                    source: src_display,
-                    target: dst_display,
-                    size_bytes: Some(size),
-                    size_human: Some(humansize::format_size(size as u64, humansize::BINARY)),
-                };
-                formatter.json(&output);
+            if let Some(acl) = args.acl
+                && let Err(e) = client.set_object_acl(&target, acl).await
+            {
+                return Err(fail(
+                    ExitCode::from(&e),
+                    format!("Uploaded {dst_display} but failed to apply ACL '{acl}': {e}"),
+                ));
+            }
+
+            if show_file_lines(args, formatter) {
+                if formatter.is_json() {
+                    let output = CpOutput {
+                        status: "success",
+                        source: src_display,
+                        target: dst_display,
+                        size_bytes: Some(size),
+                        size_human: Some(humansize::format_size(size as u64, humansize::BINARY)),
+                    };
+                    formatter.json(&output);
+                } else {
+                    let styled_src = formatter.style_file(&src_display);
+                    let styled_dst = formatter.style_file(&dst_display);
+                    let styled_size = formatter.style_size(&info.size_human.unwrap_or_default());
+                    formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+                }
+            }
+            Ok(())
+        }
+        Err((ExitCode::Interrupted, e)) => Err(fail(
+            ExitCode::Interrupted,
+            format!("Interrupted upload of {src_display}: {e}"),
+        )),
+        Err((code, e)) => Err(fail(code, format!("Failed to upload {src_display}: {e}"))),
+    }
+}
+
+/// Files larger than this are uploaded in parts, unless `--part-size` is given
+const MULTIPART_THRESHOLD: u64 = rc_s3::multipart::DEFAULT_PART_SIZE;
+
+/// Parse `--part-size`, which must lie within the S3 part size limits
+pub(crate) fn parse_part_size(s: &str) -> Result<u64, String> {
+    let size = parse_size(s)?;
+    if !(MIN_PART_SIZE..=MAX_PART_SIZE).contains(&size) {
+        return Err(format!(
+            "part size must be between {} and {}",
+            humansize::format_size(MIN_PART_SIZE, humansize::BINARY),
+            humansize::format_size(MAX_PART_SIZE, humansize::BINARY)
+        ));
+    }
+    Ok(size)
+}
+
+/// Multipart settings from `--part-size` and `--upload-concurrency`/`--concurrency`
+fn multipart_config(args: &CpArgs) -> MultipartConfig {
+    let config =
+        MultipartConfig::default().concurrency(args.upload_concurrency.unwrap_or(args.concurrency));
+    match args.part_size {
+        Some(size) => config.part_size(size),
+        None => config,
+    }
+}
+
+/// Part size for a multipart upload of `total_size` bytes
+///
+/// Without `--part-size` the size grows as needed to stay within
+/// `MAX_PARTS`. An explicit part size is used as given, so a file that would
+/// need more parts than that is an error instead.
+fn upload_part_size(
+    config: &MultipartConfig,
+    explicit: bool,
+    total_size: u64,
+) -> Result<u64, String> {
+    if !explicit {
+        return Ok(config.calculate_part_size(total_size));
+    }
+
+    let parts = total_size.div_ceil(config.part_size);
+    if parts > MAX_PARTS as u64 {
+        let minimum = total_size.div_ceil(MAX_PARTS as u64);
+        return Err(format!(
+            "--part-size {} splits {} into {parts} parts, over the {MAX_PARTS}-part limit; \
+             use at least {}",
+            humansize::format_size(config.part_size, humansize::BINARY),
+            humansize::format_size(total_size, humansize::BINARY),
+            humansize::format_size(minimum, humansize::BINARY)
+        ));
+    }
+    Ok(config.part_size)
+}
+
+/// Upload a large file as a multipart upload
+///
+/// Ctrl-C or a failed part stops the upload without leaving it orphaned: it
+/// is aborted on the server, or with `--resume` its state is saved and the
+/// next `--resume` copy of the same file to the same target continues from
+/// the parts already uploaded. The error says which of the two happened.
+async fn upload_multipart(
+    client: &S3Client,
+    target: &RemotePath,
+    src: &Path,
+    data: Vec<u8>,
+    options: &PutOptions,
+    args: &CpArgs,
+) -> Result<ObjectInfo, (ExitCode, String)> {
+    let config = multipart_config(args);
+    let total_size = data.len() as u64;
+    let part_size = upload_part_size(&config, args.part_size.is_some(), total_size)
+        .map_err(|e| (ExitCode::UsageError, e))?;
+    let source = std::fs::canonicalize(src)
+        .unwrap_or_else(|_| src.to_path_buf())
+        .display()
+        .to_string();
+    let source_mtime = std::fs::metadata(src)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| jiff::Timestamp::try_from(t).ok());
+    let target_display = target.to_string();
+    let state_dir = upload_state_dir();
+
+    // With --resume, pick up a saved upload of this file; other saved uploads
+    // (or all of them without --resume) are aborted since the target is being replaced
+    let mut resumed = None;
+    if let Some(dir) = &state_dir {
+        for state in UploadState::find_pending(dir, &target_display).unwrap_or_default() {
+            if args.resume
+                && resumed.is_none()
+                && state.matches_source(&source, source_mtime, total_size, part_size)
+            {
+                resumed = Some(state);
             } else {
-                let styled_src = formatter.style_file(&src_display);
-                let styled_dst = formatter.style_file(&dst_display);
-                let styled_size = formatter.style_size(&info.size_human.unwrap_or_default());
-                formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+                let _ = client
+                    .abort_multipart_upload(target, &state.upload_id)
+                    .await;
+                let _ = UploadState::delete(dir, &state.upload_id);
+            }
+        }
+    }
+
+    let mut state = match resumed {
+        Some(state) => state,
+        None => {
+            let upload_id = client
+                .create_multipart_upload(target, options)
+                .await
+                .map_err(|e| (ExitCode::NetworkError, e.to_string()))?;
+            UploadState::new(upload_id, &target_display, total_size, part_size)
+                .with_source(source)
+                .with_source_mtime(source_mtime)
+        }
+    };
+
+    let mut guard = interrupt::Guard::new();
+    let outcome = tokio::select! {
+        result = upload_parts(client, target, &data, &mut state, config.concurrency) => Some(result),
+        _ = guard.interrupted() => None,
+    };
+
+    let result = match outcome {
+        Some(Ok(())) => client.complete_multipart_upload(target, &state).await,
+        Some(Err(e)) => Err(e),
+        None => {
+            let note = stop_upload(client, target, &state, state_dir.as_deref(), args.resume).await;
+            return Err((ExitCode::Interrupted, note));
+        }
+    };
+
+    match result {
+        Ok(info) => {
+            if let Some(dir) = &state_dir {
+                let _ = UploadState::delete(dir, &state.upload_id);
             }
-            ExitCode::Success
+            Ok(info)
         }
         Err(e) => {
-            formatter.error(&format!("Failed to upload {src_display}: {e}"));
-            ExitCode::NetworkError
+            let note = stop_upload(client, target, &state, state_dir.as_deref(), args.resume).await;
+            Err((ExitCode::NetworkError, format!("{e}; {note}")))
         }
     }
 }
 
+/// Upload the parts of `data` that `state` does not list as completed yet
+async fn upload_parts(
+    client: &S3Client,
+    target: &RemotePath,
+    data: &[u8],
+    state: &mut UploadState,
+    concurrency: usize,
+) -> rc_core::Result<()> {
+    let done: HashSet<i32> = state
+        .completed_parts
+        .iter()
+        .map(|p| p.part_number)
+        .collect();
+    let parts = calculate_parts(state.total_size, state.part_size) as i32;
+    let (part_size, total_size) = (state.part_size, state.total_size);
+    let upload_id = state.upload_id.clone();
+
+    let mut uploads = stream::iter((1..=parts).filter(|n| !done.contains(n)))
+        .map(|part_number| {
+            let (start, end) = part_byte_range(part_number, part_size, total_size);
+            let body = data[start as usize..end as usize].to_vec();
+            let upload_id = &upload_id;
+            async move {
+                client
+                    .upload_part(target, upload_id, part_number, body)
+                    .await
+                    .map(|etag| (part_number, etag))
+            }
+        })
+        .buffer_unordered(concurrency);
+
+    while let Some(result) = uploads.next().await {
+        let (part_number, etag) = result?;
+        state.add_completed_part(part_number, etag);
+    }
+    Ok(())
+}
+
+/// Aborts multipart uploads; implemented by `S3Client` and faked in tests
+pub(crate) trait AbortUpload {
+    async fn abort_upload(&self, target: &RemotePath, upload_id: &str) -> rc_core::Result<()>;
+}
+
+impl AbortUpload for S3Client {
+    async fn abort_upload(&self, target: &RemotePath, upload_id: &str) -> rc_core::Result<()> {
+        self.abort_multipart_upload(target, upload_id).await
+    }
+}
+
+/// Abort an unfinished upload, or save it for resuming with `--resume`
+///
+/// Returns a note for the user saying which of the two happened.
+pub(crate) async fn stop_upload(
+    client: &impl AbortUpload,
+    target: &RemotePath,
+    state: &UploadState,
+    state_dir: Option<&Path>,
+    resume: bool,
+) -> String {
+    if resume
+        && let Some(dir) = state_dir
+        && state.save(dir).is_ok()
+    {
+        return format!(
+            "upload saved for resume ({} of {} parts done); run the same copy with --resume to continue",
+            state.completed_parts.len(),
+            calculate_parts(state.total_size, state.part_size)
+        );
+    }
+
+    match client.abort_upload(target, &state.upload_id).await {
+        Ok(()) => "incomplete upload aborted".to_string(),
+        Err(e) => format!("could not abort incomplete upload {}: {e}", state.upload_id),
+    }
+}
+
+/// Directory holding saved multipart upload state (`<config dir>/uploads`)
+fn upload_state_dir() -> Option<PathBuf> {
+    let config = ConfigManager::new().ok()?;
+    config.config_path().parent().map(|dir| dir.join("uploads"))
+}
+
+/// Pick the content type for an upload
+///
+/// Precedence: `--content-type`, then the alias's content type for the
+/// extension, then magic-byte sniffing (files without an extension), then the
+/// extension guess, then none (server default). Sniffing is also tried when
+/// the extension is not recognised.
+fn upload_content_type(args: &CpArgs, alias: &Alias, src: &Path, data: &[u8]) -> Option<String> {
+    if let Some(explicit) = &args.content_type {
+        return Some(explicit.clone());
+    }
+    if let Some(configured) = alias.content_type_for(&src.to_string_lossy()) {
+        return Some(configured.to_string());
+    }
+    if args.no_guess_content_type {
+        return None;
+    }
+
+    let guessed = if src.extension().is_some() {
+        mime_guess::from_path(src)
+            .first()
+            .map(|m| m.essence_str().to_string())
+    } else {
+        None
+    };
+
+    guessed.or_else(|| sniff_content_type(data).map(str::to_string))
+}
+
+/// Detect common formats from their leading magic bytes
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"BZh", "application/x-bzip2"),
+        (b"\xfd7zXZ\x00", "application/x-xz"),
+        (b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+        (b"\x7fELF", "application/x-executable"),
+        (b"<?xml", "application/xml"),
+    ];
+
+    if let Some((_, mime)) = SIGNATURES.iter().find(|(magic, _)| data.starts_with(magic)) {
+        return Some(mime);
+    }
+    if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    // Treat NUL-free UTF-8 as text; a multi-byte character may be cut at the window edge
+    let head = &data[..data.len().min(512)];
+    if head.is_empty() || head.contains(&0) {
+        return None;
+    }
+    let text = match std::str::from_utf8(head) {
+        Ok(text) => text,
+        Err(e) if e.error_len().is_none() => {
+            std::str::from_utf8(&head[..e.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return None,
+    };
+    let lower = text.trim_start().to_ascii_lowercase();
+    if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+        Some("text/html")
+    } else {
+        Some("text/plain")
+    }
+}
+
 async fn upload_directory(
     client: &S3Client,
     src: &Path,
@@ -232,73 +1083,432 @@ async fn upload_directory(
     args: &CpArgs,
     formatter: &Formatter,
 ) -> ExitCode {
-    use std::fs;
-
     let mut success_count = 0;
-    let mut error_count = 0;
+    let mut failures = Vec::new();
 
-    // Walk directory
-    fn walk_dir(dir: &Path, base: &Path) -> std::io::Result<Vec<(std::path::PathBuf, String)>> {
-        let mut files = Vec::new();
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_file() {
-                let relative = path.strip_prefix(base).unwrap_or(&path);
-                let relative_str = relative.to_string_lossy().to_string();
-                files.push((path, relative_str));
-            } else if path.is_dir() {
-                files.extend(walk_dir(&path, base)?);
-            }
-        }
-        Ok(files)
-    }
-
-    let files = match walk_dir(src, src) {
-        Ok(f) => f,
+    let walked = match walk_dir(src, args.follow_symlinks) {
+        Ok(w) => w,
         Err(e) => {
             formatter.error(&format!("Failed to read directory: {e}"));
             return ExitCode::GeneralError;
         }
     };
+    for (path, reason) in &walked.skipped {
+        formatter.warning(&reason.warning(path));
+    }
+
+    let filter = path_filter(args);
+    let files: Vec<_> = walked
+        .files
+        .into_iter()
+        .map(|(path, relative)| (path, relative.replace('\\', "/")))
+        .filter(|(_, relative)| filter.matches(relative))
+        .collect();
+
+    let total = files
+        .iter()
+        .filter_map(|(path, _)| std::fs::metadata(path).ok())
+        .map(|m| m.len())
+        .sum();
+    let progress = transfer_progress(args, formatter, Some(total));
 
     for (file_path, relative_path) in files {
-        // Build destination key
-        let dst_key = if dst.key.is_empty() {
-            relative_path.replace('\\', "/")
-        } else if dst.key.ends_with('/') {
-            format!("{}{}", dst.key, relative_path.replace('\\', "/"))
-        } else {
-            format!("{}/{}", dst.key, relative_path.replace('\\', "/"))
-        };
+        let dst_key = rebase_key("", &relative_path, &dst.key);
 
         let target = RemotePath::new(&dst.alias, &dst.bucket, &dst_key);
 
-        let result = upload_file(client, &file_path, &target, args, formatter).await;
+        progress.set_message(&relative_path);
+        match try_upload_file(client, &file_path, &target, args, formatter).await {
+            Ok(()) => {
+                success_count += 1;
+                progress.inc(std::fs::metadata(&file_path).map_or(0, |m| m.len()));
+            }
+            Err(failure) => {
+                let code = record_failure(failure, &mut failures, formatter);
+                if !args.continue_on_error || code == ExitCode::Interrupted {
+                    progress.finish_and_clear();
+                    return code;
+                }
+            }
+        }
+    }
 
-        if result == ExitCode::Success {
-            success_count += 1;
-        } else {
-            error_count += 1;
-            if !args.continue_on_error {
-                return result;
+    let mut marker_count = 0;
+    if args.preserve_empty_dirs {
+        for relative in &walked.empty_dirs {
+            let relative = format!("{}/", relative.replace('\\', "/"));
+            if !filter.matches(&relative) {
+                continue;
+            }
+            let target =
+                RemotePath::new(&dst.alias, &dst.bucket, rebase_key("", &relative, &dst.key));
+            match upload_dir_marker(client, &src.join(&relative), &target, args, formatter).await {
+                Ok(()) => marker_count += 1,
+                Err(failure) => {
+                    let code = record_failure(failure, &mut failures, formatter);
+                    if !args.continue_on_error || code == ExitCode::Interrupted {
+                        progress.finish_and_clear();
+                        return code;
+                    }
+                }
             }
         }
     }
+    progress.finish_and_clear();
 
-    if error_count > 0 {
-        formatter.warning(&format!(
-            "Completed with errors: {success_count} succeeded, {error_count} failed"
-        ));
-        ExitCode::GeneralError
+    if !failures.is_empty() {
+        report_failures(success_count + marker_count, failures, formatter)
     } else {
         if !formatter.is_json() {
-            formatter.success(&format!("Uploaded {success_count} file(s)."));
+            if marker_count > 0 {
+                formatter.success(&format!(
+                    "Uploaded {success_count} file(s) and {marker_count} empty directory marker(s)."
+                ));
+            } else {
+                formatter.success(&format!("Uploaded {success_count} file(s)."));
+            }
         }
         ExitCode::Success
     }
 }
 
+/// Create the zero-byte `dir/` marker object for an empty local directory
+async fn upload_dir_marker(
+    client: &S3Client,
+    src: &Path,
+    target: &RemotePath,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> Result<(), TransferFailure> {
+    let src_display = src.display().to_string();
+    let dst_display = format!("{}/{}/{}", target.alias, target.bucket, target.key);
+
+    if args.dry_run {
+        let styled_src = formatter.style_dir(&src_display);
+        let styled_dst = formatter.style_dir(&dst_display);
+        formatter.println(&format!("Would create: {styled_src} -> {styled_dst}"));
+        return Ok(());
+    }
+
+    match client
+        .put_object_with_options(target, Vec::new(), &PutOptions::default())
+        .await
+    {
+        Ok(_) => {
+            if show_file_lines(args, formatter) {
+                if formatter.is_json() {
+                    formatter.json(&CpOutput {
+                        status: "success",
+                        source: src_display,
+                        target: dst_display,
+                        size_bytes: Some(0),
+                        size_human: Some("0 B".to_string()),
+                    });
+                } else {
+                    let styled_src = formatter.style_dir(&src_display);
+                    let styled_dst = formatter.style_dir(&dst_display);
+                    formatter.println(&format!("{styled_src} -> {styled_dst}"));
+                }
+            }
+            Ok(())
+        }
+        Err(e) => Err(TransferFailure::new(
+            ExitCode::NetworkError,
+            &src_display,
+            &dst_display,
+            format!("Failed to create {dst_display}: {e}"),
+        )),
+    }
+}
+
+/// Aggregate byte progress for a recursive copy
+///
+/// Hidden when a line is printed per file (`--verbose`, JSON) or on dry runs,
+/// in addition to `--quiet` and `--no-progress`.
+fn transfer_progress(args: &CpArgs, formatter: &Formatter, total: Option<u64>) -> ProgressBar {
+    let mut config = formatter.output_config().clone();
+    config.no_progress |= args.dry_run || show_file_lines(args, formatter);
+    ProgressBar::transfer(config, total)
+}
+
+/// Build the include/exclude filter for recursive copies
+///
+/// The patterns are validated in `execute`, so an error cannot occur here.
+fn path_filter(args: &CpArgs) -> PathFilter {
+    PathFilter::new(&args.include, &args.exclude).unwrap_or_default()
+}
+
+/// User metadata key holding a file's mtime (Unix seconds) for `--preserve`
+const MTIME_METADATA_KEY: &str = "mtime";
+
+/// User metadata recorded for an upload with `--preserve`
+fn preserved_metadata(src: &Path) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    let mtime = std::fs::metadata(src)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok());
+    if let Some(mtime) = mtime {
+        metadata.insert(MTIME_METADATA_KEY.to_string(), mtime.as_secs().to_string());
+    }
+    metadata
+}
+
+/// Set a downloaded file's mtime to the object's last-modified time
+///
+/// `known` is the time from the listing or a request already made for the
+/// download; the object is only HEADed when there is none.
+async fn preserve_mtime(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &Path,
+    known: Option<jiff::Timestamp>,
+) -> Result<(), String> {
+    let modified = match known {
+        Some(modified) => modified,
+        None => {
+            let info = client.head_object(src).await.map_err(|e| e.to_string())?;
+            let Some(modified) = info.last_modified else {
+                return Ok(());
+            };
+            modified
+        }
+    };
+    set_mtime(dst, modified).map_err(|e| e.to_string())
+}
+
+/// Set a file's mtime; times before the epoch are left alone
+fn set_mtime(path: &Path, modified: jiff::Timestamp) -> std::io::Result<()> {
+    let Some(modified) = system_time(modified) else {
+        return Ok(());
+    };
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|f| f.set_modified(modified))
+}
+
+/// Convert a timestamp to `SystemTime`; times before the epoch are not representable here
+fn system_time(ts: jiff::Timestamp) -> Option<SystemTime> {
+    let secs = u64::try_from(ts.as_second()).ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Copy object tags to the destination after a server-side copy
+///
+/// `CopyObject` is asked to copy tags already, but not every backend honours
+/// the tagging directive, so they are copied explicitly as well.
+async fn copy_tags(client: &S3Client, src: &RemotePath, dst: &RemotePath, formatter: &Formatter) {
+    let result = match client.get_object_tags(src).await {
+        Ok(tags) if tags.is_empty() => Ok(()),
+        Ok(tags) => client.set_object_tags(dst, tags).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        formatter.warning(&format!("Could not copy tags to {dst}: {e}"));
+    }
+}
+
+/// Give `dst` the ACL grants of `src`, warning instead of failing the copy
+///
+/// On failure the copy keeps its default ACL, which is never more open than the source's.
+async fn copy_acl(client: &S3Client, src: &RemotePath, dst: &RemotePath, formatter: &Formatter) {
+    let result = match (
+        client.get_object_acl(src).await,
+        client.get_object_acl(dst).await,
+    ) {
+        (Ok(source), Ok(current)) => {
+            client
+                .put_object_acl(dst, &rebase_acl(source, current.owner))
+                .await
+        }
+        (Err(e), _) | (_, Err(e)) => Err(e),
+    };
+    if let Err(e) = result {
+        formatter.warning(&format!("Could not copy ACL to {dst}: {e}"));
+    }
+}
+
+/// Move an ACL onto a new owner, carrying the old owner's own grants over to them
+fn rebase_acl(acl: Acl, owner: Option<Owner>) -> Acl {
+    let old_id = acl.owner.as_ref().and_then(|o| o.id.clone());
+    let new_id = owner.as_ref().and_then(|o| o.id.clone());
+    let grants = acl
+        .grants
+        .into_iter()
+        .map(|mut grant| {
+            if let (Some(old), Some(new)) = (&old_id, &new_id)
+                && grant.grantee_type == "CanonicalUser"
+                && &grant.grantee == old
+            {
+                grant.grantee = new.clone();
+            }
+            grant
+        })
+        .collect();
+    Acl { owner, grants }
+}
+
+/// Print a failed file as it happens and keep it for the final summary
+fn record_failure(
+    failure: TransferFailure,
+    failures: &mut Vec<TransferFailure>,
+    formatter: &Formatter,
+) -> ExitCode {
+    formatter.error(&failure.error);
+    let code = failure.code;
+    failures.push(failure);
+    code
+}
+
+/// Summarize a recursive copy that finished with failures
+///
+/// Every failed `source -> target` pair is listed with its error so the
+/// transfers can be retried individually; JSON output gets a `failures` array.
+fn report_failures(
+    succeeded: usize,
+    failures: Vec<TransferFailure>,
+    formatter: &Formatter,
+) -> ExitCode {
+    if formatter.is_json() {
+        formatter.json(&CpFailureSummary {
+            status: "error",
+            succeeded,
+            failed: failures.len(),
+            failures,
+        });
+    } else {
+        formatter.warning(&format!(
+            "Completed with errors: {succeeded} succeeded, {} failed:",
+            failures.len()
+        ));
+        for failure in &failures {
+            formatter.warning(&format!(
+                "  {} -> {}: {}",
+                failure.source, failure.target, failure.error
+            ));
+        }
+    }
+    ExitCode::GeneralError
+}
+
+/// Whether to print a line per transferred file
+///
+/// Recursive copies only print the final summary unless `--verbose` is set.
+/// JSON mode keeps its per-file records, and quiet mode suppresses them all.
+fn show_file_lines(args: &CpArgs, formatter: &Formatter) -> bool {
+    !formatter.is_quiet() && (args.verbose || formatter.is_json() || !args.recursive)
+}
+
+/// Why [`walk_dir`] left a symlink out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SkippedLink {
+    /// Symlinks are only followed with `--follow-symlinks`
+    NotFollowed,
+    /// The link target does not exist
+    Broken,
+    /// The link points at a directory already being walked
+    Cycle,
+}
+
+impl SkippedLink {
+    /// Warning shown for a skipped link
+    pub(crate) fn warning(self, path: &Path) -> String {
+        let path = path.display();
+        match self {
+            SkippedLink::NotFollowed => {
+                format!("Skipping symlink {path} (use --follow-symlinks to upload its target)")
+            }
+            SkippedLink::Broken => format!("Skipping broken symlink {path}"),
+            SkippedLink::Cycle => {
+                format!("Skipping symlink {path}: it points back into a parent directory")
+            }
+        }
+    }
+}
+
+/// Files found by [`walk_dir`] and the symlinks it skipped
+#[derive(Debug, Default)]
+pub(crate) struct WalkedDir {
+    /// `(path, path relative to the walked directory)`
+    pub files: Vec<(std::path::PathBuf, String)>,
+    pub skipped: Vec<(std::path::PathBuf, SkippedLink)>,
+    /// Relative paths of subdirectories with no entries at all
+    pub empty_dirs: Vec<String>,
+}
+
+/// Recursively list files under `dir`
+///
+/// Symlinks are skipped unless `follow_symlinks` is set. When following, a
+/// link to a directory that is already an ancestor on the current walk is
+/// skipped as a cycle; the same directory reached through two different
+/// links is walked under both names.
+pub(crate) fn walk_dir(dir: &Path, follow_symlinks: bool) -> std::io::Result<WalkedDir> {
+    let mut walked = WalkedDir::default();
+    let mut ancestors = vec![dir.canonicalize()?];
+    walk_into(dir, dir, follow_symlinks, &mut ancestors, &mut walked)?;
+    Ok(walked)
+}
+
+fn walk_into(
+    dir: &Path,
+    base: &Path,
+    follow_symlinks: bool,
+    ancestors: &mut Vec<std::path::PathBuf>,
+    walked: &mut WalkedDir,
+) -> std::io::Result<()> {
+    let mut has_entries = false;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        has_entries = true;
+        let path = entry.path();
+        let is_link = entry.file_type()?.is_symlink();
+        if is_link && !follow_symlinks {
+            walked.skipped.push((path, SkippedLink::NotFollowed));
+            continue;
+        }
+
+        // Follows the link, if any
+        let metadata = match std::fs::metadata(&path) {
+            Ok(m) => m,
+            Err(e) if is_link && e.kind() == std::io::ErrorKind::NotFound => {
+                walked.skipped.push((path, SkippedLink::Broken));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        if metadata.is_file() {
+            let relative = path.strip_prefix(base).unwrap_or(&path);
+            let relative_str = relative.to_string_lossy().to_string();
+            walked.files.push((path, relative_str));
+        } else if metadata.is_dir() {
+            let canonical = path.canonicalize()?;
+            if ancestors.contains(&canonical) {
+                walked.skipped.push((path, SkippedLink::Cycle));
+                continue;
+            }
+            ancestors.push(canonical);
+            walk_into(&path, base, follow_symlinks, ancestors, walked)?;
+            ancestors.pop();
+        }
+    }
+
+    if !has_entries && dir != base {
+        let relative = dir.strip_prefix(base).unwrap_or(dir);
+        walked
+            .empty_dirs
+            .push(relative.to_string_lossy().to_string());
+    }
+    Ok(())
+}
+
+/// Local destination for an object listed under `src_prefix`
+pub(crate) fn local_target(dst: &Path, src_prefix: &str, key: &str) -> std::path::PathBuf {
+    let relative = rebase_key(src_prefix, key, "");
+    dst.join(relative.replace('/', std::path::MAIN_SEPARATOR_STR))
+}
+
 async fn copy_s3_to_local(
     src: &RemotePath,
     dst: &Path,
@@ -314,7 +1524,7 @@ async fn copy_s3_to_local(
         }
     };
 
-    let alias = match alias_manager.get(&src.alias) {
+    let alias = match resolve_alias(&alias_manager, &src.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", src.alias));
@@ -342,13 +1552,30 @@ async fn copy_s3_to_local(
     }
 }
 
-async fn download_file(
+pub(crate) async fn download_file(
     client: &S3Client,
     src: &RemotePath,
     dst: &Path,
     args: &CpArgs,
     formatter: &Formatter,
 ) -> ExitCode {
+    exit_code(
+        try_download_file(client, src, dst, None, args, formatter).await,
+        formatter,
+    )
+}
+
+/// Download one object, returning what failed instead of printing it
+///
+/// `listed_mtime` is the object's last-modified time from a listing, if any.
+async fn try_download_file(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &Path,
+    listed_mtime: Option<jiff::Timestamp>,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> Result<(), TransferFailure> {
     let src_display = format!("{}/{}/{}", src.alias, src.bucket, src.key);
 
     // Determine destination path
@@ -365,15 +1592,16 @@ async fn download_file(
         let styled_src = formatter.style_file(&src_display);
         let styled_dst = formatter.style_file(&dst_display);
         formatter.println(&format!("Would copy: {styled_src} -> {styled_dst}"));
-        return ExitCode::Success;
+        return Ok(());
     }
+    let fail = |code, error| TransferFailure::new(code, &src_display, &dst_display, error);
 
     // Check if destination exists
     if dst_path.exists() && !args.overwrite {
-        formatter.error(&format!(
-            "Destination exists: {dst_display}. Use --overwrite to replace."
+        return Err(fail(
+            ExitCode::Conflict,
+            format!("Destination exists: {dst_display}. Use --overwrite to replace."),
         ));
-        return ExitCode::Conflict;
     }
 
     // Create parent directories
@@ -381,49 +1609,403 @@ async fn download_file(
         && !parent.exists()
         && let Err(e) = std::fs::create_dir_all(parent)
     {
-        formatter.error(&format!("Failed to create directory: {e}"));
-        return ExitCode::GeneralError;
+        return Err(fail(
+            ExitCode::GeneralError,
+            format!("Failed to create directory: {e}"),
+        ));
     }
 
-    // Download object
-    match client.get_object(src).await {
-        Ok(data) => {
-            let size = data.len() as i64;
-
-            if let Err(e) = std::fs::write(&dst_path, &data) {
-                formatter.error(&format!("Failed to write {dst_display}: {e}"));
-                return ExitCode::GeneralError;
+    // Download object; a download that fails verification is fetched again,
+    // up to the alias retry limit, as the corruption is usually transient
+    let max_attempts = client.alias().retry_config().max_attempts.max(1);
+    let mut attempt = 1;
+    let fetched = loop {
+        let fetched = match fetch_object(client, src, &dst_path, args).await {
+            Ok(fetched) => fetched,
+            Err(e) => break Err(e),
+        };
+        if !args.verify {
+            break Ok(fetched);
+        }
+        match verify_download(client, src, &dst_path).await {
+            Ok(Verification::Verified) => break Ok(fetched),
+            Ok(Verification::Unavailable) => {
+                formatter.warning(&format!(
+                    "Could not verify {dst_display}: object has no full-object SHA-256 checksum"
+                ));
+                break Ok(fetched);
+            }
+            Ok(Verification::Mismatch { expected, actual }) => {
+                let _ = std::fs::remove_file(&dst_path);
+                if attempt >= max_attempts {
+                    return Err(fail(
+                        ExitCode::GeneralError,
+                        format!(
+                            "SHA-256 mismatch for {src_display} after {attempt} attempt(s): \
+                             expected {expected}, got {actual}"
+                        ),
+                    ));
+                }
+                formatter.warning(&format!(
+                    "SHA-256 mismatch for {src_display} (attempt {attempt} of {max_attempts}), \
+                     downloading again"
+                ));
+                attempt += 1;
+            }
+            Err(e) => {
+                let _ = std::fs::remove_file(&dst_path);
+                return Err(fail(
+                    ExitCode::GeneralError,
+                    format!("Failed to verify {dst_display}: {e}"),
+                ));
             }
+        }
+    };
 
-            if formatter.is_json() {
-                let output = CpOutput {
-                    status: "success",
-                    source: src_display,
-                    target: dst_display,
-                    size_bytes: Some(size),
-                    size_human: Some(humansize::format_size(size as u64, humansize::BINARY)),
-                };
-                formatter.json(&output);
+    match fetched {
+        Ok((size, fetched_mtime)) => {
+            let size = if args.decompress {
+                match decompress_download(client, src, &dst_path).await {
+                    Ok(Some(inflated)) => inflated,
+                    Ok(None) => size,
+                    Err(e) => {
+                        return Err(fail(
+                            ExitCode::GeneralError,
+                            format!("Failed to decompress {dst_display}: {e}"),
+                        ));
+                    }
+                }
             } else {
-                let styled_src = formatter.style_file(&src_display);
-                let styled_dst = formatter.style_file(&dst_display);
-                let styled_size =
-                    formatter.style_size(&humansize::format_size(size as u64, humansize::BINARY));
-                formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+                size
+            };
+            let size = size as i64;
+
+            if !args.no_preserve_mtime
+                && let Err(e) =
+                    preserve_mtime(client, src, &dst_path, listed_mtime.or(fetched_mtime)).await
+            {
+                formatter.warning(&format!("Could not preserve mtime of {dst_display}: {e}"));
+            }
+
+            if show_file_lines(args, formatter) {
+                if formatter.is_json() {
+                    let output = CpOutput {
+                        status: "success",
+                        source: src_display,
+                        target: dst_display,
+                        size_bytes: Some(size),
+                        size_human: Some(humansize::format_size(size as u64, humansize::BINARY)),
+                    };
+                    formatter.json(&output);
+                } else {
+                    let styled_src = formatter.style_file(&src_display);
+                    let styled_dst = formatter.style_file(&dst_display);
+                    let styled_size = formatter
+                        .style_size(&humansize::format_size(size as u64, humansize::BINARY));
+                    formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+                }
             }
-            ExitCode::Success
+            Ok(())
         }
-        Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                formatter.error(&format!("Object not found: {src_display}"));
-                ExitCode::NotFound
-            } else {
-                formatter.error(&format!("Failed to download {src_display}: {e}"));
-                ExitCode::NetworkError
+        Err(rc_core::Error::NotFound(_)) => Err(fail(
+            ExitCode::NotFound,
+            format!("Object not found: {src_display}"),
+        )),
+        Err(rc_core::Error::Io(e)) => Err(fail(
+            ExitCode::GeneralError,
+            format!("Failed to write {dst_display}: {e}"),
+        )),
+        Err(rc_core::Error::Conflict(_)) => Err(fail(
+            ExitCode::Conflict,
+            format!(
+                "Failed to download {src_display}: the object changed during the download; run the copy again"
+            ),
+        )),
+        Err(e) => Err(fail(
+            ExitCode::NetworkError,
+            format!("Failed to download {src_display}: {e}"),
+        )),
+    }
+}
+
+/// Decompress a downloaded file in place according to the object's Content-Encoding
+///
+/// Returns the decompressed size, or `None` when the object is not stored
+/// with a supported encoding and the file was left as is.
+async fn decompress_download(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &Path,
+) -> rc_core::Result<Option<u64>> {
+    let info = client.head_object(src).await?;
+    let Some(codec) = info
+        .content_encoding
+        .as_deref()
+        .and_then(Compression::from_encoding)
+    else {
+        return Ok(None);
+    };
+
+    let data = std::fs::read(dst)?;
+    let inflated = codec.decompress(&data)?;
+    std::fs::write(dst, &inflated)?;
+    Ok(Some(inflated.len() as u64))
+}
+
+/// Bytes requested per ranged GET; a failed download resumes from the last whole chunk
+const DOWNLOAD_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Objects of at least this size are downloaded with concurrent ranged GETs
+const PARALLEL_DOWNLOAD_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+/// Default `--concurrency`
+pub(crate) const DEFAULT_CONCURRENCY: usize = 4;
+
+/// How many ranged GETs to keep in flight for an object of `size` bytes
+fn download_concurrency(args: &CpArgs, size: u64) -> usize {
+    if args.parallel_download || size >= PARALLEL_DOWNLOAD_THRESHOLD {
+        args.concurrency.max(1)
+    } else {
+        1
+    }
+}
+
+/// Download `src` to `dst`, returning the object size and, for objects larger
+/// than one chunk, the object's last-modified time
+///
+/// Objects larger than one chunk are written to `<dst>.part`, with the ETag
+/// kept in `<dst>.part.etag`, and renamed into place when complete. Every
+/// chunk after the first is requested with `If-Match` on the ETag the first
+/// one returned, so an object overwritten mid-download fails with a conflict
+/// instead of producing a file stitched from two versions. With resume
+/// enabled, an existing part file is continued from its length as long as the
+/// object's ETag still matches; otherwise it is discarded. Large objects (see
+/// [`download_concurrency`]) are fetched with concurrent ranged GETs instead
+/// and are not resumable.
+async fn fetch_object(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &Path,
+    args: &CpArgs,
+) -> rc_core::Result<(u64, Option<jiff::Timestamp>)> {
+    use std::io::Write;
+
+    let part = part_path(dst);
+    let etag_file = part_etag_path(&part);
+
+    let mut resumed = None;
+    if !args.no_resume && part.exists() {
+        let info = client.head_object(src).await?;
+        let saved_etag = std::fs::read_to_string(&etag_file).ok();
+        let received = std::fs::metadata(&part)?.len();
+        if can_resume(saved_etag.as_deref(), &info, received) {
+            resumed = Some((received, object_size(&info), info.last_modified, info.etag));
+        }
+    }
+
+    let (mut received, size, modified, etag) = match resumed {
+        Some(state) => state,
+        None => {
+            let _ = std::fs::remove_file(&part);
+            let _ = std::fs::remove_file(&etag_file);
+
+            let (first, info) = client
+                .get_object_range_pinned(src, 0, DOWNLOAD_CHUNK_SIZE, None)
+                .await?;
+            if (first.len() as u64) < DOWNLOAD_CHUNK_SIZE {
+                std::fs::write(dst, &first)?;
+                return Ok((first.len() as u64, None));
+            }
+
+            if let Some(size) = object_size(&info) {
+                let concurrency = download_concurrency(args, size);
+                if concurrency > 1 {
+                    let etag = info.etag.as_deref();
+                    if let Err(e) =
+                        fetch_ranges(client, src, &part, &first, size, etag, concurrency).await
+                    {
+                        let _ = std::fs::remove_file(&part);
+                        return Err(e);
+                    }
+                    std::fs::rename(&part, dst)?;
+                    return Ok((size, info.last_modified));
+                }
             }
+
+            std::fs::write(&part, &first)?;
+            if let Some(etag) = &info.etag {
+                std::fs::write(&etag_file, etag)?;
+            }
+            (
+                first.len() as u64,
+                object_size(&info),
+                info.last_modified,
+                info.etag,
+            )
         }
+    };
+
+    let mut file = std::fs::OpenOptions::new().append(true).open(&part)?;
+    while size.is_none_or(|size| received < size) {
+        let chunk = match client
+            .get_object_range_pinned(src, received, DOWNLOAD_CHUNK_SIZE, etag.as_deref())
+            .await
+        {
+            Ok((chunk, _)) => chunk,
+            Err(e @ rc_core::Error::Conflict(_)) => {
+                // The saved part belongs to the old version; start over next time
+                drop(file);
+                let _ = std::fs::remove_file(&part);
+                let _ = std::fs::remove_file(&etag_file);
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+        if chunk.is_empty() {
+            break;
+        }
+        file.write_all(&chunk)?;
+        received += chunk.len() as u64;
     }
+    drop(file);
+
+    if let Some(size) = size
+        && received != size
+    {
+        return Err(rc_core::Error::General(format!(
+            "object changed during download ({received} of {size} bytes received)"
+        )));
+    }
+
+    std::fs::rename(&part, dst)?;
+    let _ = std::fs::remove_file(&etag_file);
+    Ok((received, modified))
+}
+
+/// Fetch the rest of `src` into `part` with concurrent ranged GETs
+///
+/// `first` is the already fetched start of the object. Each chunk is written
+/// at its own offset as it arrives, so the part file has holes until every
+/// chunk is in; no ETag file is written, so it is never resumed. Every range
+/// is requested with `If-Match` on `etag`, the ETag the first chunk returned,
+/// so an overwrite mid-download fails with a conflict.
+async fn fetch_ranges(
+    client: &S3Client,
+    src: &RemotePath,
+    part: &Path,
+    first: &[u8],
+    size: u64,
+    etag: Option<&str>,
+    concurrency: usize,
+) -> rc_core::Result<()> {
+    use std::io::{Seek, SeekFrom, Write};
+
+    let mut file = std::fs::File::create(part)?;
+    file.set_len(size)?;
+    file.write_all(first)?;
+
+    let mut chunks = stream::iter(chunk_offsets(first.len() as u64, size))
+        .map(|(offset, length)| async move {
+            client
+                .get_object_range_pinned(src, offset, length, etag)
+                .await
+                .map(|(data, _)| (offset, length, data))
+        })
+        .buffer_unordered(concurrency);
+
+    while let Some(chunk) = chunks.next().await {
+        let (offset, length, data) = chunk?;
+        if data.len() as u64 != length {
+            return Err(rc_core::Error::General(format!(
+                "object changed during download ({} of {length} bytes received at offset {offset})",
+                data.len()
+            )));
+        }
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(&data)?;
+    }
+    Ok(())
+}
+
+/// Split the bytes from `start` to `size` into `(offset, length)` download chunks
+fn chunk_offsets(start: u64, size: u64) -> impl Iterator<Item = (u64, u64)> {
+    (start..size)
+        .step_by(DOWNLOAD_CHUNK_SIZE as usize)
+        .map(move |offset| (offset, DOWNLOAD_CHUNK_SIZE.min(size - offset)))
+}
+
+/// Compare a downloaded file with the object's SHA-256 checksum
+///
+/// Errors are failures to read the checksum or the file, not mismatches.
+async fn verify_download(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &Path,
+) -> rc_core::Result<Verification> {
+    let Some(checksum) = client.object_checksum(src).await? else {
+        return Ok(Verification::Unavailable);
+    };
+    if !checksum.algorithm.eq_ignore_ascii_case("SHA256")
+        || checksum.checksum_type.as_deref() == Some("COMPOSITE")
+    {
+        return Ok(Verification::Unavailable);
+    }
+
+    let actual = sha256_base64(std::fs::File::open(dst)?)?;
+    Ok(compare_checksum(checksum.value, actual))
+}
+
+/// Outcome of checking a downloaded file against the object's checksum
+#[derive(Debug, PartialEq, Eq)]
+enum Verification {
+    /// The file matches the object's SHA-256 checksum
+    Verified,
+    /// The object has no full-object SHA-256 checksum to compare with
+    Unavailable,
+    /// The file differs from the object (both values base64-encoded)
+    Mismatch { expected: String, actual: String },
+}
+
+fn compare_checksum(expected: String, actual: String) -> Verification {
+    if expected == actual {
+        Verification::Verified
+    } else {
+        Verification::Mismatch { expected, actual }
+    }
+}
+
+/// Base64-encoded SHA-256 of everything `reader` yields
+fn sha256_base64(mut reader: impl std::io::Read) -> std::io::Result<String> {
+    use base64::Engine as _;
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut reader, &mut hasher)?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(hasher.finalize()))
+}
+
+/// Whether a part file of `received` bytes can be continued for `info`
+fn can_resume(saved_etag: Option<&str>, info: &rc_core::ObjectInfo, received: u64) -> bool {
+    let same_object = matches!((saved_etag, info.etag.as_deref()), (Some(a), Some(b)) if a == b);
+    same_object && object_size(info).is_none_or(|size| received <= size)
+}
+
+fn object_size(info: &rc_core::ObjectInfo) -> Option<u64> {
+    info.size_bytes.and_then(|s| u64::try_from(s).ok())
+}
+
+/// Partial download path for `dst`
+fn part_path(dst: &Path) -> std::path::PathBuf {
+    let mut name = dst.as_os_str().to_owned();
+    name.push(".part");
+    name.into()
+}
+
+/// Sidecar holding the ETag of the object a part file belongs to
+fn part_etag_path(part: &Path) -> std::path::PathBuf {
+    let mut name = part.as_os_str().to_owned();
+    name.push(".etag");
+    name.into()
 }
 
 async fn download_prefix(
@@ -435,14 +2017,18 @@ async fn download_prefix(
 ) -> ExitCode {
     use rc_core::ListOptions;
 
+    let filter = path_filter(args);
     let mut success_count = 0;
-    let mut error_count = 0;
+    let mut dir_count = 0;
+    let mut failures = Vec::new();
     let mut continuation_token: Option<String> = None;
+    // Listings are paged, so the total size is not known up front
+    let progress = transfer_progress(args, formatter, None);
 
     loop {
         let options = ListOptions {
             recursive: true,
-            max_keys: Some(1000),
+            max_keys: Some(page_size(args.page_size)),
             continuation_token: continuation_token.clone(),
             ..Default::default()
         };
@@ -450,24 +2036,66 @@ async fn download_prefix(
         match client.list_objects(src, options).await {
             Ok(result) => {
                 for item in result.items {
-                    if item.is_dir {
+                    if item.is_dir
+                        || !key_in_prefix(&src.key, &item.key)
+                        || !filter.matches(&rebase_key(&src.key, &item.key, ""))
+                    {
                         continue;
                     }
 
-                    // Calculate relative path from prefix
-                    let relative_key = item.key.strip_prefix(&src.key).unwrap_or(&item.key);
-                    let dst_path =
-                        dst.join(relative_key.replace('/', std::path::MAIN_SEPARATOR_STR));
+                    let dst_path = local_target(dst, &src.key, &item.key);
+
+                    // `dir/` marker objects stand for directories, never files
+                    if item.key.ends_with('/') {
+                        if !args.preserve_empty_dirs {
+                            continue;
+                        }
+                        if args.dry_run {
+                            formatter.println(&format!(
+                                "Would create: {}",
+                                formatter.style_dir(&dst_path.display().to_string())
+                            ));
+                        } else if let Err(e) = std::fs::create_dir_all(&dst_path) {
+                            let target = dst_path.display().to_string();
+                            let failure = TransferFailure::new(
+                                ExitCode::GeneralError,
+                                &format!("{}/{}/{}", src.alias, src.bucket, item.key),
+                                &target,
+                                format!("Failed to create {target}: {e}"),
+                            );
+                            let code = record_failure(failure, &mut failures, formatter);
+                            if !args.continue_on_error {
+                                progress.finish_and_clear();
+                                return code;
+                            }
+                            continue;
+                        }
+                        dir_count += 1;
+                        continue;
+                    }
 
                     let obj_src = RemotePath::new(&src.alias, &src.bucket, &item.key);
-                    let result = download_file(client, &obj_src, &dst_path, args, formatter).await;
-
-                    if result == ExitCode::Success {
-                        success_count += 1;
-                    } else {
-                        error_count += 1;
-                        if !args.continue_on_error {
-                            return result;
+                    progress.set_message(&item.key);
+                    match try_download_file(
+                        client,
+                        &obj_src,
+                        &dst_path,
+                        item.last_modified,
+                        args,
+                        formatter,
+                    )
+                    .await
+                    {
+                        Ok(()) => {
+                            success_count += 1;
+                            progress.inc(item.size_bytes.unwrap_or(0).max(0) as u64);
+                        }
+                        Err(failure) => {
+                            let code = record_failure(failure, &mut failures, formatter);
+                            if !args.continue_on_error {
+                                progress.finish_and_clear();
+                                return code;
+                            }
                         }
                     }
                 }
@@ -479,23 +2107,28 @@ async fn download_prefix(
                 }
             }
             Err(e) => {
+                progress.finish_and_clear();
                 formatter.error(&format!("Failed to list objects: {e}"));
                 return ExitCode::NetworkError;
             }
         }
     }
+    progress.finish_and_clear();
 
-    if error_count > 0 {
-        formatter.warning(&format!(
-            "Completed with errors: {success_count} succeeded, {error_count} failed"
-        ));
-        ExitCode::GeneralError
-    } else if success_count == 0 {
+    if !failures.is_empty() {
+        report_failures(success_count + dir_count, failures, formatter)
+    } else if success_count == 0 && dir_count == 0 {
         formatter.warning("No objects found to download.");
         ExitCode::Success
     } else {
         if !formatter.is_json() {
-            formatter.success(&format!("Downloaded {success_count} file(s)."));
+            if dir_count > 0 {
+                formatter.success(&format!(
+                    "Downloaded {success_count} file(s) and created {dir_count} directory(ies)."
+                ));
+            } else {
+                formatter.success(&format!("Downloaded {success_count} file(s)."));
+            }
         }
         ExitCode::Success
     }
@@ -522,7 +2155,7 @@ async fn copy_s3_to_s3(
         return ExitCode::UnsupportedFeature;
     }
 
-    let alias = match alias_manager.get(&src.alias) {
+    let alias = match resolve_alias(&alias_manager, &src.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", src.alias));
@@ -538,6 +2171,45 @@ async fn copy_s3_to_s3(
         }
     };
 
+    // A trailing slash or -r means "everything under this prefix"
+    let is_prefix = src.key.is_empty() || src.key.ends_with('/');
+
+    if is_prefix || args.recursive {
+        copy_prefix(&client, src, dst, args, formatter).await
+    } else {
+        copy_s3_object(&client, src, dst, args, formatter).await
+    }
+}
+
+pub(crate) async fn copy_s3_object(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &RemotePath,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> ExitCode {
+    exit_code(
+        try_copy_s3_object(client, src, dst, args, formatter).await,
+        formatter,
+    )
+}
+
+/// Copy one object server-side, returning what failed instead of printing it
+async fn try_copy_s3_object(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &RemotePath,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> Result<(), TransferFailure> {
+    // Copying into a "directory" keeps the source object name
+    let dst = if dst.key.is_empty() || dst.key.ends_with('/') {
+        let filename = src.key.rsplit('/').next().unwrap_or(&src.key);
+        RemotePath::new(&dst.alias, &dst.bucket, format!("{}{filename}", dst.key))
+    } else {
+        dst.clone()
+    };
+
     let src_display = format!("{}/{}/{}", src.alias, src.bucket, src.key);
     let dst_display = format!("{}/{}/{}", dst.alias, dst.bucket, dst.key);
 
@@ -545,38 +2217,237 @@ async fn copy_s3_to_s3(
         let styled_src = formatter.style_file(&src_display);
         let styled_dst = formatter.style_file(&dst_display);
         formatter.println(&format!("Would copy: {styled_src} -> {styled_dst}"));
-        return ExitCode::Success;
+        return Ok(());
     }
 
-    match client.copy_object(src, dst).await {
+    let result = match replacement_options(args) {
+        Some(options) if args.metadata_directive == MetadataDirective::Replace => {
+            client.copy_object_with_options(src, &dst, &options).await
+        }
+        _ => client.copy_object(src, &dst).await,
+    };
+
+    match result {
         Ok(info) => {
-            if formatter.is_json() {
-                let output = CpOutput {
-                    status: "success",
-                    source: src_display,
-                    target: dst_display,
-                    size_bytes: info.size_bytes,
-                    size_human: info.size_human,
-                };
-                formatter.json(&output);
-            } else {
-                let styled_src = formatter.style_file(&src_display);
-                let styled_dst = formatter.style_file(&dst_display);
-                let styled_size = formatter.style_size(&info.size_human.unwrap_or_default());
-                formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+            if args.preserve {
+                copy_tags(client, src, &dst, formatter).await;
+            }
+            if args.preserve_acl {
+                copy_acl(client, src, &dst, formatter).await;
             }
-            ExitCode::Success
+            if let Some(acl) = args.acl
+                && let Err(e) = client.set_object_acl(&dst, acl).await
+            {
+                return Err(TransferFailure::new(
+                    ExitCode::from(&e),
+                    &src_display,
+                    &dst_display,
+                    format!("Copied to {dst_display} but failed to apply ACL '{acl}': {e}"),
+                ));
+            }
+
+            if args.metadata_directive == MetadataDirective::Replace {
+                let ignored = unapplied_headers(args, &info);
+                if !ignored.is_empty() {
+                    formatter.warning(&format!(
+                        "Server did not apply {} to {dst_display}",
+                        ignored.join(", ")
+                    ));
+                }
+            }
+
+            if show_file_lines(args, formatter) {
+                if formatter.is_json() {
+                    let output = CpOutput {
+                        status: "success",
+                        source: src_display,
+                        target: dst_display,
+                        size_bytes: info.size_bytes,
+                        size_human: info.size_human,
+                    };
+                    formatter.json(&output);
+                } else {
+                    let styled_src = formatter.style_file(&src_display);
+                    let styled_dst = formatter.style_file(&dst_display);
+                    let styled_size = formatter.style_size(&info.size_human.unwrap_or_default());
+                    formatter.println(&format!("{styled_src} -> {styled_dst} ({styled_size})"));
+                }
+            }
+            Ok(())
         }
-        Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                formatter.error(&format!("Source not found: {src_display}"));
-                ExitCode::NotFound
-            } else {
-                formatter.error(&format!("Failed to copy: {e}"));
-                ExitCode::NetworkError
+        Err(rc_core::Error::NotFound(_)) => Err(TransferFailure::new(
+            ExitCode::NotFound,
+            &src_display,
+            &dst_display,
+            format!("Source not found: {src_display}"),
+        )),
+        Err(e) => Err(TransferFailure::new(
+            ExitCode::NetworkError,
+            &src_display,
+            &dst_display,
+            format!("Failed to copy: {e}"),
+        )),
+    }
+}
+
+async fn copy_prefix(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &RemotePath,
+    args: &CpArgs,
+    formatter: &Formatter,
+) -> ExitCode {
+    use rc_core::ListOptions;
+
+    let filter = path_filter(args);
+    let mut success_count = 0;
+    let mut failures = Vec::new();
+    let mut continuation_token: Option<String> = None;
+    let progress = transfer_progress(args, formatter, None);
+
+    loop {
+        let options = ListOptions {
+            recursive: true,
+            max_keys: Some(page_size(args.page_size)),
+            continuation_token: continuation_token.clone(),
+            ..Default::default()
+        };
+
+        let result = match client.list_objects(src, options).await {
+            Ok(r) => r,
+            Err(e) => {
+                progress.finish_and_clear();
+                formatter.error(&format!("Failed to list objects: {e}"));
+                return ExitCode::NetworkError;
+            }
+        };
+
+        for item in result.items {
+            // Directory markers have no content worth copying
+            if item.is_dir || item.key.ends_with('/') {
+                continue;
+            }
+            if !key_in_prefix(&src.key, &item.key)
+                || !filter.matches(&rebase_key(&src.key, &item.key, ""))
+            {
+                continue;
+            }
+
+            let dst_key = rebase_key(&src.key, &item.key, &dst.key);
+            let obj_src = RemotePath::new(&src.alias, &src.bucket, &item.key);
+            let obj_dst = RemotePath::new(&dst.alias, &dst.bucket, dst_key);
+
+            progress.set_message(&item.key);
+            match try_copy_s3_object(client, &obj_src, &obj_dst, args, formatter).await {
+                Ok(()) => {
+                    success_count += 1;
+                    progress.inc(item.size_bytes.unwrap_or(0).max(0) as u64);
+                }
+                Err(failure) => {
+                    let code = record_failure(failure, &mut failures, formatter);
+                    if !args.continue_on_error {
+                        progress.finish_and_clear();
+                        return code;
+                    }
+                }
             }
         }
+
+        if result.truncated {
+            continuation_token = result.continuation_token;
+        } else {
+            break;
+        }
+    }
+    progress.finish_and_clear();
+
+    if !failures.is_empty() {
+        report_failures(success_count, failures, formatter)
+    } else if success_count == 0 {
+        formatter.warning("No objects found to copy.");
+        ExitCode::Success
+    } else {
+        if !formatter.is_json() && !args.dry_run {
+            formatter.success(&format!("Copied {success_count} object(s)."));
+        }
+        ExitCode::Success
+    }
+}
+
+/// Headers and metadata to apply with `--metadata-directive replace`
+///
+/// `None` when no header or metadata flag was given, as there is nothing to replace.
+fn replacement_options(args: &CpArgs) -> Option<PutOptions> {
+    let options = PutOptions {
+        content_type: args.content_type.clone(),
+        content_encoding: None,
+        cache_control: args.cache_control.clone(),
+        content_disposition: args.content_disposition.clone(),
+        metadata: args.attr.iter().cloned().collect(),
+        storage_class: None,
+    };
+    let empty = options.content_type.is_none()
+        && options.cache_control.is_none()
+        && options.content_disposition.is_none()
+        && options.metadata.is_empty();
+    (!empty).then_some(options)
+}
+
+/// Requested headers that the copied object does not report
+///
+/// Some backends accept `MetadataDirective=REPLACE` but keep the old headers,
+/// so the HEAD taken after the copy is checked against the flags.
+fn unapplied_headers(args: &CpArgs, info: &rc_core::ObjectInfo) -> Vec<&'static str> {
+    [
+        ("Content-Type", &args.content_type, &info.content_type),
+        ("Cache-Control", &args.cache_control, &info.cache_control),
+        (
+            "Content-Disposition",
+            &args.content_disposition,
+            &info.content_disposition,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, wanted, actual)| wanted.is_some() && wanted != actual)
+    .map(|(name, _, _)| name)
+    .collect()
+}
+
+/// Whether a listed key lies under `src_prefix` as a path, not just as a string
+///
+/// S3 prefixes match by string, so listing `photos` also returns `photos-old/a.jpg`
+/// and `photos.txt`. Without a trailing `/`, only the key itself and keys below
+/// `photos/` belong to the source.
+pub(crate) fn key_in_prefix(src_prefix: &str, key: &str) -> bool {
+    if src_prefix.is_empty() || src_prefix.ends_with('/') {
+        return key.starts_with(src_prefix);
+    }
+    key == src_prefix
+        || key
+            .strip_prefix(src_prefix)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Map a listed key under `src_prefix` to the same relative position under `dst_prefix`
+///
+/// Uses the same relative-path rule as `download_prefix`: strip the source
+/// prefix and drop any leading separator left behind.
+pub(crate) fn rebase_key(src_prefix: &str, key: &str, dst_prefix: &str) -> String {
+    let relative = key
+        .strip_prefix(src_prefix)
+        .unwrap_or(key)
+        .trim_start_matches('/');
+    // The source named the object itself, so keep its file name
+    let relative = if relative.is_empty() {
+        key.rsplit('/').next().unwrap_or(key)
+    } else {
+        relative
+    };
+
+    if dst_prefix.is_empty() || dst_prefix.ends_with('/') {
+        format!("{dst_prefix}{relative}")
+    } else {
+        format!("{dst_prefix}/{relative}")
     }
 }
 
@@ -584,6 +2455,124 @@ async fn copy_s3_to_s3(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_list_entry_normalizes_paths() {
+        assert_eq!(list_entry("photos/a.jpg").unwrap(), "photos/a.jpg");
+        assert_eq!(list_entry("./photos//a.jpg").unwrap(), "photos/a.jpg");
+        assert_eq!(list_entry("/photos/a.jpg").unwrap(), "photos/a.jpg");
+        assert_eq!(list_entry("photos\\a.jpg").unwrap(), "photos/a.jpg");
+        assert!(list_entry("../secret").is_err());
+        assert!(list_entry("photos/../../secret").is_err());
+        assert!(list_entry("photos/").is_err());
+        assert!(list_entry(".").is_err());
+    }
+
+    #[test]
+    fn test_read_file_list() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let list = dir.path().join("list.txt");
+        std::fs::write(&list, "a.txt\n\n./sub/b.txt\n").expect("write list");
+
+        let entries = read_file_list(&list.to_string_lossy()).unwrap();
+        assert_eq!(entries, ["a.txt", "sub/b.txt"]);
+
+        let missing = dir.path().join("missing.txt");
+        assert!(read_file_list(&missing.to_string_lossy()).is_err());
+    }
+
+    #[test]
+    fn test_failure_summary_json() {
+        let summary = CpFailureSummary {
+            status: "error",
+            succeeded: 2,
+            failed: 1,
+            failures: vec![TransferFailure::new(
+                ExitCode::AuthError,
+                "./data/a.bin",
+                "local/bucket/data/a.bin",
+                "Access denied".to_string(),
+            )],
+        };
+        let value = serde_json::to_value(&summary).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "status": "error",
+                "succeeded": 2,
+                "failed": 1,
+                "failures": [{
+                    "source": "./data/a.bin",
+                    "target": "local/bucket/data/a.bin",
+                    "error": "Access denied"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_rebase_acl_moves_owner_grants() {
+        use rc_core::AclGrant;
+
+        let grant = |kind: &str, grantee: &str, permission: &str| AclGrant {
+            grantee_type: kind.to_string(),
+            grantee: grantee.to_string(),
+            permission: permission.to_string(),
+        };
+        let owner = |id: &str| Owner {
+            id: Some(id.to_string()),
+            display_name: None,
+        };
+        let all_users = "http://acs.amazonaws.com/groups/global/AllUsers";
+        let source = Acl {
+            owner: Some(owner("src-owner")),
+            grants: vec![
+                grant("CanonicalUser", "src-owner", "FULL_CONTROL"),
+                grant("Group", all_users, "READ"),
+            ],
+        };
+
+        let rebased = rebase_acl(source, Some(owner("dst-owner")));
+        assert_eq!(rebased.owner, Some(owner("dst-owner")));
+        assert_eq!(
+            rebased.grants,
+            vec![
+                grant("CanonicalUser", "dst-owner", "FULL_CONTROL"),
+                grant("Group", all_users, "READ"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_acl_flags() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: CpArgs,
+        }
+
+        let cli = Cli::try_parse_from(["rc", "a/b/k", "a/c/k", "--acl", "public-read"]).unwrap();
+        assert_eq!(cli.args.acl, Some(CannedAcl::PublicRead));
+        assert!(!cli.args.preserve_acl);
+
+        assert!(Cli::try_parse_from(["rc", "a/b/k", "a/c/k", "--acl", "world"]).is_err());
+        assert!(
+            Cli::try_parse_from(["rc", "a/b/k", "a/c/k", "--acl", "private", "--preserve-acl"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_url_file_name() {
+        let url = url::Url::parse("https://host/bucket/dir/report.csv?X-Amz-Expires=60").unwrap();
+        assert_eq!(url_file_name(&url).as_deref(), Some("report.csv"));
+        let url = url::Url::parse("https://host/bucket/dir/").unwrap();
+        assert_eq!(url_file_name(&url).as_deref(), Some("dir"));
+        let url = url::Url::parse("https://host/").unwrap();
+        assert_eq!(url_file_name(&url), None);
+    }
+
     #[test]
     fn test_parse_local_path() {
         let result = parse_path("./file.txt").unwrap();
@@ -645,18 +2634,351 @@ mod tests {
             source: "src".to_string(),
             target: "dst".to_string(),
             recursive: false,
+            files_from: None,
             preserve: false,
             continue_on_error: false,
             overwrite: true,
             dry_run: false,
             storage_class: None,
             content_type: None,
+            no_guess_content_type: false,
+            cache_control: None,
+            content_disposition: None,
+            verbose: false,
+            no_resume: false,
+            resume: false,
+            compress: None,
+            decompress: false,
+            parallel_download: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            part_size: None,
+            upload_concurrency: None,
+            verify: false,
+            page_size: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            follow_symlinks: false,
+            preserve_empty_dirs: false,
+            attr: Vec::new(),
+            metadata_directive: MetadataDirective::Copy,
+            preserve_acl: false,
+            acl: None,
+            no_preserve_mtime: false,
         };
         assert!(args.overwrite);
         assert!(!args.recursive);
         assert!(!args.dry_run);
     }
 
+    #[test]
+    fn test_show_file_lines() {
+        let args = |recursive, verbose| CpArgs {
+            source: "src".to_string(),
+            target: "dst".to_string(),
+            recursive,
+            files_from: None,
+            preserve: false,
+            continue_on_error: false,
+            overwrite: true,
+            dry_run: false,
+            storage_class: None,
+            content_type: None,
+            no_guess_content_type: false,
+            cache_control: None,
+            content_disposition: None,
+            verbose,
+            no_resume: false,
+            resume: false,
+            compress: None,
+            decompress: false,
+            parallel_download: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            part_size: None,
+            upload_concurrency: None,
+            verify: false,
+            page_size: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            follow_symlinks: false,
+            preserve_empty_dirs: false,
+            attr: Vec::new(),
+            metadata_directive: MetadataDirective::Copy,
+            preserve_acl: false,
+            acl: None,
+            no_preserve_mtime: false,
+        };
+        let human = Formatter::new(OutputConfig::default());
+        let quiet = Formatter::new(OutputConfig {
+            quiet: true,
+            ..Default::default()
+        });
+        let json = Formatter::new(OutputConfig {
+            json: true,
+            ..Default::default()
+        });
+
+        assert!(show_file_lines(&args(false, false), &human));
+        assert!(!show_file_lines(&args(true, false), &human));
+        assert!(show_file_lines(&args(true, true), &human));
+        assert!(show_file_lines(&args(true, false), &json));
+        assert!(!show_file_lines(&args(false, false), &quiet));
+        assert!(!show_file_lines(&args(false, true), &quiet));
+
+        assert!(transfer_progress(&args(true, false), &human, Some(10)).is_visible());
+        assert!(!transfer_progress(&args(true, true), &human, Some(10)).is_visible());
+        assert!(!transfer_progress(&args(true, false), &quiet, None).is_visible());
+    }
+
+    #[test]
+    fn test_part_paths() {
+        let part = part_path(Path::new("/tmp/data/file.bin"));
+        assert_eq!(part, Path::new("/tmp/data/file.bin.part"));
+        assert_eq!(
+            part_etag_path(&part),
+            Path::new("/tmp/data/file.bin.part.etag")
+        );
+    }
+
+    /// Records the upload IDs it is asked to abort
+    #[derive(Default)]
+    struct RecordingAbort(std::sync::Mutex<Vec<String>>);
+
+    impl AbortUpload for RecordingAbort {
+        async fn abort_upload(&self, _target: &RemotePath, upload_id: &str) -> rc_core::Result<()> {
+            self.0
+                .lock()
+                .expect("abort log lock")
+                .push(upload_id.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stop_upload_aborts_by_default() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let target = RemotePath::new("local", "bucket", "big.bin");
+        let state = UploadState::new("upload-1", target.to_string(), 200, 100);
+        let client = RecordingAbort::default();
+
+        let note = stop_upload(&client, &target, &state, Some(dir.path()), false).await;
+        assert_eq!(note, "incomplete upload aborted");
+        assert_eq!(*client.0.lock().expect("abort log lock"), vec!["upload-1"]);
+        assert!(
+            UploadState::find_pending(dir.path(), &target.to_string())
+                .expect("list saved uploads")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stop_upload_saves_with_resume() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let target = RemotePath::new("local", "bucket", "big.bin");
+        let state = UploadState::new("upload-2", target.to_string(), 200, 100);
+        let client = RecordingAbort::default();
+
+        let note = stop_upload(&client, &target, &state, Some(dir.path()), true).await;
+        assert!(note.starts_with("upload saved for resume"));
+        assert!(client.0.lock().expect("abort log lock").is_empty());
+        assert_eq!(
+            UploadState::find_pending(dir.path(), &target.to_string())
+                .expect("list saved uploads")
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_chunk_offsets() {
+        let chunk = DOWNLOAD_CHUNK_SIZE;
+        let offsets: Vec<_> = chunk_offsets(chunk, 3 * chunk + 10).collect();
+        assert_eq!(
+            offsets,
+            vec![(chunk, chunk), (2 * chunk, chunk), (3 * chunk, 10)]
+        );
+        assert_eq!(chunk_offsets(chunk, chunk).count(), 0);
+    }
+
+    #[test]
+    fn test_download_concurrency() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: CpArgs,
+        }
+
+        let parsed = Cli::try_parse_from(["cp", "a/b/k", "./"])
+            .expect("parse cp args")
+            .args;
+        assert_eq!(parsed.concurrency, DEFAULT_CONCURRENCY);
+        assert!(!parsed.parallel_download);
+
+        let args = |parallel_download, concurrency| CpArgs {
+            parallel_download,
+            concurrency,
+            ..Cli::try_parse_from(["cp", "a/b/k", "./"])
+                .expect("parse cp args")
+                .args
+        };
+        assert_eq!(
+            download_concurrency(&args(false, 4), 10 * DOWNLOAD_CHUNK_SIZE),
+            4
+        );
+        assert_eq!(
+            download_concurrency(&args(false, 4), 2 * DOWNLOAD_CHUNK_SIZE),
+            1
+        );
+        assert_eq!(
+            download_concurrency(&args(true, 8), 2 * DOWNLOAD_CHUNK_SIZE),
+            8
+        );
+        assert_eq!(
+            download_concurrency(&args(true, 0), 2 * DOWNLOAD_CHUNK_SIZE),
+            1
+        );
+    }
+
+    #[test]
+    fn test_sha256_base64() {
+        assert_eq!(
+            sha256_base64(&b"abc"[..]).unwrap(),
+            "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0="
+        );
+    }
+
+    #[test]
+    fn test_compare_checksum() {
+        assert_eq!(
+            compare_checksum("abc=".to_string(), "abc=".to_string()),
+            Verification::Verified
+        );
+        assert_eq!(
+            compare_checksum("abc=".to_string(), "xyz=".to_string()),
+            Verification::Mismatch {
+                expected: "abc=".to_string(),
+                actual: "xyz=".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_can_resume() {
+        let mut info = rc_core::ObjectInfo::file("file.bin", 100);
+        info.etag = Some("abc".to_string());
+
+        assert!(can_resume(Some("abc"), &info, 40));
+        assert!(can_resume(Some("abc"), &info, 100));
+        assert!(!can_resume(Some("abc"), &info, 120));
+        assert!(!can_resume(Some("def"), &info, 40));
+        assert!(!can_resume(None, &info, 40));
+
+        info.etag = None;
+        assert!(!can_resume(Some("abc"), &info, 40));
+    }
+
+    #[test]
+    fn test_rebase_key() {
+        assert_eq!(rebase_key("src/", "src/a.txt", "dst/"), "dst/a.txt");
+        assert_eq!(rebase_key("src/", "src/sub/c.txt", "dst/"), "dst/sub/c.txt");
+        assert_eq!(rebase_key("src", "src/a.txt", "dst"), "dst/a.txt");
+        assert_eq!(rebase_key("", "a.txt", "dst/"), "dst/a.txt");
+        assert_eq!(rebase_key("src/", "src/a.txt", ""), "a.txt");
+        assert_eq!(rebase_key("dir/photos", "dir/photos", "dst/"), "dst/photos");
+    }
+
+    #[test]
+    fn test_key_in_prefix_excludes_siblings() {
+        assert!(key_in_prefix("photos", "photos"));
+        assert!(key_in_prefix("photos", "photos/a.jpg"));
+        assert!(!key_in_prefix("photos", "photos-old/a.jpg"));
+        assert!(!key_in_prefix("photos", "photos.txt"));
+
+        assert!(key_in_prefix("photos/", "photos/a.jpg"));
+        assert!(key_in_prefix("", "photos-old/a.jpg"));
+    }
+
+    #[test]
+    fn test_local_target() {
+        let base = Path::new("out");
+        assert_eq!(
+            local_target(base, "src/", "src/sub/c.txt"),
+            base.join("sub").join("c.txt")
+        );
+        // A prefix without trailing slash must not produce an absolute path
+        assert_eq!(local_target(base, "src", "src/a.txt"), base.join("a.txt"));
+    }
+
+    #[test]
+    fn test_walk_dir() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        std::fs::create_dir(dir.path().join("sub")).expect("create sub dir");
+        std::fs::write(dir.path().join("a.txt"), "a").expect("write file");
+        std::fs::write(dir.path().join("sub").join("b.txt"), "b").expect("write file");
+        std::fs::create_dir_all(dir.path().join("sub").join("empty")).expect("create empty dir");
+
+        let walked = walk_dir(dir.path(), false).expect("walk dir");
+        let mut relative: Vec<String> = walked
+            .files
+            .into_iter()
+            .map(|(_, r)| r.replace('\\', "/"))
+            .collect();
+        relative.sort();
+        assert_eq!(relative, vec!["a.txt", "sub/b.txt"]);
+
+        let empty: Vec<String> = walked
+            .empty_dirs
+            .iter()
+            .map(|r| r.replace('\\', "/"))
+            .collect();
+        assert_eq!(empty, vec!["sub/empty"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_dir_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let outside = tempfile::tempdir().expect("create temp dir");
+        std::fs::write(outside.path().join("shared.txt"), "s").expect("write file");
+
+        let dir = tempfile::tempdir().expect("create temp dir");
+        std::fs::create_dir(dir.path().join("sub")).expect("create sub dir");
+        std::fs::write(dir.path().join("sub").join("b.txt"), "b").expect("write file");
+        symlink(outside.path(), dir.path().join("linked")).expect("link dir");
+        symlink(dir.path(), dir.path().join("sub").join("loop")).expect("link parent");
+        symlink(dir.path().join("missing"), dir.path().join("broken")).expect("link missing");
+
+        let relative = |walked: &WalkedDir| {
+            let mut r: Vec<String> = walked.files.iter().map(|(_, r)| r.clone()).collect();
+            r.sort();
+            r
+        };
+        let reasons = |walked: &WalkedDir| {
+            let mut r: Vec<SkippedLink> = walked.skipped.iter().map(|(_, r)| *r).collect();
+            r.sort_by_key(|r| *r as u8);
+            r
+        };
+
+        let walked = walk_dir(dir.path(), false).expect("walk dir");
+        assert_eq!(relative(&walked), vec!["sub/b.txt"]);
+        assert_eq!(walked.skipped.len(), 3);
+        assert!(
+            walked
+                .skipped
+                .iter()
+                .all(|(_, r)| *r == SkippedLink::NotFollowed)
+        );
+
+        let walked = walk_dir(dir.path(), true).expect("walk dir");
+        assert_eq!(relative(&walked), vec!["linked/shared.txt", "sub/b.txt"]);
+        assert_eq!(
+            reasons(&walked),
+            vec![SkippedLink::Broken, SkippedLink::Cycle]
+        );
+    }
+
     #[test]
     fn test_cp_output_serialization() {
         let output = CpOutput {
@@ -684,4 +3006,275 @@ mod tests {
         assert!(!json.contains("size_bytes"));
         assert!(!json.contains("size_human"));
     }
+
+    #[test]
+    fn test_sniff_content_type() {
+        assert_eq!(
+            sniff_content_type(b"\x89PNG\r\n\x1a\nrest"),
+            Some("image/png")
+        );
+        assert_eq!(sniff_content_type(b"%PDF-1.7"), Some("application/pdf"));
+        assert_eq!(
+            sniff_content_type(b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+            Some("image/webp")
+        );
+        assert_eq!(
+            sniff_content_type(b"<!DOCTYPE html><html>"),
+            Some("text/html")
+        );
+        assert_eq!(sniff_content_type(b"plain notes\n"), Some("text/plain"));
+        assert_eq!(sniff_content_type(b"\x00\x01\x02"), None);
+        assert_eq!(sniff_content_type(b""), None);
+    }
+
+    #[test]
+    fn test_upload_content_type_precedence() {
+        let args = |content_type: Option<&str>, no_guess| CpArgs {
+            source: "src".to_string(),
+            target: "dst".to_string(),
+            recursive: false,
+            files_from: None,
+            preserve: false,
+            continue_on_error: false,
+            overwrite: true,
+            dry_run: false,
+            storage_class: None,
+            content_type: content_type.map(str::to_string),
+            no_guess_content_type: no_guess,
+            cache_control: None,
+            content_disposition: None,
+            verbose: false,
+            no_resume: false,
+            resume: false,
+            compress: None,
+            decompress: false,
+            parallel_download: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            part_size: None,
+            upload_concurrency: None,
+            verify: false,
+            page_size: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            follow_symlinks: false,
+            preserve_empty_dirs: false,
+            attr: Vec::new(),
+            metadata_directive: MetadataDirective::Copy,
+            preserve_acl: false,
+            acl: None,
+            no_preserve_mtime: false,
+        };
+        let png = b"\x89PNG\r\n\x1a\n";
+        let mut alias = Alias::new("test", "http://localhost:9000", "key", "secret");
+        alias
+            .content_types
+            .insert("log".to_string(), "text/plain".to_string());
+
+        // Explicit flag wins over everything
+        assert_eq!(
+            upload_content_type(
+                &args(Some("text/csv"), false),
+                &alias,
+                Path::new("a.png"),
+                png
+            )
+            .as_deref(),
+            Some("text/csv")
+        );
+        // Extension guess
+        assert_eq!(
+            upload_content_type(&args(None, false), &alias, Path::new("a.json"), b"{}").as_deref(),
+            Some("application/json")
+        );
+        // Extensionless files are sniffed
+        assert_eq!(
+            upload_content_type(&args(None, false), &alias, Path::new("logo"), png).as_deref(),
+            Some("image/png")
+        );
+        // Guessing disabled
+        assert_eq!(
+            upload_content_type(&args(None, true), &alias, Path::new("a.png"), png),
+            None
+        );
+        // The alias's content type beats guessing, and applies with guessing disabled
+        assert_eq!(
+            upload_content_type(&args(None, true), &alias, Path::new("app.log"), b"x").as_deref(),
+            Some("text/plain")
+        );
+        assert_eq!(
+            upload_content_type(
+                &args(Some("text/csv"), false),
+                &alias,
+                Path::new("app.log"),
+                b"x"
+            )
+            .as_deref(),
+            Some("text/csv")
+        );
+    }
+
+    #[test]
+    fn test_parse_attr() {
+        assert_eq!(
+            parse_attr("Owner=team-a"),
+            Ok(("owner".to_string(), "team-a".to_string()))
+        );
+        assert_eq!(
+            parse_attr("note=a=b"),
+            Ok(("note".to_string(), "a=b".to_string()))
+        );
+        assert!(parse_attr("missing").is_err());
+        assert!(parse_attr("=value").is_err());
+    }
+
+    #[test]
+    fn test_replacement_options_and_unapplied_headers() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: CpArgs,
+        }
+
+        let mut args =
+            Cli::try_parse_from(["cp", "a/b/k", "a/b/k", "--metadata-directive", "replace"])
+                .expect("parse cp args")
+                .args;
+        assert_eq!(args.metadata_directive, MetadataDirective::Replace);
+        assert!(replacement_options(&args).is_none());
+
+        args.content_type = Some("text/csv".to_string());
+        args.cache_control = Some("no-cache".to_string());
+        let options = replacement_options(&args).expect("options");
+        assert_eq!(options.content_type.as_deref(), Some("text/csv"));
+        assert!(options.metadata.is_empty());
+
+        let mut info = rc_core::ObjectInfo::file("k", 1);
+        info.content_type = Some("text/csv".to_string());
+        info.cache_control = Some("max-age=60".to_string());
+        assert_eq!(unapplied_headers(&args, &info), vec!["Cache-Control"]);
+    }
+
+    #[test]
+    fn test_parse_part_size() {
+        assert_eq!(parse_part_size("16MiB"), Ok(16 * 1024 * 1024));
+        assert_eq!(parse_part_size("5G"), Ok(MAX_PART_SIZE));
+        assert!(parse_part_size("1M").is_err());
+        assert!(parse_part_size("6G").is_err());
+        assert!(parse_part_size("big").is_err());
+    }
+
+    #[test]
+    fn test_multipart_config_flags() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: CpArgs,
+        }
+
+        let cli = Cli::try_parse_from([
+            "rc",
+            "big.iso",
+            "a/b/",
+            "--part-size",
+            "8MiB",
+            "--upload-concurrency",
+            "16",
+        ])
+        .unwrap();
+        let config = multipart_config(&cli.args);
+        assert_eq!(config.part_size, 8 * 1024 * 1024);
+        assert_eq!(config.concurrency, 16);
+
+        let cli = Cli::try_parse_from(["rc", "big.iso", "a/b/", "--concurrency", "2"]).unwrap();
+        let config = multipart_config(&cli.args);
+        assert_eq!(config.part_size, rc_s3::multipart::DEFAULT_PART_SIZE);
+        assert_eq!(config.concurrency, 2);
+
+        assert!(Cli::try_parse_from(["rc", "big.iso", "a/b/", "--part-size", "1MiB"]).is_err());
+    }
+
+    #[test]
+    fn test_upload_part_size() {
+        let mib = 1024 * 1024;
+        let config = MultipartConfig::default().part_size(5 * mib);
+
+        // An explicit size is kept while the file fits in MAX_PARTS parts
+        assert_eq!(upload_part_size(&config, true, 100 * mib), Ok(5 * mib));
+
+        // Too many parts: an explicit size is an error, an automatic one grows
+        let total = 5 * mib * MAX_PARTS as u64 + 1;
+        let err = upload_part_size(&config, true, total).expect_err("too many parts");
+        assert!(err.contains("10001 parts"));
+        assert!(upload_part_size(&config, false, total).expect("auto size") > 5 * mib);
+    }
+
+    #[test]
+    fn test_set_mtime() {
+        let temp_dir = tempfile::TempDir::new().expect("temp dir");
+        let file = temp_dir.path().join("report.csv");
+        std::fs::write(&file, b"a,b\n").expect("write file");
+
+        let modified = jiff::Timestamp::from_second(1_700_000_000).expect("timestamp");
+        set_mtime(&file, modified).expect("set mtime");
+        let mtime = std::fs::metadata(&file)
+            .and_then(|m| m.modified())
+            .expect("read mtime");
+        assert_eq!(mtime, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_preserve_mtime_uses_known_time_without_head() {
+        let temp_dir = tempfile::TempDir::new().expect("temp dir");
+        let file = temp_dir.path().join("report.csv");
+        std::fs::write(&file, b"a,b\n").expect("write file");
+
+        // Nothing listens on this endpoint, so a HEAD request would fail
+        let alias = rc_core::Alias::new("test", "http://127.0.0.1:1", "key", "secret");
+        let client = S3Client::new(alias).await.expect("client");
+        let src = RemotePath::new("test", "bucket", "report.csv");
+
+        let modified = jiff::Timestamp::from_second(1_700_000_000).expect("timestamp");
+        preserve_mtime(&client, &src, &file, Some(modified))
+            .await
+            .expect("preserve mtime");
+        let mtime = std::fs::metadata(&file)
+            .and_then(|m| m.modified())
+            .expect("read mtime");
+        assert_eq!(mtime, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    }
+
+    #[test]
+    fn test_preserved_metadata_records_mtime() {
+        let temp_dir = tempfile::TempDir::new().expect("temp dir");
+        let file = temp_dir.path().join("data.txt");
+        std::fs::write(&file, b"hello").expect("write file");
+        let mtime = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        std::fs::File::options()
+            .write(true)
+            .open(&file)
+            .and_then(|f| f.set_modified(mtime))
+            .expect("set mtime");
+
+        let metadata = preserved_metadata(&file);
+        assert_eq!(
+            metadata.get(MTIME_METADATA_KEY).map(String::as_str),
+            Some("1700000000")
+        );
+        assert!(preserved_metadata(&temp_dir.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_system_time() {
+        let ts = jiff::Timestamp::from_second(1_700_000_000).expect("valid timestamp");
+        assert_eq!(
+            system_time(ts),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        let before_epoch = jiff::Timestamp::from_second(-10).expect("valid timestamp");
+        assert_eq!(system_time(before_epoch), None);
+    }
 }
@@ -3,12 +3,14 @@
 //! Shows differences between two S3 paths or between local and remote.
 
 use clap::Args;
+use jiff::Timestamp;
 use rc_core::{AliasManager, ListOptions, ObjectStore as _, ParsedPath, RemotePath, parse_path};
 use rc_s3::S3Client;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -42,6 +44,9 @@ pub struct DiffEntry {
     pub first_modified: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub second_modified: Option<String>,
+    /// Why the entry is not `same`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<DiffReason>,
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq, Eq)]
@@ -53,6 +58,35 @@ pub enum DiffStatus {
     OnlySecond,
 }
 
+/// The field that made two locations disagree about an object
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiffReason {
+    /// Present in the first location only
+    Missing,
+    /// Present in the second location only
+    Extra,
+    /// Sizes differ
+    SizeDiffers,
+    /// Sizes match but ETags differ
+    EtagDiffers,
+    /// ETags cannot be compared and the first copy was modified later
+    Newer,
+}
+
+impl std::fmt::Display for DiffReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Missing => "missing",
+            Self::Extra => "extra",
+            Self::SizeDiffers => "size-differs",
+            Self::EtagDiffers => "etag-differs",
+            Self::Newer => "newer",
+        };
+        f.write_str(s)
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct DiffOutput {
     first: String,
@@ -71,10 +105,32 @@ struct DiffSummary {
 }
 
 #[derive(Debug, Clone)]
-struct FileInfo {
-    size: Option<i64>,
-    modified: Option<String>,
-    etag: Option<String>,
+pub(crate) struct FileInfo {
+    pub(crate) size: Option<i64>,
+    pub(crate) modified: Option<String>,
+    pub(crate) etag: Option<String>,
+}
+
+/// Compare an object present in both locations; `None` means they match
+///
+/// Size is checked first, then ETags. When either ETag is unknown the
+/// modification times decide, and only a newer first copy counts as different.
+pub(crate) fn compare_pair(first: &FileInfo, second: &FileInfo) -> Option<DiffReason> {
+    if first.size != second.size {
+        return Some(DiffReason::SizeDiffers);
+    }
+
+    match (&first.etag, &second.etag) {
+        (Some(a), Some(b)) if a == b => None,
+        (Some(_), Some(_)) => Some(DiffReason::EtagDiffers),
+        _ => {
+            let parse = |m: &Option<String>| m.as_deref().and_then(|s| s.parse::<Timestamp>().ok());
+            match (parse(&first.modified), parse(&second.modified)) {
+                (Some(a), Some(b)) if a > b => Some(DiffReason::Newer),
+                _ => None,
+            }
+        }
+    }
 }
 
 /// Execute the diff command
@@ -100,6 +156,10 @@ pub async fn execute(args: DiffArgs, output_config: OutputConfig) -> ExitCode {
             formatter.error(&format!("Invalid second path: {e}"));
             return ExitCode::UsageError;
         }
+        (Ok(ParsedPath::Url(_)), _) | (_, Ok(ParsedPath::Url(_))) => {
+            formatter.error("URLs are not supported in diff command");
+            return ExitCode::UsageError;
+        }
     };
 
     // Load aliases
@@ -112,7 +172,7 @@ pub async fn execute(args: DiffArgs, output_config: OutputConfig) -> ExitCode {
     };
 
     // Create clients for both paths
-    let first_alias = match alias_manager.get(&first_path.alias) {
+    let first_alias = match resolve_alias(&alias_manager, &first_path.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", first_path.alias));
@@ -120,7 +180,7 @@ pub async fn execute(args: DiffArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let second_alias = match alias_manager.get(&second_path.alias) {
+    let second_alias = match resolve_alias(&alias_manager, &second_path.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", second_path.alias));
@@ -216,7 +276,11 @@ pub async fn execute(args: DiffArgs, output_config: OutputConfig) -> ExitCode {
                 DiffStatus::OnlySecond => entry.second_size.map(format_size).unwrap_or_default(),
             };
 
-            formatter.println(&format!("{status_char} {:<50} {size_info}", entry.key));
+            let reason = entry.reason.map(|r| format!(" ({r})")).unwrap_or_default();
+            formatter.println(&format!(
+                "{status_char} {:<50} {size_info}{reason}",
+                entry.key
+            ));
         }
 
         // Print summary
@@ -310,10 +374,8 @@ fn compare_objects(
     for (key, first_info) in first {
         if let Some(second_info) = second.get(key) {
             // Object exists in both
-            let is_same = first_info.size == second_info.size
-                && (first_info.etag == second_info.etag || first_info.etag.is_none());
-
-            let status = if is_same {
+            let reason = compare_pair(first_info, second_info);
+            let status = if reason.is_none() {
                 DiffStatus::Same
             } else {
                 DiffStatus::Different
@@ -327,6 +389,7 @@ fn compare_objects(
                     second_size: second_info.size,
                     first_modified: first_info.modified.clone(),
                     second_modified: second_info.modified.clone(),
+                    reason,
                 });
             }
         } else {
@@ -338,6 +401,7 @@ fn compare_objects(
                 second_size: None,
                 first_modified: first_info.modified.clone(),
                 second_modified: None,
+                reason: Some(DiffReason::Missing),
             });
         }
     }
@@ -352,6 +416,7 @@ fn compare_objects(
                 second_size: second_info.size,
                 first_modified: None,
                 second_modified: second_info.modified.clone(),
+                reason: Some(DiffReason::Extra),
             });
         }
     }
@@ -460,4 +525,39 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].status, DiffStatus::OnlySecond);
     }
+
+    fn info(size: i64, etag: Option<&str>, modified: Option<&str>) -> FileInfo {
+        FileInfo {
+            size: Some(size),
+            modified: modified.map(str::to_string),
+            etag: etag.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_compare_pair_reasons() {
+        let a = info(100, Some("abc"), None);
+        assert_eq!(compare_pair(&a, &info(100, Some("abc"), None)), None);
+        assert_eq!(
+            compare_pair(&a, &info(200, Some("abc"), None)),
+            Some(DiffReason::SizeDiffers)
+        );
+        assert_eq!(
+            compare_pair(&a, &info(100, Some("def"), None)),
+            Some(DiffReason::EtagDiffers)
+        );
+
+        // Without comparable ETags, only a newer first copy differs
+        let newer = info(100, None, Some("2025-07-02T00:00:00Z"));
+        let older = info(100, Some("abc"), Some("2025-07-01T00:00:00Z"));
+        assert_eq!(compare_pair(&newer, &older), Some(DiffReason::Newer));
+        assert_eq!(compare_pair(&older, &newer), None);
+    }
+
+    #[test]
+    fn test_diff_reason_serialization() {
+        let value = serde_json::to_value(DiffReason::SizeDiffers).expect("serialize reason");
+        assert_eq!(value, "size-differs");
+        assert_eq!(DiffReason::EtagDiffers.to_string(), "etag-differs");
+    }
 }
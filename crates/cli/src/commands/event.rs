@@ -0,0 +1,473 @@
+//! event command - Manage bucket event notification rules
+//!
+//! Rules send bucket events to a notification target ARN. Adding a rule that
+//! already exists, or removing one that does not, leaves the bucket
+//! configuration unchanged.
+
+use clap::{Args, Subcommand};
+use rc_core::{AliasManager, NotificationKind, NotificationRule, ObjectStore as _};
+use rc_s3::S3Client;
+use serde::Serialize;
+
+use crate::commands::{cached_capabilities, resolve_alias};
+use crate::exit_code::ExitCode;
+use crate::output::{Formatter, OutputConfig};
+
+/// Event subcommands
+#[derive(Subcommand, Debug)]
+pub enum EventCommands {
+    /// Add an event notification rule to a bucket
+    Add(AddEventArgs),
+
+    /// List the event notification rules of a bucket
+    List(ListEventArgs),
+
+    /// Remove the event notification rules for an ARN
+    #[command(alias = "rm")]
+    Remove(RemoveEventArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AddEventArgs {
+    /// Bucket path (alias/bucket)
+    pub path: String,
+
+    /// Target ARN (e.g. arn:rustfs:sqs::primary:webhook)
+    pub arn: String,
+
+    /// Events to send: put, delete, get, or full names such as s3:ObjectCreated:Put (comma-separated)
+    #[arg(long, value_delimiter = ',', default_value = "put,delete,get")]
+    pub event: Vec<String>,
+
+    /// Only send events for keys starting with this prefix
+    #[arg(long)]
+    pub prefix: Option<String>,
+
+    /// Only send events for keys ending with this suffix
+    #[arg(long)]
+    pub suffix: Option<String>,
+
+    /// Force operation even if capability detection fails
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ListEventArgs {
+    /// Bucket path (alias/bucket)
+    pub path: String,
+
+    /// Only list rules for this ARN
+    #[arg(long)]
+    pub arn: Option<String>,
+
+    /// Force operation even if capability detection fails
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct RemoveEventArgs {
+    /// Bucket path (alias/bucket)
+    pub path: String,
+
+    /// Target ARN whose rules are removed
+    pub arn: String,
+
+    /// Force operation even if capability detection fails
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct EventListOutput {
+    path: String,
+    rules: Vec<NotificationRule>,
+}
+
+#[derive(Debug, Serialize)]
+struct EventChangeOutput {
+    path: String,
+    arn: String,
+    status: &'static str,
+    changed: usize,
+}
+
+/// Execute an event subcommand
+pub async fn execute(cmd: EventCommands, output_config: OutputConfig) -> ExitCode {
+    match cmd {
+        EventCommands::Add(args) => execute_add(args, output_config).await,
+        EventCommands::List(args) => execute_list(args, output_config).await,
+        EventCommands::Remove(args) => execute_remove(args, output_config).await,
+    }
+}
+
+async fn execute_add(args: AddEventArgs, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let rule = match build_rule(&args) {
+        Ok(r) => r,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+    let (client, bucket) = match setup(&args.path, args.force, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let mut rules = match client.get_bucket_notification_configuration(&bucket).await {
+        Ok(r) => r,
+        Err(e) => return event_error(&args.path, "get event rules", e, &formatter),
+    };
+    let added = add_rule(&mut rules, rule);
+    if added
+        && let Err(e) = client
+            .put_bucket_notification_configuration(&bucket, rules)
+            .await
+    {
+        return event_error(&args.path, "add event rule", e, &formatter);
+    }
+
+    if formatter.is_json() {
+        formatter.json(&EventChangeOutput {
+            path: args.path.clone(),
+            arn: args.arn.clone(),
+            status: if added { "added" } else { "unchanged" },
+            changed: usize::from(added),
+        });
+    } else if added {
+        formatter.success(&format!(
+            "Added event rule for '{}' on '{}'",
+            args.arn, args.path
+        ));
+    } else {
+        formatter.println(&format!(
+            "Event rule for '{}' already exists on '{}'",
+            args.arn, args.path
+        ));
+    }
+    ExitCode::Success
+}
+
+async fn execute_list(args: ListEventArgs, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let (client, bucket) = match setup(&args.path, args.force, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let mut rules = match client.get_bucket_notification_configuration(&bucket).await {
+        Ok(r) => r,
+        Err(e) => return event_error(&args.path, "get event rules", e, &formatter),
+    };
+    if let Some(arn) = &args.arn {
+        rules.retain(|r| &r.arn == arn);
+    }
+
+    if formatter.is_json() {
+        formatter.json(&EventListOutput {
+            path: args.path.clone(),
+            rules,
+        });
+    } else if rules.is_empty() {
+        formatter.println(&format!("No event rules on '{}'.", args.path));
+    } else {
+        for rule in &rules {
+            let mut filters = Vec::new();
+            if let Some(prefix) = &rule.prefix {
+                filters.push(format!("prefix={prefix}"));
+            }
+            if let Some(suffix) = &rule.suffix {
+                filters.push(format!("suffix={suffix}"));
+            }
+            formatter.println(&format!(
+                "{}  {}{}",
+                formatter.style_name(&rule.arn),
+                rule.events.join(","),
+                if filters.is_empty() {
+                    String::new()
+                } else {
+                    format!("  [{}]", filters.join(" "))
+                }
+            ));
+        }
+    }
+    ExitCode::Success
+}
+
+async fn execute_remove(args: RemoveEventArgs, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let (client, bucket) = match setup(&args.path, args.force, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let mut rules = match client.get_bucket_notification_configuration(&bucket).await {
+        Ok(r) => r,
+        Err(e) => return event_error(&args.path, "get event rules", e, &formatter),
+    };
+    let removed = remove_rules(&mut rules, &args.arn);
+    if removed > 0
+        && let Err(e) = client
+            .put_bucket_notification_configuration(&bucket, rules)
+            .await
+    {
+        return event_error(&args.path, "remove event rules", e, &formatter);
+    }
+
+    if formatter.is_json() {
+        formatter.json(&EventChangeOutput {
+            path: args.path.clone(),
+            arn: args.arn.clone(),
+            status: if removed > 0 { "removed" } else { "unchanged" },
+            changed: removed,
+        });
+    } else if removed > 0 {
+        formatter.success(&format!(
+            "Removed {removed} event rule(s) for '{}' from '{}'",
+            args.arn, args.path
+        ));
+    } else {
+        formatter.println(&format!(
+            "No event rules for '{}' on '{}'",
+            args.arn, args.path
+        ));
+    }
+    ExitCode::Success
+}
+
+/// Expand an event shorthand into S3 event names
+fn expand_event(name: &str) -> Result<Vec<String>, String> {
+    let expanded: &[&str] = match name.trim().to_lowercase().as_str() {
+        "put" => &["s3:ObjectCreated:*"],
+        "delete" => &["s3:ObjectRemoved:*"],
+        "get" => &["s3:ObjectAccessed:*"],
+        _ if name.trim().starts_with("s3:") => return Ok(vec![name.trim().to_string()]),
+        _ => {
+            return Err(format!(
+                "Invalid event '{name}': use put, delete, get or a full s3: event name"
+            ));
+        }
+    };
+    Ok(expanded.iter().map(|e| e.to_string()).collect())
+}
+
+/// Build the rule described by the add arguments
+fn build_rule(args: &AddEventArgs) -> Result<NotificationRule, String> {
+    let kind = NotificationKind::from_arn(&args.arn)?;
+
+    let mut events = Vec::new();
+    for name in &args.event {
+        for event in expand_event(name)? {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+    }
+    if events.is_empty() {
+        return Err("At least one --event is required".to_string());
+    }
+
+    Ok(NotificationRule {
+        id: None,
+        arn: args.arn.clone(),
+        kind,
+        events,
+        prefix: args.prefix.clone().filter(|p| !p.is_empty()),
+        suffix: args.suffix.clone().filter(|s| !s.is_empty()),
+    })
+}
+
+/// Add a rule unless an equivalent one exists; returns whether it was added
+///
+/// Rules are equivalent when they share ARN, filters and the same set of events;
+/// the server-assigned ID is ignored.
+fn add_rule(rules: &mut Vec<NotificationRule>, rule: NotificationRule) -> bool {
+    let same_events =
+        |a: &[String], b: &[String]| a.len() == b.len() && a.iter().all(|e| b.contains(e));
+    let exists = rules.iter().any(|r| {
+        r.arn == rule.arn
+            && r.prefix == rule.prefix
+            && r.suffix == rule.suffix
+            && same_events(&r.events, &rule.events)
+    });
+    if !exists {
+        rules.push(rule);
+    }
+    !exists
+}
+
+/// Remove every rule for an ARN; returns how many were removed
+fn remove_rules(rules: &mut Vec<NotificationRule>, arn: &str) -> usize {
+    let before = rules.len();
+    rules.retain(|r| r.arn != arn);
+    before - rules.len()
+}
+
+fn event_error(path: &str, action: &str, error: rc_core::Error, formatter: &Formatter) -> ExitCode {
+    match error {
+        rc_core::Error::NotFound(_) => {
+            formatter.error(&format!("Not found: {path}"));
+            ExitCode::NotFound
+        }
+        e => {
+            formatter.error(&format!("Failed to {action}: {e}"));
+            ExitCode::from(&e)
+        }
+    }
+}
+
+/// Parse the bucket path, build the client and check notification support
+async fn setup(
+    path: &str,
+    force: bool,
+    formatter: &Formatter,
+) -> Result<(S3Client, String), ExitCode> {
+    let (alias_name, bucket) = match parse_bucket_path(path) {
+        Ok(p) => p,
+        Err(e) => {
+            formatter.error(&e);
+            return Err(ExitCode::UsageError);
+        }
+    };
+
+    let alias_manager = match AliasManager::new() {
+        Ok(am) => am,
+        Err(e) => {
+            formatter.error(&format!("Failed to load aliases: {e}"));
+            return Err(ExitCode::GeneralError);
+        }
+    };
+
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
+        Ok(a) => a,
+        Err(_) => {
+            formatter.error(&format!("Alias '{alias_name}' not found"));
+            return Err(ExitCode::NotFound);
+        }
+    };
+
+    let client = match S3Client::new(alias).await {
+        Ok(c) => c,
+        Err(e) => {
+            formatter.error(&format!("Failed to create S3 client: {e}"));
+            return Err(ExitCode::NetworkError);
+        }
+    };
+
+    if !force {
+        match cached_capabilities(&client, &bucket, false).await {
+            Ok(caps) => {
+                if !caps.notifications {
+                    formatter.error(
+                        "Backend does not support event notifications. Use --force to attempt anyway.",
+                    );
+                    return Err(ExitCode::UnsupportedFeature);
+                }
+            }
+            Err(e) => {
+                formatter.error(&format!("Failed to detect capabilities: {e}"));
+                return Err(ExitCode::from(&e));
+            }
+        }
+    }
+
+    Ok((client, bucket))
+}
+
+/// Parse a bucket path into (alias, bucket)
+fn parse_bucket_path(path: &str) -> Result<(String, String), String> {
+    let trimmed = path.trim_end_matches('/');
+    match trimmed.split_once('/') {
+        Some((alias, bucket))
+            if !alias.is_empty() && !bucket.is_empty() && !bucket.contains('/') =>
+        {
+            Ok((alias.to_string(), bucket.to_string()))
+        }
+        _ => Err(format!(
+            "Invalid bucket path '{path}', expected alias/bucket"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARN: &str = "arn:rustfs:sqs::primary:webhook";
+
+    fn add_args(events: &[&str], prefix: Option<&str>) -> AddEventArgs {
+        AddEventArgs {
+            path: "local/bucket".to_string(),
+            arn: ARN.to_string(),
+            event: events.iter().map(|e| e.to_string()).collect(),
+            prefix: prefix.map(str::to_string),
+            suffix: None,
+            force: false,
+        }
+    }
+
+    #[test]
+    fn test_build_rule_expands_events() {
+        let rule = build_rule(&add_args(&["put", "s3:ObjectRemoved:Delete", "put"], None)).unwrap();
+        assert_eq!(rule.kind, NotificationKind::Queue);
+        assert_eq!(
+            rule.events,
+            vec!["s3:ObjectCreated:*", "s3:ObjectRemoved:Delete"]
+        );
+
+        assert!(build_rule(&add_args(&["rename"], None)).is_err());
+        let mut bad_arn = add_args(&["put"], None);
+        bad_arn.arn = "webhook".to_string();
+        assert!(build_rule(&bad_arn).is_err());
+    }
+
+    #[test]
+    fn test_add_rule_is_idempotent() {
+        let mut rules = Vec::new();
+        assert!(add_rule(
+            &mut rules,
+            build_rule(&add_args(&["put", "delete"], None)).unwrap()
+        ));
+
+        let mut existing = rules[0].clone();
+        existing.id = Some("server-id".to_string());
+        existing.events.reverse();
+        rules[0] = existing;
+        assert!(!add_rule(
+            &mut rules,
+            build_rule(&add_args(&["delete", "put"], None)).unwrap()
+        ));
+
+        assert!(add_rule(
+            &mut rules,
+            build_rule(&add_args(&["put"], Some("logs/"))).unwrap()
+        ));
+        assert_eq!(rules.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_rules_by_arn() {
+        let mut rules = vec![
+            build_rule(&add_args(&["put"], None)).unwrap(),
+            build_rule(&add_args(&["get"], Some("a/"))).unwrap(),
+        ];
+        assert_eq!(remove_rules(&mut rules, "arn:rustfs:sqs::other:webhook"), 0);
+        assert_eq!(remove_rules(&mut rules, ARN), 2);
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn test_parse_bucket_path() {
+        assert_eq!(
+            parse_bucket_path("local/bucket/").unwrap(),
+            ("local".to_string(), "bucket".to_string())
+        );
+        assert!(parse_bucket_path("local").is_err());
+        assert!(parse_bucket_path("local/bucket/key").is_err());
+    }
+}
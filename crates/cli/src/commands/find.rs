@@ -3,11 +3,14 @@
 //! Searches for objects matching specified patterns and filters.
 
 use clap::Args;
-use rc_core::{AliasManager, ListOptions, ObjectStore as _, RemotePath};
-use rc_s3::S3Client;
+use futures::TryStreamExt;
+use rc_core::{AliasManager, ObjectInfo, RemotePath};
+use rc_s3::{DEFAULT_LIST_CONCURRENCY, MAX_PAGE_SIZE, S3Client, list_objects_stream};
 use serde::Serialize;
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
+use crate::filter::TimeFilter;
 use crate::output::{Formatter, OutputConfig};
 
 /// Find objects matching criteria
@@ -29,13 +32,21 @@ pub struct FindArgs {
     pub smaller: Option<String>,
 
     /// Match objects newer than duration (e.g., 1h, 1d, 7d)
-    #[arg(long)]
+    #[arg(long, conflicts_with = "after")]
     pub newer: Option<String>,
 
     /// Match objects older than duration (e.g., 1h, 1d, 7d)
-    #[arg(long)]
+    #[arg(long, conflicts_with = "before")]
     pub older: Option<String>,
 
+    /// Match objects modified at or after this time (RFC3339, YYYY-MM-DD or duration like 7d)
+    #[arg(long, value_name = "TIME")]
+    pub after: Option<String>,
+
+    /// Match objects modified before this time (RFC3339, YYYY-MM-DD or duration like 7d)
+    #[arg(long, value_name = "TIME")]
+    pub before: Option<String>,
+
     /// Maximum depth to search (0 = unlimited)
     #[arg(long, default_value = "0")]
     pub maxdepth: usize,
@@ -94,7 +105,7 @@ pub async fn execute(args: FindArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -120,19 +131,31 @@ pub async fn execute(args: FindArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    // Search for objects
+    // Search for objects; text output is printed as matches arrive, JSON is collected
     let remote_path = RemotePath::new(&alias_name, &bucket, prefix.as_deref().unwrap_or(""));
-    let matches = match find_objects(&client, &remote_path, &filters, args.maxdepth).await {
-        Ok(m) => m,
-        Err(e) => {
-            formatter.error(&format!("Search failed: {e}"));
-            return ExitCode::NetworkError;
+    let mut matches = Vec::new();
+    let mut total_count = 0usize;
+    let mut total_size: i64 = 0;
+    let search = find_objects(&client, &remote_path, &filters, args.maxdepth, |m| {
+        total_count += 1;
+        total_size += m.size_bytes.unwrap_or(0);
+        if args.count {
+            return;
         }
-    };
-
-    // Calculate totals
-    let total_count = matches.len();
-    let total_size: i64 = matches.iter().filter_map(|m| m.size_bytes).sum();
+        if formatter.is_json() {
+            matches.push(m);
+        } else {
+            let size = m.size_human.as_deref().unwrap_or("0B");
+            let styled_size = formatter.style_size(&format!("{:>10}", size));
+            let styled_key = formatter.style_file(&m.key);
+            formatter.println(&format!("{styled_size} {styled_key}"));
+        }
+    })
+    .await;
+    if let Err(e) = search {
+        formatter.error(&format!("Search failed: {e}"));
+        return ExitCode::NetworkError;
+    }
 
     if args.count {
         // Only print count
@@ -152,6 +175,7 @@ pub async fn execute(args: FindArgs, output_config: OutputConfig) -> ExitCode {
             ));
         }
     } else if formatter.is_json() {
+        matches.sort_by(|a, b| a.key.cmp(&b.key));
         let output = FindOutput {
             matches,
             total_count,
@@ -159,15 +183,9 @@ pub async fn execute(args: FindArgs, output_config: OutputConfig) -> ExitCode {
             total_size_human: humansize::format_size(total_size as u64, humansize::BINARY),
         };
         formatter.json(&output);
-    } else if matches.is_empty() {
+    } else if total_count == 0 {
         formatter.println("No matches found.");
     } else {
-        for m in &matches {
-            let size = m.size_human.as_deref().unwrap_or("0B");
-            let styled_size = formatter.style_size(&format!("{:>10}", size));
-            let styled_key = formatter.style_file(&m.key);
-            formatter.println(&format!("{styled_size} {styled_key}"));
-        }
         let total_size_human = humansize::format_size(total_size as u64, humansize::BINARY);
         formatter.println(&format!(
             "\nTotal: {} object(s), {}",
@@ -184,8 +202,7 @@ struct FindFilters {
     name_pattern: Option<glob::Pattern>,
     min_size: Option<i64>,
     max_size: Option<i64>,
-    newer_than: Option<jiff::Timestamp>,
-    older_than: Option<jiff::Timestamp>,
+    modified: TimeFilter,
 }
 
 fn build_filters(args: &FindArgs) -> Result<FindFilters, String> {
@@ -200,25 +217,18 @@ fn build_filters(args: &FindArgs) -> Result<FindFilters, String> {
     let min_size = args.larger.as_ref().map(|s| parse_size(s)).transpose()?;
     let max_size = args.smaller.as_ref().map(|s| parse_size(s)).transpose()?;
 
-    // Parse time filters
-    let now = jiff::Timestamp::now();
-    let newer_than = args
-        .newer
-        .as_ref()
-        .map(|d| parse_duration_ago(d, now))
-        .transpose()?;
-    let older_than = args
-        .older
-        .as_ref()
-        .map(|d| parse_duration_ago(d, now))
-        .transpose()?;
+    // Parse time filters; --newer and --older are the original names of --after and --before
+    let modified = TimeFilter::new(
+        args.after.as_deref().or(args.newer.as_deref()),
+        args.before.as_deref().or(args.older.as_deref()),
+        jiff::Timestamp::now(),
+    )?;
 
     Ok(FindFilters {
         name_pattern,
         min_size,
         max_size,
-        newer_than,
-        older_than,
+        modified,
     })
 }
 
@@ -251,126 +261,81 @@ fn parse_size(s: &str) -> Result<i64, String> {
     Ok(num * multiplier)
 }
 
-/// Parse duration string and return timestamp that far in the past
-fn parse_duration_ago(s: &str, now: jiff::Timestamp) -> Result<jiff::Timestamp, String> {
-    let s = s.trim();
-    if s.is_empty() {
-        return Err("Duration cannot be empty".to_string());
-    }
-
-    let (num_str, suffix) = if s.ends_with(|c: char| c.is_ascii_alphabetic()) {
-        let idx = s.len() - 1;
-        (&s[..idx], &s[idx..])
-    } else {
-        (s, "s") // Default to seconds
-    };
-
-    let num: i64 = num_str
-        .parse()
-        .map_err(|_| format!("Invalid duration number: {num_str}"))?;
-
-    let seconds = match suffix.to_lowercase().as_str() {
-        "s" => num,
-        "m" => num * 60,
-        "h" => num * 3600,
-        "d" => num * 86400,
-        "w" => num * 604800,
-        _ => return Err(format!("Unknown duration suffix: {suffix}")),
-    };
-
-    let duration = jiff::Span::new().seconds(seconds);
-    now.checked_sub(duration)
-        .map_err(|e| format!("Duration overflow: {e}"))
-}
-
+/// Stream the listing under `path`, passing each match to `on_match`
+///
+/// Matches are handed over one listing page at a time, sorted within the page,
+/// so only the current page is held in memory.
 async fn find_objects(
     client: &S3Client,
     path: &RemotePath,
     filters: &FindFilters,
     maxdepth: usize,
-) -> Result<Vec<MatchInfo>, rc_core::Error> {
-    let mut matches = Vec::new();
-    let mut continuation_token: Option<String> = None;
-    let base_prefix = &path.key;
-    let base_depth = base_prefix.matches('/').count();
-
-    loop {
-        let options = ListOptions {
-            recursive: true,
-            max_keys: Some(1000),
-            continuation_token: continuation_token.clone(),
-            ..Default::default()
-        };
+    mut on_match: impl FnMut(MatchInfo),
+) -> Result<(), rc_core::Error> {
+    let base_depth = path.key.matches('/').count();
+    let mut pages = list_objects_stream(client, path, DEFAULT_LIST_CONCURRENCY, MAX_PAGE_SIZE);
 
-        let result = client.list_objects(path, options).await?;
-
-        for item in result.items {
-            // Skip directories
-            if item.is_dir {
-                continue;
-            }
-
-            // Check depth
-            if maxdepth > 0 {
-                let item_depth = item.key.matches('/').count();
-                if item_depth - base_depth > maxdepth {
-                    continue;
-                }
-            }
-
-            // Check name pattern
-            if let Some(ref pattern) = filters.name_pattern {
-                let filename = item.key.rsplit('/').next().unwrap_or(&item.key);
-                if !pattern.matches(filename) {
-                    continue;
-                }
-            }
-
-            // Check size filters
-            if let Some(size) = item.size_bytes {
-                if let Some(min) = filters.min_size
-                    && size < min
-                {
-                    continue;
-                }
-                if let Some(max) = filters.max_size
-                    && size > max
-                {
-                    continue;
-                }
-            }
-
-            // Check time filters
-            if let Some(modified) = item.last_modified {
-                if let Some(ref newer) = filters.newer_than
-                    && modified < *newer
-                {
-                    continue;
-                }
-                if let Some(ref older) = filters.older_than
-                    && modified > *older
-                {
-                    continue;
-                }
-            }
-
-            // Match found
-            matches.push(MatchInfo {
+    while let Some(mut items) = pages.try_next().await? {
+        items.retain(|item| matches_filters(item, filters, base_depth, maxdepth));
+        items.sort_by(|a, b| a.key.cmp(&b.key));
+
+        for item in items {
+            on_match(MatchInfo {
                 key: item.key,
                 size_bytes: item.size_bytes,
                 size_human: item.size_human,
                 last_modified: item.last_modified.map(|t| t.to_string()),
             });
         }
+    }
 
-        if result.truncated {
-            continuation_token = result.continuation_token;
-        } else {
-            break;
+    Ok(())
+}
+
+/// Check a listed object against the depth, name, size and time filters
+fn matches_filters(
+    item: &ObjectInfo,
+    filters: &FindFilters,
+    base_depth: usize,
+    maxdepth: usize,
+) -> bool {
+    // Skip directories
+    if item.is_dir {
+        return false;
+    }
+
+    // Check depth
+    if maxdepth > 0 {
+        let item_depth = item.key.matches('/').count();
+        if item_depth - base_depth > maxdepth {
+            return false;
+        }
+    }
+
+    // Check name pattern
+    if let Some(ref pattern) = filters.name_pattern {
+        let filename = item.key.rsplit('/').next().unwrap_or(&item.key);
+        if !pattern.matches(filename) {
+            return false;
+        }
+    }
+
+    // Check size filters
+    if let Some(size) = item.size_bytes {
+        if let Some(min) = filters.min_size
+            && size < min
+        {
+            return false;
+        }
+        if let Some(max) = filters.max_size
+            && size > max
+        {
+            return false;
         }
     }
 
-    Ok(matches)
+    // Check time filters
+    filters.modified.matches(item.last_modified)
 }
 
 /// Parse find path into (alias, bucket, prefix)
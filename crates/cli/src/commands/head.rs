@@ -7,6 +7,7 @@ use rc_core::{AliasManager, ObjectStore as _, RemotePath};
 use rc_s3::S3Client;
 use std::io::{self, Write};
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -20,8 +21,8 @@ pub struct HeadArgs {
     #[arg(short = 'n', long, default_value = "10")]
     pub lines: usize,
 
-    /// Display first N bytes instead of lines
-    #[arg(short = 'c', long)]
+    /// Display first N bytes instead of lines (takes precedence over -n)
+    #[arg(short = 'c', long, value_name = "N")]
     pub bytes: Option<usize>,
 
     /// Specific version ID to retrieve
@@ -51,7 +52,7 @@ pub async fn execute(args: HeadArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -70,50 +71,53 @@ pub async fn execute(args: HeadArgs, output_config: OutputConfig) -> ExitCode {
 
     let path = RemotePath::new(&alias_name, &bucket, &key);
 
-    // Get object content
-    match client.get_object(&path).await {
+    // Byte mode fetches only the requested range; line mode needs the text
+    let result = match args.bytes {
+        Some(0) => client.head_object(&path).await.map(|_| Vec::new()),
+        Some(num_bytes) => {
+            client
+                .get_object_range(&path, 0, Some(num_bytes as u64))
+                .await
+        }
+        None => client.get_object(&path).await,
+    };
+
+    match result {
         Ok(data) => {
-            let output = if let Some(num_bytes) = args.bytes {
-                // Output first N bytes
-                let end = num_bytes.min(data.len());
-                &data[..end]
+            let written = if args.bytes.is_some() {
+                // Bytes are written as-is so binary content survives
+                io::stdout().write_all(&data)
             } else {
-                // Output first N lines
-                let content = String::from_utf8_lossy(&data);
-                let lines: Vec<&str> = content.lines().take(args.lines).collect();
-                let result = lines.join("\n");
-
-                // Write string content and add newline
-                if let Err(e) = writeln!(io::stdout(), "{result}") {
-                    formatter.error(&format!("Failed to write to stdout: {e}"));
-                    return ExitCode::GeneralError;
-                }
-                return ExitCode::Success;
+                writeln!(io::stdout(), "{}", first_lines(&data, args.lines))
             };
 
-            // Write bytes directly to stdout
-            if let Err(e) = io::stdout().write_all(output) {
+            if let Err(e) = written {
                 formatter.error(&format!("Failed to write to stdout: {e}"));
                 return ExitCode::GeneralError;
             }
             ExitCode::Success
         }
+        Err(rc_core::Error::NotFound(_)) => {
+            formatter.error(&format!("Object not found: {}", args.path));
+            ExitCode::NotFound
+        }
+        Err(rc_core::Error::Auth(_)) => {
+            formatter.error(&format!("Access denied: {}", args.path));
+            ExitCode::AuthError
+        }
         Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                formatter.error(&format!("Object not found: {}", args.path));
-                ExitCode::NotFound
-            } else if err_str.contains("AccessDenied") {
-                formatter.error(&format!("Access denied: {}", args.path));
-                ExitCode::AuthError
-            } else {
-                formatter.error(&format!("Failed to get object: {e}"));
-                ExitCode::NetworkError
-            }
+            formatter.error(&format!("Failed to get object: {e}"));
+            ExitCode::NetworkError
         }
     }
 }
 
+/// Take the first `count` lines of `data`, decoded as UTF-8 (lossily)
+fn first_lines(data: &[u8], count: usize) -> String {
+    let content = String::from_utf8_lossy(data);
+    content.lines().take(count).collect::<Vec<_>>().join("\n")
+}
+
 /// Parse head path into (alias, bucket, key)
 fn parse_head_path(path: &str) -> Result<(String, String, String), String> {
     if path.is_empty() {
@@ -172,4 +176,11 @@ mod tests {
     fn test_parse_head_path_empty() {
         assert!(parse_head_path("").is_err());
     }
+
+    #[test]
+    fn test_first_lines() {
+        assert_eq!(first_lines(b"a\nb\nc\n", 2), "a\nb");
+        assert_eq!(first_lines(b"a\r\nb", 10), "a\nb");
+        assert_eq!(first_lines(b"", 3), "");
+    }
 }
@@ -3,11 +3,16 @@
 //! Lists buckets when given an alias only, or lists objects when given a bucket path.
 
 use clap::Args;
+use futures::{StreamExt, stream};
 use rc_core::{AliasManager, ListOptions, ObjectInfo, ObjectStore as _, RemotePath};
 use rc_s3::S3Client;
 use serde::Serialize;
+use std::collections::BTreeMap;
 
+use crate::commands::stat::print_headers;
+use crate::commands::{page_size, resolve_alias};
 use crate::exit_code::ExitCode;
+use crate::filter::TimeFilter;
 use crate::output::{Formatter, OutputConfig};
 
 /// List buckets or objects
@@ -31,6 +36,112 @@ pub struct LsArgs {
     /// Summarize output (show totals only)
     #[arg(long)]
     pub summarize: bool,
+
+    /// Break the summary down by a field (implies --summarize)
+    #[arg(long, value_enum, value_name = "FIELD", conflicts_with = "jsonl")]
+    pub summarize_by: Option<SummaryGroup>,
+
+    /// Start listing after this key (resume a previous listing)
+    #[arg(long, value_name = "KEY")]
+    pub start_after: Option<String>,
+
+    /// Stream one JSON object per line as each page arrives
+    #[arg(long, conflicts_with = "summarize")]
+    pub jsonl: bool,
+
+    /// Keys requested per listing page (1-1000, default 1000)
+    #[arg(long, visible_alias = "max-keys", value_name = "N")]
+    pub page_size: Option<i32>,
+
+    /// Only list entries modified at or after this time (RFC3339, YYYY-MM-DD or duration like 7d)
+    #[arg(long, value_name = "TIME")]
+    pub after: Option<String>,
+
+    /// Only list entries modified before this time (RFC3339, YYYY-MM-DD or duration like 7d)
+    #[arg(long, value_name = "TIME")]
+    pub before: Option<String>,
+
+    /// Add content type, headers and user metadata to each object (one HEAD request per object)
+    #[arg(long)]
+    pub metadata: bool,
+
+    /// Group keys on this separator instead of `/` (an empty value lists flat)
+    #[arg(long, value_name = "SEP", conflicts_with = "recursive")]
+    pub delimiter: Option<String>,
+
+    /// Show only objects (`files`) or only prefixes (`dirs`)
+    #[arg(long, value_enum, value_name = "KIND")]
+    pub only: Option<EntryKind>,
+
+    /// Debugging aid: print the raw ListObjectsV2 response for the first page
+    #[arg(
+        long,
+        hide = true,
+        conflicts_with_all = ["versions", "incomplete", "summarize", "summarize_by", "jsonl", "metadata"]
+    )]
+    pub raw: bool,
+
+    /// Print one bare path per line: `alias` (alias/bucket/key, default) or `s3` (s3://bucket/key)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "STYLE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "alias",
+        conflicts_with_all = ["summarize", "summarize_by", "jsonl", "metadata", "raw"]
+    )]
+    pub uri: Option<UriStyle>,
+}
+
+/// Concurrent HEAD requests made by `--metadata`
+const METADATA_CONCURRENCY: usize = 8;
+
+/// Field a summary can be broken down by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SummaryGroup {
+    StorageClass,
+}
+
+/// Kind of listing entry kept by `--only`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum EntryKind {
+    Files,
+    Dirs,
+}
+
+impl EntryKind {
+    /// Whether a listed entry is of this kind
+    fn matches(self, item: &ObjectInfo) -> bool {
+        match self {
+            EntryKind::Files => !item.is_dir,
+            EntryKind::Dirs => item.is_dir,
+        }
+    }
+}
+
+/// Path form printed by `--uri`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum UriStyle {
+    /// `alias/bucket/key`, accepted by other rc commands
+    Alias,
+    /// `s3://bucket/key`
+    S3,
+}
+
+/// Full path of a listed entry in the given style
+///
+/// An empty key gives the bucket itself, written with a trailing `/`.
+fn entry_uri(style: UriStyle, alias: &str, bucket: &str, key: &str) -> String {
+    match style {
+        UriStyle::Alias => format!("{alias}/{bucket}/{key}"),
+        UriStyle::S3 => format!("s3://{bucket}/{key}"),
+    }
+}
+
+/// Whether an entry passes the `--only` filter
+fn keep_entry(only: Option<EntryKind>, item: &ObjectInfo) -> bool {
+    only.is_none_or(|kind| kind.matches(item))
 }
 
 /// Output structure for ls command (JSON format)
@@ -49,10 +160,42 @@ struct Summary {
     total_objects: usize,
     total_size_bytes: i64,
     total_size_human: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    by_storage_class: Option<BTreeMap<String, ClassSummary>>,
+}
+
+/// Totals for one storage class
+#[derive(Debug, Default, Serialize)]
+struct ClassSummary {
+    objects: usize,
+    size_bytes: i64,
+    size_human: String,
+}
+
+/// Total objects and bytes per storage class
+///
+/// Listings may omit the class for standard storage, so missing classes count
+/// as `STANDARD`.
+fn storage_class_totals(items: &[ObjectInfo]) -> BTreeMap<String, ClassSummary> {
+    let mut totals: BTreeMap<String, ClassSummary> = BTreeMap::new();
+    for item in items.iter().filter(|i| !i.is_dir) {
+        let class = item.storage_class.as_deref().unwrap_or("STANDARD");
+        let entry = totals.entry(class.to_string()).or_default();
+        entry.objects += 1;
+        entry.size_bytes += item.size_bytes.unwrap_or(0);
+    }
+    for entry in totals.values_mut() {
+        entry.size_human = humansize::format_size(entry.size_bytes as u64, humansize::BINARY);
+    }
+    totals
 }
 
 /// Execute the ls command
-pub async fn execute(args: LsArgs, output_config: OutputConfig) -> ExitCode {
+pub async fn execute(args: LsArgs, mut output_config: OutputConfig) -> ExitCode {
+    // JSON lines is machine output too: no colors, JSON errors
+    if args.jsonl {
+        output_config.json = true;
+    }
     let formatter = Formatter::new(output_config);
 
     // Parse the path
@@ -64,6 +207,18 @@ pub async fn execute(args: LsArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
+    let modified = match TimeFilter::new(
+        args.after.as_deref(),
+        args.before.as_deref(),
+        jiff::Timestamp::now(),
+    ) {
+        Ok(f) => f,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
     // Load alias
     let alias_manager = match AliasManager::new() {
         Ok(am) => am,
@@ -73,7 +228,7 @@ pub async fn execute(args: LsArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -92,20 +247,51 @@ pub async fn execute(args: LsArgs, output_config: OutputConfig) -> ExitCode {
 
     // If no bucket specified, list buckets
     if bucket.is_none() {
-        return list_buckets(&client, &formatter, args.summarize).await;
+        if args.raw {
+            formatter.error("--raw needs a bucket path");
+            return ExitCode::UsageError;
+        }
+        return list_buckets(&client, &alias_name, &formatter, &args, &modified).await;
     }
 
     let bucket = bucket.unwrap();
+    let prefix = list_prefix(&args.path, prefix, args.delimiter.as_deref());
     let path = RemotePath::new(&alias_name, &bucket, prefix.unwrap_or_default());
 
+    if args.raw {
+        return match client.list_objects_raw(&path, &list_options(&args)).await {
+            Ok(response) => print_headers(&args.path, response, &formatter),
+            Err(e) => {
+                formatter.error(&format!("Failed to list objects: {e}"));
+                ExitCode::from(&e)
+            }
+        };
+    }
+
     // List objects
-    list_objects(&client, &path, &args, &formatter).await
+    list_objects(&client, &path, &args, &modified, &formatter).await
 }
 
-async fn list_buckets(client: &S3Client, formatter: &Formatter, summarize: bool) -> ExitCode {
+async fn list_buckets(
+    client: &S3Client,
+    alias_name: &str,
+    formatter: &Formatter,
+    args: &LsArgs,
+    modified: &TimeFilter,
+) -> ExitCode {
+    let summarize = args.summarize || args.summarize_by.is_some();
     match client.list_buckets().await {
-        Ok(buckets) => {
-            if formatter.is_json() {
+        Ok(mut buckets) => {
+            buckets.retain(|b| modified.matches(b.last_modified) && keep_entry(args.only, b));
+            if let Some(style) = args.uri {
+                let uris: Vec<String> = buckets
+                    .iter()
+                    .map(|b| entry_uri(style, alias_name, &b.key, ""))
+                    .collect();
+                formatter.plain_lines(&uris);
+            } else if args.jsonl {
+                formatter.json_lines(&buckets);
+            } else if formatter.is_json() {
                 let output = LsOutput {
                     items: buckets.clone(),
                     truncated: false,
@@ -115,6 +301,7 @@ async fn list_buckets(client: &S3Client, formatter: &Formatter, summarize: bool)
                             total_objects: buckets.len(),
                             total_size_bytes: 0,
                             total_size_human: "0 B".to_string(),
+                            by_storage_class: None,
                         })
                     } else {
                         None
@@ -152,13 +339,17 @@ async fn list_objects(
     client: &S3Client,
     path: &RemotePath,
     args: &LsArgs,
+    modified: &TimeFilter,
     formatter: &Formatter,
 ) -> ExitCode {
-    let options = ListOptions {
-        recursive: args.recursive,
-        max_keys: Some(1000),
-        ..Default::default()
-    };
+    let options = list_options(args);
+
+    if args.metadata {
+        formatter.warning("--metadata sends a HEAD request per object; large listings are slower");
+    }
+    if args.recursive && args.only == Some(EntryKind::Dirs) {
+        formatter.warning("--recursive lists objects only, so --only dirs shows nothing");
+    }
 
     let mut all_items = Vec::new();
     let mut continuation_token: Option<String> = None;
@@ -172,8 +363,25 @@ async fn list_objects(
         };
 
         match client.list_objects(path, opts).await {
-            Ok(result) => {
-                all_items.extend(result.items);
+            Ok(mut result) => {
+                result
+                    .items
+                    .retain(|i| modified.matches(i.last_modified) && keep_entry(args.only, i));
+                if args.metadata {
+                    add_metadata(client, path, &mut result.items, formatter).await;
+                }
+                if let Some(style) = args.uri {
+                    let uris: Vec<String> = result
+                        .items
+                        .iter()
+                        .map(|i| entry_uri(style, &path.alias, &path.bucket, &i.key))
+                        .collect();
+                    formatter.plain_lines(&uris);
+                } else if args.jsonl {
+                    formatter.json_lines(&result.items);
+                } else {
+                    all_items.extend(result.items);
+                }
                 is_truncated = result.truncated;
                 continuation_token = result.continuation_token.clone();
 
@@ -181,32 +389,43 @@ async fn list_objects(
                     break;
                 }
             }
+            Err(rc_core::Error::NotFound(_)) => {
+                formatter.error(&format!("Bucket not found: {}", path.bucket));
+                return ExitCode::NotFound;
+            }
+            Err(rc_core::Error::Auth(e)) => {
+                formatter.error(&format!("Access denied: {e}"));
+                return ExitCode::AuthError;
+            }
             Err(e) => {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchBucket") {
-                    formatter.error(&format!("Bucket not found: {}", path.bucket));
-                    return ExitCode::NotFound;
-                }
                 formatter.error(&format!("Failed to list objects: {e}"));
                 return ExitCode::NetworkError;
             }
         }
     }
 
+    if args.jsonl || args.uri.is_some() {
+        return ExitCode::Success;
+    }
+
     // Calculate summary
+    let summarize = args.summarize || args.summarize_by.is_some();
     let total_objects = all_items.iter().filter(|i| !i.is_dir).count();
     let total_size: i64 = all_items.iter().filter_map(|i| i.size_bytes).sum();
+    let by_storage_class = (args.summarize_by == Some(SummaryGroup::StorageClass))
+        .then(|| storage_class_totals(&all_items));
 
     if formatter.is_json() {
         let output = LsOutput {
             items: all_items,
             truncated: is_truncated,
             continuation_token,
-            summary: if args.summarize {
+            summary: if summarize {
                 Some(Summary {
                     total_objects,
                     total_size_bytes: total_size,
                     total_size_human: humansize::format_size(total_size as u64, humansize::BINARY),
+                    by_storage_class,
                 })
             } else {
                 None
@@ -215,37 +434,145 @@ async fn list_objects(
         formatter.json(&output);
     } else {
         for item in &all_items {
-            let date = item
-                .last_modified
-                .map(|d| d.strftime("%Y-%m-%d %H:%M:%S").to_string())
-                .unwrap_or_else(|| "                   ".to_string());
-            let styled_date = formatter.style_date(&format!("[{date}]"));
-
-            if item.is_dir {
-                let styled_size = formatter.style_size(&format!("{:>10}", "0B"));
-                let styled_name = formatter.style_dir(&item.key);
-                formatter.println(&format!("{styled_date} {styled_size} {styled_name}"));
-            } else {
-                let size = item.size_human.clone().unwrap_or_else(|| "0 B".to_string());
-                let styled_size = formatter.style_size(&format!("{:>10}", size));
-                let styled_name = formatter.style_file(&item.key);
-                formatter.println(&format!("{styled_date} {styled_size} {styled_name}"));
-            }
+            formatter.println(&listing_row(formatter, item, args.metadata));
         }
 
-        if args.summarize {
+        if summarize {
             let total_size_human = humansize::format_size(total_size as u64, humansize::BINARY);
             formatter.println(&format!(
                 "\nTotal: {} objects, {}",
                 formatter.style_size(&total_objects.to_string()),
                 formatter.style_size(&total_size_human)
             ));
+            for (class, totals) in by_storage_class.iter().flatten() {
+                formatter.println(&format!(
+                    "  {class}: {} ({} objects)",
+                    formatter.style_size(&totals.size_human),
+                    totals.objects
+                ));
+            }
         }
     }
 
     ExitCode::Success
 }
 
+/// Fill in headers and user metadata for listed objects with HEAD requests
+///
+/// Requests run `METADATA_CONCURRENCY` at a time. An object that cannot be
+/// read (e.g. deleted since it was listed) is reported and keeps its listing
+/// fields only.
+async fn add_metadata(
+    client: &S3Client,
+    path: &RemotePath,
+    items: &mut [ObjectInfo],
+    formatter: &Formatter,
+) {
+    let heads: Vec<_> = stream::iter(items.iter().enumerate().filter(|(_, i)| !i.is_dir))
+        .map(|(index, item)| async move {
+            let object = RemotePath::new(&path.alias, &path.bucket, &item.key);
+            (index, client.head_object(&object).await)
+        })
+        .buffered(METADATA_CONCURRENCY)
+        .collect()
+        .await;
+
+    for (index, result) in heads {
+        let item = &mut items[index];
+        match result {
+            Ok(head) => {
+                item.content_type = head.content_type;
+                item.content_encoding = head.content_encoding;
+                item.cache_control = head.cache_control;
+                item.content_disposition = head.content_disposition;
+                item.metadata = head.metadata;
+            }
+            Err(e) => formatter.warning(&format!("Failed to read metadata for {}: {e}", item.key)),
+        }
+    }
+}
+
+/// Content type and user metadata appended to a human-readable `--metadata` row
+fn metadata_suffix(item: &ObjectInfo) -> String {
+    let mut suffix = String::new();
+    if let Some(content_type) = &item.content_type {
+        suffix.push_str(&format!("  {content_type}"));
+    }
+    for (key, value) in &item.metadata {
+        suffix.push_str(&format!("  {key}={value}"));
+    }
+    suffix
+}
+
+/// Build the base list options for an ls invocation
+///
+/// `start_after` is kept on every page request; once the server hands back a
+/// continuation token, that token takes precedence.
+fn list_options(args: &LsArgs) -> ListOptions {
+    ListOptions {
+        recursive: args.recursive,
+        max_keys: Some(page_size(args.page_size)),
+        start_after: args.start_after.clone(),
+        delimiter: args.delimiter.clone(),
+        ..Default::default()
+    }
+}
+
+/// One line of the human-readable listing: date, size and key
+///
+/// With `metadata`, file rows also get the HEAD details from
+/// [`metadata_suffix`].
+fn listing_row(formatter: &Formatter, item: &ObjectInfo, metadata: bool) -> String {
+    let date = item
+        .last_modified
+        .map(|d| d.strftime("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "                   ".to_string());
+    let styled_date = formatter.style_date(&format!("[{date}]"));
+
+    let styled_size = formatter.style_size(&size_column(item));
+    if item.is_dir {
+        let styled_name = formatter.style_dir(&item.key);
+        format!("{styled_date} {styled_size} {styled_name}")
+    } else {
+        let styled_name = formatter.style_file(&item.key);
+        let details = if metadata {
+            metadata_suffix(item)
+        } else {
+            String::new()
+        };
+        format!("{styled_date} {styled_size} {styled_name}{details}")
+    }
+}
+
+/// Right-aligned size column of a listing row
+///
+/// Prefixes (only listed without `--recursive`) have no size, so their column
+/// is left blank; their key already ends in the delimiter.
+fn size_column(item: &ObjectInfo) -> String {
+    let size = if item.is_dir {
+        ""
+    } else {
+        item.size_human.as_deref().unwrap_or("0 B")
+    };
+    format!("{size:>10}")
+}
+
+/// Key prefix to list under
+///
+/// With the `/` delimiter the path names a directory, so `parsed` ends in
+/// `/`. Other delimiters take the prefix exactly as typed: with `|`,
+/// `alias/bucket/2024|` lists the keys starting with `2024|`.
+fn list_prefix(path: &str, parsed: Option<String>, delimiter: Option<&str>) -> Option<String> {
+    match delimiter {
+        None | Some("/") | Some("") => parsed,
+        Some(_) => path
+            .splitn(3, '/')
+            .nth(2)
+            .filter(|p| !p.is_empty())
+            .map(str::to_string),
+    }
+}
+
 /// Parse ls path into (alias, bucket, prefix)
 fn parse_ls_path(path: &str) -> Result<(String, Option<String>, Option<String>), String> {
     let path = path.trim_end_matches('/');
@@ -272,6 +599,22 @@ fn parse_ls_path(path: &str) -> Result<(String, Option<String>, Option<String>),
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_metadata_suffix() {
+        let mut item = ObjectInfo::file("report.csv", 10);
+        assert_eq!(metadata_suffix(&item), "");
+
+        item.content_type = Some("text/csv".to_string());
+        item.metadata
+            .insert("owner".to_string(), "team-a".to_string());
+        item.metadata
+            .insert("mtime".to_string(), "1700000000".to_string());
+        assert_eq!(
+            metadata_suffix(&item),
+            "  text/csv  mtime=1700000000  owner=team-a"
+        );
+    }
+
     #[test]
     fn test_parse_ls_path_alias_only() {
         let (alias, bucket, prefix) = parse_ls_path("myalias").unwrap();
@@ -304,8 +647,187 @@ mod tests {
         assert!(prefix.is_none());
     }
 
+    #[test]
+    fn test_keep_entry() {
+        let file = ObjectInfo::file("a.txt", 3);
+        let dir = ObjectInfo::dir("logs/");
+
+        assert!(keep_entry(None, &file) && keep_entry(None, &dir));
+        assert!(keep_entry(Some(EntryKind::Files), &file));
+        assert!(!keep_entry(Some(EntryKind::Files), &dir));
+        assert!(keep_entry(Some(EntryKind::Dirs), &dir));
+        assert!(!keep_entry(Some(EntryKind::Dirs), &file));
+    }
+
+    #[test]
+    fn test_size_column() {
+        assert_eq!(size_column(&ObjectInfo::dir("logs/")), " ".repeat(10));
+        assert_eq!(size_column(&ObjectInfo::file("a.txt", 1024)), "     1 KiB");
+
+        let mut unknown = ObjectInfo::file("b.txt", 0);
+        unknown.size_human = None;
+        assert_eq!(size_column(&unknown), "       0 B");
+    }
+
+    #[test]
+    fn test_listing_row() {
+        let formatter = Formatter::new(OutputConfig {
+            no_color: true,
+            ..Default::default()
+        });
+        let blank_date = format!("[{}]", " ".repeat(19));
+
+        let mut dir = ObjectInfo::dir("dir/");
+        dir.last_modified = None;
+        assert_eq!(
+            listing_row(&formatter, &dir, false),
+            format!("{blank_date} {} dir/", " ".repeat(10))
+        );
+
+        let mut file = ObjectInfo::file("dir/a.txt", 1024);
+        file.last_modified = Some("2024-01-15T10:30:00Z".parse().expect("timestamp"));
+        file.content_type = Some("text/plain".to_string());
+        assert_eq!(
+            listing_row(&formatter, &file, false),
+            "[2024-01-15 10:30:00]      1 KiB dir/a.txt"
+        );
+        assert_eq!(
+            listing_row(&formatter, &file, true),
+            "[2024-01-15 10:30:00]      1 KiB dir/a.txt  text/plain"
+        );
+    }
+
+    #[test]
+    fn test_list_prefix() {
+        let parsed = || Some("logs|2024/".to_string());
+        assert_eq!(
+            list_prefix("a/b/logs|2024", parsed(), None).as_deref(),
+            Some("logs|2024/")
+        );
+        assert_eq!(
+            list_prefix("a/b/logs|2024", parsed(), Some("|")).as_deref(),
+            Some("logs|2024")
+        );
+        assert_eq!(
+            list_prefix("a/b/logs|", parsed(), Some("|")).as_deref(),
+            Some("logs|")
+        );
+        assert_eq!(list_prefix("a/b", None, Some("|")), None);
+        assert_eq!(list_prefix("a/b/", None, Some("|")), None);
+    }
+
+    #[test]
+    fn test_raw_flag() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: LsArgs,
+        }
+
+        let cli = Cli::try_parse_from(["ls", "local/bucket", "--raw"]).expect("parse ls --raw");
+        assert!(cli.args.raw);
+        assert!(Cli::try_parse_from(["ls", "local/bucket", "--raw", "--jsonl"]).is_err());
+        assert!(Cli::try_parse_from(["ls", "local/bucket", "--raw", "--versions"]).is_err());
+    }
+
+    #[test]
+    fn test_uri_flag() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: LsArgs,
+        }
+
+        let cli = Cli::try_parse_from(["ls", "--uri", "local/bucket"]).expect("parse ls --uri");
+        assert_eq!(cli.args.uri, Some(UriStyle::Alias));
+        assert_eq!(cli.args.path, "local/bucket");
+
+        let cli = Cli::try_parse_from(["ls", "-r", "--uri=s3", "local/bucket"])
+            .expect("parse ls --uri=s3");
+        assert_eq!(cli.args.uri, Some(UriStyle::S3));
+        assert!(Cli::try_parse_from(["ls", "--uri", "--jsonl", "local/bucket"]).is_err());
+        assert!(Cli::try_parse_from(["ls", "--uri", "--summarize", "local/bucket"]).is_err());
+    }
+
+    #[test]
+    fn test_entry_uri() {
+        assert_eq!(
+            entry_uri(UriStyle::Alias, "local", "photos", "2024/a.jpg"),
+            "local/photos/2024/a.jpg"
+        );
+        assert_eq!(
+            entry_uri(UriStyle::S3, "local", "photos", "2024/"),
+            "s3://photos/2024/"
+        );
+        assert_eq!(
+            entry_uri(UriStyle::Alias, "local", "photos", ""),
+            "local/photos/"
+        );
+        assert_eq!(
+            entry_uri(UriStyle::S3, "local", "photos", ""),
+            "s3://photos/"
+        );
+    }
+
     #[test]
     fn test_parse_ls_path_empty() {
         assert!(parse_ls_path("").is_err());
     }
+
+    #[test]
+    fn test_list_options_start_after() {
+        let args = LsArgs {
+            path: "local/bucket".to_string(),
+            recursive: true,
+            versions: false,
+            incomplete: false,
+            summarize: false,
+            summarize_by: None,
+            start_after: Some("logs/2024-06-01.log".to_string()),
+            jsonl: false,
+            page_size: Some(50),
+            after: None,
+            before: None,
+            metadata: false,
+            delimiter: None,
+            only: None,
+            raw: false,
+            uri: None,
+        };
+
+        let options = list_options(&args);
+        assert!(options.recursive);
+        assert!(options.delimiter.is_none());
+        assert_eq!(options.max_keys, Some(50));
+        assert_eq!(options.start_after.as_deref(), Some("logs/2024-06-01.log"));
+        assert!(options.continuation_token.is_none());
+    }
+
+    #[test]
+    fn test_storage_class_totals() {
+        let object = |size: i64, class: Option<&str>| {
+            let mut info = ObjectInfo::file("k", size);
+            info.storage_class = class.map(str::to_string);
+            info
+        };
+        let items = vec![
+            object(100, Some("STANDARD")),
+            object(50, None),
+            object(2048, Some("GLACIER")),
+            ObjectInfo::dir("logs/"),
+        ];
+
+        let totals = storage_class_totals(&items);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["STANDARD"].objects, 2);
+        assert_eq!(totals["STANDARD"].size_bytes, 150);
+        assert_eq!(totals["GLACIER"].size_human, "2 KiB");
+
+        let value = serde_json::to_value(&totals).expect("serialize totals");
+        assert_eq!(value["GLACIER"]["objects"], 1);
+    }
 }
@@ -1,20 +1,20 @@
 //! mb command - Make bucket
 //!
-//! Creates a new bucket on the specified storage service.
+//! Creates one or more buckets on the specified storage service.
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _};
-use rc_s3::S3Client;
-use serde::Serialize;
+use rc_core::ObjectStore;
 
+use crate::commands::buckets::{BucketOutcome, parse_bucket_path, parse_targets, run_targets};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
-/// Create a bucket
+/// Create one or more buckets
 #[derive(Args, Debug)]
 pub struct MbArgs {
-    /// Target path (alias/bucket)
-    pub target: String,
+    /// Target paths (alias/bucket), one bucket each
+    #[arg(required = true, value_name = "TARGET")]
+    pub targets: Vec<String>,
 
     /// Ignore error if bucket already exists
     #[arg(short = 'p', long)]
@@ -31,152 +31,127 @@ pub struct MbArgs {
     /// Enable versioning on the bucket
     #[arg(long)]
     pub with_versioning: bool,
-}
 
-#[derive(Debug, Serialize)]
-struct MbOutput {
-    status: &'static str,
-    bucket: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    message: Option<String>,
+    /// Stop at the first bucket that fails instead of continuing with the rest
+    #[arg(long)]
+    pub stop_on_error: bool,
 }
 
 /// Execute the mb command
 pub async fn execute(args: MbArgs, output_config: OutputConfig) -> ExitCode {
     let formatter = Formatter::new(output_config);
 
-    // Parse the target path
-    let (alias_name, bucket) = match parse_mb_path(&args.target) {
-        Ok(parsed) => parsed,
+    let targets = match parse_targets(&args.targets, parse_mb_path) {
+        Ok(targets) => targets,
         Err(e) => {
             formatter.error(&e);
             return ExitCode::UsageError;
         }
     };
 
-    // Load alias
-    let alias_manager = match AliasManager::new() {
-        Ok(am) => am,
-        Err(e) => {
-            formatter.error(&format!("Failed to load aliases: {e}"));
-            return ExitCode::GeneralError;
-        }
-    };
-
-    let alias = match alias_manager.get(&alias_name) {
-        Ok(a) => a,
-        Err(_) => {
-            formatter.error(&format!("Alias '{alias_name}' not found"));
-            return ExitCode::NotFound;
-        }
-    };
+    let ignore_existing = args.ignore_existing;
+    run_targets(
+        &formatter,
+        &targets,
+        args.stop_on_error,
+        |client, alias_name, bucket| {
+            Box::pin(make_bucket(client, alias_name, bucket, ignore_existing))
+        },
+    )
+    .await
+}
 
-    // Create S3 client
-    let client = match S3Client::new(alias).await {
-        Ok(c) => c,
-        Err(e) => {
-            formatter.error(&format!("Failed to create S3 client: {e}"));
-            return ExitCode::NetworkError;
-        }
+/// Create a single bucket
+async fn make_bucket(
+    client: &dyn ObjectStore,
+    alias_name: &str,
+    bucket: &str,
+    ignore_existing: bool,
+) -> BucketOutcome {
+    let already_exists = || {
+        BucketOutcome::success(
+            bucket,
+            "exists",
+            Some("Bucket already exists"),
+            format!("Bucket '{alias_name}/{bucket}' already exists."),
+        )
     };
 
     // Check if bucket already exists
-    if args.ignore_existing {
-        match client.bucket_exists(&bucket).await {
-            Ok(true) => {
-                if formatter.is_json() {
-                    let output = MbOutput {
-                        status: "success",
-                        bucket: bucket.clone(),
-                        message: Some("Bucket already exists".to_string()),
-                    };
-                    formatter.json(&output);
-                } else {
-                    formatter.success(&format!("Bucket '{alias_name}/{bucket}' already exists."));
-                }
-                return ExitCode::Success;
-            }
+    if ignore_existing {
+        match client.bucket_exists(bucket).await {
+            Ok(true) => return already_exists(),
             Ok(false) => {}
             Err(e) => {
-                formatter.error(&format!("Failed to check bucket existence: {e}"));
-                return ExitCode::NetworkError;
+                return BucketOutcome::failure(
+                    bucket,
+                    ExitCode::NetworkError,
+                    format!("Failed to check bucket existence: {e}"),
+                );
             }
         }
     }
 
-    // Create the bucket
-    match client.create_bucket(&bucket).await {
+    // Create the bucket; with --ignore-existing the status tells scripts
+    // whether this run created it or found it already there
+    let error = match client.create_bucket(bucket).await {
         Ok(()) => {
-            if formatter.is_json() {
-                let output = MbOutput {
-                    status: "success",
-                    bucket: bucket.clone(),
-                    message: None,
-                };
-                formatter.json(&output);
-            } else {
-                formatter.success(&format!(
-                    "Bucket '{alias_name}/{bucket}' created successfully."
-                ));
-            }
-            ExitCode::Success
-        }
-        Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("BucketAlreadyExists")
-                || err_str.contains("BucketAlreadyOwnedByYou")
-            {
-                if args.ignore_existing {
-                    if formatter.is_json() {
-                        let output = MbOutput {
-                            status: "success",
-                            bucket: bucket.clone(),
-                            message: Some("Bucket already exists".to_string()),
-                        };
-                        formatter.json(&output);
-                    } else {
-                        formatter
-                            .success(&format!("Bucket '{alias_name}/{bucket}' already exists."));
-                    }
-                    return ExitCode::Success;
-                }
-                formatter.error(&format!("Bucket '{alias_name}/{bucket}' already exists"));
-                ExitCode::Conflict
-            } else if err_str.contains("AccessDenied") {
-                formatter.error(&format!(
-                    "Access denied: cannot create bucket '{alias_name}/{bucket}'"
-                ));
-                ExitCode::AuthError
-            } else {
-                formatter.error(&format!("Failed to create bucket: {e}"));
-                ExitCode::NetworkError
-            }
+            return BucketOutcome::success(
+                bucket,
+                if ignore_existing {
+                    "created"
+                } else {
+                    "success"
+                },
+                None,
+                format!("Bucket '{alias_name}/{bucket}' created successfully."),
+            );
         }
-    }
-}
-
-/// Parse mb target path into (alias, bucket)
-fn parse_mb_path(path: &str) -> Result<(String, String), String> {
-    let path = path.trim_end_matches('/');
+        Err(e) => e,
+    };
 
-    if path.is_empty() {
-        return Err("Path cannot be empty".to_string());
+    // A create that was interrupted and retried can fail although the bucket
+    // now exists (`BucketAlreadyOwnedByYou`, or a dropped response), so ask
+    // the server before reporting the error
+    if ignore_existing
+        && matches!(
+            error,
+            rc_core::Error::Conflict(_) | rc_core::Error::Network(_)
+        )
+        && matches!(client.bucket_exists(bucket).await, Ok(true))
+    {
+        return already_exists();
     }
 
-    let parts: Vec<&str> = path.splitn(2, '/').collect();
-
-    if parts.len() != 2 {
-        return Err(format!(
-            "Invalid path format: '{path}'. Expected: alias/bucket"
-        ));
+    match error {
+        rc_core::Error::Conflict(_) if ignore_existing => BucketOutcome::failure(
+            bucket,
+            ExitCode::Conflict,
+            format!("Bucket name '{bucket}' is already taken by another account"),
+        ),
+        rc_core::Error::Conflict(_) => BucketOutcome::failure(
+            bucket,
+            ExitCode::Conflict,
+            format!(
+                "Bucket '{alias_name}/{bucket}' already exists (use --ignore-existing to treat this as success)"
+            ),
+        ),
+        rc_core::Error::Auth(_) => BucketOutcome::failure(
+            bucket,
+            ExitCode::AuthError,
+            format!("Access denied: cannot create bucket '{alias_name}/{bucket}'"),
+        ),
+        e => BucketOutcome::failure(
+            bucket,
+            ExitCode::NetworkError,
+            format!("Failed to create bucket: {e}"),
+        ),
     }
+}
 
-    let alias = parts[0].to_string();
-    let bucket = parts[1].to_string();
-
-    if bucket.is_empty() {
-        return Err("Bucket name cannot be empty".to_string());
-    }
+/// Parse mb target path into (alias, bucket)
+fn parse_mb_path(path: &str) -> Result<(String, String), String> {
+    let (alias, bucket) = parse_bucket_path(path)?;
 
     // Basic bucket name validation
     if bucket.len() < 3 || bucket.len() > 63 {
@@ -223,4 +198,50 @@ mod tests {
     fn test_parse_mb_path_empty() {
         assert!(parse_mb_path("").is_err());
     }
+
+    #[test]
+    fn test_mb_accepts_multiple_targets() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: MbArgs,
+        }
+
+        let cli =
+            Cli::try_parse_from(["mb", "local/a-bucket", "local/b-bucket", "--stop-on-error"])
+                .expect("parse mb args");
+        assert_eq!(cli.args.targets, vec!["local/a-bucket", "local/b-bucket"]);
+        assert!(cli.args.stop_on_error);
+
+        assert!(Cli::try_parse_from(["mb"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_make_bucket_on_local_backend() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let store = rc_core::LocalStore::new(dir.path());
+
+        let created = make_bucket(&store, "local", "photos", false).await;
+        assert_eq!(created.code, ExitCode::Success);
+        assert_eq!(created.output.status, "success");
+        assert!(dir.path().join("photos").is_dir());
+
+        let conflict = make_bucket(&store, "local", "photos", false).await;
+        assert_eq!(conflict.code, ExitCode::Conflict);
+        assert!(conflict.text.contains("--ignore-existing"));
+
+        let existing = make_bucket(&store, "local", "photos", true).await;
+        assert_eq!(existing.code, ExitCode::Success);
+        assert_eq!(existing.output.status, "exists");
+        assert_eq!(
+            existing.output.message.as_deref(),
+            Some("Bucket already exists")
+        );
+
+        let fresh = make_bucket(&store, "local", "videos", true).await;
+        assert_eq!(fresh.code, ExitCode::Success);
+        assert_eq!(fresh.output.status, "created");
+    }
 }
@@ -10,8 +10,10 @@ use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::commands::diff::{DiffEntry, DiffStatus};
+use crate::commands::diff::{DiffEntry, DiffReason, DiffStatus, FileInfo, compare_pair};
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
+use crate::filter::PathFilter;
 use crate::output::{Formatter, OutputConfig};
 
 /// Synchronize objects between two locations
@@ -42,6 +44,14 @@ pub struct MirrorArgs {
     /// Disable progress bar
     #[arg(long)]
     pub quiet: bool,
+
+    /// Only mirror paths matching this glob (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Skip paths matching this glob (repeatable, wins over --include)
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -53,17 +63,62 @@ struct MirrorOutput {
     skipped: usize,
     errors: usize,
     dry_run: bool,
+    /// Planned actions with their reasons (dry run only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    actions: Vec<PlannedAction>,
+}
+
+/// Output structure for `rc sync`
+#[derive(Debug, Serialize)]
+struct SyncOutput {
+    source: String,
+    target: String,
+    copied: usize,
+    deleted: usize,
+    unchanged: usize,
+    errors: usize,
+    dry_run: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    actions: Vec<PlannedAction>,
+}
+
+/// Whether a run only adds to the target or makes it match the source exactly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MirrorMode {
+    /// `rc mirror`: removal and overwrite are opt-in
+    Mirror,
+    /// `rc sync`: always overwrite and remove; removals need `force`
+    Sync { force: bool },
+}
+
+/// Object counts reported at the end of a run
+#[derive(Debug, Clone, Copy, Default)]
+struct Totals {
+    copied: usize,
+    removed: usize,
+    skipped: usize,
+    errors: usize,
 }
 
-#[derive(Debug, Clone)]
-struct FileInfo {
-    size: Option<i64>,
-    modified: Option<String>,
-    etag: Option<String>,
+/// What a mirror run would do to one object, and why
+#[derive(Debug, Serialize)]
+struct PlannedAction {
+    key: String,
+    action: &'static str,
+    reason: DiffReason,
 }
 
 /// Execute the mirror command
 pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode {
+    run(args, MirrorMode::Mirror, output_config).await
+}
+
+/// Plan and apply a mirror or sync run
+pub(crate) async fn run(
+    args: MirrorArgs,
+    mode: MirrorMode,
+    output_config: OutputConfig,
+) -> ExitCode {
     let formatter = Formatter::new(output_config);
 
     // Parse both paths
@@ -85,6 +140,19 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
             formatter.error(&format!("Invalid target path: {e}"));
             return ExitCode::UsageError;
         }
+        (Ok(ParsedPath::Url(_)), _) | (_, Ok(ParsedPath::Url(_))) => {
+            formatter.error("URLs are not supported in mirror command");
+            return ExitCode::UsageError;
+        }
+    };
+
+    // Excluded paths are left alone on both sides, including by --remove
+    let filter = match PathFilter::new(&args.include, &args.exclude) {
+        Ok(f) => f,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
     };
 
     // Load aliases
@@ -97,7 +165,7 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
     };
 
     // Create clients
-    let source_alias = match alias_manager.get(&source_path.alias) {
+    let source_alias = match resolve_alias(&alias_manager, &source_path.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", source_path.alias));
@@ -105,7 +173,7 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
         }
     };
 
-    let target_alias = match alias_manager.get(&target_path.alias) {
+    let target_alias = match resolve_alias(&alias_manager, &target_path.alias) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{}' not found", target_path.alias));
@@ -130,7 +198,7 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
     });
 
     // List objects from both paths
-    let source_objects = match list_objects_map(&source_client, &source_path).await {
+    let source_objects = match list_objects_map(&source_client, &source_path, &filter).await {
         Ok(o) => o,
         Err(e) => {
             formatter.error(&format!("Failed to list source: {e}"));
@@ -138,7 +206,7 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
         }
     };
 
-    let target_objects = match list_objects_map(&target_client, &target_path).await {
+    let target_objects = match list_objects_map(&target_client, &target_path, &filter).await {
         Ok(o) => o,
         Err(e) => {
             formatter.error(&format!("Failed to list target: {e}"));
@@ -152,14 +220,16 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
     let mut to_copy: Vec<(&str, &FileInfo)> = Vec::new();
     let mut to_remove: Vec<&str> = Vec::new();
     let mut skipped = 0;
+    let mut actions: Vec<PlannedAction> = Vec::new();
 
     for entry in &diff_entries {
-        match entry.status {
+        let action = match entry.status {
             DiffStatus::OnlyFirst => {
                 // New object, copy it
                 if let Some(info) = source_objects.get(&entry.key) {
                     to_copy.push((&entry.key, info));
                 }
+                "copy"
             }
             DiffStatus::Different => {
                 if args.overwrite {
@@ -167,19 +237,32 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
                     if let Some(info) = source_objects.get(&entry.key) {
                         to_copy.push((&entry.key, info));
                     }
+                    "copy"
                 } else {
                     skipped += 1;
+                    "skip"
                 }
             }
             DiffStatus::OnlySecond => {
-                if args.remove {
-                    // Extra object at destination, remove it
-                    to_remove.push(&entry.key);
+                if !args.remove {
+                    continue;
                 }
+                // Extra object at destination, remove it
+                to_remove.push(&entry.key);
+                "remove"
             }
             DiffStatus::Same => {
                 skipped += 1;
+                continue;
             }
+        };
+
+        if let Some(reason) = entry.reason {
+            actions.push(PlannedAction {
+                key: entry.key.clone(),
+                action,
+                reason,
+            });
         }
     }
 
@@ -189,6 +272,14 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
             formatter.println("Dry run mode - no changes will be made:");
             formatter.println("");
 
+            let reason_of = |key: &str| {
+                actions
+                    .iter()
+                    .find(|a| a.key == key)
+                    .map(|a| format!(" [{}]", a.reason))
+                    .unwrap_or_default()
+            };
+
             if !to_copy.is_empty() {
                 formatter.println(&format!("Would copy {} object(s):", to_copy.len()));
                 for (key, info) in &to_copy {
@@ -196,7 +287,7 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
                         .size
                         .map(|s| humansize::format_size(s as u64, humansize::BINARY))
                         .unwrap_or_default();
-                    formatter.println(&format!("  + {key} ({size})"));
+                    formatter.println(&format!("  + {key} ({size}){}", reason_of(key)));
                 }
                 formatter.println("");
             }
@@ -204,7 +295,20 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
             if !to_remove.is_empty() {
                 formatter.println(&format!("Would remove {} object(s):", to_remove.len()));
                 for key in &to_remove {
-                    formatter.println(&format!("  - {key}"));
+                    formatter.println(&format!("  - {key}{}", reason_of(key)));
+                }
+                formatter.println("");
+            }
+
+            let held_back: Vec<&PlannedAction> =
+                actions.iter().filter(|a| a.action == "skip").collect();
+            if !held_back.is_empty() {
+                formatter.println(&format!(
+                    "Would skip {} changed object(s) (use --overwrite to copy):",
+                    held_back.len()
+                ));
+                for planned in held_back {
+                    formatter.println(&format!("  = {} [{}]", planned.key, planned.reason));
                 }
                 formatter.println("");
             }
@@ -216,20 +320,26 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
                 skipped
             ));
         } else {
-            let output = MirrorOutput {
-                source: args.source.clone(),
-                target: args.target.clone(),
+            let totals = Totals {
                 copied: to_copy.len(),
                 removed: to_remove.len(),
                 skipped,
                 errors: 0,
-                dry_run: true,
             };
-            formatter.json(&output);
+            print_json(&formatter, &args, mode, totals, true, actions);
         }
         return ExitCode::Success;
     }
 
+    if mode == (MirrorMode::Sync { force: false }) && !to_remove.is_empty() {
+        formatter.error(&format!(
+            "Sync would delete {} object(s) from '{}'. Re-run with --force to delete them, or --dry-run to review.",
+            to_remove.len(),
+            args.target
+        ));
+        return ExitCode::UsageError;
+    }
+
     // Progress bar setup
     let multi_progress = if !args.quiet && !formatter.is_json() {
         Some(MultiProgress::new())
@@ -348,21 +458,23 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
 
     // Output results
     if formatter.is_json() {
-        let output = MirrorOutput {
-            source: args.source.clone(),
-            target: args.target.clone(),
+        let totals = Totals {
             copied,
             removed,
             skipped,
             errors,
-            dry_run: false,
         };
-        formatter.json(&output);
-    } else {
+        print_json(&formatter, &args, mode, totals, false, Vec::new());
+    } else if mode == MirrorMode::Mirror {
         formatter.println("");
         formatter.println(&format!(
             "Mirror complete: {copied} copied, {removed} removed, {skipped} skipped, {errors} errors"
         ));
+    } else {
+        formatter.println("");
+        formatter.println(&format!(
+            "Sync complete: {copied} copied, {removed} deleted, {skipped} unchanged, {errors} errors"
+        ));
     }
 
     if errors > 0 {
@@ -372,9 +484,43 @@ pub async fn execute(args: MirrorArgs, output_config: OutputConfig) -> ExitCode
     }
 }
 
+/// Emit the JSON result, named for the command that ran
+fn print_json(
+    formatter: &Formatter,
+    args: &MirrorArgs,
+    mode: MirrorMode,
+    totals: Totals,
+    dry_run: bool,
+    actions: Vec<PlannedAction>,
+) {
+    match mode {
+        MirrorMode::Mirror => formatter.json(&MirrorOutput {
+            source: args.source.clone(),
+            target: args.target.clone(),
+            copied: totals.copied,
+            removed: totals.removed,
+            skipped: totals.skipped,
+            errors: totals.errors,
+            dry_run,
+            actions,
+        }),
+        MirrorMode::Sync { .. } => formatter.json(&SyncOutput {
+            source: args.source.clone(),
+            target: args.target.clone(),
+            copied: totals.copied,
+            deleted: totals.removed,
+            unchanged: totals.skipped,
+            errors: totals.errors,
+            dry_run,
+            actions,
+        }),
+    }
+}
+
 async fn list_objects_map(
     client: &S3Client,
     path: &RemotePath,
+    filter: &PathFilter,
 ) -> Result<HashMap<String, FileInfo>, rc_core::Error> {
     let mut objects = HashMap::new();
     let mut continuation_token: Option<String> = None;
@@ -399,7 +545,7 @@ async fn list_objects_map(
             let relative_key = item.key.strip_prefix(base_prefix).unwrap_or(&item.key);
             let relative_key = relative_key.trim_start_matches('/').to_string();
 
-            if relative_key.is_empty() {
+            if relative_key.is_empty() || !filter.matches(&relative_key) {
                 continue;
             }
 
@@ -433,10 +579,8 @@ fn compare_objects_internal(
     for (key, source_info) in source {
         if let Some(target_info) = target.get(key) {
             // Object exists in both
-            let is_same = source_info.size == target_info.size
-                && (source_info.etag == target_info.etag || source_info.etag.is_none());
-
-            let status = if is_same {
+            let reason = compare_pair(source_info, target_info);
+            let status = if reason.is_none() {
                 DiffStatus::Same
             } else {
                 DiffStatus::Different
@@ -449,6 +593,7 @@ fn compare_objects_internal(
                 second_size: target_info.size,
                 first_modified: source_info.modified.clone(),
                 second_modified: target_info.modified.clone(),
+                reason,
             });
         } else {
             // Only in source
@@ -459,6 +604,7 @@ fn compare_objects_internal(
                 second_size: None,
                 first_modified: source_info.modified.clone(),
                 second_modified: None,
+                reason: Some(DiffReason::Missing),
             });
         }
     }
@@ -473,6 +619,7 @@ fn compare_objects_internal(
                 second_size: target_info.size,
                 first_modified: None,
                 second_modified: target_info.modified.clone(),
+                reason: Some(DiffReason::Extra),
             });
         }
     }
@@ -609,6 +756,20 @@ mod tests {
         let entries = compare_objects_internal(&source, &target);
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].status, DiffStatus::Different);
+        assert_eq!(entries[0].reason, Some(DiffReason::SizeDiffers));
+    }
+
+    #[test]
+    fn test_planned_action_serialization() {
+        let planned = PlannedAction {
+            key: "logs/app.log".to_string(),
+            action: "copy",
+            reason: DiffReason::EtagDiffers,
+        };
+        let value = serde_json::to_value(&planned).expect("serialize planned action");
+        assert_eq!(value["key"], "logs/app.log");
+        assert_eq!(value["action"], "copy");
+        assert_eq!(value["reason"], "etag-differs");
     }
 
     #[test]
@@ -621,6 +782,8 @@ mod tests {
             dry_run: false,
             parallel: 4,
             quiet: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
         };
         assert_eq!(args.parallel, 4);
         assert!(!args.remove);
@@ -637,6 +800,7 @@ mod tests {
             skipped: 5,
             errors: 0,
             dry_run: false,
+            actions: Vec::new(),
         };
         let json = serde_json::to_string(&output).unwrap();
         assert!(json.contains("\"copied\":10"));
@@ -4,17 +4,31 @@
 //! Commands are organized by functionality and follow the pattern established
 //! in the command implementation template.
 
+use std::io::BufRead;
+use std::sync::OnceLock;
+
 use clap::{Parser, Subcommand};
+use rc_core::{
+    Alias, AliasManager, BackendRegistry, Capabilities, CapabilityCache, ConfigManager,
+    ObjectStore as _,
+};
+use rc_s3::{MAX_PAGE_SIZE, S3Client};
 
+use crate::env::EnvDefaults;
 use crate::exit_code::ExitCode;
-use crate::output::OutputConfig;
+use crate::interrupt;
+use crate::output::{Formatter, OutputConfig, emit_json_errors};
 
+mod acl;
 mod admin;
 mod alias;
+mod buckets;
 mod cat;
 mod completions;
+mod config;
 pub mod cp;
 pub mod diff;
+mod event;
 mod find;
 mod head;
 mod ls;
@@ -26,6 +40,7 @@ mod rb;
 mod rm;
 mod share;
 mod stat;
+mod sync;
 mod tag;
 mod tree;
 mod version;
@@ -55,10 +70,54 @@ pub struct Cli {
     #[arg(short, long, global = true, default_value = "false")]
     pub quiet: bool,
 
-    /// Enable debug logging
+    /// Log each request and response (credentials redacted) to stderr
     #[arg(long, global = true, default_value = "false")]
     pub debug: bool,
 
+    /// Dump raw admin API requests and responses (secrets redacted) to stderr
+    #[arg(long, global = true, default_value = "false")]
+    pub trace_requests: bool,
+
+    /// S3 endpoint URL to use instead of a configured alias (needs keys unless --anonymous)
+    #[arg(long, global = true)]
+    pub endpoint_url: Option<String>,
+
+    /// Access key for --endpoint-url
+    #[arg(long, global = true, requires = "endpoint_url")]
+    pub access_key: Option<String>,
+
+    /// Secret key for --endpoint-url
+    #[arg(long, global = true, requires = "endpoint_url")]
+    pub secret_key: Option<String>,
+
+    /// Region for --endpoint-url
+    #[arg(long, global = true, requires = "endpoint_url")]
+    pub region: Option<String>,
+
+    /// Allow insecure TLS connections to --endpoint-url
+    #[arg(long, global = true, requires = "endpoint_url")]
+    pub insecure: bool,
+
+    /// Skip TLS certificate verification for every alias in this run
+    #[arg(long, global = true)]
+    pub no_verify_ssl: bool,
+
+    /// Send unsigned requests without credentials (public buckets; not for admin commands)
+    #[arg(long, global = true, conflicts_with_all = ["access_key", "secret_key"])]
+    pub anonymous: bool,
+
+    /// Alias used for `:` paths in this run, instead of the current alias
+    #[arg(long = "alias", global = true, value_name = "NAME")]
+    pub current_alias: Option<String>,
+
+    /// Retry transient failures up to N times, overriding the alias retry settings
+    #[arg(long, global = true, value_name = "N")]
+    pub max_retries: Option<u32>,
+
+    /// Fail on the first error without retrying (same as --max-retries 0)
+    #[arg(long, global = true, conflicts_with = "max_retries")]
+    pub no_retry: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -112,9 +171,12 @@ pub enum Commands {
     /// Show differences between locations
     Diff(diff::DiffArgs),
 
-    /// Mirror objects between locations
+    /// Mirror objects between locations (adds only; never deletes unless --remove)
     Mirror(mirror::MirrorArgs),
 
+    /// Make a destination match a source exactly (DELETES extra objects; needs --force)
+    Sync(sync::SyncArgs),
+
     /// Display objects in tree format
     Tree(tree::TreeArgs),
 
@@ -130,9 +192,21 @@ pub enum Commands {
     #[command(subcommand)]
     Tag(tag::TagCommands),
 
+    /// Manage bucket and object ACLs
+    #[command(subcommand)]
+    Acl(acl::AclCommands),
+
+    /// Manage bucket event notification rules
+    #[command(subcommand)]
+    Event(event::EventCommands),
+
     // Phase 6: Utilities
     /// Generate shell completion scripts
     Completions(completions::CompletionsArgs),
+
+    /// Locate, show or migrate the configuration file
+    #[command(subcommand)]
+    Config(config::ConfigCommands),
     // /// Manage object retention
     // Retention(retention::RetentionArgs),
     // /// Watch for object events
@@ -141,37 +215,466 @@ pub enum Commands {
     // Sql(sql::SqlArgs),
 }
 
+impl Cli {
+    /// Check connection flags that clap cannot express
+    ///
+    /// `--endpoint-url` needs `--access-key` and `--secret-key` unless the run is
+    /// `--anonymous`.
+    fn connection_error(&self) -> Option<&'static str> {
+        let missing_keys = self.access_key.is_none() || self.secret_key.is_none();
+        (self.endpoint_url.is_some() && !self.anonymous && missing_keys)
+            .then_some("--endpoint-url requires --access-key and --secret-key, or --anonymous")
+    }
+
+    /// Total attempts per request requested by `--max-retries`/`--no-retry`
+    fn max_attempts(&self) -> Option<u32> {
+        if self.no_retry {
+            Some(1)
+        } else {
+            self.max_retries.map(|n| n.saturating_add(1))
+        }
+    }
+
+    /// Build an ephemeral alias from the connection flags, if given
+    fn ephemeral_alias(&self, env: &EnvDefaults) -> Option<Alias> {
+        let endpoint = self.endpoint_url.as_ref()?;
+        let mut alias = Alias::new(
+            EPHEMERAL_ALIAS_NAME,
+            endpoint,
+            self.access_key.clone().unwrap_or_default(),
+            self.secret_key.clone().unwrap_or_default(),
+        );
+        if let Some(region) = &self.region {
+            alias.region = region.clone();
+        }
+        alias.insecure = self.insecure || self.no_verify_ssl || env.insecure.unwrap_or(false);
+        alias.anonymous = self.anonymous;
+        alias.ca_bundle = env.ca_bundle.clone();
+        apply_max_attempts(&mut alias, self.max_attempts());
+        Some(alias)
+    }
+}
+
+/// Name given to the alias synthesized from `--endpoint-url`
+const EPHEMERAL_ALIAS_NAME: &str = ":";
+
+/// Connection given on the command line, replacing alias lookups for this run
+static EPHEMERAL_ALIAS: OnceLock<Alias> = OnceLock::new();
+
+/// Alias given with `--alias`, overriding the current alias for this run
+static CURRENT_ALIAS: OnceLock<String> = OnceLock::new();
+
+/// Set by `--anonymous`: every resolved alias sends unsigned requests
+static ANONYMOUS: OnceLock<bool> = OnceLock::new();
+
+/// Set by `--no-verify-ssl`: every resolved alias skips certificate verification
+static NO_VERIFY_SSL: OnceLock<bool> = OnceLock::new();
+
+/// Set by `--max-retries`/`--no-retry`: attempts per request for every resolved alias
+static MAX_ATTEMPTS: OnceLock<u32> = OnceLock::new();
+
+/// Backends available to commands that construct clients through the registry
+pub(crate) fn backend_registry() -> BackendRegistry {
+    let mut registry = BackendRegistry::new();
+    rc_s3::register_backends(&mut registry);
+    registry
+}
+
+/// Override an alias's retry attempts, keeping its backoff settings
+fn apply_max_attempts(alias: &mut Alias, max_attempts: Option<u32>) {
+    if let Some(max_attempts) = max_attempts {
+        alias
+            .retry
+            .get_or_insert_with(Default::default)
+            .max_attempts = max_attempts;
+    }
+}
+
+/// Resolve an alias by name
+///
+/// When `--endpoint-url` was given, every alias name (conventionally `:`, as in
+/// `rc ls :/bucket/`) resolves to that connection instead of the config file.
+/// Otherwise `:` stands for the `--alias` flag or the current alias set with
+/// `rc alias use`, and the configured alias is returned with
+/// `RC_INSECURE`/`RC_CA_BUNDLE`, `--no-verify-ssl`, `--anonymous` and
+/// `--max-retries`/`--no-retry` applied on top.
+pub(crate) fn resolve_alias(manager: &AliasManager, name: &str) -> rc_core::Result<Alias> {
+    if let Some(alias) = EPHEMERAL_ALIAS.get() {
+        return Ok(Alias {
+            name: name.to_string(),
+            ..alias.clone()
+        });
+    }
+
+    let mut alias = if name == EPHEMERAL_ALIAS_NAME {
+        let current = match CURRENT_ALIAS.get() {
+            Some(current) => Some(current.clone()),
+            None => manager.current()?,
+        };
+        let current = current.ok_or_else(|| rc_core::Error::AliasNotFound(name.to_string()))?;
+        manager.get(&current)?
+    } else {
+        manager.get(name)?
+    };
+    EnvDefaults::from_env().apply(&mut alias);
+    if NO_VERIFY_SSL.get() == Some(&true) {
+        alias.insecure = true;
+    }
+    if ANONYMOUS.get() == Some(&true) {
+        alias.anonymous = true;
+    }
+    apply_max_attempts(&mut alias, MAX_ATTEMPTS.get().copied());
+    Ok(alias)
+}
+
+/// Get backend capabilities, reusing the per-alias cache unless `refresh` is set
+///
+/// Detection probes `bucket`, which the command is about to use anyway. Cache
+/// read and write failures are ignored; detection simply runs again.
+pub(crate) async fn cached_capabilities(
+    client: &S3Client,
+    bucket: &str,
+    refresh: bool,
+) -> rc_core::Result<Capabilities> {
+    let cache = CapabilityCache::new().ok();
+    if !refresh && let Some(caps) = cache.as_ref().and_then(|c| c.get(client.alias())) {
+        return Ok(caps);
+    }
+
+    let caps = client.capabilities(bucket).await?;
+    if let Some(cache) = &cache {
+        let _ = cache.put(client.alias(), &caps);
+    }
+    Ok(caps)
+}
+
+/// Clamp a requested listing page size to what S3 accepts (1..=1000)
+pub(crate) fn page_size(requested: Option<i32>) -> i32 {
+    requested.map_or(MAX_PAGE_SIZE, |n| n.clamp(1, MAX_PAGE_SIZE))
+}
+
+/// Clap value parser for a header value such as `--cache-control`
+///
+/// Rejects empty values, control characters and non-ASCII text, which S3
+/// would either refuse or store mangled.
+pub(crate) fn parse_header_value(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("header value cannot be empty".to_string());
+    }
+    if let Some(c) = value.chars().find(|c| !(c.is_ascii_graphic() || *c == ' ')) {
+        return Err(format!(
+            "invalid character {c:?} in header value (printable ASCII only)"
+        ));
+    }
+    Ok(value.to_string())
+}
+
+/// Parse a `KEY=VALUE` user metadata pair
+pub(crate) fn parse_attr(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid metadata '{s}': expected KEY=VALUE"))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("Invalid metadata '{s}': key cannot be empty"));
+    }
+    Ok((key.to_lowercase(), parse_header_value(value)?))
+}
+
+/// Parse a byte size (e.g., "64MiB", "4M", "1GB")
+pub(crate) fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("Size cannot be empty".to_string());
+    }
+
+    let suffix_start = s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len());
+    let num_str = &s[..suffix_start];
+    let suffix = &s[suffix_start..];
+
+    let num: u64 = num_str
+        .parse()
+        .map_err(|_| format!("Invalid size number: {num_str}"))?;
+
+    let multiplier: u64 = match suffix.to_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" | "KIB" => 1024,
+        "M" | "MB" | "MIB" => 1024 * 1024,
+        "G" | "GB" | "GIB" => 1024 * 1024 * 1024,
+        _ => return Err(format!("Unknown size suffix: {suffix}")),
+    };
+
+    match num.checked_mul(multiplier) {
+        Some(0) => Err("Size must be greater than zero".to_string()),
+        Some(size) => Ok(size),
+        None => Err(format!("Size too large: {s}")),
+    }
+}
+
+/// Read a newline-delimited path list, skipping empty lines
+///
+/// Only the line terminator is stripped: keys may legitimately start or end
+/// with spaces.
+pub(crate) fn read_path_list(reader: impl BufRead) -> std::io::Result<Vec<String>> {
+    let mut paths = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if !line.is_empty() {
+            paths.push(line);
+        }
+    }
+    Ok(paths)
+}
+
 /// Execute the CLI command and return an exit code
 pub async fn execute(cli: Cli) -> ExitCode {
+    // Flags override the environment, which overrides the config file
+    let env = EnvDefaults::from_env();
+    let defaults = ConfigManager::new()
+        .and_then(|m| m.load())
+        .map(|c| c.defaults)
+        .unwrap_or_default();
+
+    if let Some(alias) = cli.ephemeral_alias(&env) {
+        let _ = EPHEMERAL_ALIAS.set(alias);
+    }
+    if let Some(name) = &cli.current_alias {
+        let _ = CURRENT_ALIAS.set(name.clone());
+    }
+    if cli.no_verify_ssl {
+        let _ = NO_VERIFY_SSL.set(true);
+    }
+    if cli.anonymous {
+        let _ = ANONYMOUS.set(true);
+    }
+    if let Some(max_attempts) = cli.max_attempts() {
+        let _ = MAX_ATTEMPTS.set(max_attempts);
+    }
+
     let output_config = OutputConfig {
-        json: cli.json,
-        no_color: cli.no_color,
-        no_progress: cli.no_progress,
+        json: cli.json || env.json.unwrap_or(defaults.output == "json"),
+        no_color: cli.no_color || defaults.color == "never",
+        no_progress: cli.no_progress || env.no_progress.unwrap_or(!defaults.progress),
         quiet: cli.quiet,
     };
 
-    match cli.command {
-        Commands::Alias(cmd) => alias::execute(cmd, output_config).await,
-        Commands::Admin(cmd) => admin::execute(cmd, output_config).await,
-        Commands::Ls(args) => ls::execute(args, output_config).await,
-        Commands::Mb(args) => mb::execute(args, output_config).await,
-        Commands::Rb(args) => rb::execute(args, output_config).await,
-        Commands::Cat(args) => cat::execute(args, output_config).await,
-        Commands::Head(args) => head::execute(args, output_config).await,
-        Commands::Stat(args) => stat::execute(args, output_config).await,
-        Commands::Cp(args) => cp::execute(args, output_config).await,
-        Commands::Mv(args) => mv::execute(args, output_config).await,
-        Commands::Rm(args) => rm::execute(args, output_config).await,
-        Commands::Pipe(args) => pipe::execute(args, output_config).await,
-        Commands::Find(args) => find::execute(args, output_config).await,
-        Commands::Diff(args) => diff::execute(args, output_config).await,
-        Commands::Mirror(args) => mirror::execute(args, output_config).await,
-        Commands::Tree(args) => tree::execute(args, output_config).await,
-        Commands::Share(args) => share::execute(args, output_config).await,
-        Commands::Version(cmd) => {
-            version::execute(version::VersionArgs { command: cmd }, output_config).await
+    if let Some(message) = cli.connection_error() {
+        Formatter::new(output_config).error(message);
+        emit_json_errors(ExitCode::UsageError);
+        return ExitCode::UsageError;
+    }
+
+    // Ctrl-C stops the command here, so the interruption is reported like
+    // any other error (including the JSON error object)
+    let interrupted_output = output_config.clone();
+    let command = async move {
+        match cli.command {
+            Commands::Alias(cmd) => alias::execute(cmd, output_config).await,
+            Commands::Admin(cmd) => admin::execute(cmd, output_config).await,
+            Commands::Ls(args) => ls::execute(args, output_config).await,
+            Commands::Mb(args) => mb::execute(args, output_config).await,
+            Commands::Rb(args) => rb::execute(args, output_config).await,
+            Commands::Cat(args) => cat::execute(args, output_config).await,
+            Commands::Head(args) => head::execute(args, output_config).await,
+            Commands::Stat(args) => stat::execute(args, output_config).await,
+            Commands::Cp(args) => cp::execute(args, output_config).await,
+            Commands::Mv(args) => mv::execute(args, output_config).await,
+            Commands::Rm(args) => rm::execute(args, output_config).await,
+            Commands::Pipe(args) => pipe::execute(args, output_config).await,
+            Commands::Find(args) => find::execute(args, output_config).await,
+            Commands::Diff(args) => diff::execute(args, output_config).await,
+            Commands::Mirror(args) => mirror::execute(args, output_config).await,
+            Commands::Sync(args) => sync::execute(args, output_config).await,
+            Commands::Tree(args) => tree::execute(args, output_config).await,
+            Commands::Share(args) => share::execute(args, output_config).await,
+            Commands::Version(cmd) => {
+                version::execute(version::VersionArgs { command: cmd }, output_config).await
+            }
+            Commands::Tag(cmd) => tag::execute(tag::TagArgs { command: cmd }, output_config).await,
+            Commands::Acl(cmd) => acl::execute(cmd, output_config).await,
+            Commands::Event(cmd) => event::execute(cmd, output_config).await,
+            Commands::Completions(args) => completions::execute(args),
+            Commands::Config(cmd) => config::execute(cmd, output_config),
         }
-        Commands::Tag(cmd) => tag::execute(tag::TagArgs { command: cmd }, output_config).await,
-        Commands::Completions(args) => completions::execute(args),
+    };
+    let exit_code = tokio::select! {
+        exit_code = command => exit_code,
+        () = interrupt::cancelled() => {
+            Formatter::new(interrupted_output).error("Interrupted");
+            ExitCode::Interrupted
+        }
+    };
+
+    emit_json_errors(exit_code);
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_header_value() {
+        assert_eq!(
+            parse_header_value(" public, max-age=3600 ").unwrap(),
+            "public, max-age=3600"
+        );
+        assert!(parse_header_value("attachment; filename=\"report.pdf\"").is_ok());
+        assert!(parse_header_value("").is_err());
+        assert!(parse_header_value("no-cache\r\nX-Injected: 1").is_err());
+        assert!(parse_header_value("attachment; filename=\"résumé.pdf\"").is_err());
+    }
+
+    #[test]
+    fn test_read_path_list_keeps_whitespace() {
+        let input = "a/b/one.txt\n\n  a/b/two.txt \r\n";
+        assert_eq!(
+            read_path_list(input.as_bytes()).unwrap(),
+            ["a/b/one.txt", "  a/b/two.txt "]
+        );
+    }
+
+    #[test]
+    fn test_ephemeral_alias_from_flags() {
+        let cli = Cli::try_parse_from([
+            "rc",
+            "--endpoint-url",
+            "http://localhost:9000",
+            "--access-key",
+            "AKIA",
+            "--secret-key",
+            "SECRET",
+            "--region",
+            "eu-west-1",
+            "ls",
+            ":/bucket/",
+        ])
+        .expect("parse connection flags");
+
+        let alias = cli
+            .ephemeral_alias(&EnvDefaults::default())
+            .expect("ephemeral alias");
+        assert_eq!(alias.endpoint, "http://localhost:9000");
+        assert_eq!(alias.access_key, "AKIA");
+        assert_eq!(alias.region, "eu-west-1");
+        assert!(!alias.insecure);
+    }
+
+    #[test]
+    fn test_retry_flags() {
+        let cli = Cli::try_parse_from(["rc", "ls", "local/"]).expect("parse");
+        assert_eq!(cli.max_attempts(), None);
+
+        let cli = Cli::try_parse_from(["rc", "--no-retry", "ls", "local/"]).expect("parse");
+        assert_eq!(cli.max_attempts(), Some(1));
+
+        let cli = Cli::try_parse_from(["rc", "ls", "local/", "--max-retries", "5"]).expect("parse");
+        assert_eq!(cli.max_attempts(), Some(6));
+
+        assert!(
+            Cli::try_parse_from(["rc", "--no-retry", "--max-retries", "2", "ls", "local/"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_apply_max_attempts_keeps_backoff() {
+        let mut alias = Alias::new("local", "http://localhost:9000", "ak", "sk");
+        apply_max_attempts(&mut alias, None);
+        assert!(alias.retry.is_none());
+
+        alias.retry = Some(rc_core::alias::RetryConfig {
+            max_attempts: 3,
+            initial_backoff_ms: 50,
+            max_backoff_ms: 500,
+        });
+        apply_max_attempts(&mut alias, Some(1));
+        let retry = alias.retry_config();
+        assert_eq!(retry.max_attempts, 1);
+        assert_eq!(retry.initial_backoff_ms, 50);
+    }
+
+    #[test]
+    fn test_no_ephemeral_alias_without_endpoint() {
+        let cli = Cli::try_parse_from(["rc", "ls", "local/"]).expect("parse");
+        assert!(cli.ephemeral_alias(&EnvDefaults::default()).is_none());
+    }
+
+    #[test]
+    fn test_alias_flag_does_not_clash_with_positional_alias() {
+        let cli = Cli::try_parse_from(["rc", "--alias", "prod", "ls", ":/bucket/"])
+            .expect("parse --alias");
+        assert_eq!(cli.current_alias.as_deref(), Some("prod"));
+
+        let cli = Cli::try_parse_from(["rc", "admin", "info", "cluster", "local"])
+            .expect("parse admin alias positional");
+        assert!(cli.current_alias.is_none());
+    }
+
+    #[test]
+    fn test_endpoint_url_requires_credentials() {
+        let cli =
+            Cli::try_parse_from(["rc", "--endpoint-url", "http://x", "ls", ":/b/"]).expect("parse");
+        assert!(cli.connection_error().is_some());
+
+        let result = Cli::try_parse_from(["rc", "--access-key", "a", "ls", "local/"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_anonymous_endpoint_needs_no_credentials() {
+        let cli = Cli::try_parse_from([
+            "rc",
+            "--endpoint-url",
+            "http://localhost:9000",
+            "--anonymous",
+            "ls",
+            ":/public/",
+        ])
+        .expect("parse --anonymous");
+        assert!(cli.connection_error().is_none());
+
+        let alias = cli
+            .ephemeral_alias(&EnvDefaults::default())
+            .expect("ephemeral alias");
+        assert!(alias.anonymous);
+        assert!(alias.secret_key.is_empty());
+
+        let result = Cli::try_parse_from([
+            "rc",
+            "--endpoint-url",
+            "http://x",
+            "--access-key",
+            "a",
+            "--anonymous",
+            "ls",
+            ":/b/",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1024").unwrap(), 1024);
+        assert_eq!(parse_size("4M").unwrap(), 4 * 1024 * 1024);
+        assert_eq!(parse_size("64MiB").unwrap(), 64 * 1024 * 1024);
+        assert_eq!(parse_size("1GB").unwrap(), 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_parse_size_invalid() {
+        assert!(parse_size("").is_err());
+        assert!(parse_size("0").is_err());
+        assert!(parse_size("abc").is_err());
+        assert!(parse_size("1X").is_err());
+    }
+
+    #[test]
+    fn test_page_size_is_clamped() {
+        assert_eq!(page_size(None), 1000);
+        assert_eq!(page_size(Some(50)), 50);
+        assert_eq!(page_size(Some(0)), 1);
+        assert_eq!(page_size(Some(5000)), 1000);
     }
 }
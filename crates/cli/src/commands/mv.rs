@@ -3,10 +3,14 @@
 //! Moves objects between locations (copy + delete).
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _, ParsedPath, RemotePath, parse_path};
+use rc_core::{
+    AliasManager, ListOptions, ObjectInfo, ObjectStore as _, ParsedPath, RemotePath, parse_path,
+};
 use rc_s3::S3Client;
 use serde::Serialize;
+use std::path::Path;
 
+use crate::commands::{cp, resolve_alias};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -30,6 +34,10 @@ pub struct MvArgs {
     /// Only show what would be moved (dry run)
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Skip sources whose destination already exists
+    #[arg(short, long)]
+    pub no_clobber: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -80,131 +88,342 @@ pub async fn execute(args: MvArgs, output_config: OutputConfig) -> ExitCode {
             formatter.error("Cannot move between two local paths. Use system mv command.");
             ExitCode::UsageError
         }
+        (ParsedPath::Url(_), _) | (_, ParsedPath::Url(_)) => {
+            formatter.error("URLs are not supported by mv. Use cp to download a URL.");
+            ExitCode::UsageError
+        }
     }
 }
 
-async fn move_local_to_s3(
-    src: &std::path::Path,
-    dst: &RemotePath,
-    args: &MvArgs,
-    formatter: &Formatter,
-) -> ExitCode {
-    use crate::commands::cp;
-
-    // First, copy local to S3
-    let cp_args = cp::CpArgs {
-        source: src.to_string_lossy().to_string(),
-        target: format!("{}/{}/{}", dst.alias, dst.bucket, dst.key),
+/// Build the `cp` arguments used for the copy half of a move
+fn copy_args(args: &MvArgs) -> cp::CpArgs {
+    cp::CpArgs {
+        source: args.source.clone(),
+        target: args.target.clone(),
         recursive: args.recursive,
+        files_from: None,
         preserve: false,
         continue_on_error: args.continue_on_error,
         overwrite: true,
-        dry_run: args.dry_run,
+        dry_run: false,
         storage_class: None,
         content_type: None,
+        no_guess_content_type: false,
+        cache_control: None,
+        content_disposition: None,
+        verbose: false,
+        no_resume: false,
+        resume: false,
+        compress: None,
+        decompress: false,
+        parallel_download: false,
+        concurrency: cp::DEFAULT_CONCURRENCY,
+        part_size: None,
+        upload_concurrency: None,
+        verify: false,
+        page_size: None,
+        include: Vec::new(),
+        exclude: Vec::new(),
+        follow_symlinks: false,
+        preserve_empty_dirs: false,
+        attr: Vec::new(),
+        metadata_directive: cp::MetadataDirective::Copy,
+        preserve_acl: false,
+        acl: None,
+        no_preserve_mtime: false,
+    }
+}
+
+async fn create_client(alias_name: &str, formatter: &Formatter) -> Result<S3Client, ExitCode> {
+    let alias_manager = match AliasManager::new() {
+        Ok(am) => am,
+        Err(e) => {
+            formatter.error(&format!("Failed to load aliases: {e}"));
+            return Err(ExitCode::GeneralError);
+        }
     };
 
-    let cp_result = cp::execute(
-        cp_args,
-        OutputConfig {
-            json: formatter.is_json(),
-            quiet: formatter.is_quiet(),
-            ..Default::default()
-        },
-    )
-    .await;
+    let alias = match resolve_alias(&alias_manager, alias_name) {
+        Ok(a) => a,
+        Err(_) => {
+            formatter.error(&format!("Alias '{alias_name}' not found"));
+            return Err(ExitCode::NotFound);
+        }
+    };
 
-    if cp_result != ExitCode::Success {
-        return cp_result;
+    match S3Client::new(alias).await {
+        Ok(c) => Ok(c),
+        Err(e) => {
+            formatter.error(&format!("Failed to create S3 client: {e}"));
+            Err(ExitCode::NetworkError)
+        }
     }
+}
 
-    // If not dry run, delete local file(s)
-    if !args.dry_run {
-        if src.is_file()
-            && let Err(e) = std::fs::remove_file(src)
-        {
-            formatter.error(&format!("Failed to delete local file: {e}"));
-            return ExitCode::GeneralError;
-        } else if src.is_dir()
-            && args.recursive
-            && let Err(e) = std::fs::remove_dir_all(src)
-        {
-            formatter.error(&format!("Failed to delete local directory: {e}"));
-            return ExitCode::GeneralError;
+/// What happened to a single source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MoveOutcome {
+    Moved,
+    /// `--no-clobber` and the destination already existed
+    Skipped,
+}
+
+/// Exit code for a move of a single source
+fn single_exit_code(result: Result<MoveOutcome, ExitCode>) -> ExitCode {
+    result.err().unwrap_or(ExitCode::Success)
+}
+
+/// Running tally for recursive moves
+#[derive(Debug, Default)]
+struct MoveTally {
+    moved: usize,
+    skipped: usize,
+    failed: usize,
+}
+
+impl MoveTally {
+    /// Record one item's outcome; returns the code to abort with, if any
+    fn record(
+        &mut self,
+        result: Result<MoveOutcome, ExitCode>,
+        continue_on_error: bool,
+    ) -> Option<ExitCode> {
+        match result {
+            Ok(MoveOutcome::Moved) => self.moved += 1,
+            Ok(MoveOutcome::Skipped) => self.skipped += 1,
+            Err(code) => {
+                self.failed += 1;
+                return (!continue_on_error || code == ExitCode::Interrupted).then_some(code);
+            }
         }
+        None
     }
 
-    ExitCode::Success
+    fn finish(&self, dry_run: bool, formatter: &Formatter) -> ExitCode {
+        if self.failed > 0 {
+            formatter.warning(&format!(
+                "Completed with errors: {} moved, {} failed (failed sources were kept)",
+                self.moved, self.failed
+            ));
+            ExitCode::GeneralError
+        } else if self.moved == 0 && self.skipped == 0 {
+            formatter.warning("No objects found to move.");
+            ExitCode::Success
+        } else {
+            if !formatter.is_json() && !dry_run {
+                if self.skipped > 0 {
+                    formatter.success(&format!(
+                        "Moved {} object(s), skipped {} with an existing destination.",
+                        self.moved, self.skipped
+                    ));
+                } else {
+                    formatter.success(&format!("Moved {} object(s).", self.moved));
+                }
+            }
+            ExitCode::Success
+        }
+    }
 }
 
-async fn move_s3_to_local(
-    src: &RemotePath,
-    dst: &std::path::Path,
+fn print_skipped(src: &str, dst: &str, formatter: &Formatter) {
+    if formatter.is_json() {
+        formatter.json(&MvOutput {
+            status: "skipped",
+            source: src.to_string(),
+            target: dst.to_string(),
+            size_bytes: None,
+        });
+    } else {
+        formatter.println(&format!("Skipped {src}: {dst} already exists"));
+    }
+}
+
+fn print_dry_run(src: &str, dst: &str, formatter: &Formatter) {
+    let styled_src = formatter.style_file(src);
+    let styled_dst = formatter.style_file(dst);
+    formatter.println(&format!("Would move: {styled_src} -> {styled_dst}"));
+}
+
+async fn move_local_to_s3(
+    src: &Path,
+    dst: &RemotePath,
     args: &MvArgs,
     formatter: &Formatter,
 ) -> ExitCode {
-    use crate::commands::cp;
+    if !src.exists() {
+        formatter.error(&format!("Source not found: {}", src.display()));
+        return ExitCode::NotFound;
+    }
 
-    // First, copy S3 to local
-    let cp_args = cp::CpArgs {
-        source: format!("{}/{}/{}", src.alias, src.bucket, src.key),
-        target: dst.to_string_lossy().to_string(),
-        recursive: args.recursive,
-        preserve: false,
-        continue_on_error: args.continue_on_error,
-        overwrite: true,
-        dry_run: args.dry_run,
-        storage_class: None,
-        content_type: None,
+    if src.is_dir() && !args.recursive {
+        formatter.error("Source is a directory. Use -r/--recursive to move directories.");
+        return ExitCode::UsageError;
+    }
+
+    let client = match create_client(&dst.alias, formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
     };
+    let cp_args = copy_args(args);
 
-    let cp_result = cp::execute(
-        cp_args,
-        OutputConfig {
-            json: formatter.is_json(),
-            quiet: formatter.is_quiet(),
-            ..Default::default()
-        },
-    )
-    .await;
+    if src.is_file() {
+        return single_exit_code(
+            move_local_file(&client, src, dst, &cp_args, args, formatter).await,
+        );
+    }
 
-    if cp_result != ExitCode::Success {
-        return cp_result;
+    // Symlinks are never followed: moving would delete the files they point to
+    let walked = match cp::walk_dir(src, false) {
+        Ok(w) => w,
+        Err(e) => {
+            formatter.error(&format!("Failed to read directory: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+    for (path, reason) in &walked.skipped {
+        formatter.warning(&reason.warning(path));
     }
 
-    // If not dry run, delete S3 object(s)
-    if !args.dry_run {
-        let alias_manager = match AliasManager::new() {
-            Ok(am) => am,
-            Err(e) => {
-                formatter.error(&format!("Failed to load aliases: {e}"));
-                return ExitCode::GeneralError;
-            }
-        };
+    let mut tally = MoveTally::default();
+    for (file_path, relative_path) in walked.files {
+        let dst_key = cp::rebase_key("", &relative_path.replace('\\', "/"), &dst.key);
+        let target = RemotePath::new(&dst.alias, &dst.bucket, dst_key);
+        let result = move_local_file(&client, &file_path, &target, &cp_args, args, formatter).await;
+        if let Some(code) = tally.record(result, args.continue_on_error) {
+            return code;
+        }
+    }
 
-        let alias = match alias_manager.get(&src.alias) {
-            Ok(a) => a,
-            Err(_) => {
-                formatter.error(&format!("Alias '{}' not found", src.alias));
-                return ExitCode::NotFound;
-            }
-        };
+    // Only drop the (now empty) directory tree once every file made it across;
+    // skipped files and symlinks are still in it
+    if tally.failed == 0
+        && tally.skipped == 0
+        && walked.skipped.is_empty()
+        && !args.dry_run
+        && let Err(e) = std::fs::remove_dir_all(src)
+    {
+        formatter.error(&format!("Failed to delete local directory: {e}"));
+        return ExitCode::GeneralError;
+    }
 
-        let client = match S3Client::new(alias).await {
-            Ok(c) => c,
-            Err(e) => {
-                formatter.error(&format!("Failed to create S3 client: {e}"));
-                return ExitCode::NetworkError;
-            }
-        };
+    tally.finish(args.dry_run, formatter)
+}
+
+async fn move_local_file(
+    client: &S3Client,
+    src: &Path,
+    dst: &RemotePath,
+    cp_args: &cp::CpArgs,
+    args: &MvArgs,
+    formatter: &Formatter,
+) -> Result<MoveOutcome, ExitCode> {
+    // Moving into a "directory" keeps the file name
+    let dst = if dst.key.is_empty() || dst.key.ends_with('/') {
+        let filename = src.file_name().unwrap_or_default().to_string_lossy();
+        RemotePath::new(&dst.alias, &dst.bucket, format!("{}{filename}", dst.key))
+    } else {
+        dst.clone()
+    };
+    let src_display = src.display().to_string();
+    let dst_display = format!("{}/{}/{}", dst.alias, dst.bucket, dst.key);
+
+    if args.no_clobber && remote_exists(client, &dst, formatter).await? {
+        print_skipped(&src_display, &dst_display, formatter);
+        return Ok(MoveOutcome::Skipped);
+    }
+
+    if args.dry_run {
+        print_dry_run(&src_display, &dst_display, formatter);
+        return Ok(MoveOutcome::Moved);
+    }
+
+    let code = cp::upload_file(client, src, &dst, cp_args, formatter).await;
+    if code != ExitCode::Success {
+        return Err(code);
+    }
+
+    if let Err(e) = std::fs::remove_file(src) {
+        formatter.error(&format!("Uploaded but failed to delete {src_display}: {e}"));
+        return Err(ExitCode::GeneralError);
+    }
+    Ok(MoveOutcome::Moved)
+}
+
+async fn move_s3_to_local(
+    src: &RemotePath,
+    dst: &Path,
+    args: &MvArgs,
+    formatter: &Formatter,
+) -> ExitCode {
+    let client = match create_client(&src.alias, formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+    let cp_args = copy_args(args);
 
-        if let Err(e) = client.delete_object(src).await {
-            formatter.error(&format!("Failed to delete source: {e}"));
+    let is_prefix = src.key.is_empty() || src.key.ends_with('/');
+    if !is_prefix && !args.recursive {
+        return single_exit_code(
+            move_remote_to_local(&client, src, dst, &cp_args, args, formatter).await,
+        );
+    }
+
+    let keys = match list_keys(&client, src).await {
+        Ok(k) => k,
+        Err(e) => {
+            formatter.error(&format!("Failed to list objects: {e}"));
             return ExitCode::NetworkError;
         }
+    };
+
+    let mut tally = MoveTally::default();
+    for key in keys {
+        let obj_src = RemotePath::new(&src.alias, &src.bucket, &key);
+        let dst_path = cp::local_target(dst, &src.key, &key);
+        let result =
+            move_remote_to_local(&client, &obj_src, &dst_path, &cp_args, args, formatter).await;
+        if let Some(code) = tally.record(result, args.continue_on_error) {
+            return code;
+        }
+    }
+
+    tally.finish(args.dry_run, formatter)
+}
+
+async fn move_remote_to_local(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &Path,
+    cp_args: &cp::CpArgs,
+    args: &MvArgs,
+    formatter: &Formatter,
+) -> Result<MoveOutcome, ExitCode> {
+    // Moving into a directory keeps the object name
+    let dst = if dst.is_dir() || dst.to_string_lossy().ends_with('/') {
+        dst.join(src.key.rsplit('/').next().unwrap_or(&src.key))
+    } else {
+        dst.to_path_buf()
+    };
+    let src_display = format!("{}/{}/{}", src.alias, src.bucket, src.key);
+    let dst_display = dst.display().to_string();
+
+    if args.no_clobber && dst.exists() {
+        print_skipped(&src_display, &dst_display, formatter);
+        return Ok(MoveOutcome::Skipped);
     }
 
-    ExitCode::Success
+    if args.dry_run {
+        print_dry_run(&src_display, &dst_display, formatter);
+        return Ok(MoveOutcome::Moved);
+    }
+
+    let code = cp::download_file(client, src, &dst, cp_args, formatter).await;
+    if code != ExitCode::Success {
+        return Err(code);
+    }
+
+    delete_source(client, src, formatter).await?;
+    Ok(MoveOutcome::Moved)
 }
 
 async fn move_s3_to_s3(
@@ -219,74 +438,219 @@ async fn move_s3_to_s3(
         return ExitCode::UnsupportedFeature;
     }
 
-    let alias_manager = match AliasManager::new() {
-        Ok(am) => am,
-        Err(e) => {
-            formatter.error(&format!("Failed to load aliases: {e}"));
-            return ExitCode::GeneralError;
-        }
+    let client = match create_client(&src.alias, formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
     };
 
-    let alias = match alias_manager.get(&src.alias) {
-        Ok(a) => a,
-        Err(_) => {
-            formatter.error(&format!("Alias '{}' not found", src.alias));
-            return ExitCode::NotFound;
-        }
-    };
+    let is_prefix = src.key.is_empty() || src.key.ends_with('/');
+    if !is_prefix && !args.recursive {
+        return single_exit_code(move_remote_object(&client, src, dst, args, formatter).await);
+    }
 
-    let client = match S3Client::new(alias).await {
-        Ok(c) => c,
+    let keys = match list_keys(&client, src).await {
+        Ok(k) => k,
         Err(e) => {
-            formatter.error(&format!("Failed to create S3 client: {e}"));
+            formatter.error(&format!("Failed to list objects: {e}"));
             return ExitCode::NetworkError;
         }
     };
 
+    let mut tally = MoveTally::default();
+    for key in keys {
+        let obj_src = RemotePath::new(&src.alias, &src.bucket, &key);
+        let obj_dst = RemotePath::new(
+            &dst.alias,
+            &dst.bucket,
+            cp::rebase_key(&src.key, &key, &dst.key),
+        );
+        let result = move_remote_object(&client, &obj_src, &obj_dst, args, formatter).await;
+        if let Some(code) = tally.record(result, args.continue_on_error) {
+            return code;
+        }
+    }
+
+    tally.finish(args.dry_run, formatter)
+}
+
+async fn move_remote_object(
+    client: &S3Client,
+    src: &RemotePath,
+    dst: &RemotePath,
+    args: &MvArgs,
+    formatter: &Formatter,
+) -> Result<MoveOutcome, ExitCode> {
+    // Moving into a "directory" keeps the source object name
+    let dst = if dst.key.is_empty() || dst.key.ends_with('/') {
+        let filename = src.key.rsplit('/').next().unwrap_or(&src.key);
+        RemotePath::new(&dst.alias, &dst.bucket, format!("{}{filename}", dst.key))
+    } else {
+        dst.clone()
+    };
+
     let src_display = format!("{}/{}/{}", src.alias, src.bucket, src.key);
     let dst_display = format!("{}/{}/{}", dst.alias, dst.bucket, dst.key);
 
-    if args.dry_run {
-        formatter.println(&format!("Would move: {src_display} -> {dst_display}"));
-        return ExitCode::Success;
+    // Copying onto itself and then deleting the source would lose the object
+    if *src == dst {
+        formatter.error(&format!(
+            "Source and destination are the same: {src_display}"
+        ));
+        return Err(ExitCode::UsageError);
     }
 
-    // Copy
-    match client.copy_object(src, dst).await {
-        Ok(info) => {
-            // Delete source
-            if let Err(e) = client.delete_object(src).await {
-                formatter.error(&format!("Copied but failed to delete source: {e}"));
-                return ExitCode::GeneralError;
-            }
+    if args.no_clobber && remote_exists(client, &dst, formatter).await? {
+        print_skipped(&src_display, &dst_display, formatter);
+        return Ok(MoveOutcome::Skipped);
+    }
 
-            if formatter.is_json() {
-                let output = MvOutput {
-                    status: "success",
-                    source: src_display,
-                    target: dst_display,
-                    size_bytes: info.size_bytes,
-                };
-                formatter.json(&output);
-            } else {
-                formatter.println(&format!(
-                    "{src_display} -> {dst_display} ({})",
-                    info.size_human.unwrap_or_default()
-                ));
-            }
-            ExitCode::Success
+    if args.dry_run {
+        print_dry_run(&src_display, &dst_display, formatter);
+        return Ok(MoveOutcome::Moved);
+    }
+
+    let src_info = match client.head_object(src).await {
+        Ok(info) => info,
+        Err(rc_core::Error::NotFound(_)) => {
+            formatter.error(&format!("Source not found: {src_display}"));
+            return Err(ExitCode::NotFound);
         }
         Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                formatter.error(&format!("Source not found: {src_display}"));
-                ExitCode::NotFound
-            } else {
-                formatter.error(&format!("Failed to move: {e}"));
-                ExitCode::NetworkError
-            }
+            formatter.error(&format!("Failed to move: {e}"));
+            return Err(ExitCode::from(&e));
         }
+    };
+
+    if let Err(e) = client.copy_object(src, &dst).await {
+        formatter.error(&format!("Failed to move: {e}"));
+        return Err(ExitCode::NetworkError);
+    }
+
+    // Delete the source only once the copy is confirmed by a HEAD of the destination
+    let dst_info = match client.head_object(&dst).await {
+        Ok(info) => info,
+        Err(e) => {
+            formatter.error(&format!(
+                "Copied {src_display} but could not verify {dst_display}: {e}; source kept"
+            ));
+            return Err(ExitCode::GeneralError);
+        }
+    };
+    if let Err(reason) = verify_copy(&src_info, &dst_info) {
+        formatter.error(&format!(
+            "Copy of {src_display} to {dst_display} failed verification ({reason}); source kept"
+        ));
+        return Err(ExitCode::GeneralError);
+    }
+
+    delete_source(client, src, formatter).await?;
+
+    if formatter.is_json() {
+        let output = MvOutput {
+            status: "success",
+            source: src_display,
+            target: dst_display,
+            size_bytes: dst_info.size_bytes,
+        };
+        formatter.json(&output);
+    } else {
+        formatter.println(&format!(
+            "{src_display} -> {dst_display} ({})",
+            dst_info.size_human.unwrap_or_default()
+        ));
     }
+    Ok(MoveOutcome::Moved)
+}
+
+/// Check that a server-side copy matches its source
+///
+/// Sizes must match. ETags are compared only when neither is a multipart
+/// ETag (`<md5>-<parts>`), since the server may lay the copy out differently.
+fn verify_copy(src: &ObjectInfo, dst: &ObjectInfo) -> Result<(), String> {
+    if src.size_bytes != dst.size_bytes {
+        return Err(format!(
+            "size {} != {}",
+            src.size_bytes.unwrap_or_default(),
+            dst.size_bytes.unwrap_or_default()
+        ));
+    }
+    if let (Some(src_etag), Some(dst_etag)) = (&src.etag, &dst.etag)
+        && !src_etag.contains('-')
+        && !dst_etag.contains('-')
+        && src_etag != dst_etag
+    {
+        return Err(format!("ETag {src_etag} != {dst_etag}"));
+    }
+    Ok(())
+}
+
+/// Whether a remote destination exists, for `--no-clobber`
+async fn remote_exists(
+    client: &S3Client,
+    dst: &RemotePath,
+    formatter: &Formatter,
+) -> Result<bool, ExitCode> {
+    client.object_exists(dst).await.map_err(|e| {
+        formatter.error(&format!(
+            "Failed to check {}/{}/{}: {e}",
+            dst.alias, dst.bucket, dst.key
+        ));
+        ExitCode::from(&e)
+    })
+}
+
+/// Delete a remote source after its copy has been confirmed
+async fn delete_source(
+    client: &S3Client,
+    src: &RemotePath,
+    formatter: &Formatter,
+) -> Result<(), ExitCode> {
+    client.delete_object(src).await.map_err(|e| {
+        formatter.error(&format!(
+            "Copied but failed to delete source {}/{}/{}: {e}",
+            src.alias, src.bucket, src.key
+        ));
+        ExitCode::GeneralError
+    })
+}
+
+/// Whether a listed object belongs to the source and is not a directory marker
+fn is_movable(src_key: &str, item: &ObjectInfo) -> bool {
+    !item.is_dir && !item.key.ends_with('/') && cp::key_in_prefix(src_key, &item.key)
+}
+
+/// List every object key under a prefix, skipping directory markers
+///
+/// Keys are collected up front so deleting sources doesn't disturb pagination.
+async fn list_keys(client: &S3Client, src: &RemotePath) -> rc_core::Result<Vec<String>> {
+    let mut keys = Vec::new();
+    let mut continuation_token: Option<String> = None;
+
+    loop {
+        let options = ListOptions {
+            recursive: true,
+            max_keys: Some(1000),
+            continuation_token: continuation_token.clone(),
+            ..Default::default()
+        };
+
+        let result = client.list_objects(src, options).await?;
+        keys.extend(
+            result
+                .items
+                .into_iter()
+                .filter(|item| is_movable(&src.key, item))
+                .map(|item| item.key),
+        );
+
+        if result.truncated {
+            continuation_token = result.continuation_token;
+        } else {
+            break;
+        }
+    }
+
+    Ok(keys)
 }
 
 #[cfg(test)]
@@ -339,12 +703,61 @@ mod tests {
             recursive: false,
             continue_on_error: false,
             dry_run: false,
+            no_clobber: false,
         };
         assert!(!args.recursive);
         assert!(!args.dry_run);
         assert!(!args.continue_on_error);
     }
 
+    #[test]
+    fn test_move_tally_record() {
+        let mut tally = MoveTally::default();
+        assert_eq!(tally.record(Ok(MoveOutcome::Moved), false), None);
+        assert_eq!(tally.record(Ok(MoveOutcome::Skipped), false), None);
+        assert_eq!(tally.record(Err(ExitCode::NetworkError), true), None);
+        assert_eq!(
+            tally.record(Err(ExitCode::NotFound), false),
+            Some(ExitCode::NotFound)
+        );
+        assert_eq!(
+            tally.record(Err(ExitCode::Interrupted), true),
+            Some(ExitCode::Interrupted)
+        );
+        assert_eq!(tally.moved, 1);
+        assert_eq!(tally.skipped, 1);
+        assert_eq!(tally.failed, 3);
+    }
+
+    #[test]
+    fn test_is_movable_skips_sibling_prefixes() {
+        assert!(is_movable("photos", &ObjectInfo::file("photos", 1)));
+        assert!(is_movable("photos", &ObjectInfo::file("photos/a.jpg", 1)));
+        assert!(!is_movable(
+            "photos",
+            &ObjectInfo::file("photos-old/a.jpg", 1)
+        ));
+        assert!(!is_movable("photos", &ObjectInfo::file("photos.txt", 1)));
+        assert!(!is_movable("photos", &ObjectInfo::file("photos/sub/", 0)));
+        assert!(!is_movable("photos", &ObjectInfo::dir("photos/sub/")));
+        assert!(is_movable("", &ObjectInfo::file("photos-old/a.jpg", 1)));
+    }
+
+    #[test]
+    fn test_verify_copy() {
+        let object = |size: i64, etag: &str| {
+            let mut info = ObjectInfo::file("k", size);
+            info.etag = Some(etag.to_string());
+            info
+        };
+
+        assert!(verify_copy(&object(10, "abc"), &object(10, "abc")).is_ok());
+        assert!(verify_copy(&object(10, "abc"), &object(9, "abc")).is_err());
+        assert!(verify_copy(&object(10, "abc"), &object(10, "def")).is_err());
+        // Multipart ETags differ legitimately after a copy
+        assert!(verify_copy(&object(10, "abc-2"), &object(10, "def")).is_ok());
+    }
+
     #[test]
     fn test_mv_output_serialization() {
         let output = MvOutput {
@@ -1,14 +1,23 @@
 //! pipe command - Stream stdin to S3
 //!
 //! Reads from stdin and uploads to S3. Useful for piping output from other commands.
+//! Input that fits in one part is sent with a single PUT; anything larger is
+//! streamed as a multipart upload, so at most `--upload-concurrency` + 1 parts
+//! are held in memory whatever the input size.
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _, RemotePath};
-use rc_s3::S3Client;
+use futures::{StreamExt, TryStreamExt, stream};
+use rc_core::{Alias, AliasManager, ObjectInfo, ObjectStore as _, PutOptions, RemotePath};
+use rc_s3::multipart::{DEFAULT_PART_SIZE, MAX_PARTS};
+use rc_s3::{S3Client, UploadState};
 use serde::Serialize;
-use std::io::Read;
+use tokio::io::{AsyncRead, AsyncReadExt};
 
+use crate::commands::cp::{DEFAULT_CONCURRENCY, parse_part_size, stop_upload};
+use crate::commands::{parse_attr, parse_header_value, resolve_alias};
+use crate::compression::{Compression, Encoder};
 use crate::exit_code::ExitCode;
+use crate::interrupt;
 use crate::output::{Formatter, OutputConfig};
 
 /// Stream stdin to an object
@@ -17,13 +26,39 @@ pub struct PipeArgs {
     /// Destination path (alias/bucket/key)
     pub target: String,
 
-    /// Content type for the uploaded object
-    #[arg(long, default_value = "application/octet-stream")]
-    pub content_type: String,
+    /// Content type for the uploaded object (stdin has no name to guess one from);
+    /// defaults to the alias's type for the key's extension, else application/octet-stream
+    #[arg(long)]
+    pub content_type: Option<String>,
+
+    /// Cache-Control header for the object (e.g. "public, max-age=3600")
+    #[arg(long, value_parser = parse_header_value)]
+    pub cache_control: Option<String>,
+
+    /// Content-Disposition header for the object (e.g. "attachment")
+    #[arg(long, value_parser = parse_header_value)]
+    pub content_disposition: Option<String>,
 
-    /// Storage class for the object
+    /// Storage class for the object (e.g. STANDARD); the bucket default if unset
     #[arg(long)]
     pub storage_class: Option<String>,
+
+    /// User metadata (`x-amz-meta-<key>`, key lowercased; repeatable)
+    #[arg(long = "attr", alias = "meta", value_name = "KEY=VALUE", value_parser = parse_attr)]
+    pub attr: Vec<(String, String)>,
+
+    /// Compress stdin client-side and set Content-Encoding
+    #[arg(long, value_enum, value_name = "CODEC")]
+    pub compress: Option<Compression>,
+
+    /// Part size for multipart uploads (e.g. 16MiB, 1G; 5 MiB to 5 GiB; default 64 MiB);
+    /// input larger than one part is uploaded in parts, up to 10,000 of them
+    #[arg(long, value_name = "SIZE", value_parser = parse_part_size)]
+    pub part_size: Option<u64>,
+
+    /// Parts in flight per multipart upload
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_CONCURRENCY)]
+    pub upload_concurrency: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -63,7 +98,7 @@ pub async fn execute(args: PipeArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -80,23 +115,57 @@ pub async fn execute(args: PipeArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    // Read from stdin
-    let mut buffer = Vec::new();
-    if let Err(e) = std::io::stdin().read_to_end(&mut buffer) {
-        formatter.error(&format!("Failed to read from stdin: {e}"));
-        return ExitCode::GeneralError;
-    }
-
-    let size = buffer.len() as i64;
     let target = RemotePath::new(&alias_name, &bucket, &key);
     let target_display = format!("{alias_name}/{bucket}/{key}");
+    let part_size = args.part_size.unwrap_or(DEFAULT_PART_SIZE) as usize;
+    let options = PutOptions {
+        content_type: Some(pipe_content_type(args.content_type, client.alias(), &key)),
+        content_encoding: args.compress.map(|c| c.encoding().to_string()),
+        cache_control: args.cache_control,
+        content_disposition: args.content_disposition,
+        metadata: args.attr.into_iter().collect(),
+        storage_class: args
+            .storage_class
+            .or_else(|| client.alias().default_storage_class.clone()),
+    };
 
-    // Upload
-    match client
-        .put_object(&target, buffer, Some(&args.content_type))
+    let encoder = match args.compress.map(Compression::encoder).transpose() {
+        Ok(encoder) => encoder,
+        Err(e) => {
+            formatter.error(&format!("Failed to compress stdin: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+    let mut reader = PartReader::new(tokio::io::stdin(), encoder, part_size);
+    let first = match reader.next_part().await {
+        Ok(part) => part,
+        Err(e) => {
+            formatter.error(&format!("Failed to read from stdin: {e}"));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    // Input that ends within the first part needs no multipart upload
+    let uploaded = if first.len() < part_size {
+        client
+            .put_object_with_options(&target, first, &options)
+            .await
+            .map_err(|e| (ExitCode::NetworkError, e.to_string()))
+    } else {
+        upload_stream(
+            &client,
+            &target,
+            reader,
+            first,
+            &options,
+            args.upload_concurrency.max(1),
+        )
         .await
-    {
+    };
+
+    match uploaded {
         Ok(info) => {
+            let size = info.size_bytes.unwrap_or_default();
             if formatter.is_json() {
                 let output = PipeOutput {
                     status: "success",
@@ -114,13 +183,144 @@ pub async fn execute(args: PipeArgs, output_config: OutputConfig) -> ExitCode {
             }
             ExitCode::Success
         }
-        Err(e) => {
+        Err((code, e)) => {
             formatter.error(&format!("Failed to upload: {e}"));
-            ExitCode::NetworkError
+            code
+        }
+    }
+}
+
+/// Cuts the input, compressed when an encoder is given, into upload parts
+struct PartReader<R> {
+    input: R,
+    encoder: Option<Encoder>,
+    part_size: usize,
+    /// Bytes read (and compressed) but not yet handed out
+    pending: Vec<u8>,
+    done: bool,
+}
+
+impl<R: AsyncRead + Unpin> PartReader<R> {
+    fn new(input: R, encoder: Option<Encoder>, part_size: usize) -> Self {
+        Self {
+            input,
+            encoder,
+            part_size,
+            pending: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Next part: `part_size` bytes, fewer for the last one, empty at the end
+    async fn next_part(&mut self) -> std::io::Result<Vec<u8>> {
+        let mut buf = vec![0; 64 * 1024];
+        while self.pending.len() < self.part_size && !self.done {
+            let n = self.input.read(&mut buf).await?;
+            if n == 0 {
+                self.done = true;
+                if let Some(encoder) = self.encoder.take() {
+                    self.pending.extend(encoder.finish()?);
+                }
+            } else if let Some(encoder) = &mut self.encoder {
+                self.pending.extend(encoder.write(&buf[..n])?);
+            } else {
+                self.pending.extend_from_slice(&buf[..n]);
+            }
+        }
+        let rest = self
+            .pending
+            .split_off(self.part_size.min(self.pending.len()));
+        Ok(std::mem::replace(&mut self.pending, rest))
+    }
+}
+
+/// Upload `first` and the rest of `reader` as a multipart upload
+///
+/// Parts are read while earlier ones upload, with `concurrency` in flight.
+/// Ctrl-C, a failed part or input that needs more than `MAX_PARTS` parts
+/// aborts the upload; piped input cannot be replayed, so it is never saved
+/// for resuming.
+async fn upload_stream<R: AsyncRead + Unpin>(
+    client: &S3Client,
+    target: &RemotePath,
+    mut reader: PartReader<R>,
+    first: Vec<u8>,
+    options: &PutOptions,
+    concurrency: usize,
+) -> Result<ObjectInfo, (ExitCode, String)> {
+    let part_size = reader.part_size as u64;
+    let upload_id = client
+        .create_multipart_upload(target, options)
+        .await
+        .map_err(|e| (ExitCode::NetworkError, e.to_string()))?;
+    let mut state = UploadState::new(&upload_id, target.to_string(), 0, part_size);
+
+    let rest = stream::try_unfold(&mut reader, |reader| async move {
+        let part = reader.next_part().await?;
+        Ok::<_, rc_core::Error>((!part.is_empty()).then_some((part, reader)))
+    });
+    let mut uploads = std::pin::pin!(stream::once(async { Ok(first) })
+        .chain(rest)
+        .enumerate()
+        .map(|(index, part)| {
+            let upload_id = &upload_id;
+            async move {
+                if index >= MAX_PARTS {
+                    return Err(rc_core::Error::InvalidArgument(format!(
+                        "stdin needs more than {MAX_PARTS} parts of {}; pass a larger --part-size",
+                        humansize::format_size(part_size, humansize::BINARY)
+                    )));
+                }
+                let part = part?;
+                let (part_number, size) = (index as i32 + 1, part.len() as u64);
+                client
+                    .upload_part(target, upload_id, part_number, part)
+                    .await
+                    .map(|etag| (part_number, etag, size))
+            }
+        })
+        .buffer_unordered(concurrency));
+
+    let mut guard = interrupt::Guard::new();
+    let outcome = tokio::select! {
+        result = async {
+            while let Some((part_number, etag, size)) = uploads.try_next().await? {
+                state.add_completed_part(part_number, etag);
+                state.total_size += size;
+            }
+            Ok(())
+        } => Some(result),
+        _ = guard.interrupted() => None,
+    };
+
+    let result = match outcome {
+        Some(Ok(())) => client.complete_multipart_upload(target, &state).await,
+        Some(Err(e)) => Err(e),
+        None => {
+            let note = stop_upload(client, target, &state, None, false).await;
+            return Err((ExitCode::Interrupted, format!("interrupted; {note}")));
+        }
+    };
+
+    match result {
+        Ok(info) => Ok(info),
+        Err(e) => {
+            let note = stop_upload(client, target, &state, None, false).await;
+            Err((ExitCode::from(&e), format!("{e}; {note}")))
         }
     }
 }
 
+/// Content type for the piped object
+///
+/// Precedence: `--content-type` > the alias's content type for the key's
+/// extension > `application/octet-stream`.
+fn pipe_content_type(explicit: Option<String>, alias: &Alias, key: &str) -> String {
+    explicit
+        .or_else(|| alias.content_type_for(key).map(str::to_string))
+        .unwrap_or_else(|| "application/octet-stream".to_string())
+}
+
 /// Parse pipe path into (alias, bucket, key)
 fn parse_pipe_path(path: &str) -> Result<(String, String, String), String> {
     if path.is_empty() {
@@ -154,6 +354,137 @@ fn parse_pipe_path(path: &str) -> Result<(String, String, String), String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pipe_content_type() {
+        let mut alias = Alias::new("test", "http://localhost:9000", "key", "secret");
+        alias
+            .content_types
+            .insert("ndjson".to_string(), "application/x-ndjson".to_string());
+
+        assert_eq!(
+            pipe_content_type(Some("text/csv".to_string()), &alias, "logs/a.ndjson"),
+            "text/csv"
+        );
+        assert_eq!(
+            pipe_content_type(None, &alias, "logs/a.ndjson"),
+            "application/x-ndjson"
+        );
+        assert_eq!(
+            pipe_content_type(None, &alias, "logs/a.bin"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_pipe_args_headers_and_metadata() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: PipeArgs,
+        }
+
+        let cli = Cli::try_parse_from([
+            "rc",
+            "local/bucket/report.json",
+            "--content-type",
+            "application/json",
+            "--attr",
+            "Team=data",
+            "--meta",
+            "run=42",
+            "--storage-class",
+            "REDUCED_REDUNDANCY",
+        ])
+        .unwrap();
+        assert_eq!(cli.args.content_type.as_deref(), Some("application/json"));
+        assert_eq!(
+            cli.args.attr,
+            vec![
+                ("team".to_string(), "data".to_string()),
+                ("run".to_string(), "42".to_string())
+            ]
+        );
+        assert_eq!(
+            cli.args.storage_class.as_deref(),
+            Some("REDUCED_REDUNDANCY")
+        );
+
+        let cli = Cli::try_parse_from(["rc", "local/bucket/blob"]).unwrap();
+        assert!(cli.args.content_type.is_none());
+        assert!(cli.args.attr.is_empty());
+        assert!(Cli::try_parse_from(["rc", "local/bucket/blob", "--attr", "novalue"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_part_reader_splits_input() {
+        let input = (0..=255u8).cycle().take(2500).collect::<Vec<_>>();
+        let mut reader = PartReader::new(input.as_slice(), None, 1000);
+
+        let mut parts = Vec::new();
+        loop {
+            let part = reader.next_part().await.expect("read part");
+            if part.is_empty() {
+                break;
+            }
+            parts.push(part);
+        }
+        assert_eq!(
+            parts.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![1000, 1000, 500]
+        );
+        assert_eq!(parts.concat(), input);
+    }
+
+    #[tokio::test]
+    async fn test_part_reader_compresses() {
+        let input = b"hello hello hello hello hello".repeat(1000);
+        for codec in [Compression::Gzip, Compression::Zstd] {
+            let encoder = codec.encoder().expect("encoder");
+            let mut reader = PartReader::new(input.as_slice(), Some(encoder), 64);
+
+            let mut compressed = Vec::new();
+            loop {
+                let part = reader.next_part().await.expect("read part");
+                if part.is_empty() {
+                    break;
+                }
+                assert!(part.len() <= 64);
+                compressed.extend(part);
+            }
+            assert_eq!(codec.decompress(&compressed).expect("decompress"), input);
+        }
+    }
+
+    #[test]
+    fn test_pipe_args_multipart() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: PipeArgs,
+        }
+
+        let cli = Cli::try_parse_from([
+            "rc",
+            "local/bucket/blob",
+            "--part-size",
+            "16MiB",
+            "--upload-concurrency",
+            "8",
+        ])
+        .unwrap();
+        assert_eq!(cli.args.part_size, Some(16 * 1024 * 1024));
+        assert_eq!(cli.args.upload_concurrency, 8);
+
+        let cli = Cli::try_parse_from(["rc", "local/bucket/blob"]).unwrap();
+        assert_eq!(cli.args.part_size, None);
+        assert_eq!(cli.args.upload_concurrency, DEFAULT_CONCURRENCY);
+        assert!(Cli::try_parse_from(["rc", "local/bucket/blob", "--part-size", "1MiB"]).is_err());
+    }
+
     #[test]
     fn test_parse_pipe_path_valid() {
         let (alias, bucket, key) = parse_pipe_path("myalias/mybucket/file.txt").unwrap();
@@ -1,20 +1,20 @@
 //! rb command - Remove bucket
 //!
-//! Removes a bucket from the specified storage service.
+//! Removes one or more buckets from the specified storage service.
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _};
-use rc_s3::S3Client;
-use serde::Serialize;
+use rc_core::ObjectStore;
 
+use crate::commands::buckets::{BucketOutcome, parse_bucket_path, parse_targets, run_targets};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
-/// Remove a bucket
+/// Remove one or more buckets
 #[derive(Args, Debug)]
 pub struct RbArgs {
-    /// Target path (alias/bucket)
-    pub target: String,
+    /// Target paths (alias/bucket), one bucket each
+    #[arg(required = true, value_name = "TARGET")]
+    pub targets: Vec<String>,
 
     /// Force remove even if bucket is not empty (deletes all objects first)
     #[arg(long)]
@@ -23,65 +23,81 @@ pub struct RbArgs {
     /// Remove bucket even if it has incomplete multipart uploads
     #[arg(long)]
     pub dangerous: bool,
-}
 
-#[derive(Debug, Serialize)]
-struct RbOutput {
-    status: &'static str,
-    bucket: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    message: Option<String>,
+    /// Stop at the first bucket that fails instead of continuing with the rest
+    #[arg(long)]
+    pub stop_on_error: bool,
+
+    /// Treat a bucket that does not exist as already removed
+    #[arg(long)]
+    pub ignore_missing: bool,
 }
 
 /// Execute the rb command
 pub async fn execute(args: RbArgs, output_config: OutputConfig) -> ExitCode {
     let formatter = Formatter::new(output_config);
 
-    // Parse the target path
-    let (alias_name, bucket) = match parse_rb_path(&args.target) {
-        Ok(parsed) => parsed,
+    let targets = match parse_targets(&args.targets, parse_bucket_path) {
+        Ok(targets) => targets,
         Err(e) => {
             formatter.error(&e);
             return ExitCode::UsageError;
         }
     };
 
-    // Load alias
-    let alias_manager = match AliasManager::new() {
-        Ok(am) => am,
-        Err(e) => {
-            formatter.error(&format!("Failed to load aliases: {e}"));
-            return ExitCode::GeneralError;
-        }
-    };
-
-    let alias = match alias_manager.get(&alias_name) {
-        Ok(a) => a,
-        Err(_) => {
-            formatter.error(&format!("Alias '{alias_name}' not found"));
-            return ExitCode::NotFound;
-        }
-    };
+    let (force, ignore_missing) = (args.force, args.ignore_missing);
+    run_targets(
+        &formatter,
+        &targets,
+        args.stop_on_error,
+        |client, alias_name, bucket| {
+            Box::pin(remove_bucket(
+                client,
+                alias_name,
+                bucket,
+                force,
+                ignore_missing,
+            ))
+        },
+    )
+    .await
+}
 
-    // Create S3 client
-    let client = match S3Client::new(alias).await {
-        Ok(c) => c,
-        Err(e) => {
-            formatter.error(&format!("Failed to create S3 client: {e}"));
-            return ExitCode::NetworkError;
+/// Remove a single bucket
+async fn remove_bucket(
+    client: &dyn ObjectStore,
+    alias_name: &str,
+    bucket: &str,
+    force: bool,
+    ignore_missing: bool,
+) -> BucketOutcome {
+    let not_found = || {
+        if ignore_missing {
+            BucketOutcome::success(
+                bucket,
+                "success",
+                Some("Bucket does not exist"),
+                format!("Bucket '{alias_name}/{bucket}' does not exist; nothing to remove."),
+            )
+        } else {
+            BucketOutcome::failure(
+                bucket,
+                ExitCode::NotFound,
+                format!("Bucket '{alias_name}/{bucket}' does not exist"),
+            )
         }
     };
 
     // Check if bucket exists
-    match client.bucket_exists(&bucket).await {
-        Ok(false) => {
-            formatter.error(&format!("Bucket '{alias_name}/{bucket}' does not exist"));
-            return ExitCode::NotFound;
-        }
+    match client.bucket_exists(bucket).await {
+        Ok(false) => return not_found(),
         Ok(true) => {}
         Err(e) => {
-            formatter.error(&format!("Failed to check bucket existence: {e}"));
-            return ExitCode::NetworkError;
+            return BucketOutcome::failure(
+                bucket,
+                ExitCode::NetworkError,
+                format!("Failed to check bucket existence: {e}"),
+            );
         }
     }
 
@@ -89,102 +105,87 @@ pub async fn execute(args: RbArgs, output_config: OutputConfig) -> ExitCode {
     // This will be implemented in Phase 3 when we have delete_object
 
     // Delete the bucket
-    match client.delete_bucket(&bucket).await {
-        Ok(()) => {
-            if formatter.is_json() {
-                let output = RbOutput {
-                    status: "success",
-                    bucket: bucket.clone(),
-                    message: None,
-                };
-                formatter.json(&output);
+    match client.delete_bucket(bucket).await {
+        Ok(()) => BucketOutcome::success(
+            bucket,
+            "success",
+            None,
+            format!("Bucket '{alias_name}/{bucket}' removed successfully."),
+        ),
+        Err(rc_core::Error::Conflict(_)) => {
+            let text = if force {
+                format!(
+                    "Bucket '{alias_name}/{bucket}' is not empty. --force with object deletion not yet implemented."
+                )
             } else {
-                formatter.success(&format!(
-                    "Bucket '{alias_name}/{bucket}' removed successfully."
-                ));
-            }
-            ExitCode::Success
-        }
-        Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("BucketNotEmpty") {
-                if args.force {
-                    formatter.error(&format!(
-                        "Bucket '{alias_name}/{bucket}' is not empty. --force with object deletion not yet implemented."
-                    ));
-                } else {
-                    formatter.error(&format!(
-                        "Bucket '{alias_name}/{bucket}' is not empty. Use --force to delete all objects first."
-                    ));
-                }
-                ExitCode::Conflict
-            } else if err_str.contains("NoSuchBucket") || err_str.contains("NotFound") {
-                formatter.error(&format!("Bucket '{alias_name}/{bucket}' does not exist"));
-                ExitCode::NotFound
-            } else if err_str.contains("AccessDenied") {
-                formatter.error(&format!(
-                    "Access denied: cannot remove bucket '{alias_name}/{bucket}'"
-                ));
-                ExitCode::AuthError
-            } else {
-                formatter.error(&format!("Failed to remove bucket: {e}"));
-                ExitCode::NetworkError
-            }
+                format!(
+                    "Bucket '{alias_name}/{bucket}' is not empty. Use --force to delete all objects first."
+                )
+            };
+            BucketOutcome::failure(bucket, ExitCode::Conflict, text)
         }
+        Err(rc_core::Error::NotFound(_)) => not_found(),
+        Err(rc_core::Error::Auth(_)) => BucketOutcome::failure(
+            bucket,
+            ExitCode::AuthError,
+            format!("Access denied: cannot remove bucket '{alias_name}/{bucket}'"),
+        ),
+        Err(e) => BucketOutcome::failure(
+            bucket,
+            ExitCode::NetworkError,
+            format!("Failed to remove bucket: {e}"),
+        ),
     }
 }
 
-/// Parse rb target path into (alias, bucket)
-fn parse_rb_path(path: &str) -> Result<(String, String), String> {
-    let path = path.trim_end_matches('/');
-
-    if path.is_empty() {
-        return Err("Path cannot be empty".to_string());
-    }
-
-    let parts: Vec<&str> = path.splitn(2, '/').collect();
-
-    if parts.len() != 2 {
-        return Err(format!(
-            "Invalid path format: '{path}'. Expected: alias/bucket"
-        ));
-    }
-
-    let alias = parts[0].to_string();
-    let bucket = parts[1].to_string();
-
-    if bucket.is_empty() {
-        return Err("Bucket name cannot be empty".to_string());
-    }
-
-    Ok((alias, bucket))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_rb_path_valid() {
-        let (alias, bucket) = parse_rb_path("myalias/mybucket").unwrap();
-        assert_eq!(alias, "myalias");
-        assert_eq!(bucket, "mybucket");
-    }
+    fn test_rb_ignore_missing_flag() {
+        use clap::Parser;
 
-    #[test]
-    fn test_parse_rb_path_trailing_slash() {
-        let (alias, bucket) = parse_rb_path("myalias/mybucket/").unwrap();
-        assert_eq!(alias, "myalias");
-        assert_eq!(bucket, "mybucket");
-    }
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: RbArgs,
+        }
 
-    #[test]
-    fn test_parse_rb_path_no_bucket() {
-        assert!(parse_rb_path("myalias").is_err());
+        let cli = Cli::try_parse_from(["rb", "local/a-bucket", "--ignore-missing"])
+            .expect("parse rb args");
+        assert!(cli.args.ignore_missing);
+        assert!(
+            !Cli::try_parse_from(["rb", "local/a-bucket"])
+                .unwrap()
+                .args
+                .ignore_missing
+        );
     }
 
-    #[test]
-    fn test_parse_rb_path_empty() {
-        assert!(parse_rb_path("").is_err());
+    #[tokio::test]
+    async fn test_remove_bucket_on_local_backend() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let store = rc_core::LocalStore::new(dir.path());
+        store.create_bucket("photos").await.expect("create bucket");
+        let object = rc_core::RemotePath::new("local", "photos", "a.jpg");
+        store
+            .put_object(&object, b"jpeg".to_vec(), None)
+            .await
+            .expect("put object");
+
+        let not_empty = remove_bucket(&store, "local", "photos", false, false).await;
+        assert_eq!(not_empty.code, ExitCode::Conflict);
+
+        store.delete_object(&object).await.expect("delete object");
+        let removed = remove_bucket(&store, "local", "photos", false, false).await;
+        assert_eq!(removed.code, ExitCode::Success);
+        assert!(!dir.path().join("photos").exists());
+
+        let missing = remove_bucket(&store, "local", "photos", false, false).await;
+        assert_eq!(missing.code, ExitCode::NotFound);
+
+        let ignored = remove_bucket(&store, "local", "photos", false, true).await;
+        assert_eq!(ignored.code, ExitCode::Success);
     }
 }
@@ -3,20 +3,33 @@
 //! Removes one or more objects from a bucket.
 
 use clap::Args;
-use rc_core::{AliasManager, ListOptions, ObjectStore as _, RemotePath};
-use rc_s3::S3Client;
+use rc_core::{AliasManager, ObjectStore as _, RemotePath, is_retryable_error};
+use rc_s3::{DEFAULT_LIST_CONCURRENCY, S3Client, list_objects_parallel};
 use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
 
+use crate::commands::{page_size, read_path_list, resolve_alias};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
+/// Attempts per batch before transiently-failed keys are reported as failed
+const MAX_DELETE_ATTEMPTS: u32 = 3;
+
+/// Initial backoff between batch delete attempts
+const DELETE_BACKOFF_MS: u64 = 200;
+
 /// Remove objects
 #[derive(Args, Debug)]
 pub struct RmArgs {
     /// Object path(s) to remove (alias/bucket/key or alias/bucket/prefix/)
-    #[arg(required = true)]
+    #[arg(required_unless_present = "from_stdin", conflicts_with = "from_stdin")]
     pub paths: Vec<String>,
 
+    /// Read newline-delimited object paths (alias/bucket/key) from stdin
+    #[arg(long, conflicts_with_all = ["recursive", "incomplete", "versions"])]
+    pub from_stdin: bool,
+
     /// Remove recursively (remove all objects with the given prefix)
     #[arg(short, long)]
     pub recursive: bool,
@@ -40,6 +53,10 @@ pub struct RmArgs {
     /// Bypass governance retention
     #[arg(long)]
     pub bypass: bool,
+
+    /// Keys requested per listing page (1-1000, default 1000)
+    #[arg(long, value_name = "N")]
+    pub page_size: Option<i32>,
 }
 
 #[derive(Debug, Serialize)]
@@ -55,23 +72,49 @@ struct RmOutput {
 pub async fn execute(args: RmArgs, output_config: OutputConfig) -> ExitCode {
     let formatter = Formatter::new(output_config);
 
-    // Process each path
+    let groups = if args.from_stdin {
+        let paths = match read_path_list(std::io::stdin().lock()) {
+            Ok(paths) => paths,
+            Err(e) => {
+                formatter.error(&format!("Failed to read from stdin: {e}"));
+                return ExitCode::GeneralError;
+            }
+        };
+        if paths.is_empty() {
+            formatter.warning("No object paths read from stdin");
+        }
+        match group_by_bucket(&paths) {
+            Ok(groups) => groups,
+            Err(e) => {
+                formatter.error(&e);
+                return ExitCode::UsageError;
+            }
+        }
+    } else {
+        BTreeMap::new()
+    };
+
+    // Process each path, or each bucket's keys read from stdin
     let mut all_deleted = Vec::new();
     let mut all_failed = Vec::new();
     let mut has_error = false;
 
     for path_str in &args.paths {
-        match process_rm_path(path_str, &args, &formatter).await {
-            Ok(deleted) => all_deleted.extend(deleted),
-            Err((code, failed)) => {
-                has_error = true;
-                all_failed.extend(failed);
-                if code != ExitCode::Success {
-                    // Continue processing other paths unless it's a critical error
-                    if code == ExitCode::AuthError || code == ExitCode::UsageError {
-                        return code;
-                    }
-                }
+        let result = process_rm_path(path_str, &args, &formatter).await;
+        if let Some(code) = collect_result(result, &mut all_deleted, &mut all_failed) {
+            has_error = true;
+            // Continue processing other paths unless it's a critical error
+            if code == ExitCode::AuthError || code == ExitCode::UsageError {
+                return code;
+            }
+        }
+    }
+    for ((alias_name, bucket), keys) in groups {
+        let result = process_key_list(&alias_name, &bucket, keys, &args, &formatter).await;
+        if let Some(code) = collect_result(result, &mut all_deleted, &mut all_failed) {
+            has_error = true;
+            if code == ExitCode::AuthError || code == ExitCode::UsageError {
+                return code;
             }
         }
     }
@@ -100,6 +143,24 @@ pub async fn execute(args: RmArgs, output_config: OutputConfig) -> ExitCode {
     }
 }
 
+/// Add a path's outcome to the totals, returning its exit code if it failed
+fn collect_result(
+    result: Result<Vec<String>, (ExitCode, Vec<String>)>,
+    all_deleted: &mut Vec<String>,
+    all_failed: &mut Vec<String>,
+) -> Option<ExitCode> {
+    match result {
+        Ok(deleted) => {
+            all_deleted.extend(deleted);
+            None
+        }
+        Err((code, failed)) => {
+            all_failed.extend(failed);
+            Some(code)
+        }
+    }
+}
+
 async fn process_rm_path(
     path_str: &str,
     args: &RmArgs,
@@ -114,6 +175,53 @@ async fn process_rm_path(
         }
     };
 
+    let client = create_client(&alias_name, formatter).await?;
+
+    let is_prefix = key.ends_with('/') || key.is_empty();
+
+    // If recursive or prefix, list and delete all matching objects
+    if args.recursive || is_prefix {
+        delete_recursive(&client, &alias_name, &bucket, &key, args, formatter).await
+    } else {
+        // Delete single object
+        delete_single(&client, &alias_name, &bucket, &key, args, formatter).await
+    }
+}
+
+/// Delete keys of one bucket that were read from stdin
+async fn process_key_list(
+    alias_name: &str,
+    bucket: &str,
+    keys: Vec<String>,
+    args: &RmArgs,
+    formatter: &Formatter,
+) -> Result<Vec<String>, (ExitCode, Vec<String>)> {
+    if args.dry_run {
+        let paths: Vec<String> = keys
+            .iter()
+            .map(|k| format!("{alias_name}/{bucket}/{k}"))
+            .collect();
+        for full_path in &paths {
+            let styled_path = formatter.style_file(full_path);
+            formatter.println(&format!("Would remove: {styled_path}"));
+        }
+        return Ok(paths);
+    }
+
+    let client = create_client(alias_name, formatter).await?;
+    let (deleted, failed) = delete_batches(&client, alias_name, bucket, &keys, formatter).await;
+
+    if !failed.is_empty() {
+        Err((ExitCode::GeneralError, failed))
+    } else {
+        Ok(deleted)
+    }
+}
+
+async fn create_client(
+    alias_name: &str,
+    formatter: &Formatter,
+) -> Result<S3Client, (ExitCode, Vec<String>)> {
     // Load alias
     let alias_manager = match AliasManager::new() {
         Ok(am) => am,
@@ -123,7 +231,7 @@ async fn process_rm_path(
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -132,22 +240,12 @@ async fn process_rm_path(
     };
 
     // Create S3 client
-    let client = match S3Client::new(alias).await {
-        Ok(c) => c,
+    match S3Client::new(alias).await {
+        Ok(c) => Ok(c),
         Err(e) => {
             formatter.error(&format!("Failed to create S3 client: {e}"));
-            return Err((ExitCode::NetworkError, vec![]));
+            Err((ExitCode::NetworkError, vec![]))
         }
-    };
-
-    let is_prefix = key.ends_with('/') || key.is_empty();
-
-    // If recursive or prefix, list and delete all matching objects
-    if args.recursive || is_prefix {
-        delete_recursive(&client, &alias_name, &bucket, &key, args, formatter).await
-    } else {
-        // Delete single object
-        delete_single(&client, &alias_name, &bucket, &key, args, formatter).await
     }
 }
 
@@ -176,24 +274,23 @@ async fn delete_single(
             }
             Ok(vec![full_path])
         }
-        Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                if args.force {
-                    // Force mode: ignore not found errors
-                    Ok(vec![])
-                } else {
-                    formatter.error(&format!("Object not found: {full_path}"));
-                    Err((ExitCode::NotFound, vec![full_path]))
-                }
-            } else if err_str.contains("AccessDenied") {
-                formatter.error(&format!("Access denied: {full_path}"));
-                Err((ExitCode::AuthError, vec![full_path]))
+        Err(rc_core::Error::NotFound(_)) => {
+            if args.force {
+                // Force mode: ignore not found errors
+                Ok(vec![])
             } else {
-                formatter.error(&format!("Failed to remove {full_path}: {e}"));
-                Err((ExitCode::NetworkError, vec![full_path]))
+                formatter.error(&format!("Object not found: {full_path}"));
+                Err((ExitCode::NotFound, vec![full_path]))
             }
         }
+        Err(rc_core::Error::Auth(_)) => {
+            formatter.error(&format!("Access denied: {full_path}"));
+            Err((ExitCode::AuthError, vec![full_path]))
+        }
+        Err(e) => {
+            formatter.error(&format!("Failed to remove {full_path}: {e}"));
+            Err((ExitCode::NetworkError, vec![full_path]))
+        }
     }
 }
 
@@ -208,42 +305,30 @@ async fn delete_recursive(
     let path = RemotePath::new(alias_name, bucket, prefix);
 
     // Collect all objects to delete
-    let mut keys_to_delete = Vec::new();
-    let mut continuation_token: Option<String> = None;
-
-    loop {
-        let options = ListOptions {
-            recursive: true,
-            max_keys: Some(1000),
-            continuation_token: continuation_token.clone(),
-            ..Default::default()
-        };
-
-        match client.list_objects(&path, options).await {
-            Ok(result) => {
-                for item in result.items {
-                    if !item.is_dir {
-                        keys_to_delete.push(item.key);
-                    }
-                }
-
-                if result.truncated {
-                    continuation_token = result.continuation_token;
-                } else {
-                    break;
-                }
-            }
-            Err(e) => {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchBucket") {
-                    formatter.error(&format!("Bucket not found: {bucket}"));
-                    return Err((ExitCode::NotFound, vec![]));
-                }
-                formatter.error(&format!("Failed to list objects: {e}"));
-                return Err((ExitCode::NetworkError, vec![]));
-            }
+    let mut keys_to_delete: Vec<String> = match list_objects_parallel(
+        client,
+        &path,
+        DEFAULT_LIST_CONCURRENCY,
+        page_size(args.page_size),
+    )
+    .await
+    {
+        Ok(items) => items
+            .into_iter()
+            .filter(|item| !item.is_dir)
+            .map(|item| item.key)
+            .collect(),
+        Err(rc_core::Error::NotFound(_)) => {
+            formatter.error(&format!("Bucket not found: {bucket}"));
+            return Err((ExitCode::NotFound, vec![]));
         }
-    }
+        Err(e) => {
+            formatter.error(&format!("Failed to list objects: {e}"));
+            return Err((ExitCode::NetworkError, vec![]));
+        }
+    };
+    // Prefixes are listed concurrently; sort so dry runs and batches follow key order
+    keys_to_delete.sort();
 
     if keys_to_delete.is_empty() {
         if !args.force {
@@ -267,38 +352,107 @@ async fn delete_recursive(
             .collect());
     }
 
-    // Delete in batches (S3 allows up to 1000 per request)
+    let (deleted, failed) =
+        delete_batches(client, alias_name, bucket, &keys_to_delete, formatter).await;
+
+    if !failed.is_empty() {
+        Err((ExitCode::GeneralError, failed))
+    } else {
+        Ok(deleted)
+    }
+}
+
+/// Delete keys in batches (S3 allows up to 1000 per request)
+///
+/// Returns the full paths that were deleted and those that failed.
+async fn delete_batches(
+    client: &S3Client,
+    alias_name: &str,
+    bucket: &str,
+    keys: &[String],
+    formatter: &Formatter,
+) -> (Vec<String>, Vec<String>) {
     let mut deleted = Vec::new();
     let mut failed = Vec::new();
 
-    for chunk in keys_to_delete.chunks(1000) {
-        let chunk_keys: Vec<String> = chunk.to_vec();
-
-        match client.delete_objects(bucket, chunk_keys.clone()).await {
-            Ok(deleted_keys) => {
-                for key in &deleted_keys {
-                    let full_path = format!("{alias_name}/{bucket}/{key}");
-                    if !formatter.is_json() {
-                        let styled_path = formatter.style_file(&full_path);
-                        formatter.println(&format!("Removed: {styled_path}"));
-                    }
-                    deleted.push(full_path);
+    for chunk in keys.chunks(1000) {
+        let mut pending: Vec<String> = chunk.to_vec();
+        let mut attempt = 0;
+
+        while !pending.is_empty() {
+            attempt += 1;
+
+            let result = match client.delete_objects(bucket, pending.clone()).await {
+                Ok(result) => result,
+                Err(e) if attempt < MAX_DELETE_ATTEMPTS && is_retryable_error(&e) => {
+                    tokio::time::sleep(delete_backoff(attempt)).await;
+                    continue;
                 }
-            }
-            Err(e) => {
-                formatter.error(&format!("Failed to delete batch: {e}"));
-                for key in chunk_keys {
-                    failed.push(format!("{alias_name}/{bucket}/{key}"));
+                Err(e) => {
+                    formatter.error(&format!("Failed to delete batch: {e}"));
+                    failed.extend(pending.iter().map(|k| format!("{alias_name}/{bucket}/{k}")));
+                    break;
                 }
+            };
+
+            for key in &result.deleted {
+                let full_path = format!("{alias_name}/{bucket}/{key}");
+                if !formatter.is_json() {
+                    let styled_path = formatter.style_file(&full_path);
+                    formatter.println(&format!("Removed: {styled_path}"));
+                }
+                deleted.push(full_path);
+            }
+
+            // Retry throttled/transient keys; anything else is reported right away
+            let (retryable, permanent): (Vec<_>, Vec<_>) = result
+                .errors
+                .into_iter()
+                .partition(|e| e.is_retryable() && attempt < MAX_DELETE_ATTEMPTS);
+
+            for error in permanent {
+                let full_path = format!("{alias_name}/{bucket}/{}", error.key);
+                formatter.error(&format!(
+                    "Failed to remove {full_path}: {} ({})",
+                    error.message, error.code
+                ));
+                failed.push(full_path);
+            }
+
+            pending = retryable.into_iter().map(|e| e.key).collect();
+            if !pending.is_empty() {
+                tokio::time::sleep(delete_backoff(attempt)).await;
             }
         }
     }
 
-    if !failed.is_empty() {
-        Err((ExitCode::GeneralError, failed))
-    } else {
-        Ok(deleted)
+    (deleted, failed)
+}
+
+/// Group object paths by (alias, bucket) so each bucket is deleted in batches
+///
+/// Keys are sorted and duplicates dropped. Prefixes and bare buckets are
+/// rejected: stdin mode only deletes the objects it is given.
+fn group_by_bucket(paths: &[String]) -> Result<BTreeMap<(String, String), Vec<String>>, String> {
+    let mut groups: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+    for path in paths {
+        let (alias, bucket, key) = parse_rm_path(path)?;
+        if key.is_empty() || key.ends_with('/') {
+            return Err(format!(
+                "Expected an object path from stdin, got '{path}' (prefixes are not expanded)"
+            ));
+        }
+        groups.entry((alias, bucket)).or_default().insert(key);
     }
+    Ok(groups
+        .into_iter()
+        .map(|(bucket, keys)| (bucket, keys.into_iter().collect()))
+        .collect())
+}
+
+/// Backoff before retrying a batch delete, doubling per attempt
+fn delete_backoff(attempt: u32) -> Duration {
+    Duration::from_millis(DELETE_BACKOFF_MS << (attempt - 1).min(6))
 }
 
 /// Parse rm path into (alias, bucket, key)
@@ -334,6 +488,13 @@ fn parse_rm_path(path: &str) -> Result<(String, String, String), String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_delete_backoff_doubles() {
+        assert_eq!(delete_backoff(1), Duration::from_millis(200));
+        assert_eq!(delete_backoff(2), Duration::from_millis(400));
+        assert_eq!(delete_backoff(3), Duration::from_millis(800));
+    }
+
     #[test]
     fn test_parse_rm_path_with_key() {
         let (alias, bucket, key) = parse_rm_path("myalias/mybucket/file.txt").unwrap();
@@ -367,4 +528,52 @@ mod tests {
     fn test_parse_rm_path_empty() {
         assert!(parse_rm_path("").is_err());
     }
+
+    #[test]
+    fn test_group_by_bucket() {
+        let paths: Vec<String> = [
+            "a/logs/2.txt",
+            "b/logs/x",
+            "a/data/k",
+            "a/logs/1.txt",
+            "a/logs/2.txt",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect();
+        let groups = group_by_bucket(&paths).unwrap();
+
+        let keys =
+            |alias: &str, bucket: &str| groups[&(alias.to_string(), bucket.to_string())].clone();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(keys("a", "logs"), ["1.txt", "2.txt"]);
+        assert_eq!(keys("a", "data"), ["k"]);
+        assert_eq!(keys("b", "logs"), ["x"]);
+    }
+
+    #[test]
+    fn test_group_by_bucket_rejects_prefixes() {
+        assert!(group_by_bucket(&["a/logs/dir/".to_string()]).is_err());
+        assert!(group_by_bucket(&["a/logs".to_string()]).is_err());
+        assert!(group_by_bucket(&["a".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_from_stdin_args() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: RmArgs,
+        }
+
+        let cli = Cli::try_parse_from(["rm", "--from-stdin", "--dry-run"]).unwrap();
+        assert!(cli.args.from_stdin);
+        assert!(cli.args.paths.is_empty());
+
+        assert!(Cli::try_parse_from(["rm"]).is_err());
+        assert!(Cli::try_parse_from(["rm", "--from-stdin", "a/b/c"]).is_err());
+        assert!(Cli::try_parse_from(["rm", "--from-stdin", "--recursive"]).is_err());
+    }
 }
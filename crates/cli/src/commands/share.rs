@@ -7,8 +7,9 @@ use rc_core::{AliasManager, ObjectStore as _, RemotePath};
 use rc_s3::S3Client;
 use serde::Serialize;
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
-use crate::output::{Formatter, OutputConfig};
+use crate::output::{Formatter, OutputConfig, format_duration};
 
 /// Generate presigned URLs for sharing objects
 #[derive(Args, Debug)]
@@ -76,7 +77,7 @@ pub async fn execute(args: ShareArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -96,9 +97,18 @@ pub async fn execute(args: ShareArgs, output_config: OutputConfig) -> ExitCode {
     let remote_path = RemotePath::new(&alias_name, &bucket, &key);
 
     // For download URLs, verify object exists
-    if !args.upload && client.head_object(&remote_path).await.is_err() {
-        formatter.error(&format!("Object not found: {}", args.path));
-        return ExitCode::NotFound;
+    if !args.upload {
+        match client.object_exists(&remote_path).await {
+            Ok(true) => {}
+            Ok(false) => {
+                formatter.error(&format!("Object not found: {}", args.path));
+                return ExitCode::NotFound;
+            }
+            Err(e) => {
+                formatter.error(&format!("Failed to check object: {e}"));
+                return ExitCode::NetworkError;
+            }
+        }
     }
 
     // Generate presigned URL
@@ -110,7 +120,7 @@ pub async fn execute(args: ShareArgs, output_config: OutputConfig) -> ExitCode {
             Ok(url) => url,
             Err(e) => {
                 formatter.error(&format!("Failed to generate upload URL: {e}"));
-                return ExitCode::NetworkError;
+                return presign_exit_code(&e);
             }
         }
     } else {
@@ -118,7 +128,7 @@ pub async fn execute(args: ShareArgs, output_config: OutputConfig) -> ExitCode {
             Ok(url) => url,
             Err(e) => {
                 formatter.error(&format!("Failed to generate download URL: {e}"));
-                return ExitCode::NetworkError;
+                return presign_exit_code(&e);
             }
         }
     };
@@ -182,32 +192,6 @@ fn parse_expiration(s: &str) -> Result<u64, String> {
     Ok(seconds)
 }
 
-/// Format duration in human-readable form
-fn format_duration(secs: u64) -> String {
-    if secs >= 86400 {
-        let days = secs / 86400;
-        let hours = (secs % 86400) / 3600;
-        if hours > 0 {
-            format!("{days}d {hours}h")
-        } else {
-            format!("{days} day(s)")
-        }
-    } else if secs >= 3600 {
-        let hours = secs / 3600;
-        let mins = (secs % 3600) / 60;
-        if mins > 0 {
-            format!("{hours}h {mins}m")
-        } else {
-            format!("{hours} hour(s)")
-        }
-    } else if secs >= 60 {
-        let mins = secs / 60;
-        format!("{mins} minute(s)")
-    } else {
-        format!("{secs} second(s)")
-    }
-}
-
 /// Parse share path into (alias, bucket, key)
 fn parse_share_path(path: &str) -> Result<(String, String, String), String> {
     if path.is_empty() {
@@ -227,6 +211,15 @@ fn parse_share_path(path: &str) -> Result<(String, String, String), String> {
     ))
 }
 
+/// Exit code for a failed presign; anything but an unsupported signature is
+/// reported as a network error
+fn presign_exit_code(error: &rc_core::Error) -> ExitCode {
+    match error {
+        rc_core::Error::UnsupportedFeature(_) => ExitCode::from(error),
+        _ => ExitCode::NetworkError,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,13 +256,4 @@ mod tests {
         assert!(parse_share_path("myalias/mybucket").is_err());
         assert!(parse_share_path("myalias/mybucket/").is_err());
     }
-
-    #[test]
-    fn test_format_duration() {
-        assert_eq!(format_duration(30), "30 second(s)");
-        assert_eq!(format_duration(120), "2 minute(s)");
-        assert_eq!(format_duration(3600), "1 hour(s)");
-        assert_eq!(format_duration(86400), "1 day(s)");
-        assert_eq!(format_duration(90000), "1d 1h");
-    }
 }
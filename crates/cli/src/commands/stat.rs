@@ -1,12 +1,18 @@
 //! stat command - Show object metadata
 //!
-//! Displays detailed metadata information about an object.
+//! Displays detailed metadata information about an object, or with
+//! `--recursive` a table of metadata for every object under a prefix.
 
 use clap::Args;
-use rc_core::{AliasManager, ObjectStore as _, RemotePath};
-use rc_s3::S3Client;
+use futures::stream::{self, StreamExt};
+use rc_core::{AliasManager, ObjectChecksum, ObjectInfo, ObjectStore as _, Owner, RemotePath};
+use rc_s3::{
+    DEFAULT_LIST_CONCURRENCY, MAX_PAGE_SIZE, ResponseHeaders, S3Client, list_objects_parallel,
+};
 use serde::Serialize;
+use std::collections::BTreeMap;
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -23,25 +29,131 @@ pub struct StatArgs {
     /// Rewind to a specific time
     #[arg(long)]
     pub rewind: Option<String>,
+
+    /// Print the raw HEAD response headers (Date, Server, x-amz-*) verbatim
+    #[arg(long, visible_alias = "raw", conflicts_with = "recursive")]
+    pub headers: bool,
+
+    /// Show metadata for every object under the path as a table
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Number of concurrent HEAD requests with --recursive
+    #[arg(short = 'P', long, default_value = "8")]
+    pub parallel: usize,
+
+    /// Also show the checksum the object was stored with
+    #[arg(long, conflicts_with_all = ["recursive", "headers"])]
+    pub checksum: bool,
 }
 
+/// JSON output of `stat` for a single object
+///
+/// The fields up to `is_dir` are always present (`null` when the server did
+/// not report them) so scripts can rely on the keys; the rest only appear
+/// when set or requested.
 #[derive(Debug, Serialize)]
 struct StatOutput {
+    key: String,
+    /// Same as `key`; kept for scripts written against earlier releases
     name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    size_bytes: Option<i64>,
+    size_human: Option<String>,
+    etag: Option<String>,
+    content_type: Option<String>,
+    storage_class: Option<String>,
+    /// RFC 3339 UTC timestamp
     last_modified: Option<String>,
+    version_id: Option<String>,
+    metadata: BTreeMap<String, String>,
+    is_dir: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    size_bytes: Option<i64>,
+    cache_control: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    size_human: Option<String>,
+    content_disposition: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    etag: Option<String>,
+    owner: Option<Owner>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content_type: Option<String>,
+    checksum: Option<ChecksumOutput>,
+}
+
+impl StatOutput {
+    fn new(info: &ObjectInfo, checksum: Option<ChecksumOutput>) -> Self {
+        Self {
+            key: info.key.clone(),
+            name: info.key.clone(),
+            size_bytes: info.size_bytes,
+            size_human: info.size_human.clone(),
+            etag: info.etag.clone(),
+            content_type: info.content_type.clone(),
+            storage_class: info.storage_class.clone(),
+            last_modified: info.last_modified.map(|d| d.to_string()),
+            version_id: info.version_id.clone(),
+            metadata: info.metadata.clone(),
+            is_dir: info.is_dir,
+            cache_control: info.cache_control.clone(),
+            content_disposition: info.content_disposition.clone(),
+            owner: info.owner.clone(),
+            checksum,
+        }
+    }
+}
+
+/// Checksum shown by `stat --checksum`; `algorithm` is "none" for objects without one
+#[derive(Debug, Serialize)]
+struct ChecksumOutput {
+    algorithm: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    storage_class: Option<String>,
+    value: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    version_id: Option<String>,
+    checksum_type: Option<String>,
+}
+
+impl From<Option<ObjectChecksum>> for ChecksumOutput {
+    fn from(checksum: Option<ObjectChecksum>) -> Self {
+        match checksum {
+            Some(c) => Self {
+                algorithm: c.algorithm,
+                value: Some(c.value),
+                checksum_type: c.checksum_type,
+            },
+            None => Self {
+                algorithm: "none".to_string(),
+                value: None,
+                checksum_type: None,
+            },
+        }
+    }
+}
+
+impl ChecksumOutput {
+    /// One-line human form, e.g. "SHA256 n4bQ... (FULL_OBJECT)"
+    fn display(&self) -> String {
+        let mut text = self.algorithm.clone();
+        if let Some(value) = &self.value {
+            text.push(' ');
+            text.push_str(value);
+        }
+        if let Some(kind) = &self.checksum_type {
+            text.push_str(&format!(" ({kind})"));
+        }
+        text
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct HeadersOutput {
+    path: String,
+    status: u16,
+    headers: Vec<HeaderEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct HeaderEntry {
+    name: String,
+    value: String,
 }
 
 /// Execute the stat command
@@ -49,7 +161,7 @@ pub async fn execute(args: StatArgs, output_config: OutputConfig) -> ExitCode {
     let formatter = Formatter::new(output_config);
 
     // Parse the path
-    let (alias_name, bucket, key) = match parse_stat_path(&args.path) {
+    let (alias_name, bucket, key) = match parse_stat_path(&args.path, args.recursive) {
         Ok(parsed) => parsed,
         Err(e) => {
             formatter.error(&e);
@@ -66,7 +178,7 @@ pub async fn execute(args: StatArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -85,21 +197,42 @@ pub async fn execute(args: StatArgs, output_config: OutputConfig) -> ExitCode {
 
     let path = RemotePath::new(&alias_name, &bucket, &key);
 
+    if args.recursive {
+        return stat_prefix(&client, &path, args.parallel, &formatter).await;
+    }
+
+    if args.headers {
+        return match client.head_object_headers(&path).await {
+            Ok(response) => print_headers(&args.path, response, &formatter),
+            Err(e) => {
+                formatter.error(&format!("Failed to get object headers: {e}"));
+                ExitCode::NetworkError
+            }
+        };
+    }
+
     // Get object metadata
     match client.head_object(&path).await {
-        Ok(info) => {
+        Ok(mut info) => {
+            // Owner comes from the ACL, which not every backend or policy exposes
+            if let Ok(acl) = client.get_object_acl(&path).await {
+                info.owner = acl.owner;
+            }
+
+            let checksum = if args.checksum {
+                match client.object_checksum(&path).await {
+                    Ok(checksum) => Some(ChecksumOutput::from(checksum)),
+                    Err(e) => {
+                        formatter.error(&format!("Failed to get object checksum: {e}"));
+                        return ExitCode::from(&e);
+                    }
+                }
+            } else {
+                None
+            };
+
             if formatter.is_json() {
-                let output = StatOutput {
-                    name: info.key.clone(),
-                    last_modified: info.last_modified.map(|d| d.to_string()),
-                    size_bytes: info.size_bytes,
-                    size_human: info.size_human.clone(),
-                    etag: info.etag.clone(),
-                    content_type: info.content_type.clone(),
-                    storage_class: info.storage_class.clone(),
-                    version_id: args.version_id,
-                };
-                formatter.json(&output);
+                formatter.json(&StatOutput::new(&info, checksum));
             } else {
                 // Helper to format key-value pairs with styling
                 let format_kv = |key: &str, value: &str| {
@@ -130,36 +263,223 @@ pub async fn execute(args: StatArgs, output_config: OutputConfig) -> ExitCode {
                 if let Some(ct) = &info.content_type {
                     formatter.println(&format_kv("Type", ct));
                 }
+                if let Some(cc) = &info.cache_control {
+                    formatter.println(&format_kv("Cache", cc));
+                }
+                if let Some(cd) = &info.content_disposition {
+                    formatter.println(&format_kv("Disposit.", cd));
+                }
                 if let Some(sc) = &info.storage_class {
                     formatter.println(&format_kv("Class", sc));
                 }
+                if let Some(version_id) = &info.version_id {
+                    formatter.println(&format_kv("Version", version_id));
+                }
+                for (name, value) in &info.metadata {
+                    formatter.println(&format_kv("Meta", &format!("{name}={value}")));
+                }
+                if let Some(owner) = &info.owner {
+                    let name = owner.display_name.as_deref().or(owner.id.as_deref());
+                    if let Some(name) = name {
+                        formatter.println(&format_kv("Owner", name));
+                    }
+                }
+                if let Some(checksum) = &checksum {
+                    formatter.println(&format_kv("Checksum", &checksum.display()));
+                }
             }
             ExitCode::Success
         }
+        Err(rc_core::Error::NotFound(_)) => {
+            formatter.error(&format!("Object not found: {}", args.path));
+            ExitCode::NotFound
+        }
+        Err(rc_core::Error::Auth(_)) => {
+            formatter.error(&format!("Access denied: {}", args.path));
+            ExitCode::AuthError
+        }
         Err(e) => {
-            let err_str = e.to_string();
-            if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                formatter.error(&format!("Object not found: {}", args.path));
-                ExitCode::NotFound
-            } else if err_str.contains("AccessDenied") {
-                formatter.error(&format!("Access denied: {}", args.path));
-                ExitCode::AuthError
-            } else {
-                formatter.error(&format!("Failed to get object metadata: {e}"));
-                ExitCode::NetworkError
+            formatter.error(&format!("Failed to get object metadata: {e}"));
+            ExitCode::NetworkError
+        }
+    }
+}
+
+/// Row of `stat --recursive` output
+#[derive(Debug, Serialize)]
+struct StatRow {
+    key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size_bytes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    etag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage_class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified: Option<String>,
+}
+
+impl From<ObjectInfo> for StatRow {
+    fn from(info: ObjectInfo) -> Self {
+        Self {
+            key: info.key,
+            size_bytes: info.size_bytes,
+            etag: info.etag,
+            content_type: info.content_type,
+            storage_class: info.storage_class,
+            last_modified: info.last_modified.map(|d| d.to_string()),
+        }
+    }
+}
+
+/// HEAD every object under `prefix` and print one row per object
+///
+/// Requests run `parallel` at a time; rows keep the listing order. Objects
+/// that fail (e.g. deleted after listing) are reported and make the exit
+/// code non-zero without stopping the rest.
+async fn stat_prefix(
+    client: &S3Client,
+    prefix: &RemotePath,
+    parallel: usize,
+    formatter: &Formatter,
+) -> ExitCode {
+    let listed = match list_objects_parallel(
+        client,
+        prefix,
+        DEFAULT_LIST_CONCURRENCY,
+        MAX_PAGE_SIZE,
+    )
+    .await
+    {
+        Ok(items) => items,
+        Err(e) => {
+            formatter.error(&format!("Failed to list objects: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    let results: Vec<_> = stream::iter(listed.into_iter().filter(|item| !item.is_dir))
+        .map(|item| async move {
+            let path = RemotePath::new(&prefix.alias, &prefix.bucket, &item.key);
+            let result = client.head_object(&path).await;
+            (item.key, result)
+        })
+        .buffered(parallel.max(1))
+        .collect()
+        .await;
+
+    let mut rows = Vec::with_capacity(results.len());
+    let mut failed = 0;
+    for (key, result) in results {
+        match result {
+            Ok(info) => rows.push(StatRow::from(info)),
+            Err(e) => {
+                failed += 1;
+                formatter.error(&format!("Failed to stat {key}: {e}"));
             }
         }
     }
+
+    if formatter.is_json() {
+        formatter.json(&rows);
+    } else {
+        print_stat_rows(&rows, formatter);
+    }
+
+    if failed > 0 {
+        ExitCode::GeneralError
+    } else {
+        ExitCode::Success
+    }
+}
+
+fn print_stat_rows(rows: &[StatRow], formatter: &Formatter) {
+    let key_width = rows.iter().map(|r| r.key.len()).max().unwrap_or(0).max(3);
+    let etag_width = rows
+        .iter()
+        .map(|r| r.etag.as_deref().map_or(1, str::len))
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    formatter.println(&formatter.style_key(&format!(
+        "{:<key_width$}  {:>12}  {:<etag_width$}  {:<24}  CLASS",
+        "KEY", "SIZE", "ETAG", "CONTENT-TYPE"
+    )));
+    for row in rows {
+        let size = row
+            .size_bytes
+            .map(|s| humansize::format_size(s.max(0) as u64, humansize::BINARY))
+            .unwrap_or_else(|| "-".to_string());
+        formatter.println(&format!(
+            "{}  {}  {:<etag_width$}  {:<24}  {}",
+            formatter.style_file(&format!("{:<key_width$}", row.key)),
+            formatter.style_size(&format!("{size:>12}")),
+            row.etag.as_deref().unwrap_or("-"),
+            row.content_type.as_deref().unwrap_or("-"),
+            row.storage_class.as_deref().unwrap_or("-"),
+        ));
+    }
+}
+
+/// Print a raw response (headers and any captured body) and map its status to an exit code
+pub(crate) fn print_headers(
+    path: &str,
+    response: ResponseHeaders,
+    formatter: &Formatter,
+) -> ExitCode {
+    let status = response.status;
+
+    if formatter.is_json() {
+        let output = HeadersOutput {
+            path: path.to_string(),
+            status,
+            headers: response
+                .headers
+                .into_iter()
+                .map(|(name, value)| HeaderEntry { name, value })
+                .collect(),
+            body: response.body,
+        };
+        formatter.json(&output);
+    } else {
+        formatter.println(&format!("HTTP {status}"));
+        for (name, value) in &response.headers {
+            formatter.println(&format!("{}: {value}", formatter.style_key(name)));
+        }
+        if let Some(body) = &response.body {
+            formatter.println("");
+            formatter.println(body.trim_end());
+        }
+    }
+
+    match status {
+        200..=399 => ExitCode::Success,
+        404 => ExitCode::NotFound,
+        401 | 403 => ExitCode::AuthError,
+        _ => ExitCode::NetworkError,
+    }
 }
 
 /// Parse stat path into (alias, bucket, key)
-fn parse_stat_path(path: &str) -> Result<(String, String, String), String> {
+///
+/// A prefix (`--recursive`) may omit the key to cover the whole bucket.
+fn parse_stat_path(path: &str, prefix: bool) -> Result<(String, String, String), String> {
     if path.is_empty() {
         return Err("Path cannot be empty".to_string());
     }
 
     let parts: Vec<&str> = path.splitn(3, '/').collect();
 
+    if prefix && parts.len() == 2 {
+        if parts[1].is_empty() {
+            return Err("Bucket name cannot be empty".to_string());
+        }
+        return Ok((parts[0].to_string(), parts[1].to_string(), String::new()));
+    }
+
     if parts.len() < 3 {
         return Err(format!(
             "Invalid path format: '{path}'. Expected: alias/bucket/key"
@@ -174,7 +494,7 @@ fn parse_stat_path(path: &str) -> Result<(String, String, String), String> {
         return Err("Bucket name cannot be empty".to_string());
     }
 
-    if key.is_empty() {
+    if key.is_empty() && !prefix {
         return Err("Object key cannot be empty".to_string());
     }
 
@@ -185,9 +505,91 @@ fn parse_stat_path(path: &str) -> Result<(String, String, String), String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stat_output_has_fixed_keys() {
+        let value = serde_json::to_value(StatOutput::new(&ObjectInfo::file("a.txt", 3), None))
+            .expect("serialize stat output");
+        for key in [
+            "key",
+            "name",
+            "size_bytes",
+            "size_human",
+            "etag",
+            "content_type",
+            "storage_class",
+            "last_modified",
+            "version_id",
+            "metadata",
+            "is_dir",
+        ] {
+            assert!(value.get(key).is_some(), "missing {key}");
+        }
+        assert!(value["etag"].is_null());
+        assert_eq!(value["metadata"], serde_json::json!({}));
+        assert!(value.get("checksum").is_none());
+
+        let mut info = ObjectInfo::file("a.txt", 3);
+        info.version_id = Some("v1".to_string());
+        info.last_modified = Some(jiff::Timestamp::from_second(1_700_000_000).expect("timestamp"));
+        info.metadata.insert("owner".to_string(), "ops".to_string());
+        let value = serde_json::to_value(StatOutput::new(&info, None)).expect("serialize");
+        assert_eq!(value["version_id"], "v1");
+        assert_eq!(value["last_modified"], "2023-11-14T22:13:20Z");
+        assert_eq!(value["metadata"]["owner"], "ops");
+    }
+
+    #[test]
+    fn test_stat_output_matches_schema() {
+        let schema: serde_json::Value =
+            serde_json::from_str(include_str!("../../../../schemas/output_v2.json"))
+                .expect("parse schema");
+        let stat = &schema["definitions"]["stat"];
+        let properties = stat["properties"].as_object().expect("stat properties");
+
+        let value = serde_json::to_value(StatOutput::new(&ObjectInfo::file("a.txt", 3), None))
+            .expect("serialize stat output");
+        let output = value.as_object().expect("stat output is an object");
+
+        let required: Vec<&str> = stat["required"]
+            .as_array()
+            .expect("stat required keys")
+            .iter()
+            .map(|key| key.as_str().expect("key name"))
+            .collect();
+        for key in &required {
+            assert!(output.contains_key(*key), "missing {key}");
+        }
+        // Every key the output always has is required, and a null is allowed
+        for (key, field) in output {
+            assert!(required.contains(&key.as_str()), "{key} is not required");
+            if field.is_null() {
+                let types = properties[key]["type"].as_array();
+                assert!(
+                    types.is_some_and(|types| types.contains(&"null".into())),
+                    "{key} is null but not nullable"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_checksum_output() {
+        let stored = ChecksumOutput::from(Some(ObjectChecksum {
+            algorithm: "CRC32C".to_string(),
+            value: "yZRlqg==".to_string(),
+            checksum_type: Some("FULL_OBJECT".to_string()),
+        }));
+        assert_eq!(stored.display(), "CRC32C yZRlqg== (FULL_OBJECT)");
+
+        let none = ChecksumOutput::from(None);
+        assert_eq!(none.display(), "none");
+        let value = serde_json::to_value(&none).expect("serialize checksum");
+        assert_eq!(value, serde_json::json!({ "algorithm": "none" }));
+    }
+
     #[test]
     fn test_parse_stat_path_valid() {
-        let (alias, bucket, key) = parse_stat_path("myalias/mybucket/file.txt").unwrap();
+        let (alias, bucket, key) = parse_stat_path("myalias/mybucket/file.txt", false).unwrap();
         assert_eq!(alias, "myalias");
         assert_eq!(bucket, "mybucket");
         assert_eq!(key, "file.txt");
@@ -195,7 +597,8 @@ mod tests {
 
     #[test]
     fn test_parse_stat_path_with_prefix() {
-        let (alias, bucket, key) = parse_stat_path("myalias/mybucket/path/to/file.txt").unwrap();
+        let (alias, bucket, key) =
+            parse_stat_path("myalias/mybucket/path/to/file.txt", false).unwrap();
         assert_eq!(alias, "myalias");
         assert_eq!(bucket, "mybucket");
         assert_eq!(key, "path/to/file.txt");
@@ -203,16 +606,58 @@ mod tests {
 
     #[test]
     fn test_parse_stat_path_no_key() {
-        assert!(parse_stat_path("myalias/mybucket").is_err());
+        assert!(parse_stat_path("myalias/mybucket", false).is_err());
     }
 
     #[test]
     fn test_parse_stat_path_no_bucket() {
-        assert!(parse_stat_path("myalias").is_err());
+        assert!(parse_stat_path("myalias", false).is_err());
     }
 
     #[test]
     fn test_parse_stat_path_empty() {
-        assert!(parse_stat_path("").is_err());
+        assert!(parse_stat_path("", false).is_err());
+    }
+
+    #[test]
+    fn test_parse_stat_path_prefix() {
+        let (_, bucket, key) = parse_stat_path("myalias/mybucket", true).unwrap();
+        assert_eq!(bucket, "mybucket");
+        assert_eq!(key, "");
+
+        let (_, _, key) = parse_stat_path("myalias/mybucket/logs/", true).unwrap();
+        assert_eq!(key, "logs/");
+
+        assert!(parse_stat_path("myalias/", true).is_err());
+    }
+
+    #[test]
+    fn test_stat_row_from_object_info() {
+        let mut info = ObjectInfo::file("logs/app.log", 42);
+        info.etag = Some("abc".to_string());
+        info.content_type = Some("text/plain".to_string());
+
+        let value = serde_json::to_value(StatRow::from(info)).expect("serialize row");
+        assert_eq!(value["key"], "logs/app.log");
+        assert_eq!(value["size_bytes"], 42);
+        assert_eq!(value["content_type"], "text/plain");
+        assert!(value.get("storage_class").is_none());
+    }
+
+    #[test]
+    fn test_headers_output_serialization() {
+        let output = HeadersOutput {
+            path: "local/bucket/file.txt".to_string(),
+            status: 200,
+            headers: vec![HeaderEntry {
+                name: "date".to_string(),
+                value: "Tue, 01 Jul 2025 10:00:00 GMT".to_string(),
+            }],
+            body: None,
+        };
+        let value = serde_json::to_value(&output).expect("serialize headers output");
+        assert_eq!(value["status"], 200);
+        assert_eq!(value["headers"][0]["name"], "date");
+        assert!(value.get("body").is_none());
     }
 }
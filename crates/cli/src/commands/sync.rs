@@ -0,0 +1,97 @@
+//! sync command - Make a destination match a source
+//!
+//! Unlike `mirror`, which only adds objects unless told otherwise, `sync`
+//! overwrites changed objects and deletes destination objects that are not in
+//! the source. Deletions require `--force`.
+
+use clap::Args;
+
+use crate::commands::mirror::{self, MirrorArgs, MirrorMode};
+use crate::exit_code::ExitCode;
+use crate::output::OutputConfig;
+
+/// Make a destination exactly match a source (DELETES extra destination objects)
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Make TARGET an exact copy of SOURCE: copy new and changed objects and \
+                  DELETE objects in TARGET that are not in SOURCE.\n\n\
+                  Use `rc mirror` to copy without deleting. Run with --dry-run first; \
+                  deletions are refused unless --force is given."
+)]
+pub struct SyncArgs {
+    /// Source path (alias/bucket/prefix)
+    pub source: String,
+
+    /// Destination path (alias/bucket/prefix); objects not in the source are deleted
+    pub target: String,
+
+    /// Allow deleting destination objects that are not in the source
+    #[arg(long)]
+    pub force: bool,
+
+    /// Show what would be copied and deleted, with reasons, without changing anything
+    #[arg(short = 'n', long)]
+    pub dry_run: bool,
+
+    /// Number of parallel operations
+    #[arg(short = 'P', long, default_value = "4")]
+    pub parallel: usize,
+
+    /// Disable progress bar
+    #[arg(long)]
+    pub quiet: bool,
+
+    /// Only sync paths matching this glob (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Skip paths matching this glob (repeatable, wins over --include); never deleted
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+}
+
+/// Execute the sync command
+pub async fn execute(args: SyncArgs, output_config: OutputConfig) -> ExitCode {
+    let mode = MirrorMode::Sync { force: args.force };
+    mirror::run(mirror_args(args), mode, output_config).await
+}
+
+/// Mirror arguments for a sync: always overwrite and remove
+fn mirror_args(args: SyncArgs) -> MirrorArgs {
+    MirrorArgs {
+        source: args.source,
+        target: args.target,
+        remove: true,
+        overwrite: true,
+        dry_run: args.dry_run,
+        parallel: args.parallel,
+        quiet: args.quiet,
+        include: args.include,
+        exclude: args.exclude,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_always_overwrites_and_removes() {
+        let args = SyncArgs {
+            source: "local/src".to_string(),
+            target: "local/dst".to_string(),
+            force: false,
+            dry_run: true,
+            parallel: 4,
+            quiet: false,
+            include: vec!["*.log".to_string()],
+            exclude: Vec::new(),
+        };
+
+        let mirror = mirror_args(args);
+        assert!(mirror.remove);
+        assert!(mirror.overwrite);
+        assert!(mirror.dry_run);
+        assert_eq!(mirror.include, vec!["*.log"]);
+    }
+}
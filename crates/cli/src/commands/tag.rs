@@ -8,6 +8,7 @@ use rc_s3::S3Client;
 use serde::Serialize;
 use std::collections::HashMap;
 
+use crate::commands::{cached_capabilities, resolve_alias};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -81,7 +82,7 @@ async fn execute_list(args: ObjectPathArg, output_config: OutputConfig) -> ExitC
         }
     };
 
-    let client = match setup_client(&alias_name, args.force, &formatter).await {
+    let client = match setup_client(&alias_name, &bucket, args.force, &formatter).await {
         Ok(c) => c,
         Err(code) => return code,
     };
@@ -152,7 +153,7 @@ async fn execute_set(args: SetTagArgs, output_config: OutputConfig) -> ExitCode
         }
     }
 
-    let client = match setup_client(&alias_name, args.force, &formatter).await {
+    let client = match setup_client(&alias_name, &bucket, args.force, &formatter).await {
         Ok(c) => c,
         Err(code) => return code,
     };
@@ -191,7 +192,7 @@ async fn execute_remove(args: ObjectPathArg, output_config: OutputConfig) -> Exi
         }
     };
 
-    let client = match setup_client(&alias_name, args.force, &formatter).await {
+    let client = match setup_client(&alias_name, &bucket, args.force, &formatter).await {
         Ok(c) => c,
         Err(code) => return code,
     };
@@ -220,6 +221,7 @@ async fn execute_remove(args: ObjectPathArg, output_config: OutputConfig) -> Exi
 
 async fn setup_client(
     alias_name: &str,
+    bucket: &str,
     force: bool,
     formatter: &Formatter,
 ) -> Result<S3Client, ExitCode> {
@@ -231,7 +233,7 @@ async fn setup_client(
         }
     };
 
-    let alias = match alias_manager.get(alias_name) {
+    let alias = match resolve_alias(&alias_manager, alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -249,7 +251,7 @@ async fn setup_client(
 
     // Check capabilities
     if !force {
-        match client.capabilities().await {
+        match cached_capabilities(&client, bucket, false).await {
             Ok(caps) => {
                 if !caps.tagging {
                     formatter
@@ -259,7 +261,7 @@ async fn setup_client(
             }
             Err(e) => {
                 formatter.error(&format!("Failed to detect capabilities: {e}"));
-                return Err(ExitCode::NetworkError);
+                return Err(ExitCode::from(&e));
             }
         }
     }
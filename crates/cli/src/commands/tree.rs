@@ -8,6 +8,7 @@ use rc_s3::S3Client;
 use serde::Serialize;
 use std::collections::BTreeMap;
 
+use crate::commands::resolve_alias;
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -78,7 +79,7 @@ pub async fn execute(args: TreeArgs, output_config: OutputConfig) -> ExitCode {
         }
     };
 
-    let alias = match alias_manager.get(&alias_name) {
+    let alias = match resolve_alias(&alias_manager, &alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
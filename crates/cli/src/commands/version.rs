@@ -1,12 +1,14 @@
 //! version command - Manage bucket versioning
 //!
-//! Enable, disable, or check versioning status for a bucket.
+//! Enable, disable, or check versioning status for a bucket, list object
+//! versions, and delete individual versions or delete markers.
 
 use clap::{Args, Subcommand};
-use rc_core::{AliasManager, ObjectStore as _};
+use rc_core::{AliasManager, DeleteError, ObjectStore as _, ObjectVersion, RemotePath};
 use rc_s3::S3Client;
 use serde::Serialize;
 
+use crate::commands::{cached_capabilities, resolve_alias};
 use crate::exit_code::ExitCode;
 use crate::output::{Formatter, OutputConfig};
 
@@ -30,6 +32,13 @@ pub enum VersionCommands {
 
     /// List object versions
     List(ListVersionsArgs),
+
+    /// Delete specific versions of an object (or delete markers) by version ID
+    #[command(name = "rm", alias = "remove")]
+    Remove(RemoveVersionArgs),
+
+    /// Delete all delete markers under a prefix
+    ClearMarkers(ClearMarkersArgs),
 }
 
 #[derive(Args, Debug)]
@@ -56,6 +65,38 @@ pub struct ListVersionsArgs {
     pub force: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct RemoveVersionArgs {
+    /// Object path (alias/bucket/key)
+    pub path: String,
+
+    /// Version ID to delete (repeatable)
+    #[arg(long = "version-id", value_name = "ID", required = true)]
+    pub version_ids: Vec<String>,
+
+    /// Only show what would be deleted
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Force operation even if capability detection fails
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ClearMarkersArgs {
+    /// Path to clear (alias/bucket[/prefix])
+    pub path: String,
+
+    /// Only show which delete markers would be removed
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Force operation even if capability detection fails
+    #[arg(long)]
+    pub force: bool,
+}
+
 #[derive(Debug, Serialize)]
 struct VersioningStatus {
     bucket: String,
@@ -77,6 +118,29 @@ struct VersionInfo {
     size_human: Option<String>,
 }
 
+/// JSON output for `version rm` and `version clear-markers`
+#[derive(Debug, Serialize)]
+struct VersionRemoveOutput {
+    status: &'static str,
+    dry_run: bool,
+    removed: Vec<RemovedVersion>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failed: Vec<FailedVersion>,
+}
+
+#[derive(Debug, Serialize)]
+struct RemovedVersion {
+    key: String,
+    version_id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct FailedVersion {
+    key: String,
+    version_id: String,
+    error: String,
+}
+
 /// Execute the version command
 pub async fn execute(args: VersionArgs, output_config: OutputConfig) -> ExitCode {
     match args.command {
@@ -84,6 +148,10 @@ pub async fn execute(args: VersionArgs, output_config: OutputConfig) -> ExitCode
         VersionCommands::Suspend(bucket_arg) => execute_suspend(bucket_arg, output_config).await,
         VersionCommands::Info(bucket_arg) => execute_info(bucket_arg, output_config).await,
         VersionCommands::List(list_args) => execute_list(list_args, output_config).await,
+        VersionCommands::Remove(rm_args) => execute_remove(rm_args, output_config).await,
+        VersionCommands::ClearMarkers(clear_args) => {
+            execute_clear_markers(clear_args, output_config).await
+        }
     }
 }
 
@@ -270,6 +338,219 @@ async fn execute_list(args: ListVersionsArgs, output_config: OutputConfig) -> Ex
     }
 }
 
+async fn execute_remove(args: RemoveVersionArgs, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let (alias_name, bucket, key) = match parse_version_path(&args.path) {
+        Ok((alias, bucket, Some(key))) if !key.is_empty() && !key.ends_with('/') => {
+            (alias, bucket, key)
+        }
+        Ok(_) => {
+            formatter.error("An object key is required (alias/bucket/key)");
+            return ExitCode::UsageError;
+        }
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let (client, _caps) = match setup_client(&alias_name, &bucket, args.force, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let path = RemotePath::new(&alias_name, &bucket, &key);
+    let mut removed = Vec::new();
+    let mut failed = Vec::new();
+    let mut exit_code = ExitCode::Success;
+
+    for version_id in &args.version_ids {
+        let display = format!("{alias_name}/{bucket}/{key} ({version_id})");
+        if args.dry_run {
+            if !formatter.is_json() {
+                formatter.println(&format!("Would remove: {}", formatter.style_file(&display)));
+            }
+        } else if let Err(e) = client.delete_object_version(&path, version_id).await {
+            formatter.error(&format!("Failed to remove {display}: {e}"));
+            exit_code = ExitCode::from(&e);
+            failed.push(FailedVersion {
+                key: key.clone(),
+                version_id: version_id.clone(),
+                error: e.to_string(),
+            });
+            continue;
+        } else if !formatter.is_json() {
+            formatter.println(&format!("Removed: {}", formatter.style_file(&display)));
+        }
+        removed.push(RemovedVersion {
+            key: key.clone(),
+            version_id: version_id.clone(),
+        });
+    }
+
+    print_remove_summary(&formatter, args.dry_run, removed, failed, "version(s)");
+    exit_code
+}
+
+async fn execute_clear_markers(args: ClearMarkersArgs, output_config: OutputConfig) -> ExitCode {
+    let formatter = Formatter::new(output_config);
+
+    let (alias_name, bucket, prefix) = match parse_version_path(&args.path) {
+        Ok(p) => p,
+        Err(e) => {
+            formatter.error(&e);
+            return ExitCode::UsageError;
+        }
+    };
+
+    let (client, _caps) = match setup_client(&alias_name, &bucket, args.force, &formatter).await {
+        Ok(c) => c,
+        Err(code) => return code,
+    };
+
+    let path = RemotePath::new(&alias_name, &bucket, prefix.as_deref().unwrap_or(""));
+    let markers: Vec<ObjectVersion> = match client.list_object_versions(&path, None).await {
+        Ok(versions) => versions
+            .into_iter()
+            .filter(|v| v.is_delete_marker)
+            .collect(),
+        Err(e) => {
+            formatter.error(&format!("Failed to list versions: {e}"));
+            return ExitCode::from(&e);
+        }
+    };
+
+    if markers.is_empty() {
+        if formatter.is_json() {
+            print_remove_summary(&formatter, args.dry_run, vec![], vec![], "delete marker(s)");
+        } else {
+            formatter.println("No delete markers found.");
+        }
+        return ExitCode::Success;
+    }
+
+    let mut removed = Vec::new();
+    let mut failed = Vec::new();
+    let mut exit_code = ExitCode::Success;
+
+    if args.dry_run {
+        for marker in markers {
+            if !formatter.is_json() {
+                let display = format!(
+                    "{alias_name}/{bucket}/{} ({})",
+                    marker.key, marker.version_id
+                );
+                formatter.println(&format!("Would remove: {}", formatter.style_file(&display)));
+            }
+            removed.push(RemovedVersion {
+                key: marker.key,
+                version_id: marker.version_id,
+            });
+        }
+    } else {
+        for chunk in markers.chunks(1000) {
+            let batch: Vec<(String, String)> = chunk
+                .iter()
+                .map(|m| (m.key.clone(), m.version_id.clone()))
+                .collect();
+            match client.delete_object_versions(&bucket, batch.clone()).await {
+                Ok(result) => {
+                    let (batch_removed, batch_failed) = batch_outcome(batch, &result.errors);
+                    for failure in &batch_failed {
+                        formatter.error(&format!(
+                            "Failed to remove delete marker {alias_name}/{bucket}/{} ({}): {}",
+                            failure.key, failure.version_id, failure.error
+                        ));
+                        if exit_code == ExitCode::Success {
+                            exit_code = ExitCode::GeneralError;
+                        }
+                    }
+                    if !formatter.is_json() {
+                        for entry in &batch_removed {
+                            let display = format!(
+                                "{alias_name}/{bucket}/{} ({})",
+                                entry.key, entry.version_id
+                            );
+                            formatter
+                                .println(&format!("Removed: {}", formatter.style_file(&display)));
+                        }
+                    }
+                    removed.extend(batch_removed);
+                    failed.extend(batch_failed);
+                }
+                Err(e) => {
+                    formatter.error(&format!("Failed to remove delete markers: {e}"));
+                    exit_code = ExitCode::from(&e);
+                    failed.extend(batch.into_iter().map(|(key, version_id)| FailedVersion {
+                        key,
+                        version_id,
+                        error: e.to_string(),
+                    }));
+                }
+            }
+        }
+    }
+
+    print_remove_summary(
+        &formatter,
+        args.dry_run,
+        removed,
+        failed,
+        "delete marker(s)",
+    );
+    exit_code
+}
+
+/// Split a batch of `(key, version_id)` deletes by the per-entry errors
+///
+/// An error without a version ID is matched to the batch by key alone.
+fn batch_outcome(
+    batch: Vec<(String, String)>,
+    errors: &[DeleteError],
+) -> (Vec<RemovedVersion>, Vec<FailedVersion>) {
+    let mut removed = Vec::new();
+    let mut failed = Vec::new();
+    for (key, version_id) in batch {
+        let error = errors
+            .iter()
+            .find(|e| e.key == key && e.version_id.as_deref().is_none_or(|id| id == version_id));
+        match error {
+            Some(error) => failed.push(FailedVersion {
+                key,
+                version_id,
+                error: format!("{} ({})", error.message, error.code),
+            }),
+            None => removed.push(RemovedVersion { key, version_id }),
+        }
+    }
+    (removed, failed)
+}
+
+/// Print the JSON result or the closing line of a version deletion
+fn print_remove_summary(
+    formatter: &Formatter,
+    dry_run: bool,
+    removed: Vec<RemovedVersion>,
+    failed: Vec<FailedVersion>,
+    noun: &str,
+) {
+    if formatter.is_json() {
+        formatter.json(&VersionRemoveOutput {
+            status: if failed.is_empty() {
+                "success"
+            } else {
+                "partial"
+            },
+            dry_run,
+            removed,
+            failed,
+        });
+    } else if !dry_run && !removed.is_empty() {
+        formatter.success(&format!("Removed {} {noun}.", removed.len()));
+    }
+}
+
 async fn setup_client(
     alias_name: &str,
     bucket: &str,
@@ -284,7 +565,7 @@ async fn setup_client(
         }
     };
 
-    let alias = match alias_manager.get(alias_name) {
+    let alias = match resolve_alias(&alias_manager, alias_name) {
         Ok(a) => a,
         Err(_) => {
             formatter.error(&format!("Alias '{alias_name}' not found"));
@@ -301,14 +582,14 @@ async fn setup_client(
     };
 
     // Check capabilities
-    let caps = match client.capabilities().await {
+    let caps = match cached_capabilities(&client, bucket, false).await {
         Ok(c) => c,
         Err(e) => {
             if force {
                 rc_core::Capabilities::default()
             } else {
                 formatter.error(&format!("Failed to detect capabilities: {e}"));
-                return Err(ExitCode::NetworkError);
+                return Err(ExitCode::from(&e));
             }
         }
     };
@@ -392,6 +673,88 @@ mod tests {
         assert!(parse_bucket_path("myalias/").is_err());
     }
 
+    #[test]
+    fn test_remove_version_args() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(subcommand)]
+            command: VersionCommands,
+        }
+
+        let cli = Cli::try_parse_from([
+            "version",
+            "rm",
+            "local/bucket/key.txt",
+            "--version-id",
+            "v1",
+            "--version-id",
+            "v2",
+            "--dry-run",
+        ])
+        .expect("parse rm");
+        match cli.command {
+            VersionCommands::Remove(args) => {
+                assert_eq!(args.version_ids, ["v1", "v2"]);
+                assert!(args.dry_run);
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["version", "rm", "local/bucket/key.txt"]).is_err());
+
+        let cli = Cli::try_parse_from(["version", "clear-markers", "local/bucket/logs/"])
+            .expect("parse clear-markers");
+        assert!(
+            matches!(cli.command, VersionCommands::ClearMarkers(ref a) if a.path == "local/bucket/logs/")
+        );
+    }
+
+    #[test]
+    fn test_remove_output_json() {
+        let output = VersionRemoveOutput {
+            status: "partial",
+            dry_run: false,
+            removed: vec![RemovedVersion {
+                key: "a.txt".to_string(),
+                version_id: "v1".to_string(),
+            }],
+            failed: vec![FailedVersion {
+                key: "b.txt".to_string(),
+                version_id: "v2".to_string(),
+                error: "Access Denied (AccessDenied)".to_string(),
+            }],
+        };
+        let value = serde_json::to_value(&output).expect("serialize");
+        assert_eq!(value["removed"][0]["version_id"], "v1");
+        assert_eq!(value["failed"][0]["key"], "b.txt");
+        assert_eq!(value["failed"][0]["version_id"], "v2");
+    }
+
+    #[test]
+    fn test_batch_outcome_keeps_version_ids() {
+        let batch = vec![
+            ("a.txt".to_string(), "m1".to_string()),
+            ("a.txt".to_string(), "m2".to_string()),
+            ("b.txt".to_string(), "m3".to_string()),
+        ];
+        let errors = [DeleteError {
+            key: "a.txt".to_string(),
+            version_id: Some("m2".to_string()),
+            code: "AccessDenied".to_string(),
+            message: "Access Denied".to_string(),
+        }];
+
+        let (removed, failed) = batch_outcome(batch, &errors);
+        let removed: Vec<_> = removed.iter().map(|r| r.version_id.as_str()).collect();
+        assert_eq!(removed, ["m1", "m3"]);
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].key, "a.txt");
+        assert_eq!(failed[0].version_id, "m2");
+        assert_eq!(failed[0].error, "Access Denied (AccessDenied)");
+    }
+
     #[test]
     fn test_parse_version_path() {
         let (alias, bucket, prefix) = parse_version_path("myalias/mybucket").unwrap();
@@ -0,0 +1,146 @@
+//! Client-side compression for uploads
+//!
+//! `--compress` encodes the payload before it is sent (`pipe` encodes stdin
+//! as it streams) and records the codec in `Content-Encoding`, so the stored
+//! object (and its size) is the compressed data. `--decompress` reverses this on download based on the
+//! object's `Content-Encoding`.
+
+use std::io::{self, Read, Write};
+
+/// Compression codec for uploads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Compression {
+    /// gzip (RFC 1952)
+    Gzip,
+    /// Zstandard
+    Zstd,
+}
+
+/// zstd level used for uploads (the library default)
+const ZSTD_LEVEL: i32 = 3;
+
+impl Compression {
+    /// Codec for a `Content-Encoding` value, if it is one we can decode
+    pub fn from_encoding(encoding: &str) -> Option<Self> {
+        match encoding.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Some(Self::Gzip),
+            "zstd" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+
+    /// `Content-Encoding` value for this codec
+    pub fn encoding(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// Compress a complete payload
+    pub fn compress(self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            Self::Zstd => zstd::encode_all(data, ZSTD_LEVEL),
+        }
+    }
+
+    /// Start compressing a payload that arrives in pieces
+    pub fn encoder(self) -> io::Result<Encoder> {
+        Ok(match self {
+            Self::Gzip => Encoder::Gzip(flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            )),
+            Self::Zstd => Encoder::Zstd(zstd::Encoder::new(Vec::new(), ZSTD_LEVEL)?),
+        })
+    }
+
+    /// Decompress a complete payload
+    pub fn decompress(self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip => {
+                let mut out = Vec::new();
+                flate2::read::MultiGzDecoder::new(data).read_to_end(&mut out)?;
+                Ok(out)
+            }
+            Self::Zstd => zstd::decode_all(data),
+        }
+    }
+}
+
+/// Incremental compressor returned by [`Compression::encoder`]
+///
+/// The output is the same format [`Compression::compress`] produces for the
+/// concatenated input.
+pub enum Encoder {
+    Gzip(flate2::write::GzEncoder<Vec<u8>>),
+    Zstd(zstd::Encoder<'static, Vec<u8>>),
+}
+
+impl Encoder {
+    /// Compress `data`, returning the compressed bytes produced so far
+    pub fn write(&mut self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(encoder) => {
+                encoder.write_all(data)?;
+                Ok(std::mem::take(encoder.get_mut()))
+            }
+            Self::Zstd(encoder) => {
+                encoder.write_all(data)?;
+                Ok(std::mem::take(encoder.get_mut()))
+            }
+        }
+    }
+
+    /// End the stream, returning the remaining compressed bytes
+    pub fn finish(self) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(encoder) => encoder.finish(),
+            Self::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let data = b"hello hello hello hello hello".repeat(100);
+        for codec in [Compression::Gzip, Compression::Zstd] {
+            let compressed = codec.compress(&data).expect("compress");
+            assert!(compressed.len() < data.len());
+            assert_eq!(codec.decompress(&compressed).expect("decompress"), data);
+        }
+    }
+
+    #[test]
+    fn test_encoder_round_trip() {
+        let data = b"hello hello hello hello hello".repeat(100);
+        for codec in [Compression::Gzip, Compression::Zstd] {
+            let mut encoder = codec.encoder().expect("encoder");
+            let mut compressed = Vec::new();
+            for piece in data.chunks(100) {
+                compressed.extend(encoder.write(piece).expect("write"));
+            }
+            compressed.extend(encoder.finish().expect("finish"));
+            assert_eq!(codec.decompress(&compressed).expect("decompress"), data);
+        }
+    }
+
+    #[test]
+    fn test_from_encoding() {
+        assert_eq!(Compression::from_encoding("gzip"), Some(Compression::Gzip));
+        assert_eq!(Compression::from_encoding(" GZIP"), Some(Compression::Gzip));
+        assert_eq!(Compression::from_encoding("zstd"), Some(Compression::Zstd));
+        assert_eq!(Compression::from_encoding("br"), None);
+        assert_eq!(Compression::Zstd.encoding(), "zstd");
+    }
+}
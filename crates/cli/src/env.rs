@@ -0,0 +1,126 @@
+//! Environment variable defaults
+//!
+//! `RC_*` variables let pipelines set output and connection defaults once.
+//! Command-line flags take precedence over these, and these take precedence
+//! over values stored in the config file.
+
+use rc_core::Alias;
+
+/// Output format: `json` or `human`
+pub const RC_OUTPUT: &str = "RC_OUTPUT";
+
+/// Disable progress bars when truthy
+pub const RC_NO_PROGRESS: &str = "RC_NO_PROGRESS";
+
+/// Allow insecure TLS connections when truthy
+pub const RC_INSECURE: &str = "RC_INSECURE";
+
+/// Path to a PEM CA bundle used to verify the server
+pub const RC_CA_BUNDLE: &str = "RC_CA_BUNDLE";
+
+/// Defaults read from `RC_*` environment variables
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvDefaults {
+    /// Emit JSON output (`None` when unset)
+    pub json: Option<bool>,
+    /// Disable progress bars (`None` when unset)
+    pub no_progress: Option<bool>,
+    /// Override the alias `insecure` setting
+    pub insecure: Option<bool>,
+    /// Override the alias CA bundle
+    pub ca_bundle: Option<String>,
+}
+
+impl EnvDefaults {
+    /// Read defaults from the process environment
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// Read defaults using a custom variable lookup
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let json = lookup(RC_OUTPUT).map(|v| v.trim().eq_ignore_ascii_case("json"));
+        let no_progress = lookup(RC_NO_PROGRESS).and_then(|v| parse_bool(&v));
+        let insecure = lookup(RC_INSECURE).and_then(|v| parse_bool(&v));
+        let ca_bundle = lookup(RC_CA_BUNDLE).filter(|v| !v.trim().is_empty());
+
+        Self {
+            json,
+            no_progress,
+            insecure,
+            ca_bundle,
+        }
+    }
+
+    /// Apply connection overrides to an alias loaded from the config file
+    pub fn apply(&self, alias: &mut Alias) {
+        if let Some(insecure) = self.insecure {
+            alias.insecure = insecure;
+        }
+        if let Some(ca_bundle) = &self.ca_bundle {
+            alias.ca_bundle = Some(ca_bundle.clone());
+        }
+    }
+}
+
+/// Parse a boolean-ish environment value ("1", "true", "yes", "on" and their negatives)
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" | "" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn defaults_from(vars: &[(&str, &str)]) -> EnvDefaults {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        EnvDefaults::from_lookup(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn test_empty_environment() {
+        assert_eq!(defaults_from(&[]), EnvDefaults::default());
+    }
+
+    #[test]
+    fn test_output_and_progress() {
+        let env = defaults_from(&[(RC_OUTPUT, "JSON"), (RC_NO_PROGRESS, "1")]);
+        assert_eq!(env.json, Some(true));
+        assert_eq!(env.no_progress, Some(true));
+
+        // Explicit negatives are kept so they can override the config file
+        let env = defaults_from(&[(RC_OUTPUT, "human"), (RC_NO_PROGRESS, "off")]);
+        assert_eq!(env.json, Some(false));
+        assert_eq!(env.no_progress, Some(false));
+    }
+
+    #[test]
+    fn test_connection_overrides() {
+        let env = defaults_from(&[(RC_INSECURE, "yes"), (RC_CA_BUNDLE, "/etc/ca.pem")]);
+        let mut alias = Alias::new("local", "https://localhost:9000", "a", "b");
+        env.apply(&mut alias);
+        assert!(alias.insecure);
+        assert_eq!(alias.ca_bundle.as_deref(), Some("/etc/ca.pem"));
+
+        // Unset variables leave the configured values alone
+        let mut alias = Alias::new("local", "https://localhost:9000", "a", "b");
+        alias.insecure = true;
+        defaults_from(&[]).apply(&mut alias);
+        assert!(alias.insecure);
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        assert_eq!(parse_bool("TRUE"), Some(true));
+        assert_eq!(parse_bool("0"), Some(false));
+        assert_eq!(parse_bool("maybe"), None);
+    }
+}
@@ -37,6 +37,9 @@ pub enum ExitCode {
     /// Backend does not support this feature
     UnsupportedFeature = 7,
 
+    /// Backend answered that the operation is not implemented (HTTP 501)
+    NotImplemented = 8,
+
     /// Operation was interrupted (e.g., Ctrl+C)
     Interrupted = 130,
 }
@@ -61,6 +64,7 @@ impl ExitCode {
             5 => Some(Self::NotFound),
             6 => Some(Self::Conflict),
             7 => Some(Self::UnsupportedFeature),
+            8 => Some(Self::NotImplemented),
             130 => Some(Self::Interrupted),
             _ => None,
         }
@@ -77,6 +81,7 @@ impl ExitCode {
             Self::NotFound => "Resource not found",
             Self::Conflict => "Conflict or precondition failure",
             Self::UnsupportedFeature => "Feature not supported by backend",
+            Self::NotImplemented => "Operation not implemented by backend",
             Self::Interrupted => "Operation interrupted",
         }
     }
@@ -88,6 +93,12 @@ impl From<ExitCode> for i32 {
     }
 }
 
+impl From<&rc_core::Error> for ExitCode {
+    fn from(err: &rc_core::Error) -> Self {
+        Self::from_i32(err.exit_code()).unwrap_or(Self::GeneralError)
+    }
+}
+
 impl std::fmt::Display for ExitCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.description(), self.as_i32())
@@ -108,6 +119,7 @@ mod tests {
         assert_eq!(ExitCode::NotFound.as_i32(), 5);
         assert_eq!(ExitCode::Conflict.as_i32(), 6);
         assert_eq!(ExitCode::UnsupportedFeature.as_i32(), 7);
+        assert_eq!(ExitCode::NotImplemented.as_i32(), 8);
         assert_eq!(ExitCode::Interrupted.as_i32(), 130);
     }
 
@@ -121,6 +133,7 @@ mod tests {
         assert_eq!(ExitCode::from_i32(5), Some(ExitCode::NotFound));
         assert_eq!(ExitCode::from_i32(6), Some(ExitCode::Conflict));
         assert_eq!(ExitCode::from_i32(7), Some(ExitCode::UnsupportedFeature));
+        assert_eq!(ExitCode::from_i32(8), Some(ExitCode::NotImplemented));
         assert_eq!(ExitCode::from_i32(130), Some(ExitCode::Interrupted));
         assert_eq!(ExitCode::from_i32(99), None);
     }
@@ -134,6 +147,15 @@ mod tests {
         assert_eq!(code, 5);
     }
 
+    #[test]
+    fn test_exit_code_from_core_error() {
+        let err = rc_core::Error::NotImplemented("heal".into());
+        assert_eq!(ExitCode::from(&err), ExitCode::NotImplemented);
+
+        let err = rc_core::Error::General("boom".into());
+        assert_eq!(ExitCode::from(&err), ExitCode::GeneralError);
+    }
+
     #[test]
     fn test_exit_code_display() {
         let display = format!("{}", ExitCode::Success);
@@ -0,0 +1,253 @@
+//! Include/exclude and modification-time filters
+//!
+//! `--include` and `--exclude` globs are matched against the path relative to
+//! the transfer root, using `/` as the separator. `*` also matches across `/`,
+//! so `*.log` selects log files at any depth.
+//!
+//! `--after` and `--before` bound an object's last-modified time. Each accepts
+//! an RFC3339 timestamp, a date or date-time (taken as UTC), or a duration
+//! before now such as `7d`.
+
+use std::time::Duration;
+
+/// Glob allowlist and denylist applied to relative keys
+#[derive(Debug, Clone, Default)]
+pub struct PathFilter {
+    include: Vec<glob::Pattern>,
+    exclude: Vec<glob::Pattern>,
+}
+
+impl PathFilter {
+    /// Compile include and exclude patterns
+    pub fn new(include: &[String], exclude: &[String]) -> Result<Self, String> {
+        let compile = |kind: &str, patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| {
+                    glob::Pattern::new(p).map_err(|e| format!("Invalid {kind} pattern '{p}': {e}"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(Self {
+            include: compile("include", include)?,
+            exclude: compile("exclude", exclude)?,
+        })
+    }
+
+    /// Whether a relative path should be transferred
+    ///
+    /// Excludes win over includes; when any include is given, only matching
+    /// paths pass.
+    pub fn matches(&self, relative_path: &str) -> bool {
+        let path = relative_path.replace('\\', "/");
+        let path = path.trim_start_matches('/');
+
+        if self.exclude.iter().any(|p| p.matches(path)) {
+            return false;
+        }
+        self.include.is_empty() || self.include.iter().any(|p| p.matches(path))
+    }
+}
+
+/// Half-open `[after, before)` window on last-modified time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeFilter {
+    after: Option<jiff::Timestamp>,
+    before: Option<jiff::Timestamp>,
+}
+
+impl TimeFilter {
+    /// Parse `--after` and `--before` values relative to `now`
+    pub fn new(
+        after: Option<&str>,
+        before: Option<&str>,
+        now: jiff::Timestamp,
+    ) -> Result<Self, String> {
+        let after = after.map(|s| parse_time_bound(s, now)).transpose()?;
+        let before = before.map(|s| parse_time_bound(s, now)).transpose()?;
+        if let (Some(a), Some(b)) = (after, before)
+            && a >= b
+        {
+            return Err(format!("--after ({a}) must be earlier than --before ({b})"));
+        }
+        Ok(Self { after, before })
+    }
+
+    /// Whether a last-modified time falls inside the window
+    ///
+    /// Entries without a timestamp, such as common prefixes, always match.
+    pub fn matches(&self, modified: Option<jiff::Timestamp>) -> bool {
+        let Some(modified) = modified else {
+            return true;
+        };
+        self.after.is_none_or(|a| modified >= a) && self.before.is_none_or(|b| modified < b)
+    }
+}
+
+/// Parse a timestamp, date, date-time (UTC) or duration before `now`
+pub fn parse_time_bound(s: &str, now: jiff::Timestamp) -> Result<jiff::Timestamp, String> {
+    let s = s.trim();
+    if let Some(ts) = parse_absolute_time(s)? {
+        return Ok(ts);
+    }
+    parse_duration_ago(s, now).map_err(|e| {
+        format!("Invalid time '{s}': expected RFC3339, YYYY-MM-DD or a duration like 7d ({e})")
+    })
+}
+
+/// Parse a timestamp, date or date-time (UTC); `None` if `s` is none of these
+pub fn parse_absolute_time(s: &str) -> Result<Option<jiff::Timestamp>, String> {
+    if let Ok(ts) = s.parse::<jiff::Timestamp>() {
+        return Ok(Some(ts));
+    }
+    let utc = |dt: jiff::civil::DateTime| {
+        dt.to_zoned(jiff::tz::TimeZone::UTC)
+            .map(|z| Some(z.timestamp()))
+            .map_err(|e| format!("Invalid time '{s}': {e}"))
+    };
+    if let Ok(dt) = s.parse::<jiff::civil::DateTime>() {
+        return utc(dt);
+    }
+    if let Ok(date) = s.parse::<jiff::civil::Date>() {
+        return utc(date.to_datetime(jiff::civil::Time::midnight()));
+    }
+    Ok(None)
+}
+
+/// Parse a duration like `90` (seconds), `30s`, `15m`, `12h`, `7d` or `2w`
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("Duration cannot be empty".to_string());
+    }
+
+    let (num_str, suffix) = if s.ends_with(|c: char| c.is_ascii_alphabetic()) {
+        let idx = s.len() - 1;
+        (&s[..idx], &s[idx..])
+    } else {
+        (s, "s") // Default to seconds
+    };
+
+    let num: u64 = num_str
+        .parse()
+        .map_err(|_| format!("Invalid duration number: {num_str}"))?;
+
+    let unit = match suffix.to_lowercase().as_str() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => return Err(format!("Unknown duration suffix: {suffix}")),
+    };
+
+    num.checked_mul(unit)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("Duration '{s}' is too long"))
+}
+
+/// Parse duration string and return timestamp that far in the past
+pub fn parse_duration_ago(s: &str, now: jiff::Timestamp) -> Result<jiff::Timestamp, String> {
+    now.checked_sub(parse_duration(s)?)
+        .map_err(|e| format!("Duration overflow: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> PathFilter {
+        let to_vec = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        PathFilter::new(&to_vec(include), &to_vec(exclude)).expect("valid patterns")
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        assert!(PathFilter::default().matches("any/key.txt"));
+    }
+
+    #[test]
+    fn test_include_acts_as_allowlist() {
+        let f = filter(&["*.log"], &[]);
+        assert!(f.matches("app.log"));
+        assert!(f.matches("nested/dir/app.log"));
+        assert!(!f.matches("app.txt"));
+    }
+
+    #[test]
+    fn test_exclude_takes_precedence() {
+        let f = filter(&["*.log"], &["tmp/*"]);
+        assert!(f.matches("var/app.log"));
+        assert!(!f.matches("tmp/app.log"));
+        assert!(!f.matches("tmp/deep/app.log"));
+
+        let f = filter(&[], &["*.tmp"]);
+        assert!(f.matches("keep.txt"));
+        assert!(!f.matches("dir/skip.tmp"));
+    }
+
+    #[test]
+    fn test_windows_separators_are_normalized() {
+        let f = filter(&[], &["tmp/*"]);
+        assert!(!f.matches("tmp\\file.txt"));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let err = PathFilter::new(&["[".to_string()], &[]).unwrap_err();
+        assert!(err.contains("include"));
+    }
+
+    #[test]
+    fn test_parse_time_bound_formats() {
+        let now: jiff::Timestamp = "2026-03-10T12:00:00Z".parse().unwrap();
+        let expect = |s: &str| parse_time_bound(s, now).unwrap().to_string();
+
+        assert_eq!(expect("2026-03-01T08:30:00+02:00"), "2026-03-01T06:30:00Z");
+        assert_eq!(expect("2026-03-01T08:30:00"), "2026-03-01T08:30:00Z");
+        assert_eq!(expect("2026-03-01"), "2026-03-01T00:00:00Z");
+        assert_eq!(expect("7d"), "2026-03-03T12:00:00Z");
+        assert_eq!(expect("90m"), "2026-03-10T10:30:00Z");
+        assert!(parse_time_bound("yesterday", now).is_err());
+        assert!(parse_time_bound("7y", now).is_err());
+    }
+
+    #[test]
+    fn test_time_filter_window() {
+        let now: jiff::Timestamp = "2026-03-10T12:00:00Z".parse().unwrap();
+        let ts = |s: &str| Some(s.parse::<jiff::Timestamp>().unwrap());
+
+        let f = TimeFilter::new(Some("2026-03-01"), Some("2026-03-02"), now).unwrap();
+        assert!(f.matches(ts("2026-03-01T00:00:00Z")));
+        assert!(f.matches(ts("2026-03-01T23:59:59Z")));
+        assert!(!f.matches(ts("2026-03-02T00:00:00Z")));
+        assert!(!f.matches(ts("2026-02-28T23:59:59Z")));
+        assert!(f.matches(None));
+
+        assert!(TimeFilter::default().matches(ts("1970-01-01T00:00:00Z")));
+        assert!(TimeFilter::new(Some("1d"), Some("7d"), now).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("15m").unwrap(), Duration::from_secs(900));
+        assert_eq!(parse_duration("12h").unwrap(), Duration::from_secs(43_200));
+        assert_eq!(
+            parse_duration("2w").unwrap(),
+            Duration::from_secs(1_209_600)
+        );
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("-5m").is_err());
+        assert!(parse_duration("xs").is_err());
+        assert!(parse_duration("99999999999999999w").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_ago_out_of_range() {
+        let now: jiff::Timestamp = "2026-03-10T12:00:00Z".parse().unwrap();
+        assert!(parse_duration_ago("99999999w", now).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Ctrl-C handling
+//!
+//! Ctrl-C normally cancels the running command: [`cancelled`] resolves and the
+//! command dispatcher stops it and reports the interruption like any other
+//! error, so `--json` still gets an error object. While a [`Guard`] is alive
+//! the first Ctrl-C is delivered to the guard instead, so the code holding it
+//! can clean up (for example abort or save a multipart upload) before
+//! returning. A further Ctrl-C cancels the command, and one after that exits
+//! at once in case the command cannot be stopped.
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::watch;
+
+use crate::exit_code::ExitCode;
+
+/// Number of live guards
+static GUARDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Set to `true` by the first Ctrl-C that a guard receives
+static INTERRUPTED: OnceLock<watch::Sender<bool>> = OnceLock::new();
+
+/// Set to `true` once the running command should stop
+static CANCELLED: OnceLock<watch::Sender<bool>> = OnceLock::new();
+
+/// Whether the Ctrl-C listener has been spawned
+static LISTENER: OnceLock<()> = OnceLock::new();
+
+/// What a Ctrl-C press does
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    /// Tell the guards, which clean up and return
+    Interrupt,
+    /// Stop the running command
+    Cancel,
+    /// Exit immediately
+    Exit,
+}
+
+/// Decide what a Ctrl-C does given the live guards and earlier presses
+fn action(guards: usize, interrupted: bool, cancelled: bool) -> Action {
+    if cancelled {
+        Action::Exit
+    } else if interrupted || guards == 0 {
+        Action::Cancel
+    } else {
+        Action::Interrupt
+    }
+}
+
+fn interrupted_flag() -> &'static watch::Sender<bool> {
+    INTERRUPTED.get_or_init(|| watch::channel(false).0)
+}
+
+fn cancelled_flag() -> &'static watch::Sender<bool> {
+    CANCELLED.get_or_init(|| watch::channel(false).0)
+}
+
+/// Install the Ctrl-C listener on first use
+fn listen() {
+    LISTENER.get_or_init(|| {
+        tokio::spawn(async {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                let state = action(
+                    GUARDS.load(Ordering::SeqCst),
+                    *interrupted_flag().borrow(),
+                    *cancelled_flag().borrow(),
+                );
+                match state {
+                    Action::Interrupt => {
+                        interrupted_flag().send_replace(true);
+                    }
+                    Action::Cancel => {
+                        cancelled_flag().send_replace(true);
+                    }
+                    Action::Exit => std::process::exit(ExitCode::Interrupted.as_i32()),
+                }
+            }
+        });
+    });
+}
+
+/// Wait until Ctrl-C cancels the running command
+///
+/// The first call installs the Ctrl-C listener.
+pub async fn cancelled() {
+    listen();
+    let mut rx = cancelled_flag().subscribe();
+    // The sender lives in a static, so this only returns once it is set
+    let _ = rx.wait_for(|cancelled| *cancelled).await;
+}
+
+/// Receives Ctrl-C for as long as it is alive
+pub struct Guard {
+    rx: watch::Receiver<bool>,
+}
+
+impl Guard {
+    /// Start receiving Ctrl-C
+    pub fn new() -> Self {
+        listen();
+        GUARDS.fetch_add(1, Ordering::SeqCst);
+        Self {
+            rx: interrupted_flag().subscribe(),
+        }
+    }
+
+    /// Wait until Ctrl-C is pressed
+    pub async fn interrupted(&mut self) {
+        // The sender lives in a static, so this only returns once it is set
+        let _ = self.rx.wait_for(|interrupted| *interrupted).await;
+    }
+}
+
+impl Default for Guard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        GUARDS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_guard_counts_holders() {
+        let before = GUARDS.load(Ordering::SeqCst);
+        let guard = Guard::new();
+        assert_eq!(GUARDS.load(Ordering::SeqCst), before + 1);
+        assert!(!*guard.rx.borrow());
+        drop(guard);
+        assert_eq!(GUARDS.load(Ordering::SeqCst), before);
+    }
+
+    #[test]
+    fn test_ctrl_c_escalates() {
+        // Without a guard the command is cancelled straight away
+        assert_eq!(action(0, false, false), Action::Cancel);
+
+        // A guard gets the first press, the next one cancels
+        assert_eq!(action(1, false, false), Action::Interrupt);
+        assert_eq!(action(1, true, false), Action::Cancel);
+
+        // Once cancelled, another press exits
+        assert_eq!(action(0, false, true), Action::Exit);
+        assert_eq!(action(1, true, true), Action::Exit);
+    }
+}
@@ -3,5 +3,9 @@
 //! This module exports the CLI components for use in integration tests.
 
 pub mod commands;
+pub mod compression;
+pub mod env;
 pub mod exit_code;
+pub mod filter;
+pub mod interrupt;
 pub mod output;
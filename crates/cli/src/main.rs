@@ -7,20 +7,39 @@ use clap::Parser;
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 
 mod commands;
+mod compression;
+mod env;
 mod exit_code;
+mod filter;
+mod interrupt;
 mod output;
 
 use commands::Cli;
 
+/// Log directives added by `--debug`; `RUST_LOG` directives still take precedence
+const DEBUG_DIRECTIVES: &str = "rc=debug,rustfs_cli=debug,rc_core=debug,rc_s3=debug";
+
+/// Log directive added by `--trace-requests`
+const TRACE_REQUESTS_DIRECTIVE: &str = "rc_s3::wire=trace";
+
 #[tokio::main]
 async fn main() {
-    // Initialize tracing subscriber for logging
+    let cli = Cli::parse();
+
+    // Initialize tracing subscriber for logging; logs go to stderr so they
+    // never mix with command output
+    let mut directives = std::env::var("RUST_LOG").unwrap_or_default();
+    if cli.debug {
+        directives = format!("{DEBUG_DIRECTIVES},{directives}");
+    }
+    if cli.trace_requests {
+        directives = format!("{TRACE_REQUESTS_DIRECTIVE},{directives}");
+    }
     tracing_subscriber::registry()
-        .with(fmt::layer())
-        .with(EnvFilter::from_default_env())
+        .with(fmt::layer().with_writer(std::io::stderr))
+        .with(EnvFilter::new(directives))
         .init();
 
-    let cli = Cli::parse();
     let exit_code = commands::execute(cli).await;
 
     std::process::exit(exit_code.as_i32());
@@ -1,12 +1,18 @@
 //! Output formatter for human-readable and JSON output
 //!
 //! Ensures consistent output formatting across all commands.
-//! JSON output follows the schema defined in schemas/output_v1.json.
+//! JSON output follows the schema defined in schemas/output_v2.json.
+
+use std::sync::Mutex;
 
 use console::Style;
 use serde::Serialize;
 
 use super::OutputConfig;
+use crate::exit_code::ExitCode;
+
+/// Error messages reported in JSON mode, emitted as one envelope on exit
+static JSON_ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
 
 /// Color theme for styled output (exa/eza inspired)
 #[derive(Debug, Clone)]
@@ -115,6 +121,11 @@ impl Formatter {
         &self.theme
     }
 
+    /// Get the output configuration this formatter was built from
+    pub fn output_config(&self) -> &OutputConfig {
+        &self.config
+    }
+
     // ========== Style helper methods ==========
 
     /// Style a directory name (blue + bold)
@@ -196,16 +207,15 @@ impl Formatter {
 
     /// Output an error message
     ///
-    /// Errors are always printed, even in quiet mode.
+    /// Errors are always printed, even in quiet mode. In JSON mode they are
+    /// collected and written to stderr as a single error envelope once the
+    /// command's exit code is known (see [`emit_json_errors`]).
     pub fn error(&self, message: &str) {
         if self.config.json {
-            let error = serde_json::json!({
-                "error": message
-            });
-            eprintln!(
-                "{}",
-                serde_json::to_string_pretty(&error).unwrap_or_else(|_| message.to_string())
-            );
+            JSON_ERRORS
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(message.to_string());
         } else {
             let cross = self.theme.error.apply_to("✗");
             eprintln!("{cross} {message}");
@@ -232,6 +242,28 @@ impl Formatter {
         }
     }
 
+    /// Output values as JSON lines (one compact object per line)
+    ///
+    /// Stdout is flushed after the batch so consumers see each page as it arrives.
+    pub fn json_lines<T: Serialize>(&self, values: &[T]) {
+        let stdout = std::io::stdout();
+        let mut out = stdout.lock();
+        if let Err(e) = write_json_lines(&mut out, values) {
+            eprintln!("Error writing output: {e}");
+        }
+    }
+
+    /// Output bare lines for piping into other tools
+    ///
+    /// Like [`Formatter::json_lines`] this ignores quiet mode and flushes after the batch.
+    pub fn plain_lines(&self, lines: &[String]) {
+        let stdout = std::io::stdout();
+        let mut out = stdout.lock();
+        if let Err(e) = write_plain_lines(&mut out, lines) {
+            eprintln!("Error writing output: {e}");
+        }
+    }
+
     /// Print a line of text (respects quiet mode)
     pub fn println(&self, message: &str) {
         if self.config.quiet {
@@ -241,16 +273,128 @@ impl Formatter {
     }
 }
 
+/// Write each value as a single-line JSON object and flush
+fn write_json_lines<W: std::io::Write, T: Serialize>(
+    out: &mut W,
+    values: &[T],
+) -> std::io::Result<()> {
+    for value in values {
+        serde_json::to_writer(&mut *out, value)?;
+        out.write_all(b"\n")?;
+    }
+    out.flush()
+}
+
+/// Write each line followed by a newline and flush
+fn write_plain_lines<W: std::io::Write>(out: &mut W, lines: &[String]) -> std::io::Result<()> {
+    for line in lines {
+        out.write_all(line.as_bytes())?;
+        out.write_all(b"\n")?;
+    }
+    out.flush()
+}
+
 impl Default for Formatter {
     fn default() -> Self {
         Self::new(OutputConfig::default())
     }
 }
 
+/// Stable machine-readable code for an exit code
+pub fn error_code(exit_code: ExitCode) -> &'static str {
+    match exit_code {
+        ExitCode::Success | ExitCode::GeneralError => "general_error",
+        ExitCode::UsageError => "usage_error",
+        ExitCode::NetworkError => "network_error",
+        ExitCode::AuthError => "auth_error",
+        ExitCode::NotFound => "not_found",
+        ExitCode::Conflict => "conflict",
+        ExitCode::UnsupportedFeature => "unsupported_feature",
+        ExitCode::NotImplemented => "not_implemented",
+        ExitCode::Interrupted => "interrupted",
+    }
+}
+
+/// Build the `{"error": {...}}` envelope for the reported messages
+///
+/// The last message is the headline; when several were reported, all of them
+/// are listed in `details` in the order they occurred.
+fn error_envelope(messages: &[String], exit_code: ExitCode) -> serde_json::Value {
+    let message = messages
+        .last()
+        .map(String::as_str)
+        .unwrap_or_else(|| exit_code.description());
+    let mut error = serde_json::json!({
+        "code": error_code(exit_code),
+        "message": message,
+        "exit_code": exit_code.as_i32(),
+    });
+    if messages.len() > 1 {
+        error["details"] = serde_json::json!(messages);
+    }
+    serde_json::json!({ "error": error })
+}
+
+/// Write the JSON error envelope for errors reported during the command
+///
+/// Does nothing when no error was reported, so commands that signal a result
+/// through the exit code alone (such as `diff` finding differences) keep a
+/// clean stderr.
+pub fn emit_json_errors(exit_code: ExitCode) {
+    let messages = std::mem::take(&mut *JSON_ERRORS.lock().unwrap_or_else(|e| e.into_inner()));
+    if messages.is_empty() {
+        return;
+    }
+
+    let envelope = error_envelope(&messages, exit_code);
+    match serde_json::to_string_pretty(&envelope) {
+        Ok(json) => eprintln!("{json}"),
+        Err(_) => eprintln!("{}", messages.join("\n")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_envelope_single_message() {
+        let envelope = error_envelope(&["Bucket not found".to_string()], ExitCode::NotFound);
+        assert_eq!(
+            envelope,
+            serde_json::json!({
+                "error": {
+                    "code": "not_found",
+                    "message": "Bucket not found",
+                    "exit_code": 5,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_envelope_multiple_messages() {
+        let messages = vec!["a failed".to_string(), "2 transfers failed".to_string()];
+        let envelope = error_envelope(&messages, ExitCode::GeneralError);
+        assert_eq!(envelope["error"]["code"], "general_error");
+        assert_eq!(envelope["error"]["message"], "2 transfers failed");
+        assert_eq!(envelope["error"]["details"][0], "a failed");
+    }
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        assert_eq!(error_code(ExitCode::UsageError), "usage_error");
+        assert_eq!(error_code(ExitCode::NetworkError), "network_error");
+        assert_eq!(error_code(ExitCode::AuthError), "auth_error");
+        assert_eq!(error_code(ExitCode::Conflict), "conflict");
+        assert_eq!(
+            error_code(ExitCode::UnsupportedFeature),
+            "unsupported_feature"
+        );
+        assert_eq!(error_code(ExitCode::NotImplemented), "not_implemented");
+        assert_eq!(error_code(ExitCode::Interrupted), "interrupted");
+    }
+
     #[test]
     fn test_formatter_default() {
         let formatter = Formatter::default();
@@ -279,4 +423,30 @@ mod tests {
         let formatter = Formatter::new(config);
         assert!(!formatter.colors_enabled());
     }
+
+    #[test]
+    fn test_write_json_lines() {
+        let mut buf = Vec::new();
+        let values = vec![
+            serde_json::json!({"key": "a.txt"}),
+            serde_json::json!({"key": "b.txt"}),
+        ];
+        write_json_lines(&mut buf, &values).expect("write json lines");
+
+        let text = String::from_utf8(buf).expect("utf8 output");
+        assert_eq!(text, "{\"key\":\"a.txt\"}\n{\"key\":\"b.txt\"}\n");
+    }
+
+    #[test]
+    fn test_write_plain_lines() {
+        let mut buf = Vec::new();
+        let lines = vec![
+            "local/bucket/a.txt".to_string(),
+            "local/bucket/b.txt".to_string(),
+        ];
+        write_plain_lines(&mut buf, &lines).expect("write plain lines");
+
+        let text = String::from_utf8(buf).expect("utf8 output");
+        assert_eq!(text, "local/bucket/a.txt\nlocal/bucket/b.txt\n");
+    }
 }
@@ -5,6 +5,7 @@
 
 mod formatter;
 mod progress;
+pub mod table;
 
 // These exports will be used in Phase 2+ when commands are implemented
 #[allow(unused_imports)]
@@ -12,8 +13,52 @@ pub use formatter::Formatter;
 #[allow(unused_imports)]
 pub use formatter::Theme;
 #[allow(unused_imports)]
+pub use formatter::{emit_json_errors, error_code};
+#[allow(unused_imports)]
 pub use progress::ProgressBar;
 
+/// Format a byte count with binary units and two decimals (`1.50 GiB`)
+pub fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+    const TB: u64 = GB * 1024;
+    const PB: u64 = TB * 1024;
+
+    if bytes >= PB {
+        format!("{:.2} PiB", bytes as f64 / PB as f64)
+    } else if bytes >= TB {
+        format!("{:.2} TiB", bytes as f64 / TB as f64)
+    } else if bytes >= GB {
+        format!("{:.2} GiB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.2} MiB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.2} KiB", bytes as f64 / KB as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Format a duration in seconds with its two largest units (`42s`, `2m 5s`,
+/// `3h 0m`, `1d 4h`)
+pub fn format_duration(seconds: u64) -> String {
+    let days = seconds / 86400;
+    let hours = (seconds % 86400) / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let secs = seconds % 60;
+
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {secs}s")
+    } else {
+        format!("{secs}s")
+    }
+}
+
 /// Output configuration derived from CLI flags
 #[derive(Debug, Clone, Default)]
 #[allow(dead_code)]
@@ -27,3 +72,30 @@ pub struct OutputConfig {
     /// Suppress non-error output
     pub quiet: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1024), "1.00 KiB");
+        assert_eq!(format_bytes(1024 * 1024), "1.00 MiB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024), "1.00 GiB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024 * 1024), "1.00 TiB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024 * 1024 * 1024), "1.00 PiB");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(125), "2m 5s");
+        assert_eq!(format_duration(3600), "1h 0m");
+        assert_eq!(format_duration(7260), "2h 1m");
+        assert_eq!(format_duration(86400), "1d 0h");
+        assert_eq!(format_duration(90061), "1d 1h");
+    }
+}
@@ -36,6 +36,40 @@ impl ProgressBar {
         Self { config, bar }
     }
 
+    /// Create an aggregate progress display for a multi-file transfer
+    ///
+    /// With a known `total` in bytes, shows a percentage and an ETA based on
+    /// observed throughput. Without one (remote listings are paged, so the
+    /// size is only known at the end), shows bytes transferred and the rate.
+    /// The message is the object currently being transferred.
+    pub fn transfer(config: OutputConfig, total: Option<u64>) -> Self {
+        let bar = if config.quiet || config.json || config.no_progress {
+            None
+        } else {
+            let (bar, template) = match total {
+                Some(total) => (
+                    indicatif::ProgressBar::new(total),
+                    "{spinner:.green} [{bar:30.cyan/blue}] {percent:>3}% {bytes}/{total_bytes} \
+                     {binary_bytes_per_sec} ETA {eta} {wide_msg}",
+                ),
+                None => (
+                    indicatif::ProgressBar::new_spinner(),
+                    "{spinner:.green} {bytes} transferred {binary_bytes_per_sec} {wide_msg}",
+                ),
+            };
+            bar.set_style(
+                indicatif::ProgressStyle::default_bar()
+                    .template(template)
+                    .expect("valid template")
+                    .progress_chars("#>-"),
+            );
+            bar.enable_steady_tick(std::time::Duration::from_millis(100));
+            Some(bar)
+        };
+
+        Self { config, bar }
+    }
+
     /// Create a spinner for indeterminate progress
     pub fn spinner(config: OutputConfig, message: &str) -> Self {
         let bar = if config.quiet || config.json || config.no_progress {
@@ -130,6 +164,16 @@ mod tests {
         assert!(!bar.is_visible());
     }
 
+    #[test]
+    fn test_transfer_progress_visibility() {
+        let config = OutputConfig {
+            quiet: true,
+            ..Default::default()
+        };
+        assert!(!ProgressBar::transfer(config, Some(100)).is_visible());
+        assert!(ProgressBar::transfer(OutputConfig::default(), None).is_visible());
+    }
+
     #[test]
     fn test_progress_bar_normal() {
         let config = OutputConfig::default();
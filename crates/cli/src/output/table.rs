@@ -0,0 +1,145 @@
+//! Column table rendering
+//!
+//! Listings describe their columns once and let the user pick them with
+//! `--wide` or `--columns`; the renderer pads every column but the last to
+//! its widest cell.
+
+/// A column a listing can show
+pub struct Column<T> {
+    /// Name used by `--columns`; the header is its uppercase form
+    pub name: &'static str,
+    /// Only shown by default with `--wide`
+    pub wide: bool,
+    /// Cell text for a row
+    pub value: fn(&T) -> String,
+}
+
+/// Pick the columns to show
+///
+/// An explicit `requested` list is used as given (in that order) after
+/// checking every name against `columns`; otherwise the default columns are
+/// shown, plus the wide ones when `wide` is set.
+pub fn select_columns<'a, T>(
+    columns: &'a [Column<T>],
+    wide: bool,
+    requested: Option<&[String]>,
+) -> Result<Vec<&'a Column<T>>, String> {
+    let Some(requested) = requested else {
+        return Ok(columns.iter().filter(|c| wide || !c.wide).collect());
+    };
+
+    let mut selected = Vec::with_capacity(requested.len());
+    for name in requested {
+        let name = name.trim().to_ascii_lowercase();
+        match columns.iter().find(|c| c.name == name) {
+            Some(column) => selected.push(column),
+            None => {
+                let known: Vec<_> = columns.iter().map(|c| c.name).collect();
+                return Err(format!(
+                    "Unknown column '{name}' (available: {})",
+                    known.join(", ")
+                ));
+            }
+        }
+    }
+    if selected.is_empty() {
+        return Err("--columns needs at least one column".to_string());
+    }
+    Ok(selected)
+}
+
+/// Render a header line followed by one line per row
+pub fn render<'r, T: 'r>(
+    columns: &[&Column<T>],
+    rows: impl IntoIterator<Item = &'r T>,
+) -> Vec<String> {
+    let header: Vec<String> = columns.iter().map(|c| c.name.to_uppercase()).collect();
+    let cells: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(|row| columns.iter().map(|c| (c.value)(row)).collect())
+        .collect();
+
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|i| {
+            std::iter::once(&header)
+                .chain(&cells)
+                .map(|line| line[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    std::iter::once(&header)
+        .chain(&cells)
+        .map(|line| {
+            let last = line.len().saturating_sub(1);
+            line.iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    if i == last {
+                        cell.clone()
+                    } else {
+                        format!("{cell:<width$}", width = widths[i])
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns() -> Vec<Column<(&'static str, u32)>> {
+        vec![
+            Column {
+                name: "name",
+                wide: false,
+                value: |r| r.0.to_string(),
+            },
+            Column {
+                name: "count",
+                wide: true,
+                value: |r| r.1.to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let columns = columns();
+        let names =
+            |selected: Vec<&Column<_>>| -> Vec<&str> { selected.iter().map(|c| c.name).collect() };
+
+        assert_eq!(
+            names(select_columns(&columns, false, None).unwrap()),
+            ["name"]
+        );
+        assert_eq!(
+            names(select_columns(&columns, true, None).unwrap()),
+            ["name", "count"]
+        );
+
+        let requested = vec!["COUNT".to_string(), "name".to_string()];
+        assert_eq!(
+            names(select_columns(&columns, false, Some(&requested)).unwrap()),
+            ["count", "name"]
+        );
+
+        let err = select_columns(&columns, false, Some(&["size".to_string()])).err();
+        assert_eq!(
+            err.as_deref(),
+            Some("Unknown column 'size' (available: name, count)")
+        );
+    }
+
+    #[test]
+    fn test_render_pads_all_but_last_column() {
+        let columns = columns();
+        let selected = select_columns(&columns, true, None).unwrap();
+        let lines = render(&selected, &[("a", 1), ("longer", 20)]);
+        assert_eq!(lines, ["NAME    COUNT", "a       1", "longer  20"]);
+    }
+}
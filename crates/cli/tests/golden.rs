@@ -281,8 +281,13 @@ mod s3_tests {
         let json: serde_json::Value =
             serde_json::from_str(&stdout).expect("Output should be valid JSON");
 
-        assert_eq!(json["success"], true);
-        assert!(json["bucket"].as_str().unwrap().contains("test-bucket"));
+        assert_eq!(json["results"][0]["status"], "success");
+        assert!(
+            json["results"][0]["bucket"]
+                .as_str()
+                .unwrap()
+                .contains("test-bucket")
+        );
 
         // Cleanup: remove the bucket
         Command::new(rc_binary())
@@ -323,7 +328,7 @@ mod s3_tests {
         let json: serde_json::Value =
             serde_json::from_str(&stdout).expect("Output should be valid JSON");
 
-        assert_eq!(json["success"], true);
+        assert_eq!(json["results"][0]["status"], "success");
 
         drop(temp_dir);
     }
@@ -693,6 +693,38 @@ mod listing_operations {
         cleanup_bucket(config_dir.path(), &bucket_name);
     }
 
+    #[test]
+    fn test_ls_anonymous_sends_unsigned_requests() {
+        let (config_dir, bucket_name) = match setup_with_alias("anon") {
+            Some(v) => v,
+            None => {
+                eprintln!("Skipping: S3 test config not available");
+                return;
+            }
+        };
+
+        // A new bucket is private, so an unsigned listing is refused
+        let output = run_rc(
+            &["ls", &format!("test/{}/", bucket_name), "--anonymous"],
+            config_dir.path(),
+        );
+        assert_eq!(
+            output.status.code(),
+            Some(4),
+            "anonymous listing of a private bucket: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // The same listing with the alias's keys works
+        let output = run_rc(
+            &["ls", &format!("test/{}/", bucket_name)],
+            config_dir.path(),
+        );
+        assert!(output.status.success(), "signed listing failed");
+
+        cleanup_bucket(config_dir.path(), &bucket_name);
+    }
+
     #[test]
     fn test_recursive_listing() {
         let (config_dir, bucket_name) = match setup_with_alias("recursive") {
@@ -847,9 +879,7 @@ mod admin_operations {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let combined = format!("{stdout}{stderr}");
-            if combined.contains("NotImplemented") || combined.contains("Not Implemented") {
+            if output.status.code() == Some(8) {
                 eprintln!("Skipping: heal status not supported by backend");
                 return;
             }
@@ -861,6 +891,84 @@ mod admin_operations {
         assert!(json.get("healId").is_some(), "Expected healId in output");
         assert!(json.get("healing").is_some(), "Expected healing in output");
     }
+
+    #[test]
+    fn test_admin_rejects_anonymous() {
+        let config_dir = match setup_alias_only() {
+            Some(v) => v,
+            None => {
+                eprintln!("Skipping: S3 test config not available");
+                return;
+            }
+        };
+
+        let output = run_rc(
+            &["admin", "info", "cluster", "test", "--anonymous"],
+            config_dir.path(),
+        );
+        assert_eq!(
+            output.status.code(),
+            Some(2),
+            "anonymous admin is a usage error"
+        );
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("signed"), "unexpected error: {stderr}");
+    }
+
+    #[test]
+    fn test_admin_user_disable_then_enable() {
+        let config_dir = match setup_alias_only() {
+            Some(v) => v,
+            None => {
+                eprintln!("Skipping: S3 test config not available");
+                return;
+            }
+        };
+        let user = format!("rc-status-{}", uuid_suffix());
+
+        let output = run_rc(
+            &[
+                "admin",
+                "user",
+                "add",
+                "test",
+                &user,
+                "status-secret-123",
+                "--json",
+            ],
+            config_dir.path(),
+        );
+        assert!(
+            output.status.success(),
+            "Failed to add user: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        for (action, expected) in [("disable", "disabled"), ("enable", "enabled")] {
+            let output = run_rc(
+                &["admin", "user", action, "test", &user, "--json"],
+                config_dir.path(),
+            );
+            assert!(
+                output.status.success(),
+                "Failed to {action} user: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            let json: serde_json::Value =
+                serde_json::from_slice(&output.stdout).expect("Invalid JSON output");
+            assert_eq!(json["status"], expected);
+
+            let output = run_rc(
+                &["admin", "user", "info", "test", &user, "--json"],
+                config_dir.path(),
+            );
+            let json: serde_json::Value =
+                serde_json::from_slice(&output.stdout).expect("Invalid JSON output");
+            assert_eq!(json["status"], expected, "get_user should reflect {action}");
+        }
+
+        let _ = run_rc(&["admin", "user", "rm", "test", &user], config_dir.path());
+    }
 }
 
 mod error_handling {
@@ -1233,7 +1341,7 @@ mod recursive_operations {
             assert!(output.status.success(), "Failed to upload {}", file);
         }
 
-        // Copy src/ to dst/ - Note: recursive S3-to-S3 copy may not be fully implemented
+        // Copy src/ to dst/ (server-side, same alias)
         let output = run_rc(
             &[
                 "cp",
@@ -1244,16 +1352,11 @@ mod recursive_operations {
             ],
             config_dir.path(),
         );
-
-        // If recursive copy is not supported, skip the rest of the test
-        if !output.status.success() {
-            eprintln!(
-                "Recursive S3-to-S3 copy not fully implemented, skipping: {}",
-                String::from_utf8_lossy(&output.stderr)
-            );
-            cleanup_bucket(config_dir.path(), &bucket_name);
-            return;
-        }
+        assert!(
+            output.status.success(),
+            "Recursive copy failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
 
         // Verify both src and dst exist
         let output = run_rc(
@@ -1995,6 +2098,135 @@ mod mirror_operations {
     }
 }
 
+mod sync_operations {
+    use super::*;
+
+    /// Upload a small object with the given key
+    fn put_object(config_dir: &std::path::Path, target: &str) {
+        let temp_file = tempfile::NamedTempFile::new().expect("Failed to create temp file");
+        std::fs::write(temp_file.path(), format!("content for {}", target))
+            .expect("Failed to write");
+
+        let output = run_rc(
+            &["cp", temp_file.path().to_str().unwrap(), target],
+            config_dir,
+        );
+        assert!(output.status.success(), "Failed to upload {}", target);
+    }
+
+    /// Source prefix with one object, destination bucket with one extra object
+    fn setup_sync(name: &str) -> Option<(TempDir, String, String)> {
+        let (config_dir, bucket_name) = setup_with_alias(name)?;
+
+        let bucket_name2 = format!("{}-dest", bucket_name);
+        let output = run_rc(
+            &["mb", &format!("test/{}", bucket_name2)],
+            config_dir.path(),
+        );
+        assert!(
+            output.status.success(),
+            "Failed to create destination bucket: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        put_object(
+            config_dir.path(),
+            &format!("test/{}/source/keep.txt", bucket_name),
+        );
+        put_object(
+            config_dir.path(),
+            &format!("test/{}/extra.txt", bucket_name2),
+        );
+
+        Some((config_dir, bucket_name, bucket_name2))
+    }
+
+    fn destination_listing(config_dir: &std::path::Path, bucket: &str) -> String {
+        let output = run_rc(
+            &["ls", &format!("test/{}/", bucket), "--recursive", "--json"],
+            config_dir,
+        );
+        assert!(output.status.success(), "Failed to list destination");
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
+
+    #[test]
+    fn test_sync_delete_without_force_is_usage_error() {
+        let (config_dir, bucket_name, bucket_name2) = match setup_sync("syncforce") {
+            Some(v) => v,
+            None => {
+                eprintln!("Skipping: S3 test config not available");
+                return;
+            }
+        };
+
+        let output = run_rc(
+            &[
+                "sync",
+                &format!("test/{}/source/", bucket_name),
+                &format!("test/{}/", bucket_name2),
+            ],
+            config_dir.path(),
+        );
+
+        // Exit code should be 2 (USAGE_ERROR) and nothing deleted
+        assert_eq!(
+            output.status.code(),
+            Some(2),
+            "Expected exit code 2 (USAGE_ERROR): {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let stdout = destination_listing(config_dir.path(), &bucket_name2);
+        assert!(stdout.contains("extra.txt"), "extra.txt should be kept");
+
+        cleanup_bucket(config_dir.path(), &bucket_name);
+        cleanup_bucket(config_dir.path(), &bucket_name2);
+    }
+
+    #[test]
+    fn test_sync_dry_run_succeeds_without_changes() {
+        let (config_dir, bucket_name, bucket_name2) = match setup_sync("syncdryrun") {
+            Some(v) => v,
+            None => {
+                eprintln!("Skipping: S3 test config not available");
+                return;
+            }
+        };
+
+        let output = run_rc(
+            &[
+                "sync",
+                &format!("test/{}/source/", bucket_name),
+                &format!("test/{}/", bucket_name2),
+                "--dry-run",
+            ],
+            config_dir.path(),
+        );
+
+        assert_eq!(
+            output.status.code(),
+            Some(0),
+            "Dry run should succeed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains("extra.txt"),
+            "extra.txt should be listed for removal"
+        );
+
+        let stdout = destination_listing(config_dir.path(), &bucket_name2);
+        assert!(stdout.contains("extra.txt"), "extra.txt should be kept");
+        assert!(
+            !stdout.contains("keep.txt"),
+            "keep.txt should not be copied"
+        );
+
+        cleanup_bucket(config_dir.path(), &bucket_name);
+        cleanup_bucket(config_dir.path(), &bucket_name2);
+    }
+}
+
 mod tree_operations {
     use super::*;
 
@@ -2235,6 +2467,62 @@ mod tag_operations {
     }
 }
 
+mod acl_operations {
+    use super::*;
+
+    #[test]
+    fn test_object_canned_acl() {
+        let (config_dir, bucket_name) = match setup_with_alias("acl") {
+            Some(v) => v,
+            None => {
+                eprintln!("Skipping: S3 test config not available");
+                return;
+            }
+        };
+
+        let temp_file = tempfile::Builder::new()
+            .suffix(".txt")
+            .tempfile()
+            .expect("Failed to create temp file");
+        std::fs::write(temp_file.path(), "acl test content").expect("Failed to write");
+
+        let object_path = format!("test/{}/acl.txt", bucket_name);
+        let output = run_rc(
+            &["cp", temp_file.path().to_str().unwrap(), &object_path],
+            config_dir.path(),
+        );
+        assert!(output.status.success(), "Failed to upload");
+
+        let output = run_rc(
+            &["acl", "set-canned", &object_path, "private", "--json"],
+            config_dir.path(),
+        );
+
+        // ACLs may not be supported by all S3 implementations
+        if !output.status.success() {
+            eprintln!(
+                "ACLs not supported: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            cleanup_bucket(config_dir.path(), &bucket_name);
+            return;
+        }
+
+        let output = run_rc(&["acl", "get", &object_path, "--json"], config_dir.path());
+        assert!(
+            output.status.success(),
+            "Failed to get ACL: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect("Invalid JSON output");
+        assert!(json["grants"].is_array(), "Should list grants");
+
+        cleanup_bucket(config_dir.path(), &bucket_name);
+    }
+}
+
 mod alias_operations {
     use super::*;
 
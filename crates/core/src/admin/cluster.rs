@@ -4,7 +4,7 @@
 //! including server information, disk status, and heal operations.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Server information representing a RustFS node
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -326,6 +326,17 @@ pub struct ClusterInfo {
 }
 
 impl ClusterInfo {
+    /// Find a disk by its endpoint or UUID
+    pub fn find_disk(&self, id: &str) -> Option<&DiskInfo> {
+        self.servers
+            .iter()
+            .flatten()
+            .flat_map(|s| &s.disks)
+            .find(|d| {
+                (!d.endpoint.is_empty() && d.endpoint == id) || (!d.uuid.is_empty() && d.uuid == id)
+            })
+    }
+
     /// Get the total number of online disks across all servers
     pub fn online_disks(&self) -> usize {
         self.servers
@@ -442,6 +453,10 @@ pub struct HealStartRequest {
     /// Dry run mode (don't actually heal)
     #[serde(default)]
     pub dry_run: bool,
+
+    /// Endpoint of a single drive to heal (empty for all drives)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk: Option<String>,
 }
 
 /// Information about a single heal drive
@@ -566,10 +581,653 @@ pub struct HealStatus {
     pub last_update: Option<String>,
 }
 
+/// Progress of a pool decommission
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolDecommissionInfo {
+    /// Start time
+    #[serde(default)]
+    pub start_time: Option<String>,
+
+    /// Pool usage in bytes when the decommission started
+    #[serde(default)]
+    pub start_size: u64,
+
+    /// Total pool capacity in bytes
+    #[serde(default)]
+    pub total_size: u64,
+
+    /// Bytes still stored in the pool
+    #[serde(default)]
+    pub current_size: u64,
+
+    /// Whether the decommission finished
+    #[serde(default)]
+    pub complete: bool,
+
+    /// Whether the decommission failed
+    #[serde(default)]
+    pub failed: bool,
+
+    /// Whether the decommission was canceled
+    #[serde(default)]
+    pub canceled: bool,
+
+    /// Number of objects moved off the pool
+    #[serde(default)]
+    pub objects_decommissioned: u64,
+
+    /// Number of objects that failed to move
+    #[serde(default)]
+    pub objects_decommissioned_failed: u64,
+
+    /// Bytes moved off the pool
+    #[serde(default)]
+    pub bytes_decommissioned: u64,
+
+    /// Bytes that failed to move
+    #[serde(default)]
+    pub bytes_decommissioned_failed: u64,
+}
+
+impl PoolDecommissionInfo {
+    /// Whether the decommission is still running
+    pub fn is_active(&self) -> bool {
+        !self.complete && !self.failed && !self.canceled
+    }
+}
+
+/// Status of a storage pool
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolStatus {
+    /// Pool index
+    #[serde(default)]
+    pub id: usize,
+
+    /// Pool command line (its endpoint pattern), used to name the pool
+    #[serde(default)]
+    pub cmdline: String,
+
+    /// Last update time
+    #[serde(default)]
+    pub last_update: Option<String>,
+
+    /// Decommission progress, if the pool was ever decommissioned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decommission_info: Option<PoolDecommissionInfo>,
+}
+
+/// Progress of the rebalance on one pool
+///
+/// Durations are reported by the server in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalancePoolProgress {
+    /// Objects moved off the pool
+    #[serde(default)]
+    pub objects: u64,
+
+    /// Object versions moved off the pool
+    #[serde(default)]
+    pub versions: u64,
+
+    /// Bytes moved off the pool
+    #[serde(default)]
+    pub bytes: u64,
+
+    /// Bucket currently being rebalanced
+    #[serde(default)]
+    pub bucket: String,
+
+    /// Object currently being rebalanced
+    #[serde(default)]
+    pub object: String,
+
+    /// Time spent so far, in nanoseconds
+    #[serde(default)]
+    pub elapsed: u64,
+
+    /// Estimated time remaining, in nanoseconds
+    #[serde(default)]
+    pub eta: u64,
+}
+
+/// Rebalance state of one pool
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalancePoolStatus {
+    /// Pool index
+    #[serde(default)]
+    pub id: usize,
+
+    /// Rebalance state of the pool (e.g. "Started", "Completed", "Stopped")
+    #[serde(default)]
+    pub status: String,
+
+    /// Fraction of the pool capacity in use, 0.0 to 1.0
+    #[serde(default)]
+    pub used: f64,
+
+    /// Progress, once the pool has started moving data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<RebalancePoolProgress>,
+}
+
+/// Status of a cluster rebalance
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalanceStatus {
+    /// Rebalance ID
+    #[serde(default, rename = "ID")]
+    pub id: String,
+
+    /// When the rebalance was stopped, if it was
+    #[serde(default)]
+    pub stopped_at: Option<String>,
+
+    /// Per-pool state
+    #[serde(default)]
+    pub pools: Vec<RebalancePoolStatus>,
+}
+
+impl RebalanceStatus {
+    /// Whether any pool is still moving data
+    pub fn is_running(&self) -> bool {
+        self.stopped_at.is_none() && self.pools.iter().any(|p| p.status == "Started")
+    }
+}
+
+/// A site taking part in site replication
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SiteReplicationPeer {
+    /// Site name
+    #[serde(default)]
+    pub name: String,
+
+    /// Site endpoint URL
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// Deployment ID of the site
+    #[serde(default, rename = "deploymentID")]
+    pub deployment_id: String,
+}
+
+/// Site replication configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SiteReplicationInfo {
+    /// Whether site replication is configured
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Name of the site answering the request
+    #[serde(default)]
+    pub name: String,
+
+    /// All sites, including this one
+    #[serde(default)]
+    pub sites: Vec<SiteReplicationPeer>,
+}
+
+/// Replication latency to a site, in nanoseconds
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SiteReplicationLatency {
+    /// Most recent latency
+    #[serde(default)]
+    pub curr: u64,
+
+    /// Average latency
+    #[serde(default)]
+    pub avg: u64,
+
+    /// Maximum latency
+    #[serde(default)]
+    pub max: u64,
+}
+
+/// Replication metrics for one peer site
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct SiteReplicationMetric {
+    /// Deployment ID of the site
+    #[serde(default, rename = "DeploymentID")]
+    pub deployment_id: String,
+
+    /// Site endpoint URL
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// Whether the site is reachable
+    #[serde(default)]
+    pub online: bool,
+
+    /// When the site was last reachable
+    #[serde(default)]
+    pub last_online: Option<String>,
+
+    /// Replication latency (lag) to the site
+    #[serde(default)]
+    pub latency: SiteReplicationLatency,
+
+    /// Objects replicated to the site
+    #[serde(default)]
+    pub replicated_count: u64,
+
+    /// Bytes replicated to the site
+    #[serde(default)]
+    pub replicated_size: u64,
+}
+
+/// Replication metrics across all peer sites
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct SiteReplicationMetrics {
+    /// Metrics keyed by deployment ID
+    #[serde(default)]
+    pub metrics: BTreeMap<String, SiteReplicationMetric>,
+}
+
+/// State of one bucket on one site
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct SiteReplicationBucketStatus {
+    /// Whether the bucket exists on the site
+    #[serde(default)]
+    pub has_bucket: bool,
+
+    /// Whether the bucket's replication config differs from the other sites
+    #[serde(default)]
+    pub replication_cfg_mismatch: bool,
+
+    /// Whether the bucket policy differs from the other sites
+    #[serde(default)]
+    pub policy_mismatch: bool,
+
+    /// Whether the bucket tags differ from the other sites
+    #[serde(default)]
+    pub tag_mismatch: bool,
+}
+
+impl SiteReplicationBucketStatus {
+    /// Whether the bucket is present and in sync on this site
+    pub fn is_healthy(&self) -> bool {
+        self.has_bucket
+            && !self.replication_cfg_mismatch
+            && !self.policy_mismatch
+            && !self.tag_mismatch
+    }
+}
+
+/// Site replication health
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct SiteReplicationStatus {
+    /// Whether site replication is configured
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Sites keyed by deployment ID
+    #[serde(default)]
+    pub sites: BTreeMap<String, SiteReplicationPeer>,
+
+    /// Per-bucket state, keyed by bucket and then deployment ID
+    #[serde(default)]
+    pub bucket_stats: BTreeMap<String, BTreeMap<String, SiteReplicationBucketStatus>>,
+
+    /// Replication metrics per peer site
+    #[serde(default)]
+    pub metrics: SiteReplicationMetrics,
+}
+
+/// Kind of server log entries to stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogKind {
+    /// Storage server errors
+    Minio,
+    /// Application errors
+    Application,
+    /// Every entry
+    #[default]
+    All,
+}
+
+impl std::fmt::Display for LogKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogKind::Minio => write!(f, "MINIO"),
+            LogKind::Application => write!(f, "APPLICATION"),
+            LogKind::All => write!(f, "ALL"),
+        }
+    }
+}
+
+/// Options for streaming server logs
+#[derive(Debug, Clone, Default)]
+pub struct LogOpts {
+    /// Only stream entries from this node (host:port)
+    pub node: Option<String>,
+
+    /// Kind of entries to stream
+    pub kind: LogKind,
+
+    /// Number of recent entries the server replays before new ones
+    pub last: usize,
+}
+
+/// Error details attached to a server log entry
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LogErrorDetail {
+    /// Error message
+    #[serde(default)]
+    pub message: String,
+
+    /// Call site trace, innermost first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source: Vec<String>,
+}
+
+/// One entry from the server log stream
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    /// Node that logged the entry
+    #[serde(default)]
+    pub node: String,
+
+    /// Severity (e.g. "ERROR")
+    #[serde(default)]
+    pub level: String,
+
+    /// RFC 3339 time the entry was logged
+    #[serde(default)]
+    pub time: String,
+
+    /// Request the entry belongs to, if any
+    #[serde(
+        default,
+        rename(deserialize = "requestID"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub request_id: Option<String>,
+
+    /// Log message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// Console output captured by the server
+    #[serde(
+        default,
+        rename(deserialize = "ConsoleMsg"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub console_msg: Option<String>,
+
+    /// Error details, for error entries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<LogErrorDetail>,
+}
+
+impl LogEntry {
+    /// Main text of the entry: the error message, then the message, then console output
+    pub fn text(&self) -> &str {
+        self.error
+            .as_ref()
+            .map(|e| e.message.as_str())
+            .filter(|m| !m.is_empty())
+            .or(self.message.as_deref())
+            .or(self.console_msg.as_deref())
+            .unwrap_or_default()
+            .trim_end()
+    }
+}
+
+/// Prometheus scrape target exposed by the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsTarget {
+    /// Cluster-wide metrics
+    #[default]
+    Cluster,
+    /// Per-node metrics
+    Node,
+    /// Per-bucket metrics
+    Bucket,
+    /// Host resource metrics (CPU, memory, drives, network)
+    Resource,
+}
+
+impl std::fmt::Display for MetricsTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetricsTarget::Cluster => write!(f, "cluster"),
+            MetricsTarget::Node => write!(f, "node"),
+            MetricsTarget::Bucket => write!(f, "bucket"),
+            MetricsTarget::Resource => write!(f, "resource"),
+        }
+    }
+}
+
+/// Options for a cluster speedtest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeedTestOpts {
+    /// Object size in bytes used for each PUT/GET
+    pub size: u64,
+
+    /// Number of concurrent requests per server
+    pub concurrent: usize,
+
+    /// Test duration (e.g. "10s", "1m")
+    pub duration: String,
+
+    /// Let the server increase concurrency until throughput stops improving
+    pub autotune: bool,
+}
+
+impl Default for SpeedTestOpts {
+    fn default() -> Self {
+        Self {
+            size: 64 * 1024 * 1024,
+            concurrent: 32,
+            duration: "10s".to_string(),
+            autotune: false,
+        }
+    }
+}
+
+/// Per-server throughput numbers from a speedtest
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeedTestServerStats {
+    /// Server endpoint
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// Bytes per second
+    #[serde(default)]
+    pub throughput_per_sec: u64,
+
+    /// Objects per second
+    #[serde(default)]
+    pub objects_per_sec: u64,
+
+    /// Error reported by this server, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+}
+
+/// Aggregate throughput numbers for one direction (PUT or GET)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeedTestStats {
+    /// Bytes per second across the cluster
+    #[serde(default)]
+    pub throughput_per_sec: u64,
+
+    /// Objects per second across the cluster
+    #[serde(default)]
+    pub objects_per_sec: u64,
+
+    /// Per-server breakdown
+    #[serde(default)]
+    pub servers: Vec<SpeedTestServerStats>,
+}
+
+/// Result of a cluster speedtest
+///
+/// The server streams one of these per line while the test runs; the last
+/// one is the final result.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeedTestResult {
+    /// Server version
+    #[serde(default)]
+    pub version: String,
+
+    /// Number of servers that took part
+    #[serde(default)]
+    pub servers: usize,
+
+    /// Number of disks that took part
+    #[serde(default)]
+    pub disks: usize,
+
+    /// Object size in bytes
+    #[serde(default)]
+    pub size: u64,
+
+    /// Concurrency used
+    #[serde(default)]
+    pub concurrent: usize,
+
+    /// PUT statistics
+    #[serde(default, rename = "PUTStats")]
+    pub put_stats: SpeedTestStats,
+
+    /// GET statistics
+    #[serde(default, rename = "GETStats")]
+    pub get_stats: SpeedTestStats,
+}
+
+/// Profile kinds that can be captured on the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfilerType {
+    /// CPU profile
+    Cpu,
+    /// Heap/memory profile
+    Mem,
+    /// Blocking profile
+    Block,
+    /// Mutex contention profile
+    Mutex,
+    /// Execution trace
+    Trace,
+    /// Thread creation profile
+    Threads,
+    /// Goroutine/task dump
+    Goroutines,
+}
+
+impl ProfilerType {
+    /// All supported profile kinds
+    pub const ALL: [ProfilerType; 7] = [
+        ProfilerType::Cpu,
+        ProfilerType::Mem,
+        ProfilerType::Block,
+        ProfilerType::Mutex,
+        ProfilerType::Trace,
+        ProfilerType::Threads,
+        ProfilerType::Goroutines,
+    ];
+}
+
+impl std::fmt::Display for ProfilerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfilerType::Cpu => write!(f, "cpu"),
+            ProfilerType::Mem => write!(f, "mem"),
+            ProfilerType::Block => write!(f, "block"),
+            ProfilerType::Mutex => write!(f, "mutex"),
+            ProfilerType::Trace => write!(f, "trace"),
+            ProfilerType::Threads => write!(f, "threads"),
+            ProfilerType::Goroutines => write!(f, "goroutines"),
+        }
+    }
+}
+
+impl std::str::FromStr for ProfilerType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cpu" => Ok(ProfilerType::Cpu),
+            "mem" => Ok(ProfilerType::Mem),
+            "block" => Ok(ProfilerType::Block),
+            "mutex" => Ok(ProfilerType::Mutex),
+            "trace" => Ok(ProfilerType::Trace),
+            "threads" => Ok(ProfilerType::Threads),
+            "goroutines" | "goroutine" => Ok(ProfilerType::Goroutines),
+            _ => Err(format!("Invalid profiler type: {s}")),
+        }
+    }
+}
+
+/// Per-node result of starting a profiling session
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfilingStartResult {
+    /// Node that handled the request
+    #[serde(default)]
+    pub node_name: String,
+
+    /// Whether profiling started on this node
+    #[serde(default)]
+    pub success: bool,
+
+    /// Error message if profiling failed to start
+    #[serde(default)]
+    pub error: String,
+}
+
+/// Service control action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceAction {
+    /// Restart all nodes
+    Restart,
+    /// Stop all nodes
+    Stop,
+}
+
+impl std::fmt::Display for ServiceAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServiceAction::Restart => write!(f, "restart"),
+            ServiceAction::Stop => write!(f, "stop"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_log_entry_parses_server_fields() {
+        let json = r#"{"node":"n1:9000","level":"ERROR","time":"2026-01-02T03:04:05Z","requestID":"17A","error":{"message":"signature mismatch","source":["auth.go:12"]}}"#;
+        let entry: LogEntry = serde_json::from_str(json).expect("parse log entry");
+        assert_eq!(entry.request_id.as_deref(), Some("17A"));
+        assert_eq!(entry.text(), "signature mismatch");
+
+        let console: LogEntry =
+            serde_json::from_str(r#"{"ConsoleMsg":"starting\n"}"#).expect("parse console entry");
+        assert_eq!(console.text(), "starting");
+    }
+
     #[test]
     fn test_backend_type_display() {
         assert_eq!(BackendType::Fs.to_string(), "FS");
@@ -678,6 +1336,32 @@ mod tests {
         assert_eq!(req.scan_mode, HealScanMode::Normal);
         assert!(!req.remove);
         assert!(!req.dry_run);
+        assert!(req.disk.is_none());
+    }
+
+    #[test]
+    fn test_find_disk_by_endpoint_or_uuid() {
+        let disk = |endpoint: &str, uuid: &str| DiskInfo {
+            endpoint: endpoint.to_string(),
+            uuid: uuid.to_string(),
+            ..Default::default()
+        };
+        let info = ClusterInfo {
+            servers: Some(vec![ServerInfo {
+                disks: vec![
+                    disk("http://node1:9000/data1", "uuid-1"),
+                    disk("http://node1:9000/data2", ""),
+                ],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let found = info.find_disk("uuid-1").expect("disk by uuid");
+        assert_eq!(found.endpoint, "http://node1:9000/data1");
+        assert!(info.find_disk("http://node1:9000/data2").is_some());
+        assert!(info.find_disk("").is_none());
+        assert!(info.find_disk("uuid-9").is_none());
     }
 
     #[test]
@@ -703,4 +1387,62 @@ mod tests {
         let deserialized: ClusterInfo = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.mode, Some("distributed".to_string()));
     }
+
+    #[test]
+    fn test_speedtest_opts_default() {
+        let opts = SpeedTestOpts::default();
+        assert_eq!(opts.size, 64 * 1024 * 1024);
+        assert_eq!(opts.concurrent, 32);
+        assert_eq!(opts.duration, "10s");
+        assert!(!opts.autotune);
+    }
+
+    #[test]
+    fn test_speedtest_result_deserialization() {
+        let json = r#"{
+            "version": "1.0.0",
+            "servers": 2,
+            "disks": 8,
+            "size": 67108864,
+            "concurrent": 32,
+            "PUTStats": {
+                "throughputPerSec": 1000,
+                "objectsPerSec": 10,
+                "servers": [{"endpoint": "node1:9000", "throughputPerSec": 500, "objectsPerSec": 5}]
+            },
+            "GETStats": {"throughputPerSec": 2000, "objectsPerSec": 20}
+        }"#;
+
+        let result: SpeedTestResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.servers, 2);
+        assert_eq!(result.put_stats.throughput_per_sec, 1000);
+        assert_eq!(result.put_stats.servers.len(), 1);
+        assert!(result.put_stats.servers[0].err.is_none());
+        assert_eq!(result.get_stats.objects_per_sec, 20);
+        assert!(result.get_stats.servers.is_empty());
+    }
+
+    #[test]
+    fn test_profiler_type_display() {
+        assert_eq!(ProfilerType::Cpu.to_string(), "cpu");
+        assert_eq!(ProfilerType::Goroutines.to_string(), "goroutines");
+    }
+
+    #[test]
+    fn test_profiler_type_from_str() {
+        for kind in ProfilerType::ALL {
+            assert_eq!(kind.to_string().parse::<ProfilerType>().unwrap(), kind);
+        }
+        assert_eq!(
+            "goroutine".parse::<ProfilerType>().unwrap(),
+            ProfilerType::Goroutines
+        );
+        assert!("invalid".parse::<ProfilerType>().is_err());
+    }
+
+    #[test]
+    fn test_service_action_display() {
+        assert_eq!(ServiceAction::Restart.to_string(), "restart");
+        assert_eq!(ServiceAction::Stop.to_string(), "stop");
+    }
 }
@@ -4,18 +4,34 @@
 //! IAM users, policies, groups, service accounts, and cluster operations.
 
 mod cluster;
+mod notify;
+mod quota;
+mod remote;
+mod sts;
 mod types;
 
 pub use cluster::{
     BackendInfo, BackendType, BucketsInfo, ClusterInfo, DiskInfo, HealDriveInfo, HealDriveInfos,
-    HealResultItem, HealScanMode, HealStartRequest, HealStatus, HealingDiskInfo, MemStats,
-    ObjectsInfo, ServerInfo, UsageInfo,
+    HealResultItem, HealScanMode, HealStartRequest, HealStatus, HealingDiskInfo, LogEntry,
+    LogErrorDetail, LogKind, LogOpts, MemStats, MetricsTarget, ObjectsInfo, PoolDecommissionInfo,
+    PoolStatus, ProfilerType, ProfilingStartResult, RebalancePoolProgress, RebalancePoolStatus,
+    RebalanceStatus, ServerInfo, ServiceAction, SiteReplicationBucketStatus, SiteReplicationInfo,
+    SiteReplicationLatency, SiteReplicationMetric, SiteReplicationMetrics, SiteReplicationPeer,
+    SiteReplicationStatus, SpeedTestOpts, SpeedTestResult, SpeedTestServerStats, SpeedTestStats,
+    UsageInfo,
 };
+pub use notify::{NotifyTarget, NotifyTargetType, parse_notify_targets};
+pub use quota::BucketQuota;
+pub use remote::{BucketTarget, REDACTED_SECRET, TargetCredentials};
+pub use sts::{AssumeRoleRequest, TemporaryCredentials};
 pub use types::{
     CreateServiceAccountRequest, Group, GroupStatus, Policy, PolicyEntity, PolicyInfo,
-    ServiceAccount, SetPolicyRequest, UpdateGroupMembersRequest, User, UserStatus,
+    PolicyValidation, ServiceAccount, SetPolicyRequest, UpdateGroupMembersRequest,
+    UpdateServiceAccountRequest, User, UserStatus,
 };
 
+use std::path::Path;
+
 use async_trait::async_trait;
 
 use crate::error::Result;
@@ -41,6 +57,92 @@ pub trait AdminApi: Send + Sync {
     /// Stop a running heal operation
     async fn heal_stop(&self) -> Result<()>;
 
+    /// List storage pools with their decommission progress
+    async fn list_pools(&self) -> Result<Vec<PoolStatus>>;
+
+    /// Start decommissioning a pool, named by its command line (endpoint pattern)
+    async fn decommission_start(&self, pool: &str) -> Result<()>;
+
+    /// Cancel a running pool decommission
+    async fn decommission_cancel(&self, pool: &str) -> Result<()>;
+
+    /// Start rebalancing data across pools and return the rebalance ID
+    async fn rebalance_start(&self) -> Result<String>;
+
+    /// Get the status of the current or last rebalance
+    async fn rebalance_status(&self) -> Result<RebalanceStatus>;
+
+    /// Stop a running rebalance
+    async fn rebalance_stop(&self) -> Result<()>;
+
+    /// Get the site replication configuration
+    async fn site_replication_info(&self) -> Result<SiteReplicationInfo>;
+
+    /// Get site replication health, including per-bucket state and per-site metrics
+    async fn site_replication_status(&self) -> Result<SiteReplicationStatus>;
+
+    /// List the notification targets of one type
+    async fn list_notify_targets(&self, target_type: NotifyTargetType)
+    -> Result<Vec<NotifyTarget>>;
+
+    /// Create or replace a notification target
+    async fn set_notify_target(&self, target: &NotifyTarget) -> Result<()>;
+
+    /// Remove a notification target
+    async fn remove_notify_target(&self, target_type: NotifyTargetType, name: &str) -> Result<()>;
+
+    /// Register a remote target for a bucket and return the ARN the server assigned
+    async fn set_remote_target(&self, target: &BucketTarget) -> Result<String>;
+
+    /// List the remote targets of a bucket, or of every bucket when `bucket` is `None`
+    async fn list_remote_targets(&self, bucket: Option<&str>) -> Result<Vec<BucketTarget>>;
+
+    /// Remove a bucket's remote target by ARN
+    async fn remove_remote_target(&self, bucket: &str, arn: &str) -> Result<()>;
+
+    /// Get a bucket's quota; `None` when no quota is set
+    async fn get_bucket_quota(&self, bucket: &str) -> Result<Option<BucketQuota>>;
+
+    /// Request temporary credentials from the server's STS endpoint
+    async fn assume_role(&self, request: &AssumeRoleRequest) -> Result<TemporaryCredentials>;
+
+    /// Stream server log entries
+    ///
+    /// `on_entry` is called for every entry until the server closes the
+    /// stream.
+    async fn stream_logs(
+        &self,
+        opts: &LogOpts,
+        on_entry: &(dyn for<'a> Fn(&'a LogEntry) + Send + Sync),
+    ) -> Result<()>;
+
+    /// Fetch a Prometheus metrics snapshot in text exposition format
+    async fn prometheus_metrics(&self, target: MetricsTarget) -> Result<String>;
+
+    /// Run a cluster speedtest
+    ///
+    /// `progress` is called for every intermediate result the server streams
+    /// back; the final result is returned.
+    async fn speedtest(
+        &self,
+        opts: SpeedTestOpts,
+        progress: &(dyn for<'a> Fn(&'a SpeedTestResult) + Send + Sync),
+    ) -> Result<SpeedTestResult>;
+
+    /// Start profiling on all nodes
+    async fn start_profiling(
+        &self,
+        profilers: &[ProfilerType],
+    ) -> Result<Vec<ProfilingStartResult>>;
+
+    /// Download the profiles collected since `start_profiling` as a zip archive
+    ///
+    /// The archive is streamed to `dest`; the number of bytes written is returned.
+    async fn download_profiling_data(&self, dest: &Path) -> Result<u64>;
+
+    /// Send a restart or stop signal to all nodes
+    async fn service_action(&self, action: ServiceAction) -> Result<()>;
+
     // ==================== User Operations ====================
 
     /// List all users
@@ -127,6 +229,16 @@ pub trait AdminApi: Send + Sync {
 
     /// Delete a service account
     async fn delete_service_account(&self, access_key: &str) -> Result<()>;
+
+    /// Change a service account's secret, policy, name, description or expiration
+    async fn update_service_account(
+        &self,
+        access_key: &str,
+        request: UpdateServiceAccountRequest,
+    ) -> Result<()>;
+
+    /// Enable or disable a service account
+    async fn set_service_account_status(&self, access_key: &str, status: UserStatus) -> Result<()>;
 }
 
 #[cfg(test)]
@@ -0,0 +1,350 @@
+//! Notification target type definitions
+//!
+//! Notification targets (webhook, Kafka, AMQP, ...) are stored server-side as
+//! config subsystems named `notify_<type>`, one `notify_<type>:<name>` entry
+//! per target with space-separated `key=value` pairs.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// Kind of notification target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyTargetType {
+    /// HTTP webhook
+    Webhook,
+    /// Kafka topic
+    Kafka,
+    /// AMQP exchange
+    Amqp,
+    /// MQTT topic
+    Mqtt,
+    /// NATS subject
+    Nats,
+    /// NSQ topic
+    Nsq,
+    /// Redis key
+    Redis,
+    /// MySQL table
+    Mysql,
+    /// PostgreSQL table
+    Postgresql,
+    /// Elasticsearch index
+    Elasticsearch,
+}
+
+impl NotifyTargetType {
+    /// All supported target types
+    pub const ALL: [NotifyTargetType; 10] = [
+        NotifyTargetType::Webhook,
+        NotifyTargetType::Kafka,
+        NotifyTargetType::Amqp,
+        NotifyTargetType::Mqtt,
+        NotifyTargetType::Nats,
+        NotifyTargetType::Nsq,
+        NotifyTargetType::Redis,
+        NotifyTargetType::Mysql,
+        NotifyTargetType::Postgresql,
+        NotifyTargetType::Elasticsearch,
+    ];
+
+    /// Config subsystem holding targets of this type
+    pub fn subsystem(&self) -> String {
+        format!("notify_{self}")
+    }
+
+    /// Key holding the address the server delivers events to
+    pub fn endpoint_key(&self) -> &'static str {
+        match self {
+            NotifyTargetType::Webhook => "endpoint",
+            NotifyTargetType::Kafka => "brokers",
+            NotifyTargetType::Amqp => "url",
+            NotifyTargetType::Mqtt => "broker",
+            NotifyTargetType::Nats => "address",
+            NotifyTargetType::Nsq => "nsqd_address",
+            NotifyTargetType::Redis => "address",
+            NotifyTargetType::Mysql => "dsn_string",
+            NotifyTargetType::Postgresql => "connection_string",
+            NotifyTargetType::Elasticsearch => "url",
+        }
+    }
+
+    /// Keys that must be set for a target of this type
+    pub fn required_keys(&self) -> &'static [&'static str] {
+        match self {
+            NotifyTargetType::Webhook => &["endpoint"],
+            NotifyTargetType::Kafka => &["brokers", "topic"],
+            NotifyTargetType::Amqp => &["url", "exchange"],
+            NotifyTargetType::Mqtt => &["broker", "topic"],
+            NotifyTargetType::Nats => &["address", "subject"],
+            NotifyTargetType::Nsq => &["nsqd_address", "topic"],
+            NotifyTargetType::Redis => &["address", "key", "format"],
+            NotifyTargetType::Mysql => &["dsn_string", "table", "format"],
+            NotifyTargetType::Postgresql => &["connection_string", "table", "format"],
+            NotifyTargetType::Elasticsearch => &["url", "index", "format"],
+        }
+    }
+}
+
+impl std::fmt::Display for NotifyTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            NotifyTargetType::Webhook => "webhook",
+            NotifyTargetType::Kafka => "kafka",
+            NotifyTargetType::Amqp => "amqp",
+            NotifyTargetType::Mqtt => "mqtt",
+            NotifyTargetType::Nats => "nats",
+            NotifyTargetType::Nsq => "nsq",
+            NotifyTargetType::Redis => "redis",
+            NotifyTargetType::Mysql => "mysql",
+            NotifyTargetType::Postgresql => "postgresql",
+            NotifyTargetType::Elasticsearch => "elasticsearch",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for NotifyTargetType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        NotifyTargetType::ALL
+            .into_iter()
+            .find(|t| t.to_string() == s.to_lowercase())
+            .ok_or_else(|| {
+                format!(
+                    "Invalid notification target type: {s} (expected one of: {})",
+                    NotifyTargetType::ALL.map(|t| t.to_string()).join(", ")
+                )
+            })
+    }
+}
+
+/// A configured notification target
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyTarget {
+    /// Target type
+    pub target_type: NotifyTargetType,
+
+    /// Target name, unique per type
+    pub name: String,
+
+    /// Target settings
+    pub config: BTreeMap<String, String>,
+}
+
+impl NotifyTarget {
+    /// Create a target, checking the name and that every required key is set
+    pub fn new(
+        target_type: NotifyTargetType,
+        name: impl Into<String>,
+        config: BTreeMap<String, String>,
+    ) -> std::result::Result<Self, String> {
+        let name = name.into();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!(
+                "Invalid target name '{name}': use letters, digits, '-' and '_'"
+            ));
+        }
+
+        let missing: Vec<&str> = target_type
+            .required_keys()
+            .iter()
+            .copied()
+            .filter(|key| config.get(*key).is_none_or(|v| v.is_empty()))
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!(
+                "{target_type} target requires: {}",
+                missing.join(", ")
+            ));
+        }
+
+        Ok(Self {
+            target_type,
+            name,
+            config,
+        })
+    }
+
+    /// Config key identifying this target (`notify_<type>:<name>`)
+    pub fn config_key(&self) -> String {
+        format!("{}:{}", self.target_type.subsystem(), self.name)
+    }
+
+    /// Render the target as a config line for `set-config-kv`
+    pub fn to_config_line(&self) -> String {
+        let mut line = self.config_key();
+        for (key, value) in &self.config {
+            line.push(' ');
+            line.push_str(key);
+            line.push('=');
+            if value.is_empty() || value.contains(char::is_whitespace) || value.contains('"') {
+                line.push('"');
+                line.push_str(&value.replace('"', "\\\""));
+                line.push('"');
+            } else {
+                line.push_str(value);
+            }
+        }
+        line
+    }
+}
+
+/// Parse the `get-config-kv` output for a `notify_<type>` subsystem
+///
+/// Comment lines, lines for other subsystems and the subsystem defaults (the
+/// entry without a `:<name>` suffix) are skipped.
+pub fn parse_notify_targets(
+    target_type: NotifyTargetType,
+    text: &str,
+) -> Result<Vec<NotifyTarget>> {
+    let subsystem = target_type.subsystem();
+    let mut targets = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let Some(name) = key
+            .split_once(':')
+            .and_then(|(sys, name)| (sys == subsystem).then_some(name))
+        else {
+            continue;
+        };
+        targets.push(NotifyTarget {
+            target_type,
+            name: name.to_string(),
+            config: parse_kv_pairs(rest)?,
+        });
+    }
+    Ok(targets)
+}
+
+/// Parse space-separated `key=value` pairs, where values may be double-quoted
+fn parse_kv_pairs(text: &str) -> Result<BTreeMap<String, String>> {
+    let mut pairs = BTreeMap::new();
+    let mut chars = text.trim().chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            return Ok(pairs);
+        }
+
+        let key: String = std::iter::from_fn(|| chars.next_if(|c| *c != '=')).collect();
+        if chars.next() != Some('=') {
+            return Err(Error::General(format!(
+                "Malformed config entry near '{key}'"
+            )));
+        }
+
+        let mut value = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('\\') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        value.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => value.push(c),
+                    None => {
+                        return Err(Error::General(format!(
+                            "Unterminated quoted value for '{key}'"
+                        )));
+                    }
+                }
+            }
+        } else {
+            value.extend(std::iter::from_fn(|| chars.next_if(|c| !c.is_whitespace())));
+        }
+        pairs.insert(key.trim().to_string(), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_target_type_round_trip() {
+        for t in NotifyTargetType::ALL {
+            assert_eq!(t.to_string().parse::<NotifyTargetType>(), Ok(t));
+            assert!(t.required_keys().contains(&t.endpoint_key()));
+        }
+        assert!("WEBHOOK".parse::<NotifyTargetType>().is_ok());
+        assert!("smtp".parse::<NotifyTargetType>().is_err());
+    }
+
+    #[test]
+    fn test_new_validates_required_keys_and_name() {
+        let err = NotifyTarget::new(
+            NotifyTargetType::Kafka,
+            "events",
+            config(&[("brokers", "k1:9092")]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("topic"));
+
+        assert!(
+            NotifyTarget::new(
+                NotifyTargetType::Webhook,
+                "bad name",
+                config(&[("endpoint", "http://hook")]),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_line_round_trip() {
+        let target = NotifyTarget::new(
+            NotifyTargetType::Webhook,
+            "audit",
+            config(&[
+                ("endpoint", "https://hook.example.com/events"),
+                ("auth_token", "Bearer a b"),
+            ]),
+        )
+        .unwrap();
+
+        let line = target.to_config_line();
+        assert_eq!(
+            line,
+            r#"notify_webhook:audit auth_token="Bearer a b" endpoint=https://hook.example.com/events"#
+        );
+        let parsed = parse_notify_targets(NotifyTargetType::Webhook, &line).unwrap();
+        assert_eq!(parsed, vec![target]);
+    }
+
+    #[test]
+    fn test_parse_skips_other_subsystems() {
+        let text = "# comment\n\
+                    notify_webhook enable=off endpoint=\"\"\n\
+                    notify_webhook:one enable=on endpoint=http://a\n\
+                    notify_kafka:two brokers=k:9092 topic=t\n";
+        let targets = parse_notify_targets(NotifyTargetType::Webhook, text).unwrap();
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].name, "one");
+        assert_eq!(targets[0].config["enable"], "on");
+
+        assert!(parse_notify_targets(NotifyTargetType::Webhook, "notify_webhook:x a=\"b").is_err());
+    }
+}
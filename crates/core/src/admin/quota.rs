@@ -0,0 +1,43 @@
+//! Bucket quota definitions
+//!
+//! A quota caps how much data a bucket may hold. The server reports it as
+//! a size in bytes with a type; a size of 0 means no quota is set.
+
+use serde::{Deserialize, Serialize};
+
+/// Quota configured on a bucket
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BucketQuota {
+    /// Quota size in bytes (0 when no quota is set)
+    #[serde(default)]
+    pub quota: u64,
+
+    /// Quota type (e.g. "hard")
+    #[serde(rename = "quotatype", alias = "quota_type", default)]
+    pub quota_type: String,
+}
+
+impl BucketQuota {
+    /// Whether a quota is actually in force
+    pub fn is_set(&self) -> bool {
+        self.quota > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_quota_deserialize() {
+        let quota: BucketQuota =
+            serde_json::from_str(r#"{"quota":1073741824,"size":1073741824,"quotatype":"hard"}"#)
+                .expect("parse quota");
+        assert_eq!(quota.quota, 1_073_741_824);
+        assert_eq!(quota.quota_type, "hard");
+        assert!(quota.is_set());
+
+        let empty: BucketQuota = serde_json::from_str("{}").expect("parse empty quota");
+        assert!(!empty.is_set());
+    }
+}
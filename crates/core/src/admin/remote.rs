@@ -0,0 +1,192 @@
+//! Bucket remote target definitions
+//!
+//! A remote target registers another S3 endpoint and bucket for a source
+//! bucket. The server assigns each target an ARN, which bucket replication
+//! rules then name as their destination.
+
+use serde::{Deserialize, Serialize};
+
+/// Placeholder shown instead of a target's secret key
+pub const REDACTED_SECRET: &str = "REDACTED";
+
+/// Credentials the server uses to reach a remote target
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TargetCredentials {
+    /// Access key on the remote endpoint
+    #[serde(rename = "accessKey", default)]
+    pub access_key: String,
+
+    /// Secret key on the remote endpoint
+    #[serde(rename = "secretKey", default)]
+    pub secret_key: String,
+}
+
+/// A remote target registered for a bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BucketTarget {
+    /// Bucket the target is registered for
+    #[serde(rename = "sourcebucket", default)]
+    pub source_bucket: String,
+
+    /// Remote endpoint as `host[:port]`
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// Credentials for the remote endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<TargetCredentials>,
+
+    /// Bucket on the remote endpoint
+    #[serde(rename = "targetbucket", default)]
+    pub target_bucket: String,
+
+    /// Whether the remote endpoint uses HTTPS
+    #[serde(default)]
+    pub secure: bool,
+
+    /// Signature API used towards the remote (e.g. `s3v4`)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub api: String,
+
+    /// ARN assigned by the server; empty when registering a new target
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub arn: String,
+
+    /// Service the target is used for (`replication`)
+    #[serde(rename = "type", default)]
+    pub target_type: String,
+
+    /// Region of the remote bucket
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub region: String,
+}
+
+impl BucketTarget {
+    /// Describe a new replication target for `source_bucket`
+    ///
+    /// `endpoint` is a URL such as `https://backup.example.com:9000`; its
+    /// scheme decides `secure`.
+    pub fn replication(
+        source_bucket: impl Into<String>,
+        endpoint: &str,
+        target_bucket: impl Into<String>,
+        credentials: TargetCredentials,
+    ) -> std::result::Result<Self, String> {
+        let url =
+            url::Url::parse(endpoint).map_err(|e| format!("Invalid endpoint '{endpoint}': {e}"))?;
+        let secure = match url.scheme() {
+            "https" => true,
+            "http" => false,
+            other => {
+                return Err(format!(
+                    "Invalid endpoint '{endpoint}': scheme must be http or https, not {other}"
+                ));
+            }
+        };
+        let host = url
+            .host_str()
+            .ok_or_else(|| format!("Invalid endpoint '{endpoint}': missing host"))?;
+        if !url.path().trim_matches('/').is_empty() {
+            return Err(format!(
+                "Invalid endpoint '{endpoint}': give the bucket with --target-bucket, not in the URL"
+            ));
+        }
+        let endpoint = match url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+
+        let target_bucket = target_bucket.into();
+        if target_bucket.is_empty() {
+            return Err("Target bucket cannot be empty".to_string());
+        }
+
+        Ok(Self {
+            source_bucket: source_bucket.into(),
+            endpoint,
+            credentials: Some(credentials),
+            target_bucket,
+            secure,
+            api: "s3v4".to_string(),
+            arn: String::new(),
+            target_type: "replication".to_string(),
+            region: String::new(),
+        })
+    }
+
+    /// Remote endpoint as a URL
+    pub fn endpoint_url(&self) -> String {
+        let scheme = if self.secure { "https" } else { "http" };
+        format!("{scheme}://{}", self.endpoint)
+    }
+
+    /// Copy of the target with the secret key replaced
+    pub fn redacted(&self) -> Self {
+        let mut target = self.clone();
+        if let Some(credentials) = &mut target.credentials
+            && !credentials.secret_key.is_empty()
+        {
+            credentials.secret_key = REDACTED_SECRET.to_string();
+        }
+        target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials() -> TargetCredentials {
+        TargetCredentials {
+            access_key: "replicator".to_string(),
+            secret_key: "s3cr3t-key".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_replication_target_from_url() {
+        let target = BucketTarget::replication(
+            "photos",
+            "https://backup.example.com:9000",
+            "photos-copy",
+            credentials(),
+        )
+        .unwrap();
+        assert_eq!(target.endpoint, "backup.example.com:9000");
+        assert!(target.secure);
+        assert_eq!(target.endpoint_url(), "https://backup.example.com:9000");
+
+        let value = serde_json::to_value(&target).unwrap();
+        assert_eq!(value["sourcebucket"], "photos");
+        assert_eq!(value["targetbucket"], "photos-copy");
+        assert_eq!(value["type"], "replication");
+        assert_eq!(value["credentials"]["accessKey"], "replicator");
+        assert!(value.get("arn").is_none());
+    }
+
+    #[test]
+    fn test_replication_target_rejects_bad_endpoints() {
+        let target = |endpoint| BucketTarget::replication("b", endpoint, "t", credentials());
+        assert!(target("ftp://host").is_err());
+        assert!(target("not a url").is_err());
+        assert!(target("http://host/bucket").is_err());
+        assert!(BucketTarget::replication("b", "http://host", "", credentials()).is_err());
+        assert!(!target("http://host/").unwrap().secure);
+    }
+
+    #[test]
+    fn test_parse_listing_and_redact() {
+        let json = r#"[{"sourcebucket":"photos","endpoint":"10.0.0.2:9000",
+            "credentials":{"accessKey":"replicator","secretKey":"s3cr3t-key"},
+            "targetbucket":"photos-copy","secure":false,"api":"s3v4",
+            "arn":"arn:rustfs:replication::1234:photos-copy","type":"replication",
+            "bandwidthlimit":0}]"#;
+        let targets: Vec<BucketTarget> = serde_json::from_str(json).unwrap();
+        assert_eq!(targets[0].arn, "arn:rustfs:replication::1234:photos-copy");
+
+        let shown = targets[0].redacted();
+        let credentials = shown.credentials.unwrap();
+        assert_eq!(credentials.access_key, "replicator");
+        assert_eq!(credentials.secret_key, REDACTED_SECRET);
+    }
+}
@@ -0,0 +1,91 @@
+//! Security Token Service (STS) definitions
+//!
+//! STS hands out temporary credentials: an access key, secret key and
+//! session token that stop working at an expiration time. They are obtained
+//! either with the caller's own keys (AssumeRole) or with an OIDC token
+//! (AssumeRoleWithWebIdentity).
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Request for temporary credentials
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssumeRoleRequest {
+    /// Lifetime of the credentials in seconds; the server default when `None`
+    pub duration_seconds: Option<u32>,
+
+    /// Inline session policy (JSON) that narrows the caller's permissions
+    pub policy: Option<String>,
+
+    /// Role to assume, for servers that require one with web identity
+    pub role_arn: Option<String>,
+
+    /// OIDC token; selects AssumeRoleWithWebIdentity instead of AssumeRole
+    pub web_identity_token: Option<String>,
+}
+
+impl AssumeRoleRequest {
+    /// STS action name for this request
+    pub fn action(&self) -> &'static str {
+        if self.web_identity_token.is_some() {
+            "AssumeRoleWithWebIdentity"
+        } else {
+            "AssumeRole"
+        }
+    }
+}
+
+/// Temporary credentials returned by STS
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemporaryCredentials {
+    pub access_key: String,
+    pub secret_key: String,
+    pub session_token: String,
+
+    /// When the credentials stop working
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<Timestamp>,
+}
+
+impl TemporaryCredentials {
+    /// Whole seconds left before expiry (0 once expired), if the server sent an expiration
+    pub fn seconds_left(&self, now: Timestamp) -> Option<i64> {
+        self.expiration
+            .map(|exp| exp.as_second().saturating_sub(now.as_second()).max(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assume_role_action() {
+        let request = AssumeRoleRequest::default();
+        assert_eq!(request.action(), "AssumeRole");
+
+        let request = AssumeRoleRequest {
+            web_identity_token: Some("eyJ...".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(request.action(), "AssumeRoleWithWebIdentity");
+    }
+
+    #[test]
+    fn test_seconds_left() {
+        let now: Timestamp = "2026-01-15T10:00:00Z".parse().expect("parse now");
+        let mut creds = TemporaryCredentials {
+            access_key: "AK".to_string(),
+            secret_key: "SK".to_string(),
+            session_token: "TOKEN".to_string(),
+            expiration: Some("2026-01-15T11:00:00Z".parse().expect("parse expiration")),
+        };
+        assert_eq!(creds.seconds_left(now), Some(3600));
+
+        creds.expiration = Some("2026-01-15T09:00:00Z".parse().expect("parse expiration"));
+        assert_eq!(creds.seconds_left(now), Some(0));
+
+        creds.expiration = None;
+        assert_eq!(creds.seconds_left(now), None);
+    }
+}
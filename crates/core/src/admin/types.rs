@@ -37,6 +37,18 @@ impl std::str::FromStr for UserStatus {
     }
 }
 
+/// Split a comma-separated policy list, skipping empty entries
+///
+/// The server reports "no policy" as an empty string rather than omitting the field.
+fn split_policy_names(names: &str) -> Vec<String> {
+    names
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// Represents an IAM user
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -76,8 +88,8 @@ impl User {
     /// Get the list of policy names as a vector
     pub fn policies(&self) -> Vec<String> {
         self.policy_name
-            .as_ref()
-            .map(|s| s.split(',').map(|p| p.trim().to_string()).collect())
+            .as_deref()
+            .map(split_policy_names)
             .unwrap_or_default()
     }
 }
@@ -148,8 +160,8 @@ impl Group {
     /// Get the list of policy names as a vector
     pub fn policies(&self) -> Vec<String> {
         self.policy
-            .as_ref()
-            .map(|s| s.split(',').map(|p| p.trim().to_string()).collect())
+            .as_deref()
+            .map(split_policy_names)
             .unwrap_or_default()
     }
 }
@@ -178,6 +190,97 @@ impl Policy {
     pub fn parse_document(&self) -> Result<serde_json::Value, serde_json::Error> {
         serde_json::from_str(&self.policy)
     }
+
+    /// Check the document's structure without contacting the server
+    ///
+    /// Errors cover invalid JSON (with line and column), a missing `Version`
+    /// or `Statement`, and statements whose `Effect` is not `Allow`/`Deny`.
+    /// Unknown top-level keys are reported as warnings.
+    pub fn validate(&self) -> PolicyValidation {
+        let mut result = PolicyValidation::default();
+
+        let doc = match self.parse_document() {
+            Ok(doc) => doc,
+            Err(e) => {
+                result.errors.push(format!("Invalid JSON: {e}"));
+                return result;
+            }
+        };
+        let Some(doc) = doc.as_object() else {
+            result
+                .errors
+                .push("Policy document must be a JSON object".to_string());
+            return result;
+        };
+
+        match doc.get("Version") {
+            Some(serde_json::Value::String(_)) => {}
+            Some(_) => result.errors.push("Version: must be a string".to_string()),
+            None => result.errors.push("Version: missing".to_string()),
+        }
+
+        let statements = match doc.get("Statement") {
+            Some(serde_json::Value::Array(items)) => items.iter().collect(),
+            Some(item @ serde_json::Value::Object(_)) => vec![item],
+            Some(_) => {
+                result
+                    .errors
+                    .push("Statement: must be an object or an array".to_string());
+                Vec::new()
+            }
+            None => {
+                result.errors.push("Statement: missing".to_string());
+                Vec::new()
+            }
+        };
+        for (i, statement) in statements.into_iter().enumerate() {
+            let Some(statement) = statement.as_object() else {
+                result
+                    .errors
+                    .push(format!("Statement[{i}]: must be an object"));
+                continue;
+            };
+            match statement.get("Effect").and_then(|e| e.as_str()) {
+                Some("Allow") | Some("Deny") => {}
+                Some(other) => result.errors.push(format!(
+                    "Statement[{i}].Effect: must be \"Allow\" or \"Deny\", got \"{other}\""
+                )),
+                None => result
+                    .errors
+                    .push(format!("Statement[{i}].Effect: missing")),
+            }
+        }
+
+        for key in doc.keys() {
+            if !POLICY_TOP_LEVEL_KEYS.contains(&key.as_str()) {
+                result
+                    .warnings
+                    .push(format!("Unknown top-level key: {key}"));
+            }
+        }
+
+        result
+    }
+}
+
+/// Top-level keys of an IAM policy document
+const POLICY_TOP_LEVEL_KEYS: &[&str] = &["Version", "Id", "Statement"];
+
+/// Outcome of [`Policy::validate`]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PolicyValidation {
+    /// Problems that make the document unusable
+    pub errors: Vec<String>,
+
+    /// Suspicious but accepted content
+    pub warnings: Vec<String>,
+}
+
+impl PolicyValidation {
+    /// Whether the document has no errors
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
 }
 
 /// Summary information about a policy (without the full document)
@@ -228,6 +331,15 @@ impl ServiceAccount {
             expiration: None,
         }
     }
+
+    /// Account status as enabled/disabled (the API reports `on`/`off`)
+    pub fn status(&self) -> Option<UserStatus> {
+        match self.account_status.as_deref()? {
+            "on" | "enabled" => Some(UserStatus::Enabled),
+            "off" | "disabled" => Some(UserStatus::Disabled),
+            _ => None,
+        }
+    }
 }
 
 /// Entity type for policy attachment
@@ -299,6 +411,47 @@ pub struct CreateServiceAccountRequest {
     pub description: Option<String>,
 }
 
+/// Request to change an existing service account; unset fields are left as they are
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateServiceAccountRequest {
+    /// Replacement policy document (JSON string)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_policy: Option<String>,
+
+    /// Replacement secret key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_secret_key: Option<String>,
+
+    /// New account status (`on` or `off`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_status: Option<String>,
+
+    /// New name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+
+    /// New description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_description: Option<String>,
+
+    /// New expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_expiration: Option<String>,
+}
+
+impl UpdateServiceAccountRequest {
+    /// Whether the request changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.new_policy.is_none()
+            && self.new_secret_key.is_none()
+            && self.new_status.is_none()
+            && self.new_name.is_none()
+            && self.new_description.is_none()
+            && self.new_expiration.is_none()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,6 +499,9 @@ mod tests {
         assert_eq!(policies[0], "policy1");
         assert_eq!(policies[1], "policy2");
         assert_eq!(policies[2], "policy3");
+
+        user.policy_name = Some(String::new());
+        assert!(user.policies().is_empty());
     }
 
     #[test]
@@ -366,6 +522,9 @@ mod tests {
         assert_eq!(policies.len(), 2);
         assert_eq!(policies[0], "readonly");
         assert_eq!(policies[1], "writeonly");
+
+        group.policy = Some("readonly,,".to_string());
+        assert_eq!(group.policies(), vec!["readonly"]);
     }
 
     #[test]
@@ -382,6 +541,54 @@ mod tests {
         assert!(doc.get("Statement").is_some());
     }
 
+    #[test]
+    fn test_policy_validate_ok() {
+        let policy = Policy::new(
+            "test",
+            r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}]}"#,
+        );
+        let result = policy.validate();
+        assert!(result.is_valid());
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_policy_validate_errors() {
+        let policy = Policy::new("test", "{\"Version\": \"2012-10-17\",\n  \"Statement\": [}");
+        let result = policy.validate();
+        assert!(!result.is_valid());
+        assert!(result.errors[0].contains("line 2"));
+
+        let policy = Policy::new("test", r#"{"Statement":{"Effect":"Permit"},"Comment":"x"}"#);
+        let result = policy.validate();
+        assert_eq!(result.errors.len(), 2);
+        assert!(result.errors[0].starts_with("Version"));
+        assert!(result.errors[1].contains("Statement[0].Effect"));
+        assert_eq!(result.warnings, vec!["Unknown top-level key: Comment"]);
+    }
+
+    #[test]
+    fn test_update_service_account_request_serialization() {
+        let request = UpdateServiceAccountRequest {
+            new_secret_key: Some("newsecret123".to_string()),
+            new_status: Some("off".to_string()),
+            ..Default::default()
+        };
+        assert!(!request.is_empty());
+        assert!(UpdateServiceAccountRequest::default().is_empty());
+
+        let mut sa = ServiceAccount::new("key");
+        assert_eq!(sa.status(), None);
+        sa.account_status = Some("off".to_string());
+        assert_eq!(sa.status(), Some(UserStatus::Disabled));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"newSecretKey": "newsecret123", "newStatus": "off"})
+        );
+    }
+
     #[test]
     fn test_service_account_new() {
         let sa = ServiceAccount::new("accesskey123");
@@ -3,10 +3,14 @@
 //! Aliases are named references to S3-compatible storage endpoints,
 //! including connection details and credentials.
 
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
 use serde::{Deserialize, Serialize};
 
 use crate::config::ConfigManager;
 use crate::error::{Error, Result};
+use crate::secret;
 
 /// Retry configuration for an alias
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,10 +110,33 @@ pub struct Alias {
     #[serde(default)]
     pub insecure: bool,
 
+    /// Send unsigned requests, for reading public buckets without credentials
+    #[serde(default)]
+    pub anonymous: bool,
+
     /// Path to custom CA bundle
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ca_bundle: Option<String>,
 
+    /// Minimum TLS version: "1.2" or "1.3"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_min_version: Option<String>,
+
+    /// Path to a PEM client certificate for mutual TLS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<String>,
+
+    /// Path to the PEM private key for `client_cert`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<String>,
+
+    /// Static host mappings (`HOST:ADDR`) used instead of DNS
+    ///
+    /// A host may be listed several times, e.g. once with an IPv4 and once
+    /// with an IPv6 address, and the connection tries each.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resolve: Vec<String>,
+
     /// Retry configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retry: Option<RetryConfig>,
@@ -117,6 +144,15 @@ pub struct Alias {
     /// Timeout configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout: Option<TimeoutConfig>,
+
+    /// Storage class for uploads that don't pass `--storage-class`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_storage_class: Option<String>,
+
+    /// Content types by file extension (lowercase, no dot) for uploads that
+    /// don't pass `--content-type`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub content_types: BTreeMap<String, String>,
 }
 
 fn default_region() -> String {
@@ -148,9 +184,16 @@ impl Alias {
             signature: default_signature(),
             bucket_lookup: default_bucket_lookup(),
             insecure: false,
+            anonymous: false,
             ca_bundle: None,
+            tls_min_version: None,
+            client_cert: None,
+            client_key: None,
+            resolve: Vec::new(),
             retry: None,
             timeout: None,
+            default_storage_class: None,
+            content_types: BTreeMap::new(),
         }
     }
 
@@ -163,6 +206,37 @@ impl Alias {
     pub fn timeout_config(&self) -> TimeoutConfig {
         self.timeout.clone().unwrap_or_default()
     }
+
+    /// Configured content type for an object key or file name, by extension
+    pub fn content_type_for(&self, name: &str) -> Option<&str> {
+        let file_name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+        let (_, extension) = file_name.rsplit_once('.')?;
+        self.content_types
+            .get(&extension.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Parse the static host mappings
+    pub fn resolve_overrides(&self) -> Result<Vec<(String, IpAddr)>> {
+        self.resolve.iter().map(|e| parse_resolve(e)).collect()
+    }
+}
+
+/// Parse a `HOST:ADDR` host mapping; IPv6 addresses may be bracketed
+pub fn parse_resolve(entry: &str) -> Result<(String, IpAddr)> {
+    let invalid = || {
+        Error::Config(format!(
+            "Invalid resolve entry '{entry}', expected HOST:ADDR"
+        ))
+    };
+
+    let (host, addr) = entry.split_once(':').ok_or_else(invalid)?;
+    let addr = addr.trim_start_matches('[').trim_end_matches(']');
+    if host.is_empty() {
+        return Err(invalid());
+    }
+    let ip = addr.parse::<IpAddr>().map_err(|_| invalid())?;
+    Ok((host.to_ascii_lowercase(), ip))
 }
 
 /// Manager for alias operations
@@ -188,39 +262,76 @@ impl AliasManager {
         Ok(config.aliases)
     }
 
-    /// Get an alias by name
+    /// Get an alias by name, resolving its secret key if needed
+    ///
+    /// An encrypted secret requires the passphrase in `RC_CONFIG_PASSPHRASE`;
+    /// a `keyring:` reference is read from the OS credential store.
     pub fn get(&self, name: &str) -> Result<Alias> {
         let config = self.config_manager.load()?;
-        config
+        let mut alias = config
             .aliases
             .into_iter()
             .find(|a| a.name == name)
-            .ok_or_else(|| Error::AliasNotFound(name.to_string()))
+            .ok_or_else(|| Error::AliasNotFound(name.to_string()))?;
+
+        if secret::is_encrypted(&alias.secret_key) {
+            let passphrase = require_passphrase()?;
+            alias.secret_key = secret::decrypt_secret(&alias.secret_key, &passphrase)?;
+        } else if secret::is_keyring_ref(&alias.secret_key) {
+            alias.secret_key = secret::load_from_keyring(&alias.secret_key)?;
+        }
+        Ok(alias)
     }
 
     /// Add or update an alias
-    pub fn set(&self, alias: Alias) -> Result<()> {
+    ///
+    /// When the stored secrets are encrypted, the new secret is encrypted too.
+    pub fn set(&self, mut alias: Alias) -> Result<()> {
         let mut config = self.config_manager.load()?;
 
-        // Remove existing alias with same name
+        if !secret::is_encrypted(&alias.secret_key)
+            && !secret::is_keyring_ref(&alias.secret_key)
+            && config
+                .aliases
+                .iter()
+                .any(|a| secret::is_encrypted(&a.secret_key))
+        {
+            let passphrase = require_passphrase()?;
+            alias.secret_key = secret::encrypt_secret(&alias.secret_key, &passphrase)?;
+        }
+
+        // Remove existing alias with same name, remembering a keyring entry it no longer uses
+        let stale = config
+            .aliases
+            .iter()
+            .find(|a| a.name == alias.name && a.secret_key != alias.secret_key)
+            .map(|a| a.secret_key.clone());
         config.aliases.retain(|a| a.name != alias.name);
         config.aliases.push(alias);
 
-        self.config_manager.save(&config)
+        self.config_manager.save(&config)?;
+        match stale {
+            Some(old) => secret::delete_from_keyring(&old),
+            None => Ok(()),
+        }
     }
 
     /// Remove an alias
     pub fn remove(&self, name: &str) -> Result<()> {
         let mut config = self.config_manager.load()?;
-        let original_len = config.aliases.len();
-
-        config.aliases.retain(|a| a.name != name);
+        let removed = config
+            .aliases
+            .iter()
+            .position(|a| a.name == name)
+            .map(|i| config.aliases.remove(i))
+            .ok_or_else(|| Error::AliasNotFound(name.to_string()))?;
 
-        if config.aliases.len() == original_len {
-            return Err(Error::AliasNotFound(name.to_string()));
+        if config.current_alias.as_deref() == Some(name) {
+            config.current_alias = None;
         }
 
-        self.config_manager.save(&config)
+        self.config_manager.save(&config)?;
+        secret::delete_from_keyring(&removed.secret_key)
     }
 
     /// Check if an alias exists
@@ -228,6 +339,86 @@ impl AliasManager {
         let config = self.config_manager.load()?;
         Ok(config.aliases.iter().any(|a| a.name == name))
     }
+
+    /// Get the name of the current alias, if one is set
+    pub fn current(&self) -> Result<Option<String>> {
+        let config = self.config_manager.load()?;
+        Ok(config.current_alias)
+    }
+
+    /// Make an existing alias the current alias
+    pub fn set_current(&self, name: &str) -> Result<()> {
+        let mut config = self.config_manager.load()?;
+
+        if !config.aliases.iter().any(|a| a.name == name) {
+            return Err(Error::AliasNotFound(name.to_string()));
+        }
+
+        config.current_alias = Some(name.to_string());
+        self.config_manager.save(&config)
+    }
+
+    /// Whether any stored secret key is encrypted
+    pub fn secrets_encrypted(&self) -> Result<bool> {
+        let config = self.config_manager.load()?;
+        Ok(config
+            .aliases
+            .iter()
+            .any(|a| secret::is_encrypted(&a.secret_key)))
+    }
+
+    /// Encrypt every plaintext secret key, returning the number changed
+    ///
+    /// Secrets that are already encrypted must decrypt with the same
+    /// passphrase, so a config never mixes passphrases.
+    pub fn encrypt_secrets(&self, passphrase: &str) -> Result<usize> {
+        let mut config = self.config_manager.load()?;
+        let mut changed = 0;
+
+        for alias in &mut config.aliases {
+            if secret::is_keyring_ref(&alias.secret_key) {
+                continue;
+            }
+            if secret::is_encrypted(&alias.secret_key) {
+                secret::decrypt_secret(&alias.secret_key, passphrase)?;
+            } else {
+                alias.secret_key = secret::encrypt_secret(&alias.secret_key, passphrase)?;
+                changed += 1;
+            }
+        }
+
+        if changed > 0 {
+            self.config_manager.save(&config)?;
+        }
+        Ok(changed)
+    }
+
+    /// Decrypt every encrypted secret key, returning the number changed
+    pub fn decrypt_secrets(&self, passphrase: &str) -> Result<usize> {
+        let mut config = self.config_manager.load()?;
+        let mut changed = 0;
+
+        for alias in &mut config.aliases {
+            if secret::is_encrypted(&alias.secret_key) {
+                alias.secret_key = secret::decrypt_secret(&alias.secret_key, passphrase)?;
+                changed += 1;
+            }
+        }
+
+        if changed > 0 {
+            self.config_manager.save(&config)?;
+        }
+        Ok(changed)
+    }
+}
+
+fn require_passphrase() -> Result<String> {
+    secret::passphrase_from_env().ok_or_else(|| {
+        Error::Config(format!(
+            "Secrets in the configuration are encrypted; set {} to the passphrase",
+            secret::PASSPHRASE_ENV
+        ))
+    })
 }
 
 #[cfg(test)]
@@ -252,6 +443,40 @@ mod tests {
         assert_eq!(alias.signature, "v4");
         assert_eq!(alias.bucket_lookup, "auto");
         assert!(!alias.insecure);
+        assert!(!alias.anonymous);
+    }
+
+    #[test]
+    fn test_content_type_for() {
+        let mut alias = Alias::new("test", "http://localhost:9000", "key", "secret");
+        alias.content_types.insert(
+            "parquet".to_string(),
+            "application/vnd.apache.parquet".to_string(),
+        );
+
+        assert_eq!(
+            alias.content_type_for("data/2024/part-0.PARQUET"),
+            Some("application/vnd.apache.parquet")
+        );
+        assert_eq!(alias.content_type_for("notes.txt"), None);
+        assert_eq!(alias.content_type_for("parquet"), None);
+        assert_eq!(alias.content_type_for("dir.parquet/file"), None);
+    }
+
+    #[test]
+    fn test_parse_resolve() {
+        let (host, ip) = parse_resolve("minio.svc:10.0.0.5").unwrap();
+        assert_eq!(host, "minio.svc");
+        assert_eq!(ip.to_string(), "10.0.0.5");
+
+        let (host, ip) = parse_resolve("S3.Local:[fd00::5]").unwrap();
+        assert_eq!(host, "s3.local");
+        assert!(ip.is_ipv6());
+        assert!(parse_resolve("s3.local:fd00::5").unwrap().1.is_ipv6());
+
+        assert!(parse_resolve("s3.local").is_err());
+        assert!(parse_resolve(":10.0.0.5").is_err());
+        assert!(parse_resolve("s3.local:not-an-ip").is_err());
     }
 
     #[test]
@@ -327,4 +552,47 @@ mod tests {
         assert_eq!(aliases.len(), 1);
         assert_eq!(aliases[0].endpoint, "http://new:9000");
     }
+
+    #[test]
+    fn test_alias_manager_current() {
+        let (manager, _temp_dir) = temp_alias_manager();
+        assert_eq!(manager.current().unwrap(), None);
+
+        let result = manager.set_current("missing");
+        assert!(matches!(result.unwrap_err(), Error::AliasNotFound(_)));
+
+        manager
+            .set(Alias::new("prod", "http://prod:9000", "a", "b"))
+            .unwrap();
+        manager.set_current("prod").unwrap();
+        assert_eq!(manager.current().unwrap().as_deref(), Some("prod"));
+
+        // Removing the current alias clears it
+        manager.remove("prod").unwrap();
+        assert_eq!(manager.current().unwrap(), None);
+    }
+
+    #[test]
+    fn test_alias_manager_encrypt_and_decrypt_secrets() {
+        let (manager, _temp_dir) = temp_alias_manager();
+        manager
+            .set(Alias::new("a", "http://a:9000", "ak", "plain-secret"))
+            .unwrap();
+
+        assert_eq!(manager.encrypt_secrets("pass").unwrap(), 1);
+        assert!(manager.secrets_encrypted().unwrap());
+        let stored = manager.list().unwrap();
+        assert!(secret::is_encrypted(&stored[0].secret_key));
+
+        // Already encrypted secrets are left alone, but must match the passphrase
+        assert_eq!(manager.encrypt_secrets("pass").unwrap(), 0);
+        assert!(matches!(
+            manager.encrypt_secrets("other").unwrap_err(),
+            Error::Auth(_)
+        ));
+
+        assert_eq!(manager.decrypt_secrets("pass").unwrap(), 1);
+        assert!(!manager.secrets_encrypted().unwrap());
+        assert_eq!(manager.get("a").unwrap().secret_key, "plain-secret");
+    }
 }
@@ -0,0 +1,127 @@
+//! Backend registry
+//!
+//! Maps an alias endpoint's URL scheme to the ObjectStore implementation that
+//! serves it. The registry starts empty and each backend crate registers its
+//! schemes (rc-s3 registers `http` and `https`).
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::alias::Alias;
+use crate::error::{Error, Result};
+use crate::traits::ObjectStore;
+
+/// Future returned by a backend constructor
+pub type BackendFuture = Pin<Box<dyn Future<Output = Result<Box<dyn ObjectStore>>> + Send>>;
+
+/// Builds a client for an alias
+pub type BackendConstructor = fn(Alias) -> BackendFuture;
+
+/// Registry of ObjectStore constructors keyed by endpoint scheme
+#[derive(Clone)]
+pub struct BackendRegistry {
+    constructors: HashMap<String, BackendConstructor>,
+}
+
+impl BackendRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            constructors: HashMap::new(),
+        }
+    }
+
+    /// Register (or replace) the constructor for a scheme
+    pub fn register(&mut self, scheme: &str, constructor: BackendConstructor) {
+        self.constructors
+            .insert(scheme.to_ascii_lowercase(), constructor);
+    }
+
+    /// Whether a constructor is registered for a scheme
+    pub fn supports(&self, scheme: &str) -> bool {
+        self.constructors.contains_key(&scheme.to_ascii_lowercase())
+    }
+
+    /// Build the client for an alias based on its endpoint scheme
+    pub async fn backend_for(&self, alias: &Alias) -> Result<Box<dyn ObjectStore>> {
+        let url = url::Url::parse(&alias.endpoint)?;
+        let constructor = self.constructors.get(url.scheme()).ok_or_else(|| {
+            Error::UnsupportedFeature(format!(
+                "No backend for '{}://' endpoints (alias '{}')",
+                url.scheme(),
+                alias.name
+            ))
+        })?;
+        constructor(alias.clone()).await
+    }
+}
+
+impl Default for BackendRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_registered_backend_serves_alias() {
+        fn local(alias: Alias) -> BackendFuture {
+            Box::pin(async move {
+                let root = url::Url::parse(&alias.endpoint)?
+                    .to_file_path()
+                    .map_err(|()| Error::Config(alias.endpoint.clone()))?;
+                Ok(Box::new(crate::LocalStore::new(root)) as Box<dyn ObjectStore>)
+            })
+        }
+
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let endpoint = url::Url::from_directory_path(dir.path()).expect("file url");
+        let alias = Alias::new("local", endpoint.as_str(), "", "");
+
+        let mut registry = BackendRegistry::new();
+        assert!(!registry.supports("file"));
+        registry.register("file", local);
+        let store = registry.backend_for(&alias).await.unwrap();
+        store.create_bucket("bucket").await.unwrap();
+        assert!(dir.path().join("bucket").is_dir());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_scheme() {
+        let registry = BackendRegistry::new();
+        let alias = Alias::new("remote", "https://s3.example.com", "ak", "sk");
+
+        match registry.backend_for(&alias).await {
+            Err(Error::UnsupportedFeature(msg)) => {
+                assert!(msg.contains("https://"));
+                assert!(msg.contains("remote"));
+            }
+            other => panic!("expected UnsupportedFeature, got {:?}", other.err()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_custom_scheme() {
+        fn memory(alias: Alias) -> BackendFuture {
+            Box::pin(
+                async move { Err(Error::General(format!("memory backend for {}", alias.name))) },
+            )
+        }
+
+        let mut registry = BackendRegistry::new();
+        registry.register("MEM", memory);
+        assert!(registry.supports("mem"));
+
+        let alias = Alias::new("m", "mem://anything", "", "");
+        let err = registry
+            .backend_for(&alias)
+            .await
+            .err()
+            .expect("constructor error");
+        assert_eq!(err.to_string(), "memory backend for m");
+    }
+}
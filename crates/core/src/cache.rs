@@ -0,0 +1,149 @@
+//! Capability cache
+//!
+//! Capability detection costs several requests, so results are cached per
+//! alias in `capabilities.json` next to the config file and reused until they
+//! expire.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::alias::Alias;
+use crate::config::ConfigManager;
+use crate::error::Result;
+use crate::traits::Capabilities;
+
+/// How long detected capabilities stay valid (24 hours)
+pub const CAPABILITY_CACHE_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// Cache file name, stored in the config directory
+const CACHE_FILE: &str = "capabilities.json";
+
+/// Cached detection result for one alias
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Endpoint the capabilities were detected against
+    endpoint: String,
+    /// Unix timestamp (seconds) of the detection
+    detected_at: i64,
+    capabilities: Capabilities,
+}
+
+/// Per-alias cache of detected backend capabilities
+#[derive(Debug)]
+pub struct CapabilityCache {
+    path: PathBuf,
+}
+
+impl CapabilityCache {
+    /// Create a cache stored next to the default config file
+    pub fn new() -> Result<Self> {
+        let config_manager = ConfigManager::new()?;
+        Ok(Self::with_path(
+            config_manager.config_path().with_file_name(CACHE_FILE),
+        ))
+    }
+
+    /// Create a cache at a custom path (useful for testing)
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Get cached capabilities for an alias, if present and not expired
+    ///
+    /// Entries recorded for a different endpoint are ignored.
+    pub fn get(&self, alias: &Alias) -> Option<Capabilities> {
+        self.get_at(alias, jiff::Timestamp::now().as_second())
+    }
+
+    /// Store capabilities detected for an alias
+    pub fn put(&self, alias: &Alias, capabilities: &Capabilities) -> Result<()> {
+        self.put_at(alias, capabilities, jiff::Timestamp::now().as_second())
+    }
+
+    fn get_at(&self, alias: &Alias, now: i64) -> Option<Capabilities> {
+        let entry = self.load().remove(&alias.name)?;
+        let fresh = now - entry.detected_at < CAPABILITY_CACHE_TTL_SECS;
+        (fresh && entry.endpoint == alias.endpoint).then_some(entry.capabilities)
+    }
+
+    fn put_at(&self, alias: &Alias, capabilities: &Capabilities, now: i64) -> Result<()> {
+        let mut entries = self.load();
+        entries.insert(
+            alias.name.clone(),
+            CacheEntry {
+                endpoint: alias.endpoint.clone(),
+                detected_at: now,
+                capabilities: capabilities.clone(),
+            },
+        );
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(&entries)?)?;
+        Ok(())
+    }
+
+    /// Read all entries; a missing or unreadable cache is treated as empty
+    fn load(&self) -> BTreeMap<String, CacheEntry> {
+        std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn temp_cache() -> (CapabilityCache, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CapabilityCache::with_path(temp_dir.path().join(CACHE_FILE));
+        (cache, temp_dir)
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let (cache, _temp_dir) = temp_cache();
+        let alias = Alias::new("local", "http://localhost:9000", "a", "b");
+        let caps = Capabilities {
+            versioning: true,
+            tagging: true,
+            ..Default::default()
+        };
+
+        assert!(cache.get(&alias).is_none());
+        cache.put(&alias, &caps).unwrap();
+        assert_eq!(cache.get(&alias), Some(caps));
+    }
+
+    #[test]
+    fn test_cache_expires() {
+        let (cache, _temp_dir) = temp_cache();
+        let alias = Alias::new("local", "http://localhost:9000", "a", "b");
+        cache
+            .put_at(&alias, &Capabilities::default(), 1_000)
+            .unwrap();
+
+        assert!(cache.get_at(&alias, 1_000 + 60).is_some());
+        assert!(
+            cache
+                .get_at(&alias, 1_000 + CAPABILITY_CACHE_TTL_SECS)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_cache_ignores_changed_endpoint() {
+        let (cache, _temp_dir) = temp_cache();
+        let alias = Alias::new("local", "http://localhost:9000", "a", "b");
+        cache.put(&alias, &Capabilities::default()).unwrap();
+
+        let moved = Alias::new("local", "http://other:9000", "a", "b");
+        assert!(cache.get(&moved).is_none());
+    }
+}
@@ -9,7 +9,7 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
-use crate::alias::Alias;
+use crate::alias::{Alias, RetryConfig, TimeoutConfig};
 use crate::error::{Error, Result};
 
 /// Current configuration schema version
@@ -39,6 +39,10 @@ pub struct Config {
     /// Configured aliases
     #[serde(default)]
     pub aliases: Vec<Alias>,
+
+    /// Alias used when a path names no alias (set with `rc alias use`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_alias: Option<String>,
 }
 
 /// Default settings for CLI behavior
@@ -85,10 +89,24 @@ impl Default for Config {
             schema_version: SCHEMA_VERSION,
             defaults: Defaults::default(),
             aliases: Vec::new(),
+            current_alias: None,
         }
     }
 }
 
+/// Outcome of [`ConfigManager::migrate_file`]
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationReport {
+    /// Schema version found on disk
+    pub from_version: u32,
+
+    /// Schema version written back
+    pub to_version: u32,
+
+    /// Human-readable description of each change made
+    pub changes: Vec<String>,
+}
+
 /// Configuration manager handles loading and saving config
 #[derive(Debug)]
 pub struct ConfigManager {
@@ -171,6 +189,62 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// Rewrite the configuration file in the current format
+    ///
+    /// Upgrades the schema version and writes out per-alias settings that
+    /// older files leave implicit (retry and timeout defaults). The file is
+    /// only saved when something changed; a missing file is left missing.
+    pub fn migrate_file(&self) -> Result<MigrationReport> {
+        if !self.config_path.exists() {
+            return Ok(MigrationReport {
+                from_version: SCHEMA_VERSION,
+                to_version: SCHEMA_VERSION,
+                changes: Vec::new(),
+            });
+        }
+
+        let content = std::fs::read_to_string(&self.config_path)?;
+        let mut config: Config = toml::from_str(&content)?;
+        let from_version = config.schema_version;
+        if from_version > SCHEMA_VERSION {
+            return Err(Error::Config(format!(
+                "Configuration file version {from_version} is newer than supported version {SCHEMA_VERSION}. Please upgrade rc."
+            )));
+        }
+
+        let mut changes = Vec::new();
+        if from_version < SCHEMA_VERSION {
+            config = self.migrate(config)?;
+            changes.push(format!("schema_version {from_version} -> {SCHEMA_VERSION}"));
+        }
+        for alias in &mut config.aliases {
+            if alias.retry.is_none() {
+                alias.retry = Some(RetryConfig::default());
+                changes.push(format!(
+                    "alias '{}': added default retry settings",
+                    alias.name
+                ));
+            }
+            if alias.timeout.is_none() {
+                alias.timeout = Some(TimeoutConfig::default());
+                changes.push(format!(
+                    "alias '{}': added default timeout settings",
+                    alias.name
+                ));
+            }
+        }
+
+        if !changes.is_empty() {
+            self.save(&config)?;
+        }
+
+        Ok(MigrationReport {
+            from_version,
+            to_version: config.schema_version,
+            changes,
+        })
+    }
+
     /// Migrate configuration from older schema version
     fn migrate(&self, config: Config) -> Result<Config> {
         let mut config = config;
@@ -235,9 +309,16 @@ mod tests {
             signature: "v4".to_string(),
             bucket_lookup: "auto".to_string(),
             insecure: false,
+            anonymous: false,
             ca_bundle: None,
+            tls_min_version: None,
+            client_cert: None,
+            client_key: None,
+            resolve: Vec::new(),
             retry: None,
             timeout: None,
+            default_storage_class: None,
+            content_types: Default::default(),
         });
 
         manager.save(&config).unwrap();
@@ -247,6 +328,42 @@ mod tests {
         assert_eq!(loaded.aliases[0].name, "test");
     }
 
+    #[test]
+    fn test_migrate_file_fills_alias_defaults() {
+        let (manager, _temp_dir) = temp_config_manager();
+        let content = format!(
+            r#"
+            schema_version = {SCHEMA_VERSION}
+
+            [[aliases]]
+            name = "local"
+            endpoint = "http://localhost:9000"
+            access_key = "accesskey"
+            secret_key = "secretkey"
+            "#
+        );
+        std::fs::write(manager.config_path(), content).unwrap();
+
+        let report = manager.migrate_file().unwrap();
+        assert_eq!(report.from_version, SCHEMA_VERSION);
+        assert_eq!(report.changes.len(), 2);
+
+        let loaded = manager.load().unwrap();
+        assert_eq!(loaded.aliases[0].retry.as_ref().unwrap().max_attempts, 3);
+        assert!(loaded.aliases[0].timeout.is_some());
+
+        // A second run has nothing left to do
+        assert!(manager.migrate_file().unwrap().changes.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_file_missing() {
+        let (manager, _temp_dir) = temp_config_manager();
+        let report = manager.migrate_file().unwrap();
+        assert!(report.changes.is_empty());
+        assert!(!manager.config_path().exists());
+    }
+
     #[test]
     fn test_schema_version_too_new() {
         let (manager, _temp_dir) = temp_config_manager();
@@ -18,6 +18,10 @@ pub enum Error {
     #[error("Invalid path: {0}")]
     InvalidPath(String),
 
+    /// Invalid argument, such as a byte range that cannot be expressed
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     /// Alias not found
     #[error("Alias not found: {0}")]
     AliasNotFound(String),
@@ -66,6 +70,10 @@ pub enum Error {
     #[error("Unsupported feature: {0}")]
     UnsupportedFeature(String),
 
+    /// Operation not implemented by the backend
+    #[error("Not implemented: {0}")]
+    NotImplemented(String),
+
     /// General error
     #[error("{0}")]
     General(String),
@@ -76,12 +84,14 @@ impl Error {
     pub const fn exit_code(&self) -> i32 {
         match self {
             Error::InvalidPath(_) => 2,                        // UsageError
+            Error::InvalidArgument(_) => 2,                    // UsageError
             Error::Config(_) => 2,                             // UsageError
             Error::Network(_) => 3,                            // NetworkError
             Error::Auth(_) => 4,                               // AuthError
             Error::NotFound(_) | Error::AliasNotFound(_) => 5, // NotFound
             Error::Conflict(_) | Error::AliasExists(_) => 6,   // Conflict
             Error::UnsupportedFeature(_) => 7,                 // UnsupportedFeature
+            Error::NotImplemented(_) => 8,                     // NotImplemented
             _ => 1,                                            // GeneralError
         }
     }
@@ -94,6 +104,7 @@ mod tests {
     #[test]
     fn test_error_exit_codes() {
         assert_eq!(Error::InvalidPath("test".into()).exit_code(), 2);
+        assert_eq!(Error::InvalidArgument("test".into()).exit_code(), 2);
         assert_eq!(Error::Config("test".into()).exit_code(), 2);
         assert_eq!(Error::Network("test".into()).exit_code(), 3);
         assert_eq!(Error::Auth("test".into()).exit_code(), 4);
@@ -102,6 +113,7 @@ mod tests {
         assert_eq!(Error::Conflict("test".into()).exit_code(), 6);
         assert_eq!(Error::AliasExists("test".into()).exit_code(), 6);
         assert_eq!(Error::UnsupportedFeature("test".into()).exit_code(), 7);
+        assert_eq!(Error::NotImplemented("test".into()).exit_code(), 8);
         assert_eq!(Error::General("test".into()).exit_code(), 1);
     }
 
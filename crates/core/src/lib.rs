@@ -4,22 +4,36 @@
 //! - Configuration management
 //! - Alias management
 //! - Path parsing and resolution
+//! - Capability caching
+//! - Passphrase encryption of stored secrets
 //! - ObjectStore trait for S3 operations
+//! - Backend registry and a local filesystem ObjectStore
 //!
 //! This crate is designed to be independent of any specific S3 SDK,
 //! allowing for easy testing and potential future support for other backends.
 
 pub mod admin;
 pub mod alias;
+pub mod backend;
+pub mod cache;
 pub mod config;
 pub mod error;
+pub mod local;
 pub mod path;
 pub mod retry;
+pub mod secret;
 pub mod traits;
 
 pub use alias::{Alias, AliasManager};
-pub use config::{Config, ConfigManager};
+pub use backend::{BackendConstructor, BackendFuture, BackendRegistry};
+pub use cache::CapabilityCache;
+pub use config::{Config, ConfigManager, Defaults, MigrationReport};
 pub use error::{Error, Result};
+pub use local::LocalStore;
 pub use path::{ParsedPath, RemotePath, parse_path};
 pub use retry::{RetryBuilder, is_retryable_error, retry_with_backoff};
-pub use traits::{Capabilities, ListOptions, ListResult, ObjectInfo, ObjectStore, ObjectVersion};
+pub use traits::{
+    Acl, AclGrant, CannedAcl, Capabilities, DeleteError, DeleteResult, ListOptions, ListResult,
+    NotificationKind, NotificationRule, ObjectChecksum, ObjectInfo, ObjectLockConfig, ObjectStore,
+    ObjectVersion, Owner, PutOptions,
+};
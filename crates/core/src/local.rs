@@ -0,0 +1,717 @@
+//! Local filesystem ObjectStore
+//!
+//! Lets command logic be tested without a server. Buckets are directories under the root and objects are files;
+//! `/` in a key maps to subdirectories. Headers and user metadata are not
+//! stored, and optional features (versioning, tags, ACLs, notifications,
+//! presigning) report `UnsupportedFeature`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::error::{Error, Result};
+use crate::path::RemotePath;
+use crate::traits::{
+    Acl, CannedAcl, Capabilities, DeleteError, DeleteResult, ListOptions, ListResult,
+    NotificationRule, ObjectChecksum, ObjectInfo, ObjectLockConfig, ObjectStore, ObjectVersion,
+    PutOptions,
+};
+
+/// Keys returned per listing page when `max_keys` is not set
+const DEFAULT_MAX_KEYS: usize = 1000;
+
+/// ObjectStore backed by a directory on the local filesystem
+#[derive(Debug, Clone)]
+pub struct LocalStore {
+    root: PathBuf,
+}
+
+impl LocalStore {
+    /// Create a store rooted at `root`; each subdirectory is a bucket
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Root directory of the store
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn bucket_dir(&self, bucket: &str) -> Result<PathBuf> {
+        if bucket.is_empty() || bucket == "." || bucket == ".." || bucket.contains(['/', '\\']) {
+            return Err(Error::InvalidPath(format!("Invalid bucket name: {bucket}")));
+        }
+        Ok(self.root.join(bucket))
+    }
+
+    /// Map an object to its file, rejecting keys that would escape the bucket
+    fn object_path(&self, path: &RemotePath) -> Result<PathBuf> {
+        let mut file = self.bucket_dir(&path.bucket)?;
+        if path.key.is_empty() || path.key.ends_with('/') {
+            return Err(Error::InvalidPath(format!(
+                "Object key required (directory keys are not supported): {path}"
+            )));
+        }
+        for segment in path.key.split('/') {
+            if segment.is_empty() || segment == "." || segment == ".." || segment.contains('\\') {
+                return Err(Error::InvalidPath(format!(
+                    "Invalid object key: {}",
+                    path.key
+                )));
+            }
+            file.push(segment);
+        }
+        Ok(file)
+    }
+
+    async fn existing_bucket(&self, bucket: &str) -> Result<PathBuf> {
+        let dir = self.bucket_dir(bucket)?;
+        if is_dir(&dir).await {
+            Ok(dir)
+        } else {
+            Err(Error::NotFound(format!("Bucket not found: {bucket}")))
+        }
+    }
+
+    /// Remove directories left empty by a delete, up to the bucket directory
+    async fn prune_empty_dirs(&self, bucket_dir: &Path, file: &Path) {
+        let mut dir = file.parent();
+        while let Some(current) = dir {
+            if current == bucket_dir || tokio::fs::remove_dir(current).await.is_err() {
+                break;
+            }
+            dir = current.parent();
+        }
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LocalStore {
+    async fn list_buckets(&self) -> Result<Vec<ObjectInfo>> {
+        let mut entries = match tokio::fs::read_dir(&self.root).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut buckets = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let metadata = entry.metadata().await?;
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                let mut bucket = ObjectInfo::bucket(name);
+                bucket.last_modified = modified(&metadata);
+                buckets.push(bucket);
+            }
+        }
+        buckets.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(buckets)
+    }
+
+    async fn list_objects(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult> {
+        let dir = self.existing_bucket(&path.bucket).await?;
+
+        let prefix = match &options.prefix {
+            Some(p) => format!("{}{p}", path.key),
+            None => path.key.clone(),
+        };
+        let delimiter = (!options.recursive)
+            .then(|| options.delimiter.clone().unwrap_or_else(|| "/".to_string()))
+            .filter(|d| !d.is_empty());
+        let after = options.continuation_token.or(options.start_after);
+        let max_keys = options
+            .max_keys
+            .and_then(|m| usize::try_from(m).ok())
+            .filter(|m| *m > 0)
+            .unwrap_or(DEFAULT_MAX_KEYS);
+
+        // The walk recurses through the whole bucket, so it runs off the async workers
+        let mut files = tokio::task::spawn_blocking(move || {
+            let mut files = Vec::new();
+            walk_files(&dir, "", &mut files).map(|()| files)
+        })
+        .await
+        .map_err(|e| Error::General(format!("Listing task failed: {e}")))??;
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut items: Vec<ObjectInfo> = Vec::new();
+        let mut truncated = false;
+        for (key, metadata) in files {
+            let Some(rest) = key.strip_prefix(prefix.as_str()) else {
+                continue;
+            };
+
+            // Keys below a delimiter collapse into one common prefix entry
+            let common_prefix = delimiter.as_deref().and_then(|d| {
+                rest.find(d)
+                    .map(|i| key[..prefix.len() + i + d.len()].to_string())
+            });
+            let entry_key = common_prefix.as_deref().unwrap_or(&key);
+
+            if after.as_deref().is_some_and(|a| entry_key <= a) {
+                continue;
+            }
+            if items.last().is_some_and(|i| i.key == entry_key) {
+                continue;
+            }
+            if items.len() == max_keys {
+                truncated = true;
+                break;
+            }
+
+            items.push(match common_prefix {
+                Some(dir_key) => ObjectInfo::dir(dir_key),
+                None => file_info(&key, &metadata),
+            });
+        }
+
+        let continuation_token = if truncated {
+            items.last().map(|i| i.key.clone())
+        } else {
+            None
+        };
+        Ok(ListResult {
+            items,
+            truncated,
+            continuation_token,
+        })
+    }
+
+    async fn head_object(&self, path: &RemotePath) -> Result<ObjectInfo> {
+        let file = self.object_path(path)?;
+        match tokio::fs::metadata(&file).await {
+            Ok(metadata) if metadata.is_file() => Ok(file_info(&path.key, &metadata)),
+            Ok(_) => Err(Error::NotFound(path.to_string())),
+            Err(e) => Err(io_error(e, || path.to_string())),
+        }
+    }
+
+    async fn object_checksum(&self, path: &RemotePath) -> Result<Option<ObjectChecksum>> {
+        self.head_object(path).await?;
+        Ok(None)
+    }
+
+    async fn object_exists(&self, path: &RemotePath) -> Result<bool> {
+        let file = self.object_path(path)?;
+        Ok(tokio::fs::metadata(&file)
+            .await
+            .is_ok_and(|metadata| metadata.is_file()))
+    }
+
+    async fn bucket_exists(&self, bucket: &str) -> Result<bool> {
+        Ok(is_dir(&self.bucket_dir(bucket)?).await)
+    }
+
+    async fn create_bucket(&self, bucket: &str) -> Result<()> {
+        let dir = self.bucket_dir(bucket)?;
+        if tokio::fs::try_exists(&dir).await? {
+            return Err(Error::Conflict(format!(
+                "BucketAlreadyOwnedByYou: {bucket}"
+            )));
+        }
+        tokio::fs::create_dir_all(&dir).await?;
+        Ok(())
+    }
+
+    async fn delete_bucket(&self, bucket: &str) -> Result<()> {
+        let dir = self.existing_bucket(bucket).await?;
+        if tokio::fs::read_dir(&dir)
+            .await?
+            .next_entry()
+            .await?
+            .is_some()
+        {
+            return Err(Error::Conflict(format!("BucketNotEmpty: {bucket}")));
+        }
+        tokio::fs::remove_dir(&dir).await?;
+        Ok(())
+    }
+
+    async fn capabilities(&self, _bucket: &str) -> Result<Capabilities> {
+        Ok(Capabilities::default())
+    }
+
+    async fn get_object(&self, path: &RemotePath) -> Result<Vec<u8>> {
+        let file = self.object_path(path)?;
+        tokio::fs::read(&file)
+            .await
+            .map_err(|e| io_error(e, || path.to_string()))
+    }
+
+    async fn get_object_range(
+        &self,
+        path: &RemotePath,
+        offset: u64,
+        length: Option<u64>,
+    ) -> Result<Vec<u8>> {
+        let data = self.get_object(path).await?;
+        let start = usize::try_from(offset)
+            .unwrap_or(usize::MAX)
+            .min(data.len());
+        let end = match length {
+            Some(len) => start.saturating_add(usize::try_from(len).unwrap_or(usize::MAX)),
+            None => data.len(),
+        }
+        .min(data.len());
+        Ok(data[start..end].to_vec())
+    }
+
+    async fn put_object(
+        &self,
+        path: &RemotePath,
+        data: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<ObjectInfo> {
+        let options = PutOptions {
+            content_type: content_type.map(str::to_string),
+            ..Default::default()
+        };
+        self.put_object_with_options(path, data, &options).await
+    }
+
+    async fn put_object_with_options(
+        &self,
+        path: &RemotePath,
+        data: Vec<u8>,
+        _options: &PutOptions,
+    ) -> Result<ObjectInfo> {
+        self.existing_bucket(&path.bucket).await?;
+        let file = self.object_path(path)?;
+        if let Some(parent) = file.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&file, &data).await?;
+        self.head_object(path).await
+    }
+
+    async fn delete_object(&self, path: &RemotePath) -> Result<()> {
+        let bucket_dir = self.existing_bucket(&path.bucket).await?;
+        let file = self.object_path(path)?;
+        match tokio::fs::remove_file(&file).await {
+            Ok(()) => {
+                self.prune_empty_dirs(&bucket_dir, &file).await;
+                Ok(())
+            }
+            // Deleting a missing key succeeds, as it does on S3
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn delete_objects(&self, bucket: &str, keys: Vec<String>) -> Result<DeleteResult> {
+        let mut result = DeleteResult {
+            deleted: Vec::new(),
+            errors: Vec::new(),
+        };
+        for key in keys {
+            let path = RemotePath::new("", bucket, &key);
+            match self.delete_object(&path).await {
+                Ok(()) => result.deleted.push(key),
+                Err(e) => result.errors.push(DeleteError {
+                    key,
+                    version_id: None,
+                    code: "InternalError".to_string(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+        Ok(result)
+    }
+
+    async fn copy_object(&self, src: &RemotePath, dst: &RemotePath) -> Result<ObjectInfo> {
+        let from = self.object_path(src)?;
+        self.existing_bucket(&dst.bucket).await?;
+        let to = self.object_path(dst)?;
+        if let Some(parent) = to.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        if from != to {
+            tokio::fs::copy(&from, &to)
+                .await
+                .map_err(|e| io_error(e, || src.to_string()))?;
+        }
+        self.head_object(dst).await
+    }
+
+    async fn copy_object_with_options(
+        &self,
+        src: &RemotePath,
+        dst: &RemotePath,
+        _options: &PutOptions,
+    ) -> Result<ObjectInfo> {
+        self.copy_object(src, dst).await
+    }
+
+    async fn presign_get(&self, _path: &RemotePath, _expires_secs: u64) -> Result<String> {
+        Err(unsupported("presigned URLs"))
+    }
+
+    async fn presign_put(
+        &self,
+        _path: &RemotePath,
+        _expires_secs: u64,
+        _content_type: Option<&str>,
+    ) -> Result<String> {
+        Err(unsupported("presigned URLs"))
+    }
+
+    async fn get_versioning(&self, bucket: &str) -> Result<Option<bool>> {
+        self.existing_bucket(bucket).await?;
+        Ok(None)
+    }
+
+    async fn set_versioning(&self, _bucket: &str, _enabled: bool) -> Result<()> {
+        Err(unsupported("versioning"))
+    }
+
+    async fn list_object_versions(
+        &self,
+        _path: &RemotePath,
+        _max_keys: Option<i32>,
+    ) -> Result<Vec<ObjectVersion>> {
+        Err(unsupported("versioning"))
+    }
+
+    async fn delete_object_version(&self, _path: &RemotePath, _version_id: &str) -> Result<()> {
+        Err(unsupported("versioning"))
+    }
+
+    async fn delete_object_versions(
+        &self,
+        _bucket: &str,
+        _versions: Vec<(String, String)>,
+    ) -> Result<DeleteResult> {
+        Err(unsupported("versioning"))
+    }
+
+    async fn get_object_tags(&self, _path: &RemotePath) -> Result<HashMap<String, String>> {
+        Err(unsupported("object tags"))
+    }
+
+    async fn set_object_tags(
+        &self,
+        _path: &RemotePath,
+        _tags: HashMap<String, String>,
+    ) -> Result<()> {
+        Err(unsupported("object tags"))
+    }
+
+    async fn delete_object_tags(&self, _path: &RemotePath) -> Result<()> {
+        Err(unsupported("object tags"))
+    }
+
+    async fn get_object_acl(&self, _path: &RemotePath) -> Result<Acl> {
+        Err(unsupported("ACLs"))
+    }
+
+    async fn set_object_acl(&self, _path: &RemotePath, _acl: CannedAcl) -> Result<()> {
+        Err(unsupported("ACLs"))
+    }
+
+    async fn put_object_acl(&self, _path: &RemotePath, _acl: &Acl) -> Result<()> {
+        Err(unsupported("ACLs"))
+    }
+
+    async fn get_bucket_acl(&self, _bucket: &str) -> Result<Acl> {
+        Err(unsupported("ACLs"))
+    }
+
+    async fn set_bucket_acl(&self, _bucket: &str, _acl: CannedAcl) -> Result<()> {
+        Err(unsupported("ACLs"))
+    }
+
+    async fn get_bucket_notification_configuration(
+        &self,
+        _bucket: &str,
+    ) -> Result<Vec<NotificationRule>> {
+        Err(unsupported("event notifications"))
+    }
+
+    async fn put_bucket_notification_configuration(
+        &self,
+        _bucket: &str,
+        _rules: Vec<NotificationRule>,
+    ) -> Result<()> {
+        Err(unsupported("event notifications"))
+    }
+
+    async fn get_object_lock_config(&self, _bucket: &str) -> Result<Option<ObjectLockConfig>> {
+        Err(unsupported("object lock"))
+    }
+
+    async fn get_bucket_tags(&self, _bucket: &str) -> Result<HashMap<String, String>> {
+        Err(unsupported("bucket tags"))
+    }
+
+    async fn get_bucket_policy(&self, _bucket: &str) -> Result<Option<String>> {
+        Err(unsupported("bucket policies"))
+    }
+
+    async fn lifecycle_rule_count(&self, _bucket: &str) -> Result<usize> {
+        Err(unsupported("lifecycle rules"))
+    }
+
+    async fn replication_rule_count(&self, _bucket: &str) -> Result<usize> {
+        Err(unsupported("replication"))
+    }
+}
+
+fn unsupported(feature: &str) -> Error {
+    Error::UnsupportedFeature(format!(
+        "The local filesystem backend does not support {feature}"
+    ))
+}
+
+async fn is_dir(path: &Path) -> bool {
+    tokio::fs::metadata(path)
+        .await
+        .is_ok_and(|metadata| metadata.is_dir())
+}
+
+/// Map a missing file to `NotFound` and anything else to an IO error
+fn io_error(err: std::io::Error, not_found: impl FnOnce() -> String) -> Error {
+    if err.kind() == std::io::ErrorKind::NotFound {
+        Error::NotFound(not_found())
+    } else {
+        Error::Io(err)
+    }
+}
+
+fn modified(metadata: &std::fs::Metadata) -> Option<jiff::Timestamp> {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| jiff::Timestamp::try_from(t).ok())
+}
+
+fn file_info(key: &str, metadata: &std::fs::Metadata) -> ObjectInfo {
+    let mut info = ObjectInfo::file(key, i64::try_from(metadata.len()).unwrap_or(i64::MAX));
+    info.last_modified = modified(metadata);
+    info
+}
+
+/// Collect every file under `dir` as `(key, metadata)`; names that are not UTF-8 are skipped
+fn walk_files(dir: &Path, prefix: &str, out: &mut Vec<(String, std::fs::Metadata)>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        let metadata = entry.metadata()?;
+        let key = format!("{prefix}{name}");
+        if metadata.is_dir() {
+            walk_files(&entry.path(), &format!("{key}/"), out)?;
+        } else if metadata.is_file() {
+            out.push((key, metadata));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> (tempfile::TempDir, LocalStore) {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let store = LocalStore::new(dir.path());
+        (dir, store)
+    }
+
+    fn path(key: &str) -> RemotePath {
+        RemotePath::new("local", "bucket", key)
+    }
+
+    #[tokio::test]
+    async fn test_bucket_lifecycle() {
+        let (_dir, store) = store();
+        assert!(!store.bucket_exists("bucket").await.unwrap());
+
+        store.create_bucket("bucket").await.unwrap();
+        assert!(store.bucket_exists("bucket").await.unwrap());
+        assert!(matches!(
+            store.create_bucket("bucket").await,
+            Err(Error::Conflict(_))
+        ));
+
+        store
+            .put_object(&path("a.txt"), b"hi".to_vec(), None)
+            .await
+            .unwrap();
+        assert!(matches!(
+            store.delete_bucket("bucket").await,
+            Err(Error::Conflict(_))
+        ));
+
+        store.delete_object(&path("a.txt")).await.unwrap();
+        store.delete_bucket("bucket").await.unwrap();
+        assert!(matches!(
+            store.delete_bucket("bucket").await,
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_object_round_trip() {
+        let (_dir, store) = store();
+        store.create_bucket("bucket").await.unwrap();
+
+        let info = store
+            .put_object(&path("docs/readme.md"), b"hello world".to_vec(), None)
+            .await
+            .unwrap();
+        assert_eq!(info.size_bytes, Some(11));
+        assert_eq!(
+            store.get_object(&path("docs/readme.md")).await.unwrap(),
+            b"hello world"
+        );
+        assert_eq!(
+            store
+                .get_object_range(&path("docs/readme.md"), 6, Some(3))
+                .await
+                .unwrap(),
+            b"wor"
+        );
+        assert!(
+            store
+                .get_object_range(&path("docs/readme.md"), 50, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        store
+            .copy_object(&path("docs/readme.md"), &path("copy.md"))
+            .await
+            .unwrap();
+        assert!(store.object_exists(&path("copy.md")).await.unwrap());
+
+        // Deleting the only file in a directory removes the directory too
+        store.delete_object(&path("docs/readme.md")).await.unwrap();
+        assert!(!store.root().join("bucket/docs").exists());
+        assert!(matches!(
+            store.head_object(&path("docs/readme.md")).await,
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_delimiter_and_pages() {
+        let (_dir, store) = store();
+        store.create_bucket("bucket").await.unwrap();
+        for key in ["a.txt", "dir/b.txt", "dir/c.txt", "dir/sub/d.txt", "z.txt"] {
+            store
+                .put_object(&path(key), Vec::new(), None)
+                .await
+                .unwrap();
+        }
+
+        let top = store
+            .list_objects(&path(""), ListOptions::default())
+            .await
+            .unwrap();
+        let keys: Vec<_> = top.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["a.txt", "dir/", "z.txt"]);
+        assert!(top.items[1].is_dir);
+
+        let nested = store
+            .list_objects(&path("dir/"), ListOptions::default())
+            .await
+            .unwrap();
+        let keys: Vec<_> = nested.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["dir/b.txt", "dir/c.txt", "dir/sub/"]);
+
+        let grouped = |delimiter: &str| ListOptions {
+            delimiter: Some(delimiter.to_string()),
+            ..Default::default()
+        };
+        let by_dot = store.list_objects(&path(""), grouped(".")).await.unwrap();
+        let keys: Vec<_> = by_dot.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["a.", "dir/b.", "dir/c.", "dir/sub/d.", "z."]);
+
+        let flat = store
+            .list_objects(&path("dir/"), grouped(""))
+            .await
+            .unwrap();
+        let keys: Vec<_> = flat.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys, vec!["dir/b.txt", "dir/c.txt", "dir/sub/d.txt"]);
+
+        let mut options = ListOptions {
+            recursive: true,
+            max_keys: Some(2),
+            ..Default::default()
+        };
+        let mut all = Vec::new();
+        loop {
+            let page = store
+                .list_objects(&path(""), options.clone())
+                .await
+                .unwrap();
+            all.extend(page.items.into_iter().map(|i| i.key));
+            if !page.truncated {
+                break;
+            }
+            options.continuation_token = page.continuation_token;
+        }
+        assert_eq!(
+            all,
+            vec!["a.txt", "dir/b.txt", "dir/c.txt", "dir/sub/d.txt", "z.txt"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prefixes_only_in_delimited_listings() {
+        let (_dir, store) = store();
+        store.create_bucket("bucket").await.unwrap();
+        for key in ["a.txt", "dir/b.txt", "dir/sub/c.txt"] {
+            store
+                .put_object(&path(key), Vec::new(), None)
+                .await
+                .unwrap();
+        }
+
+        let delimited = store
+            .list_objects(&path(""), ListOptions::default())
+            .await
+            .unwrap();
+        let prefixes: Vec<_> = delimited.items.iter().filter(|i| i.is_dir).collect();
+        assert_eq!(prefixes.len(), 1);
+        assert_eq!(prefixes[0].key, "dir/");
+        assert!(prefixes[0].size_bytes.is_none());
+
+        let recursive = ListOptions {
+            recursive: true,
+            ..Default::default()
+        };
+        let all = store.list_objects(&path(""), recursive).await.unwrap();
+        assert!(
+            all.items
+                .iter()
+                .all(|i| !i.is_dir && i.size_bytes.is_some())
+        );
+        assert_eq!(all.items.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_keys_outside_bucket() {
+        let (_dir, store) = store();
+        store.create_bucket("bucket").await.unwrap();
+
+        for key in ["../escape", "a/../../b", "a//b", "dir/"] {
+            assert!(
+                matches!(
+                    store.put_object(&path(key), Vec::new(), None).await,
+                    Err(Error::InvalidPath(_))
+                ),
+                "{key} should be rejected"
+            );
+        }
+        assert!(matches!(
+            store.create_bucket("..").await,
+            Err(Error::InvalidPath(_))
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 //! Path parsing and resolution
 //!
 //! Handles parsing of remote paths in the format: alias/bucket[/key]
-//! Local paths are passed through as-is.
+//! Local paths are passed through as-is, and http(s) URLs are kept as URLs.
 
 use crate::error::{Error, Result};
 
@@ -99,6 +99,8 @@ pub enum ParsedPath {
     Local(std::path::PathBuf),
     /// Remote S3 path
     Remote(RemotePath),
+    /// Plain HTTP(S) URL, fetched without signing
+    Url(url::Url),
 }
 
 impl ParsedPath {
@@ -116,7 +118,7 @@ impl ParsedPath {
     pub fn as_remote(&self) -> Option<&RemotePath> {
         match self {
             ParsedPath::Remote(p) => Some(p),
-            ParsedPath::Local(_) => None,
+            _ => None,
         }
     }
 
@@ -124,7 +126,15 @@ impl ParsedPath {
     pub fn as_local(&self) -> Option<&std::path::PathBuf> {
         match self {
             ParsedPath::Local(p) => Some(p),
-            ParsedPath::Remote(_) => None,
+            _ => None,
+        }
+    }
+
+    /// Get the URL if this is an http(s) URL
+    pub fn as_url(&self) -> Option<&url::Url> {
+        match self {
+            ParsedPath::Url(u) => Some(u),
+            _ => None,
         }
     }
 }
@@ -132,6 +142,7 @@ impl ParsedPath {
 /// Parse a path string into a ParsedPath
 ///
 /// Remote paths have the format: alias/bucket[/key]
+/// Paths starting with `http://` or `https://` are URLs.
 /// Local paths are anything that:
 /// - Starts with / (absolute path)
 /// - Starts with ./ or ../ (relative path)
@@ -143,6 +154,14 @@ pub fn parse_path(path: &str) -> Result<ParsedPath> {
         return Err(Error::InvalidPath("Path cannot be empty".into()));
     }
 
+    // Absolute URLs are fetched directly rather than through an alias
+    let lower = path.to_ascii_lowercase();
+    if lower.starts_with("http://") || lower.starts_with("https://") {
+        return url::Url::parse(path)
+            .map(ParsedPath::Url)
+            .map_err(|e| Error::InvalidPath(format!("Invalid URL '{path}': {e}")));
+    }
+
     // Absolute paths are local
     if path.starts_with('/') {
         return Ok(ParsedPath::Local(std::path::PathBuf::from(path)));
@@ -216,6 +235,11 @@ pub fn parse_path(path: &str) -> Result<ParsedPath> {
 
 /// Check if a string is a valid alias name
 fn is_valid_alias_name(name: &str) -> bool {
+    // `:` stands in for `--endpoint-url` or the current alias
+    if name == ":" {
+        return true;
+    }
+
     !name.is_empty()
         && name
             .chars()
@@ -238,6 +262,18 @@ mod tests {
         assert!(!remote.is_dir);
     }
 
+    #[test]
+    fn test_parse_placeholder_alias() {
+        let result = parse_path(":/bucket/key.txt").unwrap();
+        if let ParsedPath::Remote(r) = result {
+            assert_eq!(r.alias, ":");
+            assert_eq!(r.bucket, "bucket");
+            assert_eq!(r.key, "key.txt");
+        } else {
+            panic!("Expected Remote path");
+        }
+    }
+
     #[test]
     fn test_parse_remote_path_dir() {
         let path = parse_path("myalias/bucket/dir/").unwrap();
@@ -287,6 +323,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_url() {
+        let result = parse_path("https://s3.example.com/bucket/key.txt?X-Amz-Expires=60").unwrap();
+        let url = result.as_url().unwrap();
+        assert_eq!(url.host_str(), Some("s3.example.com"));
+        assert_eq!(url.path(), "/bucket/key.txt");
+        assert!(!result.is_remote() && !result.is_local());
+
+        assert!(
+            parse_path("HTTP://example.com/x")
+                .unwrap()
+                .as_url()
+                .is_some()
+        );
+        assert!(parse_path("http://").is_err());
+        // Aliases that merely start with "http" are still aliases
+        assert!(parse_path("https-alias/bucket").unwrap().is_remote());
+    }
+
     #[test]
     fn test_remote_path_parent() {
         let path = RemotePath::new("myalias", "bucket", "a/b/c.txt");
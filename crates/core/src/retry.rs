@@ -112,7 +112,8 @@ pub fn is_retryable_error(error: &Error) -> bool {
         | Error::Conflict(_)
         | Error::InvalidPath(_)
         | Error::Config(_)
-        | Error::UnsupportedFeature(_) => false,
+        | Error::UnsupportedFeature(_)
+        | Error::NotImplemented(_) => false,
         // General errors might be retryable
         Error::General(msg) => {
             let msg_lower = msg.to_lowercase();
@@ -0,0 +1,237 @@
+//! Protection for secrets stored in the config file
+//!
+//! Encrypted values are written as `enc:v1:<base64>`, where the payload is a
+//! random salt, a random nonce and the ChaCha20-Poly1305 ciphertext. The key is
+//! derived from the passphrase with Argon2id, so every value carries its own salt.
+//!
+//! Secrets kept in the OS credential store are written as `keyring:<alias>`; the
+//! config file then holds only that reference. The store is only reachable when
+//! built with the `keyring` feature; otherwise such references fail with an error.
+
+use argon2::Argon2;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+use crate::error::{Error, Result};
+
+/// Environment variable holding the config passphrase
+pub const PASSPHRASE_ENV: &str = "RC_CONFIG_PASSPHRASE";
+
+/// Prefix marking an encrypted value
+pub const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+/// Prefix marking a reference to the OS credential store
+pub const KEYRING_PREFIX: &str = "keyring:";
+
+/// Service name for entries in the OS credential store
+#[cfg(feature = "keyring")]
+const KEYRING_SERVICE: &str = "rustfs-cli";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Whether a stored value is encrypted
+pub fn is_encrypted(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_PREFIX)
+}
+
+/// Whether a stored value refers to the OS credential store
+pub fn is_keyring_ref(value: &str) -> bool {
+    value.starts_with(KEYRING_PREFIX)
+}
+
+#[cfg(feature = "keyring")]
+fn keyring_entry(account: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, account).map_err(keyring_error)
+}
+
+#[cfg(feature = "keyring")]
+fn keyring_error(err: keyring::Error) -> Error {
+    match err {
+        keyring::Error::NoEntry => {
+            Error::Config("Secret key not found in the OS keyring; run `rc alias set` again".into())
+        }
+        keyring::Error::NoStorageAccess(_) | keyring::Error::PlatformFailure(_) => {
+            Error::Config(format!(
+                "No usable OS keyring ({err}); store the secret in the config file instead \
+                 by omitting --use-keyring"
+            ))
+        }
+        other => Error::Config(format!("OS keyring error: {other}")),
+    }
+}
+
+/// Store an alias secret in the OS credential store and return its reference
+#[cfg(feature = "keyring")]
+pub fn store_in_keyring(alias: &str, secret: &str) -> Result<String> {
+    keyring_entry(alias)?
+        .set_password(secret)
+        .map_err(keyring_error)?;
+    Ok(format!("{KEYRING_PREFIX}{alias}"))
+}
+
+/// Read the secret a `keyring:` reference points to
+#[cfg(feature = "keyring")]
+pub fn load_from_keyring(reference: &str) -> Result<String> {
+    let account = reference
+        .strip_prefix(KEYRING_PREFIX)
+        .ok_or_else(|| Error::Config("Keyring reference is malformed".to_string()))?;
+    keyring_entry(account)?
+        .get_password()
+        .map_err(keyring_error)
+}
+
+/// Delete the secret a `keyring:` reference points to; a missing entry is not an error
+#[cfg(feature = "keyring")]
+pub fn delete_from_keyring(reference: &str) -> Result<()> {
+    let Some(account) = reference.strip_prefix(KEYRING_PREFIX) else {
+        return Ok(());
+    };
+    match keyring_entry(account)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(keyring_error(e)),
+    }
+}
+
+#[cfg(not(feature = "keyring"))]
+fn keyring_unavailable() -> Error {
+    Error::Config(
+        "This rc was built without OS keyring support (the `keyring` feature); \
+         run `rc alias set` again without --use-keyring to store the secret in the config file"
+            .to_string(),
+    )
+}
+
+/// Store an alias secret in the OS credential store and return its reference
+#[cfg(not(feature = "keyring"))]
+pub fn store_in_keyring(_alias: &str, _secret: &str) -> Result<String> {
+    Err(keyring_unavailable())
+}
+
+/// Read the secret a `keyring:` reference points to
+#[cfg(not(feature = "keyring"))]
+pub fn load_from_keyring(_reference: &str) -> Result<String> {
+    Err(keyring_unavailable())
+}
+
+/// Without keyring support there is no entry to delete, so this does nothing
+#[cfg(not(feature = "keyring"))]
+pub fn delete_from_keyring(_reference: &str) -> Result<()> {
+    Ok(())
+}
+
+/// Read the passphrase from `RC_CONFIG_PASSPHRASE`, ignoring an empty value
+pub fn passphrase_from_env() -> Option<String> {
+    std::env::var(PASSPHRASE_ENV).ok().filter(|p| !p.is_empty())
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Key> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| Error::Config(format!("Failed to derive encryption key: {e}")))?;
+    Ok(key)
+}
+
+/// Encrypt a secret with a passphrase
+pub fn encrypt_secret(plaintext: &str, passphrase: &str) -> Result<String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(passphrase, &salt)?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let ciphertext = ChaCha20Poly1305::new(&key)
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| Error::Config("Failed to encrypt secret".to_string()))?;
+
+    let mut payload = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(&salt);
+    payload.extend_from_slice(&nonce);
+    payload.extend_from_slice(&ciphertext);
+    Ok(format!("{ENCRYPTED_PREFIX}{}", STANDARD.encode(payload)))
+}
+
+/// Decrypt a value produced by [`encrypt_secret`]
+///
+/// A wrong passphrase is reported as an authentication error.
+pub fn decrypt_secret(value: &str, passphrase: &str) -> Result<String> {
+    let malformed = || Error::Config("Encrypted secret is malformed".to_string());
+
+    let encoded = value.strip_prefix(ENCRYPTED_PREFIX).ok_or_else(malformed)?;
+    let payload = STANDARD.decode(encoded).map_err(|_| malformed())?;
+    if payload.len() < SALT_LEN + NONCE_LEN {
+        return Err(malformed());
+    }
+
+    let (salt, rest) = payload.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let key = derive_key(passphrase, salt)?;
+
+    let plaintext = ChaCha20Poly1305::new(&key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::Auth("Wrong config passphrase".to_string()))?;
+    String::from_utf8(plaintext).map_err(|_| malformed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let encrypted = encrypt_secret("s3cr3t", "correct horse").unwrap();
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("s3cr3t"));
+        assert_eq!(
+            decrypt_secret(&encrypted, "correct horse").unwrap(),
+            "s3cr3t"
+        );
+    }
+
+    #[test]
+    fn test_salt_makes_output_unique() {
+        let a = encrypt_secret("s3cr3t", "pass").unwrap();
+        let b = encrypt_secret("s3cr3t", "pass").unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_wrong_passphrase() {
+        let encrypted = encrypt_secret("s3cr3t", "pass").unwrap();
+        let err = decrypt_secret(&encrypted, "nope").unwrap_err();
+        assert!(matches!(err, Error::Auth(_)));
+    }
+
+    #[test]
+    fn test_keyring_reference() {
+        assert!(is_keyring_ref("keyring:prod"));
+        assert!(!is_keyring_ref("plain"));
+        assert!(!is_encrypted("keyring:prod"));
+        assert!(delete_from_keyring("plain").is_ok());
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    #[test]
+    fn test_keyring_disabled() {
+        let err = load_from_keyring("keyring:prod").unwrap_err();
+        assert!(err.to_string().contains("without OS keyring support"));
+        assert!(store_in_keyring("prod", "secret").is_err());
+        assert!(delete_from_keyring("keyring:prod").is_ok());
+    }
+
+    #[test]
+    fn test_malformed_value() {
+        assert!(!is_encrypted("plain"));
+        assert!(matches!(
+            decrypt_secret("enc:v1:!!", "pass").unwrap_err(),
+            Error::Config(_)
+        ));
+        assert!(matches!(
+            decrypt_secret("enc:v1:AAAA", "pass").unwrap_err(),
+            Error::Config(_)
+        ));
+    }
+}
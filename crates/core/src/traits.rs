@@ -6,6 +6,7 @@
 use async_trait::async_trait;
 use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use crate::error::Result;
 use crate::path::RemotePath;
@@ -38,6 +39,205 @@ pub struct ObjectVersion {
     pub etag: Option<String>,
 }
 
+/// Checksum an object was stored with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectChecksum {
+    /// Algorithm name (CRC32, CRC32C, CRC64NVME, SHA1 or SHA256)
+    pub algorithm: String,
+
+    /// Base64-encoded value as reported by the server
+    pub value: String,
+
+    /// `FULL_OBJECT` or `COMPOSITE` (checksum of part checksums), when reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum_type: Option<String>,
+}
+
+/// Owner of a bucket or object
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Owner {
+    /// Canonical user ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Display name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+/// A single access control grant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AclGrant {
+    /// Grantee type ("CanonicalUser", "Group" or "AmazonCustomerByEmail")
+    pub grantee_type: String,
+
+    /// Grantee ID, group URI or email, whichever the server returned
+    pub grantee: String,
+
+    /// Permission (e.g. "READ", "FULL_CONTROL")
+    pub permission: String,
+}
+
+/// Access control list of a bucket or object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Acl {
+    /// Resource owner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Owner>,
+
+    /// Grants
+    pub grants: Vec<AclGrant>,
+}
+
+/// Canned ACL applied with a single request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CannedAcl {
+    /// Owner gets full control, nobody else has access
+    Private,
+    /// Anyone can read
+    PublicRead,
+    /// Anyone can read and write
+    PublicReadWrite,
+    /// Authenticated users can read
+    AuthenticatedRead,
+    /// Bucket owner can read the object (objects only)
+    BucketOwnerRead,
+    /// Bucket owner gets full control of the object (objects only)
+    BucketOwnerFullControl,
+}
+
+impl CannedAcl {
+    /// Whether this canned ACL can be applied to a bucket
+    pub fn applies_to_bucket(&self) -> bool {
+        !matches!(
+            self,
+            CannedAcl::BucketOwnerRead | CannedAcl::BucketOwnerFullControl
+        )
+    }
+}
+
+impl std::fmt::Display for CannedAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CannedAcl::Private => write!(f, "private"),
+            CannedAcl::PublicRead => write!(f, "public-read"),
+            CannedAcl::PublicReadWrite => write!(f, "public-read-write"),
+            CannedAcl::AuthenticatedRead => write!(f, "authenticated-read"),
+            CannedAcl::BucketOwnerRead => write!(f, "bucket-owner-read"),
+            CannedAcl::BucketOwnerFullControl => write!(f, "bucket-owner-full-control"),
+        }
+    }
+}
+
+impl std::str::FromStr for CannedAcl {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "private" => Ok(CannedAcl::Private),
+            "public-read" => Ok(CannedAcl::PublicRead),
+            "public-read-write" => Ok(CannedAcl::PublicReadWrite),
+            "authenticated-read" => Ok(CannedAcl::AuthenticatedRead),
+            "bucket-owner-read" => Ok(CannedAcl::BucketOwnerRead),
+            "bucket-owner-full-control" => Ok(CannedAcl::BucketOwnerFullControl),
+            _ => Err(format!("Invalid canned ACL: {s}")),
+        }
+    }
+}
+
+/// Kind of destination a bucket notification rule delivers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationKind {
+    /// Queue (`arn:...:sqs:...`), used for RustFS/MinIO notification targets
+    Queue,
+    /// Topic (`arn:...:sns:...`)
+    Topic,
+    /// Lambda function (`arn:...:lambda:...`)
+    Lambda,
+}
+
+impl NotificationKind {
+    /// Kind of destination named by an ARN, from its service field
+    pub fn from_arn(arn: &str) -> std::result::Result<Self, String> {
+        let mut parts = arn.split(':');
+        if parts.next() != Some("arn") {
+            return Err(format!("Invalid ARN '{arn}': must start with 'arn:'"));
+        }
+        match parts.nth(1) {
+            Some("sqs") => Ok(NotificationKind::Queue),
+            Some("sns") => Ok(NotificationKind::Topic),
+            Some("lambda") => Ok(NotificationKind::Lambda),
+            _ => Err(format!(
+                "Invalid ARN '{arn}': service must be sqs, sns or lambda"
+            )),
+        }
+    }
+}
+
+/// A bucket event notification rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationRule {
+    /// Rule ID assigned by the server or client
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Destination ARN
+    pub arn: String,
+
+    /// Destination kind, derived from the ARN
+    pub kind: NotificationKind,
+
+    /// Event names (e.g. "s3:ObjectCreated:*")
+    pub events: Vec<String>,
+
+    /// Only notify for keys starting with this prefix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// Only notify for keys ending with this suffix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+}
+
+/// Default retention of a bucket with object lock enabled
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectLockConfig {
+    /// Default retention mode ("GOVERNANCE" or "COMPLIANCE"), if one is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+
+    /// Default retention period in days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<i32>,
+
+    /// Default retention period in years
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub years: Option<i32>,
+}
+
+/// Headers and metadata sent with an upload
+#[derive(Debug, Clone, Default)]
+pub struct PutOptions {
+    /// `Content-Type` header
+    pub content_type: Option<String>,
+
+    /// `Content-Encoding` header; the data must already be encoded
+    pub content_encoding: Option<String>,
+
+    /// `Cache-Control` header
+    pub cache_control: Option<String>,
+
+    /// `Content-Disposition` header
+    pub content_disposition: Option<String>,
+
+    /// User metadata, sent as `x-amz-meta-*`
+    pub metadata: std::collections::HashMap<String, String>,
+
+    /// Storage class (e.g. `STANDARD`); the bucket default when unset
+    pub storage_class: Option<String>,
+}
+
 /// Metadata for an object or bucket
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObjectInfo {
@@ -68,6 +268,30 @@ pub struct ObjectInfo {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
 
+    /// Content encoding (e.g. `gzip` for objects compressed on upload)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
+
+    /// Cache-Control header (only populated by HEAD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<String>,
+
+    /// Content-Disposition header (only populated by HEAD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_disposition: Option<String>,
+
+    /// User metadata, without the `x-amz-meta-` prefix (only populated by HEAD)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+
+    /// Version ID (only populated by HEAD, for objects in versioned buckets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+
+    /// Owner (only populated when the server reports it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Owner>,
+
     /// Whether this is a directory/prefix
     pub is_dir: bool,
 }
@@ -83,6 +307,12 @@ impl ObjectInfo {
             etag: None,
             storage_class: None,
             content_type: None,
+            content_encoding: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: BTreeMap::new(),
+            version_id: None,
+            owner: None,
             is_dir: false,
         }
     }
@@ -97,6 +327,12 @@ impl ObjectInfo {
             etag: None,
             storage_class: None,
             content_type: None,
+            content_encoding: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: BTreeMap::new(),
+            version_id: None,
+            owner: None,
             is_dir: true,
         }
     }
@@ -111,6 +347,12 @@ impl ObjectInfo {
             etag: None,
             storage_class: None,
             content_type: None,
+            content_encoding: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: BTreeMap::new(),
+            version_id: None,
+            owner: None,
             is_dir: true,
         }
     }
@@ -130,13 +372,53 @@ pub struct ListResult {
     pub continuation_token: Option<String>,
 }
 
+/// A key that a batch delete failed to remove
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteError {
+    /// Object key
+    pub key: String,
+
+    /// Version the failure applies to, for deletes of specific versions
+    pub version_id: Option<String>,
+
+    /// S3 error code (e.g. "SlowDown", "AccessDenied")
+    pub code: String,
+
+    /// Error message returned by the server
+    pub message: String,
+}
+
+impl DeleteError {
+    /// Whether the failure is transient and the delete may succeed on retry
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.code.as_str(),
+            "SlowDown"
+                | "InternalError"
+                | "ServiceUnavailable"
+                | "RequestTimeout"
+                | "OperationAborted"
+        )
+    }
+}
+
+/// Result of a batch delete
+#[derive(Debug, Clone, Default)]
+pub struct DeleteResult {
+    /// Keys that were deleted
+    pub deleted: Vec<String>,
+
+    /// Keys that could not be deleted, with the reason
+    pub errors: Vec<DeleteError>,
+}
+
 /// Options for list operations
 #[derive(Debug, Clone, Default)]
 pub struct ListOptions {
     /// Maximum number of keys to return per request
     pub max_keys: Option<i32>,
 
-    /// Delimiter for grouping (usually "/")
+    /// Delimiter for grouping (`/` when unset; an empty string lists flat)
     pub delimiter: Option<String>,
 
     /// Prefix to filter by
@@ -145,12 +427,18 @@ pub struct ListOptions {
     /// Continuation token for pagination
     pub continuation_token: Option<String>,
 
+    /// Start listing after this key (exclusive)
+    ///
+    /// Ignored when `continuation_token` is also set, since the token already
+    /// encodes the position of the next page.
+    pub start_after: Option<String>,
+
     /// Whether to list recursively (ignore delimiter)
     pub recursive: bool,
 }
 
 /// Backend capability information
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Capabilities {
     /// Supports bucket versioning
     pub versioning: bool,
@@ -182,6 +470,16 @@ pub trait ObjectStore: Send + Sync {
     /// Get object metadata
     async fn head_object(&self, path: &RemotePath) -> Result<ObjectInfo>;
 
+    /// Get the checksum an object was stored with (HEAD with checksum mode enabled)
+    ///
+    /// Returns `Ok(None)` for objects stored without a checksum.
+    async fn object_checksum(&self, path: &RemotePath) -> Result<Option<ObjectChecksum>>;
+
+    /// Check if an object exists
+    ///
+    /// Returns `Ok(false)` when the object is missing; any other failure is an error.
+    async fn object_exists(&self, path: &RemotePath) -> Result<bool>;
+
     /// Check if a bucket exists
     async fn bucket_exists(&self, bucket: &str) -> Result<bool>;
 
@@ -191,12 +489,22 @@ pub trait ObjectStore: Send + Sync {
     /// Delete a bucket
     async fn delete_bucket(&self, bucket: &str) -> Result<()>;
 
-    /// Get backend capabilities
-    async fn capabilities(&self) -> Result<Capabilities>;
+    /// Get backend capabilities by probing `bucket`
+    async fn capabilities(&self, bucket: &str) -> Result<Capabilities>;
 
     /// Get object content as bytes
     async fn get_object(&self, path: &RemotePath) -> Result<Vec<u8>>;
 
+    /// Get `length` bytes of an object starting at `offset` (to the end when `None`)
+    ///
+    /// A range starting at or past the end of the object yields no data.
+    async fn get_object_range(
+        &self,
+        path: &RemotePath,
+        offset: u64,
+        length: Option<u64>,
+    ) -> Result<Vec<u8>>;
+
     /// Upload object from bytes
     async fn put_object(
         &self,
@@ -205,15 +513,49 @@ pub trait ObjectStore: Send + Sync {
         content_type: Option<&str>,
     ) -> Result<ObjectInfo>;
 
+    /// Upload object from bytes with explicit headers and user metadata
+    async fn put_object_with_options(
+        &self,
+        path: &RemotePath,
+        data: Vec<u8>,
+        options: &PutOptions,
+    ) -> Result<ObjectInfo>;
+
     /// Delete an object
     async fn delete_object(&self, path: &RemotePath) -> Result<()>;
 
     /// Delete multiple objects (batch delete)
-    async fn delete_objects(&self, bucket: &str, keys: Vec<String>) -> Result<Vec<String>>;
+    ///
+    /// Per-key failures are returned in `DeleteResult::errors` rather than as an `Err`.
+    async fn delete_objects(&self, bucket: &str, keys: Vec<String>) -> Result<DeleteResult>;
+
+    /// Delete one version of an object, or a delete marker, by version ID
+    async fn delete_object_version(&self, path: &RemotePath, version_id: &str) -> Result<()>;
+
+    /// Delete specific `(key, version_id)` pairs (batch delete, up to 1000)
+    ///
+    /// Per-version failures are returned in `DeleteResult::errors` rather than as an `Err`.
+    async fn delete_object_versions(
+        &self,
+        bucket: &str,
+        versions: Vec<(String, String)>,
+    ) -> Result<DeleteResult>;
 
     /// Copy object within S3 (server-side copy)
     async fn copy_object(&self, src: &RemotePath, dst: &RemotePath) -> Result<ObjectInfo>;
 
+    /// Server-side copy that replaces the object's headers (`MetadataDirective=REPLACE`)
+    ///
+    /// Headers left as `None` keep the source's value, and the source's user
+    /// metadata is kept unless `options.metadata` is non-empty. Copying an
+    /// object onto itself updates its metadata without re-transferring data.
+    async fn copy_object_with_options(
+        &self,
+        src: &RemotePath,
+        dst: &RemotePath,
+        options: &PutOptions,
+    ) -> Result<ObjectInfo>;
+
     /// Generate a presigned URL for an object
     async fn presign_get(&self, path: &RemotePath, expires_secs: u64) -> Result<String>;
 
@@ -234,6 +576,9 @@ pub trait ObjectStore: Send + Sync {
     async fn set_versioning(&self, bucket: &str, enabled: bool) -> Result<()>;
 
     /// List object versions
+    ///
+    /// With `max_keys` a single page of at most that many entries is
+    /// returned; without it every version under the path is listed.
     async fn list_object_versions(
         &self,
         path: &RemotePath,
@@ -255,6 +600,52 @@ pub trait ObjectStore: Send + Sync {
 
     /// Delete object tags
     async fn delete_object_tags(&self, path: &RemotePath) -> Result<()>;
+
+    /// Get object ACL
+    async fn get_object_acl(&self, path: &RemotePath) -> Result<Acl>;
+
+    /// Apply a canned ACL to an object
+    async fn set_object_acl(&self, path: &RemotePath, acl: CannedAcl) -> Result<()>;
+
+    /// Replace an object's ACL with the given owner and grants
+    async fn put_object_acl(&self, path: &RemotePath, acl: &Acl) -> Result<()>;
+
+    /// Get bucket ACL
+    async fn get_bucket_acl(&self, bucket: &str) -> Result<Acl>;
+
+    /// Apply a canned ACL to a bucket
+    async fn set_bucket_acl(&self, bucket: &str, acl: CannedAcl) -> Result<()>;
+
+    /// Get the event notification rules of a bucket
+    async fn get_bucket_notification_configuration(
+        &self,
+        bucket: &str,
+    ) -> Result<Vec<NotificationRule>>;
+
+    /// Replace the event notification rules of a bucket
+    async fn put_bucket_notification_configuration(
+        &self,
+        bucket: &str,
+        rules: Vec<NotificationRule>,
+    ) -> Result<()>;
+
+    /// Get a bucket's object lock configuration; `None` when object lock is not enabled
+    async fn get_object_lock_config(&self, bucket: &str) -> Result<Option<ObjectLockConfig>>;
+
+    /// Get bucket tags (empty when none are set)
+    async fn get_bucket_tags(
+        &self,
+        bucket: &str,
+    ) -> Result<std::collections::HashMap<String, String>>;
+
+    /// Get a bucket's policy document; `None` when no policy is set
+    async fn get_bucket_policy(&self, bucket: &str) -> Result<Option<String>>;
+
+    /// Number of lifecycle rules configured on a bucket
+    async fn lifecycle_rule_count(&self, bucket: &str) -> Result<usize>;
+
+    /// Number of replication rules configured on a bucket
+    async fn replication_rule_count(&self, bucket: &str) -> Result<usize>;
     // async fn get_versioning(&self, bucket: &str) -> Result<bool>;
     // async fn set_versioning(&self, bucket: &str, enabled: bool) -> Result<()>;
     // async fn get_tags(&self, path: &RemotePath) -> Result<HashMap<String, String>>;
@@ -281,6 +672,64 @@ mod tests {
         assert!(info.size_bytes.is_none());
     }
 
+    #[test]
+    fn test_list_options_default_has_no_cursor() {
+        let options = ListOptions::default();
+        assert!(options.continuation_token.is_none());
+        assert!(options.start_after.is_none());
+    }
+
+    #[test]
+    fn test_canned_acl_round_trip() {
+        for name in [
+            "private",
+            "public-read",
+            "public-read-write",
+            "authenticated-read",
+            "bucket-owner-read",
+            "bucket-owner-full-control",
+        ] {
+            let acl: CannedAcl = name.parse().unwrap();
+            assert_eq!(acl.to_string(), name);
+        }
+        assert!("public".parse::<CannedAcl>().is_err());
+    }
+
+    #[test]
+    fn test_notification_kind_from_arn() {
+        assert_eq!(
+            NotificationKind::from_arn("arn:rustfs:sqs::primary:webhook"),
+            Ok(NotificationKind::Queue)
+        );
+        assert_eq!(
+            NotificationKind::from_arn("arn:aws:sns:us-east-1:123:topic"),
+            Ok(NotificationKind::Topic)
+        );
+        assert!(NotificationKind::from_arn("webhook").is_err());
+        assert!(NotificationKind::from_arn("arn:aws:s3:::bucket").is_err());
+    }
+
+    #[test]
+    fn test_canned_acl_applies_to_bucket() {
+        assert!(CannedAcl::PublicRead.applies_to_bucket());
+        assert!(!CannedAcl::BucketOwnerFullControl.applies_to_bucket());
+    }
+
+    #[test]
+    fn test_delete_error_is_retryable() {
+        let error = |code: &str| DeleteError {
+            key: "a.txt".to_string(),
+            version_id: None,
+            code: code.to_string(),
+            message: String::new(),
+        };
+
+        assert!(error("SlowDown").is_retryable());
+        assert!(error("InternalError").is_retryable());
+        assert!(!error("AccessDenied").is_retryable());
+        assert!(!error("").is_retryable());
+    }
+
     #[test]
     fn test_object_info_bucket() {
         let info = ObjectInfo::bucket("my-bucket");
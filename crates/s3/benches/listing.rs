@@ -0,0 +1,125 @@
+//! Sequential vs concurrent recursive listing on a simulated wide bucket
+//!
+//! Every listing request sleeps for a fixed round-trip time, so the numbers
+//! reflect request scheduling rather than local CPU work. Run with
+//! `cargo bench -p rc-s3 --bench listing`.
+
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use rc_core::{ListOptions, ListResult, ObjectInfo, RemotePath, Result};
+use rc_s3::{DEFAULT_LIST_CONCURRENCY, PageLister, list_objects_parallel};
+
+/// Simulated latency of one `ListObjectsV2` round trip
+const ROUND_TRIP: Duration = Duration::from_millis(20);
+
+/// Keys per listing page, kept small so each prefix needs several pages
+const PAGE_SIZE: i32 = 100;
+
+/// A bucket with `prefixes` top-level prefixes holding `keys` objects each
+struct WideBucket {
+    prefixes: usize,
+    keys: usize,
+}
+
+impl WideBucket {
+    /// Every key under `prefix`, in listing order
+    fn keys_under(&self, prefix: &str) -> Vec<String> {
+        let mut keys = Vec::new();
+        for p in 0..self.prefixes {
+            for k in 0..self.keys {
+                let key = format!("prefix-{p:04}/object-{k:06}");
+                if key.starts_with(prefix) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys
+    }
+}
+
+#[async_trait]
+impl PageLister for WideBucket {
+    async fn list_page(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult> {
+        tokio::time::sleep(ROUND_TRIP).await;
+
+        let mut items: Vec<ObjectInfo> = Vec::new();
+        for key in self.keys_under(&path.key) {
+            let rest = &key[path.key.len()..];
+            match rest.find('/') {
+                Some(slash) if !options.recursive => {
+                    let prefix = format!("{}{}", path.key, &rest[..=slash]);
+                    if items.last().is_none_or(|last| last.key != prefix) {
+                        items.push(ObjectInfo::dir(&prefix));
+                    }
+                }
+                _ => items.push(ObjectInfo::file(&key, 1)),
+            }
+        }
+
+        let start = options
+            .continuation_token
+            .map_or(0, |token| token.parse().unwrap_or(0));
+        let page = options.max_keys.unwrap_or(1000) as usize;
+        let end = (start + page).min(items.len());
+        Ok(ListResult {
+            truncated: end < items.len(),
+            continuation_token: (end < items.len()).then(|| end.to_string()),
+            items: items.drain(start..end).collect(),
+        })
+    }
+}
+
+/// The single recursive walk `find` and `rm -r` used before the concurrent listing
+async fn list_sequential(bucket: &WideBucket, path: &RemotePath) -> usize {
+    let mut count = 0;
+    let mut continuation_token = None;
+    loop {
+        let options = ListOptions {
+            recursive: true,
+            max_keys: Some(PAGE_SIZE),
+            continuation_token,
+            ..Default::default()
+        };
+        let result = bucket.list_page(path, options).await.expect("listing");
+        count += result.items.len();
+        if !result.truncated {
+            return count;
+        }
+        continuation_token = result.continuation_token;
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let path = RemotePath::new("bench", "bucket", "");
+    println!(
+        "{:>8} {:>8} {:>12} {:>12} {:>8}",
+        "prefixes", "objects", "sequential", "concurrent", "speedup"
+    );
+
+    for (prefixes, keys) in [(1, 2000), (8, 250), (32, 250), (128, 50)] {
+        let bucket = WideBucket { prefixes, keys };
+
+        let start = Instant::now();
+        let sequential = list_sequential(&bucket, &path).await;
+        let sequential_time = start.elapsed();
+
+        let start = Instant::now();
+        let concurrent = list_objects_parallel(&bucket, &path, DEFAULT_LIST_CONCURRENCY, PAGE_SIZE)
+            .await
+            .expect("listing")
+            .len();
+        let concurrent_time = start.elapsed();
+
+        assert_eq!(sequential, concurrent);
+        println!(
+            "{:>8} {:>8} {:>10.0}ms {:>10.0}ms {:>7.1}x",
+            prefixes,
+            sequential,
+            sequential_time.as_secs_f64() * 1000.0,
+            concurrent_time.as_secs_f64() * 1000.0,
+            sequential_time.as_secs_f64() / concurrent_time.as_secs_f64()
+        );
+    }
+}
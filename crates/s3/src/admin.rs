@@ -1,7 +1,7 @@
 //! Admin API client implementation
 //!
 //! This module provides the AdminClient that implements the AdminApi trait
-//! using HTTP requests with AWS SigV4 signing.
+//! using HTTP requests with AWS SigV4 signing (or SigV2 for aliases that ask for it).
 
 use async_trait::async_trait;
 use aws_credential_types::Credentials;
@@ -10,17 +10,71 @@ use aws_sigv4::http_request::{
 };
 use aws_sigv4::sign::v4;
 use rc_core::admin::{
-    AdminApi, ClusterInfo, CreateServiceAccountRequest, Group, GroupStatus, HealStartRequest,
-    HealStatus, Policy, PolicyEntity, PolicyInfo, ServiceAccount, UpdateGroupMembersRequest, User,
-    UserStatus,
+    AdminApi, AssumeRoleRequest, BucketQuota, BucketTarget, ClusterInfo,
+    CreateServiceAccountRequest, Group, GroupStatus, HealStartRequest, HealStatus, LogEntry,
+    LogOpts, MetricsTarget, NotifyTarget, NotifyTargetType, Policy, PolicyEntity, PolicyInfo,
+    PoolStatus, ProfilerType, ProfilingStartResult, RebalanceStatus, ServiceAccount, ServiceAction,
+    SiteReplicationInfo, SiteReplicationStatus, SpeedTestOpts, SpeedTestResult,
+    TemporaryCredentials, UpdateGroupMembersRequest, UpdateServiceAccountRequest, User, UserStatus,
+};
+use rc_core::alias::RetryConfig;
+use rc_core::{Alias, Error, Result, is_retryable_error, retry_with_backoff};
+use reqwest::header::{
+    AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE, DATE, HeaderMap, HeaderName, HeaderValue,
 };
-use rc_core::{Alias, Error, Result};
-use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
 use reqwest::{Client, Method, StatusCode};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::time::SystemTime;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Instant, SystemTime};
+use tokio::io::AsyncWriteExt;
+
+use crate::error::{CLOCK_SKEW_CODES, clock_skew_hint};
+use crate::request_log;
+use crate::sigv2;
+use crate::{resolve, tls};
+
+/// STS API version sent with every STS action
+const STS_VERSION: &str = "2011-06-15";
+
+/// Path prefix of the Prometheus scrape targets, followed by the target name
+const METRICS_PATH: &str = "/rustfs/v2/metrics";
+
+/// How long a Prometheus bearer token stays valid, in seconds
+const METRICS_TOKEN_TTL: u64 = 3600;
+
+/// How a request is signed and what its body holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RequestKind {
+    /// Admin API call with a JSON body, signed for the `s3` service
+    Admin,
+    /// STS action with a form body, signed for the `sts` service
+    Sts,
+    /// STS action that authenticates with a token in its form body; sent unsigned
+    StsUnsigned,
+    /// Prometheus scrape, authenticated with a bearer token instead of a signature
+    Metrics,
+}
+
+impl RequestKind {
+    /// Service name used in the SigV4 scope
+    fn service(self) -> &'static str {
+        match self {
+            RequestKind::Admin | RequestKind::Metrics => "s3",
+            RequestKind::Sts | RequestKind::StsUnsigned => "sts",
+        }
+    }
+
+    /// Content type of a non-empty body
+    fn content_type(self) -> &'static str {
+        match self {
+            RequestKind::Admin | RequestKind::Metrics => "application/json",
+            RequestKind::Sts | RequestKind::StsUnsigned => "application/x-www-form-urlencoded",
+        }
+    }
+}
 
 /// Admin API client for RustFS/MinIO-compatible servers
 pub struct AdminClient {
@@ -29,13 +83,33 @@ pub struct AdminClient {
     access_key: String,
     secret_key: String,
     region: String,
+    /// Sign with SigV2 instead of SigV4
+    signature_v2: bool,
+    /// Attempts for requests that fail with a transient error
+    retry: RetryConfig,
 }
 
 impl AdminClient {
     /// Create a new AdminClient from an Alias
+    ///
+    /// Anonymous aliases are rejected: every admin API call must be signed.
     pub fn new(alias: &Alias) -> Result<Self> {
-        let http_client = Client::builder()
-            .danger_accept_invalid_certs(alias.insecure)
+        if alias.anonymous {
+            return Err(Error::Config(
+                "Admin APIs require signed requests; anonymous access is not supported".into(),
+            ));
+        }
+
+        let mut builder = Client::builder();
+        if let Some(config) = tls::client_config(alias)? {
+            builder = builder.use_preconfigured_tls(config);
+        }
+
+        for (host, ips) in resolve::group_overrides(alias.resolve_overrides()?) {
+            builder = builder.resolve_to_addrs(&host, &resolve::socket_addrs(&ips));
+        }
+
+        let http_client = builder
             .build()
             .map_err(|e| Error::Network(format!("Failed to create HTTP client: {e}")))?;
 
@@ -45,6 +119,8 @@ impl AdminClient {
             access_key: alias.access_key.clone(),
             secret_key: alias.secret_key.clone(),
             region: alias.region.clone(),
+            signature_v2: alias.signature == "v2",
+            retry: alias.retry_config(),
         })
     }
 
@@ -53,6 +129,11 @@ impl AdminClient {
         format!("{}/rustfs/admin/v3{}", self.endpoint, path)
     }
 
+    /// Build the URL of a Prometheus scrape target
+    fn metrics_url(&self, target: MetricsTarget) -> String {
+        format!("{}{METRICS_PATH}/{target}", self.endpoint)
+    }
+
     /// Calculate SHA256 hash of the body
     fn sha256_hash(body: &[u8]) -> String {
         let mut hasher = Sha256::new();
@@ -60,14 +141,19 @@ impl AdminClient {
         hex::encode(hasher.finalize())
     }
 
-    /// Sign a request using AWS SigV4
+    /// Sign a request using AWS SigV4, or SigV2 when the alias selects it
     async fn sign_request(
         &self,
         method: &Method,
         url: &str,
         headers: &HeaderMap,
         body: &[u8],
+        service: &str,
     ) -> Result<HeaderMap> {
+        if self.signature_v2 {
+            return self.sign_request_v2(method, url, headers);
+        }
+
         let credentials = Credentials::new(
             &self.access_key,
             &self.secret_key,
@@ -83,7 +169,7 @@ impl AdminClient {
         let signing_params = v4::SigningParams::builder()
             .identity(&identity)
             .region(&self.region)
-            .name("s3")
+            .name(service)
             .time(SystemTime::now())
             .settings(signing_settings)
             .build()
@@ -122,14 +208,51 @@ impl AdminClient {
         Ok(signed_headers)
     }
 
-    /// Make a signed request to the admin API
-    async fn request<T: for<'de> Deserialize<'de>>(
+    /// Sign a request using AWS SigV2 (`Date` plus an `AWS key:signature` header)
+    fn sign_request_v2(
+        &self,
+        method: &Method,
+        url: &str,
+        headers: &HeaderMap,
+    ) -> Result<HeaderMap> {
+        let mut signed_headers = headers.clone();
+        let date = HeaderValue::try_from(sigv2::http_date(jiff::Timestamp::now()))
+            .map_err(|e| Error::Auth(format!("Invalid header value: {e}")))?;
+        signed_headers.insert(DATE, date);
+
+        let string_to_sign = sigv2::string_to_sign(
+            method.as_str(),
+            url,
+            signed_headers
+                .iter()
+                .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str(), v))),
+        )?;
+        let authorization =
+            sigv2::authorization(&self.access_key, &self.secret_key, &string_to_sign)?;
+        let authorization = HeaderValue::try_from(authorization)
+            .map_err(|e| Error::Auth(format!("Invalid header value: {e}")))?;
+        signed_headers.insert(AUTHORIZATION, authorization);
+
+        Ok(signed_headers)
+    }
+
+    /// Send a signed request to the admin API and return the raw response
+    ///
+    /// Non-success statuses are mapped to errors here so callers only deal
+    /// with the response body.
+    async fn send(
         &self,
         method: Method,
         path: &str,
         query: Option<&[(&str, &str)]>,
         body: Option<&[u8]>,
-    ) -> Result<T> {
+    ) -> Result<reqwest::Response> {
+        let url = self.admin_url_with_query(path, query);
+        self.send_url(method, &url, body).await
+    }
+
+    /// Admin API URL for `path` with an encoded query string
+    fn admin_url_with_query(&self, path: &str, query: Option<&[(&str, &str)]>) -> String {
         let mut url = self.admin_url(path);
 
         if let Some(q) = query {
@@ -143,7 +266,65 @@ impl AdminClient {
                 url.push_str(&query_string);
             }
         }
+        url
+    }
+
+    /// Send a signed request to a full URL and return the raw response
+    ///
+    /// Used directly for endpoints outside the admin API prefix, such as
+    /// the metrics endpoint. Transient failures (connection errors, 429 and
+    /// 503) are retried per the alias retry settings, re-signing each attempt.
+    async fn send_url(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&[u8]>,
+    ) -> Result<reqwest::Response> {
+        self.send_kind(RequestKind::Admin, method, url, body).await
+    }
+
+    /// Send a request of the given kind to a full URL, with retries
+    async fn send_kind(
+        &self,
+        kind: RequestKind,
+        method: Method,
+        url: &str,
+        body: Option<&[u8]>,
+    ) -> Result<reqwest::Response> {
+        retry_with_backoff(
+            &self.retry,
+            || self.send_url_once(kind, method.clone(), url, body),
+            is_retryable_error,
+        )
+        .await
+    }
+
+    /// Sign and send a single attempt of a request
+    async fn send_url_once(
+        &self,
+        kind: RequestKind,
+        method: Method,
+        url: &str,
+        body: Option<&[u8]>,
+    ) -> Result<reqwest::Response> {
+        let response = self
+            .dispatch(kind, method, url, body)
+            .await?
+            .map_err(|e| Error::Network(format!("Request failed: {}", error_with_sources(&e))))?;
+        self.check_response(response).await
+    }
 
+    /// Sign and send a request, leaving transport failures to the caller
+    ///
+    /// The outer error is a signing failure; the inner one is the unmapped
+    /// reqwest error, so callers can tell how the connection failed.
+    async fn dispatch(
+        &self,
+        kind: RequestKind,
+        method: Method,
+        url: &str,
+        body: Option<&[u8]>,
+    ) -> Result<reqwest::Result<reqwest::Response>> {
         let body_bytes = body.unwrap_or(&[]);
         let content_hash = Self::sha256_hash(body_bytes);
 
@@ -152,14 +333,31 @@ impl AdminClient {
         headers.insert("host", self.get_host().parse().unwrap());
 
         if !body_bytes.is_empty() {
-            headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static(kind.content_type()));
         }
 
-        let signed_headers = self
-            .sign_request(&method, &url, &headers, body_bytes)
-            .await?;
+        let signed_headers = match kind {
+            RequestKind::StsUnsigned => headers,
+            RequestKind::Metrics => {
+                let now = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_err(|e| Error::General(format!("System clock error: {e}")))?
+                    .as_secs();
+                let token = metrics_token(&self.access_key, &self.secret_key, now);
+                headers.insert(
+                    AUTHORIZATION,
+                    HeaderValue::from_str(&format!("Bearer {token}"))
+                        .map_err(|e| Error::General(format!("Invalid bearer token: {e}")))?,
+                );
+                headers
+            }
+            RequestKind::Admin | RequestKind::Sts => {
+                self.sign_request(&method, url, &headers, body_bytes, kind.service())
+                    .await?
+            }
+        };
 
-        let mut request_builder = self.http_client.request(method.clone(), &url);
+        let mut request_builder = self.http_client.request(method.clone(), url);
 
         for (name, value) in signed_headers.iter() {
             request_builder = request_builder.header(name, value);
@@ -169,96 +367,146 @@ impl AdminClient {
             request_builder = request_builder.body(body_bytes.to_vec());
         }
 
-        let response = request_builder
-            .send()
-            .await
-            .map_err(|e| Error::Network(format!("Request failed: {e}")))?;
+        if request_log::enabled() {
+            tracing::debug!(
+                target: request_log::TARGET,
+                "{method} {url} [{}]",
+                request_log::format_headers(
+                    signed_headers
+                        .iter()
+                        .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str(), v)))
+                )
+            );
+        }
+        if request_log::wire_enabled() {
+            tracing::trace!(
+                target: request_log::WIRE_TARGET,
+                "\n> {method} {url} HTTP/1.1\n{}>\n{}",
+                request_log::header_lines(
+                    '>',
+                    signed_headers
+                        .iter()
+                        .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str(), v)))
+                ),
+                if matches!(kind, RequestKind::Sts | RequestKind::StsUnsigned) {
+                    // STS forms carry tokens that the JSON redaction does not recognise
+                    format!("<{} bytes of STS form data>", body_bytes.len())
+                } else {
+                    request_log::redact_body(body_bytes)
+                }
+            );
+        }
+        let started = Instant::now();
+        let response = request_builder.send().await;
+        if let Ok(response) = &response {
+            tracing::debug!(
+                target: request_log::TARGET,
+                "-> {} in {} ms",
+                response.status().as_u16(),
+                started.elapsed().as_millis()
+            );
+        }
+        Ok(response)
+    }
 
+    /// Log a response and map a non-success status to an error
+    async fn check_response(&self, response: reqwest::Response) -> Result<reqwest::Response> {
         let status = response.status();
+        if request_log::wire_enabled() {
+            tracing::trace!(
+                target: request_log::WIRE_TARGET,
+                "\n< {status}\n{}",
+                request_log::header_lines(
+                    '<',
+                    response
+                        .headers()
+                        .iter()
+                        .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str(), v)))
+                )
+            );
+        }
 
         if !status.is_success() {
+            let server_date = response
+                .headers()
+                .get(DATE)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
             let error_body = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(self.map_error(status, &error_body));
+            tracing::trace!(
+                target: request_log::WIRE_TARGET,
+                "< body: {}",
+                request_log::redact_body(error_body.as_bytes())
+            );
+            return Err(self.map_error(status, &error_body, server_date.as_deref()));
         }
 
+        Ok(response)
+    }
+
+    /// Make a signed request to the admin API
+    async fn request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Option<&[(&str, &str)]>,
+        body: Option<&[u8]>,
+    ) -> Result<T> {
+        let response = self.send(method, path, query, body).await?;
+
         let text = response
             .text()
             .await
             .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?;
+        tracing::trace!(
+            target: request_log::WIRE_TARGET,
+            "< body: {}",
+            request_log::redact_body(text.as_bytes())
+        );
 
-        if text.is_empty() {
+        let text = if text.is_empty() {
             // Return empty/default for empty responses
-            serde_json::from_str("null").map_err(Error::Json)
+            "null"
         } else {
-            serde_json::from_str(&text).map_err(Error::Json)
-        }
+            text.as_str()
+        };
+        serde_json::from_str(text).map_err(|e| parse_error(path, &e, text))
     }
 
-    /// Make a signed request that returns no body
-    async fn request_no_response(
+    /// Make a signed request that returns a plain-text body
+    async fn request_text(
         &self,
         method: Method,
         path: &str,
         query: Option<&[(&str, &str)]>,
         body: Option<&[u8]>,
-    ) -> Result<()> {
-        let mut url = self.admin_url(path);
+    ) -> Result<String> {
+        let response = self.send(method, path, query, body).await?;
 
-        if let Some(q) = query {
-            let query_string: String = q
-                .iter()
-                .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
-                .collect::<Vec<_>>()
-                .join("&");
-            if !query_string.is_empty() {
-                url.push('?');
-                url.push_str(&query_string);
-            }
-        }
-
-        let body_bytes = body.unwrap_or(&[]);
-        let content_hash = Self::sha256_hash(body_bytes);
-
-        let mut headers = HeaderMap::new();
-        headers.insert("x-amz-content-sha256", content_hash.parse().unwrap());
-        headers.insert("host", self.get_host().parse().unwrap());
-
-        if !body_bytes.is_empty() {
-            headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
-        }
-
-        let signed_headers = self
-            .sign_request(&method, &url, &headers, body_bytes)
-            .await?;
-
-        let mut request_builder = self.http_client.request(method.clone(), &url);
-
-        for (name, value) in signed_headers.iter() {
-            request_builder = request_builder.header(name, value);
-        }
-
-        if !body_bytes.is_empty() {
-            request_builder = request_builder.body(body_bytes.to_vec());
-        }
-
-        let response = request_builder
-            .send()
+        let text = response
+            .text()
             .await
-            .map_err(|e| Error::Network(format!("Request failed: {e}")))?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_body = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(self.map_error(status, &error_body));
-        }
+            .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?;
+        tracing::trace!(
+            target: request_log::WIRE_TARGET,
+            "< body: {}",
+            request_log::redact_body(text.as_bytes())
+        );
+        Ok(text)
+    }
 
+    /// Make a signed request that returns no body
+    async fn request_no_response(
+        &self,
+        method: Method,
+        path: &str,
+        query: Option<&[(&str, &str)]>,
+        body: Option<&[u8]>,
+    ) -> Result<()> {
+        self.send(method, path, query, body).await?;
         Ok(())
     }
 
@@ -271,17 +519,241 @@ impl AdminClient {
     }
 
     /// Map HTTP status codes to appropriate errors
-    fn map_error(&self, status: StatusCode, body: &str) -> Error {
+    ///
+    /// `server_date` is the response `Date` header, used to spot clock skew
+    /// behind signature failures.
+    fn map_error(&self, status: StatusCode, body: &str, server_date: Option<&str>) -> Error {
         match status {
             StatusCode::NOT_FOUND => Error::NotFound(body.to_string()),
-            StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED => Error::Auth(body.to_string()),
+            StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED => {
+                let code = CLOCK_SKEW_CODES.iter().copied().find(|c| body.contains(c));
+                match clock_skew_hint(code, server_date, jiff::Timestamp::now()) {
+                    Some(hint) => Error::Auth(format!("{body}; {hint}")),
+                    None => Error::Auth(body.to_string()),
+                }
+            }
             StatusCode::CONFLICT => Error::Conflict(body.to_string()),
             StatusCode::BAD_REQUEST => Error::InvalidPath(body.to_string()),
+            StatusCode::NOT_IMPLEMENTED => Error::NotImplemented(body.to_string()),
+            _ if body.contains("NotImplemented") || body.contains("Not Implemented") => {
+                Error::NotImplemented(body.to_string())
+            }
             _ => Error::Network(format!("HTTP {}: {}", status.as_u16(), body)),
         }
     }
 }
 
+/// Encode an STS request as a form body
+fn sts_form(request: &AssumeRoleRequest) -> String {
+    let duration = request.duration_seconds.map(|d| d.to_string());
+    let mut params = vec![("Action", request.action()), ("Version", STS_VERSION)];
+    if let Some(duration) = &duration {
+        params.push(("DurationSeconds", duration));
+    }
+    if let Some(policy) = &request.policy {
+        params.push(("Policy", policy));
+    }
+    if let Some(role_arn) = &request.role_arn {
+        params.push(("RoleArn", role_arn));
+    }
+    if let Some(token) = &request.web_identity_token {
+        params.push(("WebIdentityToken", token));
+    }
+    params
+        .iter()
+        .map(|(k, v)| format!("{k}={}", urlencoding::encode(v)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Text of the first `<tag>` element in an XML document, unescaped
+fn xml_text(body: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let start = body.find(&open)? + open.len();
+    let len = body[start..].find(&close)?;
+    Some(
+        body[start..start + len]
+            .trim()
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+/// Read the credentials out of an AssumeRole* response
+fn parse_sts_credentials(body: &str) -> Result<TemporaryCredentials> {
+    let field = |tag: &str| {
+        xml_text(body, tag)
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| Error::General(format!("STS response has no {tag}")))
+    };
+    let expiration = match xml_text(body, "Expiration") {
+        Some(exp) => Some(
+            exp.parse::<jiff::Timestamp>()
+                .map_err(|e| Error::General(format!("Invalid STS expiration '{exp}': {e}")))?,
+        ),
+        None => None,
+    };
+    Ok(TemporaryCredentials {
+        access_key: field("AccessKeyId")?,
+        secret_key: field("SecretAccessKey")?,
+        session_token: field("SessionToken")?,
+        expiration,
+    })
+}
+
+/// Render an error together with its source chain
+///
+/// reqwest's Display only says "error sending request"; the useful detail
+/// (connection reset, TLS failure, ...) lives in the sources.
+fn error_with_sources(err: &dyn std::error::Error) -> String {
+    let mut msg = err.to_string();
+    let mut source = err.source();
+    while let Some(s) = source {
+        msg.push_str(": ");
+        msg.push_str(&s.to_string());
+        source = s.source();
+    }
+    msg
+}
+
+/// Whether a request failed because the peer closed the connection mid-request
+///
+/// Distinct from a connect failure (e.g. "connection refused"), which means the
+/// request never reached the server.
+fn is_connection_dropped(err: &reqwest::Error) -> bool {
+    !err.is_connect() && !err.is_timeout() && (err.is_request() || has_dropped_io_source(err))
+}
+
+/// Whether an error's source chain holds an I/O error for a closed connection
+fn has_dropped_io_source(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = err.source();
+    while let Some(s) = source {
+        if let Some(io) = s.downcast_ref::<std::io::Error>()
+            && matches!(
+                io.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            )
+        {
+            return true;
+        }
+        source = s.source();
+    }
+    false
+}
+
+/// Maximum number of body characters quoted in a parse error
+const PARSE_ERROR_BODY_CHARS: usize = 512;
+
+/// Describe a response that did not match the expected shape
+///
+/// The message names the endpoint and quotes the start of the body, with
+/// secret fields redacted, so shape changes between server versions are
+/// visible without `--trace-requests`.
+fn parse_error(path: &str, err: &serde_json::Error, body: &str) -> Error {
+    let body = request_log::redact_body(body.as_bytes());
+    let mut quoted: String = body.chars().take(PARSE_ERROR_BODY_CHARS).collect();
+    if quoted.len() < body.len() {
+        quoted.push_str("...");
+    }
+    Error::General(format!(
+        "Failed to parse {path} response: {err}; body: {quoted}"
+    ))
+}
+
+/// Read the ARN returned by `/set-remote-target`
+///
+/// Servers answer with the ARN as a JSON string or as plain text.
+fn parse_arn(body: &str) -> String {
+    let body = body.trim();
+    serde_json::from_str::<String>(body).unwrap_or_else(|_| body.to_string())
+}
+
+/// Parse one line of the newline-delimited speedtest stream
+///
+/// Blank lines are keep-alives sent while the test is running.
+fn parse_speedtest_line(line: &[u8]) -> Result<Option<SpeedTestResult>> {
+    let line = line.trim_ascii();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_slice(line)
+        .map(Some)
+        .map_err(|e| parse_error("/speedtest", &e, &String::from_utf8_lossy(line)))
+}
+
+/// Parse one line of the newline-delimited log stream
+///
+/// Blank lines are keep-alives. Lines that are not log entries are skipped
+/// rather than ending the stream, so one odd entry does not stop a tail.
+fn parse_log_line(line: &[u8]) -> Option<LogEntry> {
+    let line = line.trim_ascii();
+    if line.is_empty() {
+        return None;
+    }
+    match serde_json::from_slice(line) {
+        Ok(entry) => Some(entry),
+        Err(e) => {
+            tracing::debug!(
+                "{}",
+                parse_error("/log", &e, &String::from_utf8_lossy(line))
+            );
+            None
+        }
+    }
+}
+
+/// Build the bearer token for a Prometheus scrape
+///
+/// The metrics endpoints do not take SigV4. Like `mc admin prometheus generate`,
+/// the token is an HS512 JWT issued by `prometheus` for the access key,
+/// signed with the secret key and valid for [`METRICS_TOKEN_TTL`] from `now`.
+fn metrics_token(access_key: &str, secret_key: &str, now: u64) -> String {
+    use base64::Engine as _;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use hmac::{Hmac, Mac};
+
+    let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS512","typ":"JWT"}"#);
+    let claims = URL_SAFE_NO_PAD.encode(
+        serde_json::json!({
+            "exp": now + METRICS_TOKEN_TTL,
+            "sub": access_key,
+            "iss": "prometheus",
+        })
+        .to_string(),
+    );
+    let signing_input = format!("{header}.{claims}");
+    let mut mac = Hmac::<sha2::Sha512>::new_from_slice(secret_key.as_bytes())
+        .expect("HMAC can take key of any size");
+    mac.update(signing_input.as_bytes());
+    let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+    format!("{signing_input}.{signature}")
+}
+
+/// Decode a metrics response body
+///
+/// Servers and proxies may gzip the exposition text even when it was not
+/// asked for, so the body is also sniffed for the gzip magic bytes.
+fn decode_metrics_body(body: &[u8], content_encoding: Option<&str>) -> Result<String> {
+    let gzipped = content_encoding.is_some_and(|e| e.eq_ignore_ascii_case("gzip"))
+        || body.starts_with(&[0x1f, 0x8b]);
+    if !gzipped {
+        return Ok(String::from_utf8_lossy(body).into_owned());
+    }
+
+    let mut text = Vec::new();
+    flate2::read::GzDecoder::new(body)
+        .read_to_end(&mut text)
+        .map_err(|e| Error::General(format!("Failed to decompress metrics: {e}")))?;
+    Ok(String::from_utf8_lossy(&text).into_owned())
+}
+
 /// Response wrapper for user list
 #[derive(Debug, Deserialize)]
 struct UserListResponse(HashMap<String, UserInfo>);
@@ -335,6 +807,13 @@ struct ServiceAccountInfo {
     expiration: Option<String>,
 }
 
+/// Response for rebalance start
+#[derive(Debug, Deserialize)]
+struct RebalanceStartResponse {
+    #[serde(default)]
+    id: String,
+}
+
 /// Request body for set policy
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -366,6 +845,303 @@ impl AdminApi for AdminClient {
             .await
     }
 
+    async fn list_pools(&self) -> Result<Vec<PoolStatus>> {
+        let pools: Option<Vec<PoolStatus>> =
+            self.request(Method::GET, "/pools/list", None, None).await?;
+        Ok(pools.unwrap_or_default())
+    }
+
+    async fn decommission_start(&self, pool: &str) -> Result<()> {
+        let query = [("pool", pool)];
+        self.request_no_response(Method::POST, "/pools/decommission", Some(&query), None)
+            .await
+    }
+
+    async fn decommission_cancel(&self, pool: &str) -> Result<()> {
+        let query = [("pool", pool)];
+        self.request_no_response(Method::POST, "/pools/cancel", Some(&query), None)
+            .await
+    }
+
+    async fn rebalance_start(&self) -> Result<String> {
+        let response: RebalanceStartResponse = self
+            .request(Method::POST, "/rebalance/start", None, None)
+            .await?;
+        Ok(response.id)
+    }
+
+    async fn rebalance_status(&self) -> Result<RebalanceStatus> {
+        self.request(Method::GET, "/rebalance/status", None, None)
+            .await
+    }
+
+    async fn rebalance_stop(&self) -> Result<()> {
+        self.request_no_response(Method::POST, "/rebalance/stop", None, None)
+            .await
+    }
+
+    async fn site_replication_info(&self) -> Result<SiteReplicationInfo> {
+        self.request(Method::GET, "/site-replication/info", None, None)
+            .await
+    }
+
+    async fn site_replication_status(&self) -> Result<SiteReplicationStatus> {
+        let query = [("buckets", "true"), ("metrics", "true")];
+        self.request(Method::GET, "/site-replication/status", Some(&query), None)
+            .await
+    }
+
+    async fn list_notify_targets(
+        &self,
+        target_type: NotifyTargetType,
+    ) -> Result<Vec<NotifyTarget>> {
+        let subsystem = target_type.subsystem();
+        let query = [("key", subsystem.as_str())];
+        let text = self
+            .request_text(Method::GET, "/get-config-kv", Some(&query), None)
+            .await?;
+        rc_core::admin::parse_notify_targets(target_type, &text)
+    }
+
+    async fn set_notify_target(&self, target: &NotifyTarget) -> Result<()> {
+        let body = target.to_config_line();
+        self.request_no_response(Method::PUT, "/set-config-kv", None, Some(body.as_bytes()))
+            .await
+    }
+
+    async fn remove_notify_target(&self, target_type: NotifyTargetType, name: &str) -> Result<()> {
+        let body = format!("{}:{name}", target_type.subsystem());
+        self.request_no_response(
+            Method::DELETE,
+            "/del-config-kv",
+            None,
+            Some(body.as_bytes()),
+        )
+        .await
+    }
+
+    async fn set_remote_target(&self, target: &BucketTarget) -> Result<String> {
+        let body = serde_json::to_vec(target).map_err(Error::Json)?;
+        let query = [("bucket", target.source_bucket.as_str())];
+        let text = self
+            .request_text(Method::PUT, "/set-remote-target", Some(&query), Some(&body))
+            .await?;
+        Ok(parse_arn(&text))
+    }
+
+    async fn list_remote_targets(&self, bucket: Option<&str>) -> Result<Vec<BucketTarget>> {
+        let query = [("bucket", bucket.unwrap_or("")), ("type", "replication")];
+        let targets: Option<Vec<BucketTarget>> = self
+            .request(Method::GET, "/list-remote-targets", Some(&query), None)
+            .await?;
+        Ok(targets.unwrap_or_default())
+    }
+
+    async fn remove_remote_target(&self, bucket: &str, arn: &str) -> Result<()> {
+        let query = [("bucket", bucket), ("arn", arn)];
+        self.request_no_response(Method::DELETE, "/remove-remote-target", Some(&query), None)
+            .await
+    }
+
+    async fn get_bucket_quota(&self, bucket: &str) -> Result<Option<BucketQuota>> {
+        let query = [("bucket", bucket)];
+        // Some servers answer 404 instead of an empty quota when none is configured
+        let quota: Option<BucketQuota> = match self
+            .request(Method::GET, "/get-bucket-quota", Some(&query), None)
+            .await
+        {
+            Ok(quota) => quota,
+            Err(Error::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+        Ok(quota.filter(BucketQuota::is_set))
+    }
+
+    async fn assume_role(&self, request: &AssumeRoleRequest) -> Result<TemporaryCredentials> {
+        let kind = if request.web_identity_token.is_some() {
+            RequestKind::StsUnsigned
+        } else {
+            RequestKind::Sts
+        };
+        if kind == RequestKind::Sts && self.signature_v2 {
+            return Err(Error::Config(
+                "STS requests must be signed with SigV4; this alias uses SigV2".into(),
+            ));
+        }
+
+        let url = format!("{}/", self.endpoint);
+        let form = sts_form(request);
+        let response = self
+            .send_kind(kind, Method::POST, &url, Some(form.as_bytes()))
+            .await?;
+        let text = response
+            .text()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?;
+        parse_sts_credentials(&text)
+    }
+
+    async fn stream_logs(
+        &self,
+        opts: &LogOpts,
+        on_entry: &(dyn for<'a> Fn(&'a LogEntry) + Send + Sync),
+    ) -> Result<()> {
+        let limit = opts.last.to_string();
+        let kind = opts.kind.to_string();
+        let mut query = vec![("limit", limit.as_str()), ("logType", kind.as_str())];
+        if let Some(node) = &opts.node {
+            query.push(("node", node.as_str()));
+        }
+
+        let mut response = self.send(Method::GET, "/log", Some(&query), None).await?;
+
+        let mut buffer: Vec<u8> = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?
+        {
+            buffer.extend_from_slice(&chunk);
+            while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=pos).collect();
+                if let Some(entry) = parse_log_line(&line) {
+                    on_entry(&entry);
+                }
+            }
+        }
+
+        if let Some(entry) = parse_log_line(&buffer) {
+            on_entry(&entry);
+        }
+
+        Ok(())
+    }
+
+    async fn prometheus_metrics(&self, target: MetricsTarget) -> Result<String> {
+        let url = self.metrics_url(target);
+        let response = self
+            .send_kind(RequestKind::Metrics, Method::GET, &url, None)
+            .await?;
+
+        let content_encoding = response
+            .headers()
+            .get(CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?;
+
+        decode_metrics_body(&body, content_encoding.as_deref())
+    }
+
+    async fn speedtest(
+        &self,
+        opts: SpeedTestOpts,
+        progress: &(dyn for<'a> Fn(&'a SpeedTestResult) + Send + Sync),
+    ) -> Result<SpeedTestResult> {
+        let size = opts.size.to_string();
+        let concurrent = opts.concurrent.to_string();
+        let autotune = opts.autotune.to_string();
+        let query = [
+            ("size", size.as_str()),
+            ("concurrent", concurrent.as_str()),
+            ("duration", opts.duration.as_str()),
+            ("autotune", autotune.as_str()),
+        ];
+
+        let mut response = self
+            .send(Method::POST, "/speedtest", Some(&query), None)
+            .await?;
+
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut last = None;
+
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?
+        {
+            buffer.extend_from_slice(&chunk);
+            while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=pos).collect();
+                if let Some(result) = parse_speedtest_line(&line)? {
+                    progress(&result);
+                    last = Some(result);
+                }
+            }
+        }
+
+        if let Some(result) = parse_speedtest_line(&buffer)? {
+            last = Some(result);
+        }
+
+        last.ok_or_else(|| Error::General("Speedtest returned no results".to_string()))
+    }
+
+    async fn start_profiling(
+        &self,
+        profilers: &[ProfilerType],
+    ) -> Result<Vec<ProfilingStartResult>> {
+        let profiler_type = profilers
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = [("profilerType", profiler_type.as_str())];
+        let response: Option<Vec<ProfilingStartResult>> = self
+            .request(Method::POST, "/profiling/start", Some(&query), None)
+            .await?;
+        Ok(response.unwrap_or_default())
+    }
+
+    async fn download_profiling_data(&self, dest: &Path) -> Result<u64> {
+        let mut response = self
+            .send(Method::GET, "/profiling/download", None, None)
+            .await?;
+
+        let mut file = tokio::fs::File::create(dest).await?;
+        let mut written: u64 = 0;
+
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to read response: {e}")))?
+        {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
+
+    async fn service_action(&self, action: ServiceAction) -> Result<()> {
+        let action_str = action.to_string();
+        let url = self.admin_url_with_query("/service", Some(&[("action", action_str.as_str())]));
+        retry_with_backoff(
+            &self.retry,
+            || async {
+                match self
+                    .dispatch(RequestKind::Admin, Method::POST, &url, None)
+                    .await?
+                {
+                    Ok(response) => self.check_response(response).await.map(|_| ()),
+                    // The node may go down before the response is flushed; the
+                    // signal was still delivered in that case.
+                    Err(e) if is_connection_dropped(&e) => Ok(()),
+                    Err(e) => Err(Error::Network(format!(
+                        "Request failed: {}",
+                        error_with_sources(&e)
+                    ))),
+                }
+            },
+            is_retryable_error,
+        )
+        .await
+    }
+
     // ==================== User Operations ====================
 
     async fn list_users(&self) -> Result<Vec<User>> {
@@ -649,12 +1425,51 @@ impl AdminApi for AdminClient {
         )
         .await
     }
+
+    async fn update_service_account(
+        &self,
+        access_key: &str,
+        request: UpdateServiceAccountRequest,
+    ) -> Result<()> {
+        let query = [("accessKey", access_key)];
+        let body = serde_json::to_vec(&request).map_err(Error::Json)?;
+        self.request_no_response(
+            Method::POST,
+            "/update-service-account",
+            Some(&query),
+            Some(&body),
+        )
+        .await
+    }
+
+    async fn set_service_account_status(&self, access_key: &str, status: UserStatus) -> Result<()> {
+        let request = UpdateServiceAccountRequest {
+            new_status: Some(service_account_status(status).to_string()),
+            ..Default::default()
+        };
+        self.update_service_account(access_key, request).await
+    }
+}
+
+/// Status value the service-account API uses for a user status
+fn service_account_status(status: UserStatus) -> &'static str {
+    match status {
+        UserStatus::Enabled => "on",
+        UserStatus::Disabled => "off",
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_arn() {
+        let arn = "arn:rustfs:replication::1234:photos-copy";
+        assert_eq!(parse_arn(&format!("\"{arn}\"")), arn);
+        assert_eq!(parse_arn(&format!("{arn}\n")), arn);
+    }
+
     #[test]
     fn test_admin_url_construction() {
         let alias = Alias::new("test", "http://localhost:9000", "access", "secret");
@@ -666,6 +1481,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_admin_client_rejects_anonymous_alias() {
+        let mut alias = Alias::new("public", "http://localhost:9000", "", "");
+        alias.anonymous = true;
+        assert!(matches!(AdminClient::new(&alias), Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sign_request_v2() {
+        let mut alias = Alias::new("legacy", "http://localhost:9000", "access", "secret");
+        alias.signature = "v2".to_string();
+        let client = AdminClient::new(&alias).unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("host", HeaderValue::from_static("localhost:9000"));
+        let url = client.admin_url("/list-users");
+        let signed = client
+            .sign_request(&Method::GET, &url, &headers, &[], "s3")
+            .await
+            .unwrap();
+
+        let date = signed.get(DATE).unwrap().to_str().unwrap();
+        let expected = sigv2::authorization(
+            "access",
+            "secret",
+            &format!("GET\n\n\n{date}\n/rustfs/admin/v3/list-users"),
+        )
+        .unwrap();
+        assert_eq!(signed.get(AUTHORIZATION).unwrap(), expected.as_str());
+        assert!(signed.get("x-amz-date").is_none());
+    }
+
     #[test]
     fn test_admin_url_with_trailing_slash() {
         let alias = Alias::new("test", "http://localhost:9000/", "access", "secret");
@@ -677,6 +1524,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_map_error_not_implemented() {
+        let alias = Alias::new("test", "http://localhost:9000", "access", "secret");
+        let client = AdminClient::new(&alias).unwrap();
+
+        let err = client.map_error(StatusCode::NOT_IMPLEMENTED, "heal", None);
+        assert!(matches!(err, Error::NotImplemented(_)));
+
+        let body = "<Error><Code>NotImplemented</Code></Error>";
+        let err = client.map_error(StatusCode::INTERNAL_SERVER_ERROR, body, None);
+        assert!(matches!(err, Error::NotImplemented(_)));
+    }
+
     #[test]
     fn test_get_host() {
         let alias = Alias::new("test", "https://s3.example.com", "access", "secret");
@@ -694,6 +1554,200 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_error_names_endpoint_and_quotes_body() {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        #[allow(dead_code)]
+        struct Status {
+            heal_id: String,
+        }
+
+        let body = r#"{"healing":true,"secretKey":"s3cr3t"}"#;
+        let err = serde_json::from_str::<Status>(body).unwrap_err();
+        let message = parse_error("/heal/status", &err, body).to_string();
+        assert!(
+            message.starts_with("Failed to parse /heal/status response: missing field `healId`")
+        );
+        assert!(message.contains(r#""healing":true"#));
+        assert!(!message.contains("s3cr3t"));
+
+        let long = format!("[{}]", "1,".repeat(1000));
+        let err = serde_json::from_str::<Status>(&long).unwrap_err();
+        assert!(parse_error("/x", &err, &long).to_string().ends_with("..."));
+    }
+
+    #[test]
+    fn test_parse_speedtest_line() {
+        assert!(parse_speedtest_line(b"").unwrap().is_none());
+        assert!(parse_speedtest_line(b"  \n").unwrap().is_none());
+
+        let result =
+            parse_speedtest_line(b"{\"servers\":3,\"PUTStats\":{\"throughputPerSec\":42}}\n")
+                .unwrap()
+                .expect("result parsed");
+        assert_eq!(result.servers, 3);
+        assert_eq!(result.put_stats.throughput_per_sec, 42);
+
+        assert!(parse_speedtest_line(b"not json").is_err());
+    }
+
+    #[test]
+    fn test_metrics_url() {
+        let alias = Alias::new("test", "http://localhost:9000", "access", "secret");
+        let client = AdminClient::new(&alias).unwrap();
+        for (target, path) in [
+            (MetricsTarget::Cluster, "cluster"),
+            (MetricsTarget::Node, "node"),
+            (MetricsTarget::Bucket, "bucket"),
+            (MetricsTarget::Resource, "resource"),
+        ] {
+            assert_eq!(
+                client.metrics_url(target),
+                format!("http://localhost:9000/rustfs/v2/metrics/{path}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_metrics_token() {
+        use base64::Engine as _;
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        use hmac::{Hmac, Mac};
+
+        let token = metrics_token("access", "secret", 1_700_000_000);
+        let parts: Vec<&str> = token.split('.').collect();
+        assert_eq!(parts.len(), 3);
+
+        let header: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[0]).unwrap()).unwrap();
+        assert_eq!(header["alg"], "HS512");
+        assert_eq!(header["typ"], "JWT");
+
+        let claims: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
+        assert_eq!(claims["sub"], "access");
+        assert_eq!(claims["iss"], "prometheus");
+        assert_eq!(claims["exp"], 1_700_000_000 + METRICS_TOKEN_TTL);
+
+        let mut mac = Hmac::<sha2::Sha512>::new_from_slice(b"secret").unwrap();
+        mac.update(format!("{}.{}", parts[0], parts[1]).as_bytes());
+        mac.verify_slice(&URL_SAFE_NO_PAD.decode(parts[2]).unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_decode_metrics_body() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let text = "# TYPE up gauge\nup 1\n";
+        assert_eq!(decode_metrics_body(text.as_bytes(), None).unwrap(), text);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+        assert_eq!(decode_metrics_body(&gzipped, Some("gzip")).unwrap(), text);
+        assert_eq!(decode_metrics_body(&gzipped, None).unwrap(), text);
+
+        assert!(decode_metrics_body(&[0x1f, 0x8b, 0], Some("gzip")).is_err());
+    }
+
+    #[test]
+    fn test_parse_log_line() {
+        assert!(parse_log_line(b"").is_none());
+        assert!(parse_log_line(b" \n").is_none());
+        assert!(parse_log_line(b"not json").is_none());
+
+        let entry =
+            parse_log_line(b"{\"node\":\"n1:9000\",\"message\":\"ok\"}\n").expect("entry parsed");
+        assert_eq!(entry.node, "n1:9000");
+        assert_eq!(entry.text(), "ok");
+    }
+
+    #[tokio::test]
+    async fn test_is_connection_dropped() {
+        // A server that accepts the connection and closes it without answering
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                drop(stream);
+            }
+        });
+        let client = reqwest::Client::new();
+        let err = client
+            .post(format!("http://{addr}/service"))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(is_connection_dropped(&err));
+
+        // Nothing listens on port 1, so the request never reaches a server
+        let err = client
+            .post("http://127.0.0.1:1/service")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(!is_connection_dropped(&err));
+    }
+
+    #[tokio::test]
+    async fn test_prometheus_metrics_sends_bearer_token() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                head.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nup 1\n")
+                .await
+                .unwrap();
+            String::from_utf8(head).unwrap().to_ascii_lowercase()
+        });
+
+        let alias = Alias::new("test", format!("http://{addr}"), "access", "secret");
+        let client = AdminClient::new(&alias).unwrap();
+        let body = client
+            .prometheus_metrics(MetricsTarget::Node)
+            .await
+            .unwrap();
+        assert_eq!(body, "up 1\n");
+
+        let head = server.await.unwrap();
+        assert!(head.starts_with("get /rustfs/v2/metrics/node "));
+        assert!(head.contains("authorization: bearer "));
+        assert!(!head.contains("aws4-hmac-sha256"));
+    }
+
+    #[test]
+    fn test_has_dropped_io_source() {
+        #[derive(Debug)]
+        struct Wrapper(std::io::Error);
+        impl std::fmt::Display for Wrapper {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "request failed")
+            }
+        }
+        impl std::error::Error for Wrapper {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let reset = Wrapper(std::io::ErrorKind::ConnectionReset.into());
+        assert!(has_dropped_io_source(&reset));
+        let refused = Wrapper(std::io::ErrorKind::ConnectionRefused.into());
+        assert!(!has_dropped_io_source(&refused));
+    }
+
     #[test]
     fn test_sha256_hash_empty() {
         let hash = AdminClient::sha256_hash(b"");
@@ -702,4 +1756,52 @@ mod tests {
             "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
         );
     }
+
+    #[test]
+    fn test_sts_form() {
+        let request = AssumeRoleRequest {
+            duration_seconds: Some(3600),
+            policy: Some(r#"{"Version":"2012-10-17"}"#.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            sts_form(&request),
+            "Action=AssumeRole&Version=2011-06-15&DurationSeconds=3600\
+             &Policy=%7B%22Version%22%3A%222012-10-17%22%7D"
+        );
+
+        let request = AssumeRoleRequest {
+            web_identity_token: Some("a.b+c".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            sts_form(&request),
+            "Action=AssumeRoleWithWebIdentity&Version=2011-06-15&WebIdentityToken=a.b%2Bc"
+        );
+    }
+
+    #[test]
+    fn test_parse_sts_credentials() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>Y4RJU1RNFGK48LGO9I2S</AccessKeyId>
+      <SecretAccessKey>sYLRKS1Z7hSjluf6gEbb9066hnx315wHTiACPAjg</SecretAccessKey>
+      <Expiration>2026-01-15T11:00:00Z</Expiration>
+      <SessionToken>eyJhbGciOi&amp;J9</SessionToken>
+    </Credentials>
+  </AssumeRoleResult>
+</AssumeRoleResponse>"#;
+        let creds = parse_sts_credentials(body).unwrap();
+        assert_eq!(creds.access_key, "Y4RJU1RNFGK48LGO9I2S");
+        assert_eq!(creds.secret_key, "sYLRKS1Z7hSjluf6gEbb9066hnx315wHTiACPAjg");
+        assert_eq!(creds.session_token, "eyJhbGciOi&J9");
+        assert_eq!(
+            creds.expiration,
+            Some("2026-01-15T11:00:00Z".parse().unwrap())
+        );
+
+        assert!(parse_sts_credentials("<AssumeRoleResponse/>").is_err());
+    }
 }
@@ -4,56 +4,138 @@
 //! This module provides capability detection to gracefully handle
 //! unsupported features.
 
+use aws_sdk_s3::error::{ProvideErrorMetadata, SdkError};
+use aws_sdk_s3::types::{
+    CsvInput, CsvOutput, ExpressionType, InputSerialization, OutputSerialization,
+};
 use rc_core::{Capabilities, Error, Result};
 
+use crate::error::{map_sdk_error, not_implemented_parts};
+
+/// Key used for probes that need an object; it is never created
+const PROBE_KEY: &str = ".rc-capability-probe";
+
 /// Detect capabilities of an S3 backend
 ///
-/// This function probes the backend to determine which features are supported.
-/// For optional commands, we use this to determine whether to proceed or
-/// return EXIT_UNSUPPORTED_FEATURE.
+/// Each feature is probed with a read-only request against `bucket`. Only a
+/// `NotImplemented` (HTTP 501) answer marks a feature unsupported: "not
+/// configured", "no such key" and "access denied" all mean the server knows
+/// the operation. A missing bucket, a 5xx answer or a transport failure says
+/// nothing either way and fails detection, so such results are never cached.
 pub async fn detect_capabilities(
     client: &aws_sdk_s3::Client,
     bucket: &str,
 ) -> Result<Capabilities> {
-    // Note: Object Lock and S3 Select detection would require additional probes
-    // that might have side effects. For now, we default to false and let users
-    // use --force if they know their backend supports these features.
-    let caps = Capabilities {
-        versioning: check_versioning(client, bucket).await,
-        tagging: check_tagging(client, bucket).await,
-        ..Default::default()
+    let (versioning, object_lock, tagging, select, notifications) = tokio::join!(
+        check_versioning(client, bucket),
+        check_object_lock(client, bucket),
+        check_tagging(client, bucket),
+        check_select(client, bucket),
+        check_notifications(client, bucket),
+    );
+
+    Ok(Capabilities {
+        versioning: versioning?,
+        object_lock: object_lock?,
+        tagging: tagging?,
+        select: select?,
+        notifications: notifications?,
+    })
+}
+
+/// Interpret a probe response, failing when it does not settle support
+fn supported<T, E>(result: std::result::Result<T, SdkError<E>>, bucket: &str) -> Result<bool>
+where
+    E: ProvideErrorMetadata + std::fmt::Display,
+{
+    let err = match result {
+        Ok(_) => return Ok(true),
+        Err(e) => e,
     };
 
-    Ok(caps)
+    let code = err.as_service_error().and_then(|e| e.code());
+    let status = err.raw_response().map(|r| r.status().as_u16());
+    match verdict(code, status) {
+        Some(supported) => Ok(supported),
+        None => Err(map_sdk_error(err, || format!("Bucket not found: {bucket}"))),
+    }
+}
+
+/// Whether a probe's error code and HTTP status settle support, and which way
+fn verdict(code: Option<&str>, status: Option<u16>) -> Option<bool> {
+    if not_implemented_parts(code, status) {
+        return Some(false);
+    }
+    if code == Some("NoSuchBucket") {
+        return None;
+    }
+    status.filter(|status| *status < 500).map(|_| true)
 }
 
 /// Check if bucket versioning is supported
-async fn check_versioning(client: &aws_sdk_s3::Client, bucket: &str) -> bool {
-    // Try to get versioning configuration
-    // If we get a successful response (even if versioning is not enabled),
-    // the backend supports versioning
-    client
-        .get_bucket_versioning()
+async fn check_versioning(client: &aws_sdk_s3::Client, bucket: &str) -> Result<bool> {
+    supported(
+        client.get_bucket_versioning().bucket(bucket).send().await,
+        bucket,
+    )
+}
+
+/// Check if object lock is supported
+async fn check_object_lock(client: &aws_sdk_s3::Client, bucket: &str) -> Result<bool> {
+    // ObjectLockConfigurationNotFoundError still means the feature exists
+    supported(
+        client
+            .get_object_lock_configuration()
+            .bucket(bucket)
+            .send()
+            .await,
+        bucket,
+    )
+}
+
+/// Check if object tagging is supported
+async fn check_tagging(client: &aws_sdk_s3::Client, bucket: &str) -> Result<bool> {
+    // NoSuchTagSet means tagging is supported, just no tags set
+    supported(
+        client.get_bucket_tagging().bucket(bucket).send().await,
+        bucket,
+    )
+}
+
+/// Check if S3 Select is supported
+async fn check_select(client: &aws_sdk_s3::Client, bucket: &str) -> Result<bool> {
+    // The probe key does not exist, so a supporting server answers NoSuchKey
+    let result = client
+        .select_object_content()
         .bucket(bucket)
+        .key(PROBE_KEY)
+        .expression("SELECT * FROM S3Object LIMIT 1")
+        .expression_type(ExpressionType::Sql)
+        .input_serialization(
+            InputSerialization::builder()
+                .csv(CsvInput::builder().build())
+                .build(),
+        )
+        .output_serialization(
+            OutputSerialization::builder()
+                .csv(CsvOutput::builder().build())
+                .build(),
+        )
         .send()
-        .await
-        .is_ok()
+        .await;
+    supported(result, bucket)
 }
 
-/// Check if object tagging is supported
-async fn check_tagging(client: &aws_sdk_s3::Client, bucket: &str) -> bool {
-    // Try to get bucket tagging
-    // Even if no tags are set, a supported backend will return a valid response
-    // or a specific "no tags" error, not an unsupported operation error
-    match client.get_bucket_tagging().bucket(bucket).send().await {
-        Ok(_) => true,
-        Err(e) => {
-            let err = e.into_service_error();
-            // NoSuchTagSet means tagging is supported, just no tags set
-            // AccessDenied might mean we don't have permission but feature exists
-            !err.to_string().contains("NotImplemented")
-        }
-    }
+/// Check if bucket event notifications are supported
+async fn check_notifications(client: &aws_sdk_s3::Client, bucket: &str) -> Result<bool> {
+    supported(
+        client
+            .get_bucket_notification_configuration()
+            .bucket(bucket)
+            .send()
+            .await,
+        bucket,
+    )
 }
 
 /// Check if a specific operation is supported, returning appropriate error
@@ -95,6 +177,21 @@ mod tests {
         assert!(require_capability(&caps, "versioning").is_err());
     }
 
+    #[test]
+    fn test_verdict_only_for_definitive_answers() {
+        assert_eq!(verdict(Some("NotImplemented"), Some(501)), Some(false));
+        assert_eq!(verdict(None, Some(501)), Some(false));
+        assert_eq!(verdict(Some("NoSuchTagSet"), Some(404)), Some(true));
+        assert_eq!(verdict(Some("AccessDenied"), Some(403)), Some(true));
+        assert_eq!(verdict(Some("NoSuchKey"), Some(404)), Some(true));
+
+        // Say nothing about the operation itself
+        assert_eq!(verdict(Some("NoSuchBucket"), Some(404)), None);
+        assert_eq!(verdict(Some("InternalError"), Some(500)), None);
+        assert_eq!(verdict(Some("SlowDown"), Some(503)), None);
+        assert_eq!(verdict(None, None), None);
+    }
+
     #[test]
     fn test_require_capability_unknown() {
         let caps = Capabilities::default();
@@ -2,56 +2,119 @@
 //!
 //! Wraps aws-sdk-s3 and implements the ObjectStore trait from rc-core.
 
-use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
 
+use async_trait::async_trait;
+use aws_smithy_http_client::tls::Provider;
+use aws_smithy_http_client::tls::rustls_provider::CryptoMode;
 use jiff::Timestamp;
 use rc_core::{
-    Alias, Capabilities, Error, ListOptions, ListResult, ObjectInfo, ObjectStore, ObjectVersion,
-    RemotePath, Result,
+    Acl, AclGrant, Alias, CannedAcl, Capabilities, DeleteError, DeleteResult, Error, ListOptions,
+    ListResult, NotificationKind, NotificationRule, ObjectChecksum, ObjectInfo, ObjectLockConfig,
+    ObjectStore, ObjectVersion, Owner, PutOptions, RemotePath, Result,
+};
+
+use crate::capability::detect_capabilities;
+use crate::error::{
+    is_invalid_range, is_not_configured, is_precondition_failed, map_sdk_error, region_of_mismatch,
 };
+use crate::headers::{HeaderCapture, ResponseHeaders};
+use crate::multipart::UploadState;
+use crate::request_log::RequestLogger;
+use crate::sigv2::SigV2Signer;
+use crate::{resolve, tls};
+
+/// Alias region value that enables per-bucket region detection
+pub const AUTO_REGION: &str = "auto";
+
+/// Region used to sign requests before a bucket's region is known
+const DEFAULT_REGION: &str = "us-east-1";
 
 /// S3 client wrapper
 pub struct S3Client {
     inner: aws_sdk_s3::Client,
-    #[allow(dead_code)]
     alias: Alias,
+    /// Clients bound to each bucket's detected region (region `auto` only)
+    bucket_clients: Mutex<HashMap<String, aws_sdk_s3::Client>>,
 }
 
 impl S3Client {
     /// Create a new S3 client from an alias configuration
+    ///
+    /// An anonymous alias gets no credentials provider, so requests are unsigned.
+    /// With `signature = "v2"` the SDK also runs without credentials and
+    /// [`SigV2Signer`] signs each request instead, using path-style addressing.
+    /// Hosts in the alias `resolve` mappings connect to the mapped addresses,
+    /// and the alias TLS settings replace the default TLS setup. Addressing
+    /// follows the alias `bucket_lookup` (see [`path_style`]). Transient
+    /// failures are retried by the SDK per the alias `retry` settings.
+    /// Endpoints other than `http://` and `https://` are rejected.
     pub async fn new(alias: Alias) -> Result<Self> {
         let endpoint = alias.endpoint.clone();
-        let region = alias.region.clone();
-        let access_key = alias.access_key.clone();
-        let secret_key = alias.secret_key.clone();
-
-        // Build credentials provider
-        let credentials = aws_credential_types::Credentials::new(
-            access_key,
-            secret_key,
-            None, // session token
-            None, // expiry
-            "rc-static-credentials",
-        );
-
+        check_endpoint_scheme(&endpoint)?;
+        let path_style = path_style(&alias)?;
+        let region = if alias.region == AUTO_REGION {
+            DEFAULT_REGION.to_string()
+        } else {
+            alias.region.clone()
+        };
         // Build SDK config
-        let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
-            .credentials_provider(credentials)
+        let retry = alias.retry_config();
+        let loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
             .region(aws_config::Region::new(region))
             .endpoint_url(&endpoint)
-            .load()
-            .await;
+            .retry_config(
+                aws_config::retry::RetryConfig::standard()
+                    .with_max_attempts(retry.max_attempts.max(1))
+                    .with_initial_backoff(Duration::from_millis(retry.initial_backoff_ms))
+                    .with_max_backoff(Duration::from_millis(retry.max_backoff_ms)),
+            );
+        let signature_v2 = alias.signature == "v2" && !alias.anonymous;
+        let loader = if alias.anonymous || signature_v2 {
+            loader.no_credentials()
+        } else {
+            loader.credentials_provider(aws_credential_types::Credentials::new(
+                alias.access_key.clone(),
+                alias.secret_key.clone(),
+                None, // session token
+                None, // expiry
+                "rc-static-credentials",
+            ))
+        };
+        let hosts = resolve::group_overrides(alias.resolve_overrides()?);
+        let loader = match (tls::client_config(&alias)?, hosts.is_empty()) {
+            (None, true) => loader,
+            (None, false) => loader.http_client(
+                aws_smithy_http_client::Builder::new()
+                    .tls_provider(Provider::Rustls(CryptoMode::AwsLc))
+                    .build_with_resolver(resolve::StaticResolver::new(hosts)),
+            ),
+            (Some(config), _) => loader.http_client(tls::s3_http_client(
+                config,
+                resolve::StaticResolver::new(hosts),
+            )),
+        };
+        let config = loader.load().await;
 
         // Build S3 client with path-style addressing for compatibility
-        let s3_config = aws_sdk_s3::config::Builder::from(&config)
-            .force_path_style(alias.bucket_lookup == "path" || alias.bucket_lookup == "auto")
-            .build();
+        let mut s3_config =
+            aws_sdk_s3::config::Builder::from(&config).force_path_style(signature_v2 || path_style);
+        if signature_v2 {
+            s3_config = s3_config.interceptor(SigV2Signer::new(
+                alias.access_key.clone(),
+                alias.secret_key.clone(),
+            ));
+        }
+        let s3_config = s3_config.interceptor(RequestLogger).build();
 
         let client = aws_sdk_s3::Client::from_conf(s3_config);
 
         Ok(Self {
             inner: client,
             alias,
+            bucket_clients: Mutex::new(HashMap::new()),
         })
     }
 
@@ -60,63 +123,246 @@ impl S3Client {
         &self.inner
     }
 
-    /// Format AWS SDK error into a detailed error message
-    fn format_sdk_error<E: std::fmt::Display>(error: &aws_sdk_s3::error::SdkError<E>) -> String {
-        match error {
-            aws_sdk_s3::error::SdkError::ServiceError(service_err) => {
-                let err = service_err.err();
-                let meta = service_err.raw();
-                let mut msg = format!("Service error: {}", err);
-                // Try to extract additional error information from headers
-                if let Some(code) = meta.headers().get("x-amz-error-code")
-                    && let Ok(code_str) = std::str::from_utf8(code.as_bytes())
-                {
-                    msg.push_str(&format!(" (code: {})", code_str));
+    /// Get the alias this client connects with
+    pub fn alias(&self) -> &Alias {
+        &self.alias
+    }
+
+    /// Get a client that signs requests for the region `bucket` lives in
+    ///
+    /// With region `auto`, the first request for a bucket asks the server for
+    /// its location and the answer is cached for the lifetime of this client.
+    /// Servers that do not answer `GetBucketLocation` keep the default region;
+    /// servers that reject it as signed for the wrong region are asked again
+    /// in the region they name.
+    async fn bucket_client(&self, bucket: &str) -> aws_sdk_s3::Client {
+        if self.alias.region != AUTO_REGION {
+            return self.inner.clone();
+        }
+
+        if let Some(client) = self
+            .bucket_clients
+            .lock()
+            .ok()
+            .and_then(|clients| clients.get(bucket).cloned())
+        {
+            return client;
+        }
+
+        let region = match self.inner.get_bucket_location().bucket(bucket).send().await {
+            Ok(response) => location_region(response.location_constraint().map(|c| c.as_str())),
+            Err(e) => match region_of_mismatch(&e) {
+                Some(expected) => {
+                    let retry = self
+                        .region_client(&expected)
+                        .get_bucket_location()
+                        .bucket(bucket)
+                        .send()
+                        .await;
+                    match retry {
+                        Ok(response) => {
+                            location_region(response.location_constraint().map(|c| c.as_str()))
+                        }
+                        Err(e) => {
+                            tracing::debug!("GetBucketLocation in {expected} failed: {e}");
+                            expected
+                        }
+                    }
                 }
-                msg
-            }
-            aws_sdk_s3::error::SdkError::ConstructionFailure(err) => {
-                format!("Request construction failed: {:?}", err)
-            }
-            aws_sdk_s3::error::SdkError::TimeoutError(_) => "Request timeout".to_string(),
-            aws_sdk_s3::error::SdkError::DispatchFailure(err) => {
-                format!("Network dispatch error: {:?}", err)
-            }
-            aws_sdk_s3::error::SdkError::ResponseError(err) => {
-                format!("Response error: {:?}", err)
-            }
-            _ => error.to_string(),
+                None => {
+                    tracing::debug!("GetBucketLocation failed for {bucket}: {e}");
+                    DEFAULT_REGION.to_string()
+                }
+            },
+        };
+
+        let client = self.region_client(&region);
+
+        if let Ok(mut clients) = self.bucket_clients.lock() {
+            clients.insert(bucket.to_string(), client.clone());
         }
+        client
     }
-}
 
-#[async_trait]
-impl ObjectStore for S3Client {
-    async fn list_buckets(&self) -> Result<Vec<ObjectInfo>> {
-        let response = self
+    /// A client like `inner` that signs for `region`
+    fn region_client(&self, region: &str) -> aws_sdk_s3::Client {
+        if region == DEFAULT_REGION {
+            return self.inner.clone();
+        }
+        let config = self
             .inner
-            .list_buckets()
+            .config()
+            .to_builder()
+            .region(aws_sdk_s3::config::Region::new(region.to_string()))
+            .build();
+        aws_sdk_s3::Client::from_conf(config)
+    }
+
+    /// Send one DeleteObjects request for up to 1000 keys or versions
+    async fn delete_identifiers(
+        &self,
+        bucket: &str,
+        objects: Vec<aws_sdk_s3::types::ObjectIdentifier>,
+    ) -> Result<DeleteResult> {
+        use aws_sdk_s3::types::Delete;
+
+        if objects.is_empty() {
+            return Ok(DeleteResult::default());
+        }
+
+        let delete = Delete::builder()
+            .set_objects(Some(objects))
+            .build()
+            .map_err(|e| Error::General(e.to_string()))?;
+
+        let response = self
+            .bucket_client(bucket)
+            .await
+            .delete_objects()
+            .bucket(bucket)
+            .delete(delete)
             .send()
             .await
-            .map_err(|e| Error::Network(e.to_string()))?;
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
 
-        let buckets = response
-            .buckets()
+        // Collect deleted keys
+        let deleted: Vec<String> = response
+            .deleted()
             .iter()
-            .map(|b| {
-                let mut info = ObjectInfo::bucket(b.name().unwrap_or_default());
-                if let Some(creation_date) = b.creation_date() {
-                    info.last_modified = jiff::Timestamp::from_second(creation_date.secs()).ok();
-                }
-                info
+            .filter_map(|d| d.key().map(|k| k.to_string()))
+            .collect();
+
+        // Collect per-key failures so callers can retry or report them
+        let errors: Vec<DeleteError> = response
+            .errors()
+            .iter()
+            .filter_map(|e| {
+                e.key().map(|k| DeleteError {
+                    key: k.to_string(),
+                    version_id: e.version_id().map(str::to_string),
+                    code: e.code().unwrap_or_default().to_string(),
+                    message: e.message().unwrap_or_default().to_string(),
+                })
             })
             .collect();
 
-        Ok(buckets)
+        Ok(DeleteResult { deleted, errors })
     }
 
-    async fn list_objects(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult> {
-        let mut request = self.inner.list_objects_v2().bucket(&path.bucket);
+    /// Presigned URLs are only generated with SigV4
+    fn check_presign_signature(&self) -> Result<()> {
+        if self.alias.signature == "v2" && !self.alias.anonymous {
+            return Err(Error::UnsupportedFeature(
+                "presigned URLs require signature v4; this alias uses v2".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Send a HEAD request for an object and return the raw response headers
+    ///
+    /// The status and headers are returned even when the server answers with
+    /// an error; this only fails when no response was received at all.
+    pub async fn head_object_headers(&self, path: &RemotePath) -> Result<ResponseHeaders> {
+        let capture = HeaderCapture::default();
+        let result = self
+            .bucket_client(&path.bucket)
+            .await
+            .head_object()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .customize()
+            .interceptor(capture.clone())
+            .send()
+            .await;
+
+        match (capture.take(), result) {
+            (Some(headers), _) => Ok(headers),
+            (None, Err(e)) => Err(map_sdk_error(e, || path.to_string())),
+            (None, Ok(_)) => Err(Error::General("No response headers captured".into())),
+        }
+    }
+
+    /// Fetch up to `length` bytes from `offset`, optionally pinned to one ETag
+    ///
+    /// The returned [`ObjectInfo`] describes the version that was read: its
+    /// ETag, modification time and full size (from `Content-Range`). With
+    /// `if_match`, the request is sent with `If-Match` and fails with
+    /// [`Error::Conflict`] once the object has changed, so a download assembled
+    /// from several ranges never mixes versions. A range starting past the end
+    /// of the object yields no data.
+    pub async fn get_object_range_pinned(
+        &self,
+        path: &RemotePath,
+        offset: u64,
+        length: u64,
+        if_match: Option<&str>,
+    ) -> Result<(Vec<u8>, ObjectInfo)> {
+        if length == 0 {
+            return Ok((Vec::new(), ObjectInfo::file(&path.key, 0)));
+        }
+
+        let result = self
+            .bucket_client(&path.bucket)
+            .await
+            .get_object()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .range(range_header(offset, Some(length))?)
+            .set_if_match(if_match.map(|etag| format!("\"{etag}\"")))
+            .send()
+            .await;
+
+        let response = match result {
+            Ok(response) => response,
+            Err(e) if is_invalid_range(&e) => {
+                return Ok((Vec::new(), ObjectInfo::file(&path.key, 0)));
+            }
+            Err(e) if is_precondition_failed(&e) => {
+                return Err(Error::Conflict(format!("{path} changed during download")));
+            }
+            Err(e) => return Err(map_sdk_error(e, || path.to_string())),
+        };
+
+        let size = response
+            .content_range()
+            .and_then(content_range_total)
+            .or_else(|| {
+                response
+                    .content_length()
+                    .and_then(|l| u64::try_from(l).ok())
+            })
+            .unwrap_or(0);
+        let mut info = ObjectInfo::file(&path.key, i64::try_from(size).unwrap_or(i64::MAX));
+        info.etag = response
+            .e_tag()
+            .map(|etag| etag.trim_matches('"').to_string());
+        info.last_modified = response
+            .last_modified()
+            .and_then(|modified| jiff::Timestamp::from_second(modified.secs()).ok());
+
+        let data = response
+            .body
+            .collect()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?
+            .into_bytes()
+            .to_vec();
+
+        Ok((data, info))
+    }
+
+    /// Build a ListObjectsV2 request for one page of `path`
+    async fn list_objects_request(
+        &self,
+        path: &RemotePath,
+        options: &ListOptions,
+    ) -> aws_sdk_s3::operation::list_objects_v2::builders::ListObjectsV2FluentBuilder {
+        let mut request = self
+            .bucket_client(&path.bucket)
+            .await
+            .list_objects_v2()
+            .bucket(&path.bucket);
 
         // Set prefix
         let prefix = if path.key.is_empty() {
@@ -131,9 +377,10 @@ impl ObjectStore for S3Client {
             request = request.prefix(p);
         }
 
-        // Set delimiter (for non-recursive listing)
-        if !options.recursive {
-            request = request.delimiter(options.delimiter.as_deref().unwrap_or("/"));
+        // Set delimiter (for non-recursive listing); an empty one lists flat
+        let delimiter = options.delimiter.as_deref().unwrap_or("/");
+        if !options.recursive && !delimiter.is_empty() {
+            request = request.delimiter(delimiter);
         }
 
         // Set max keys
@@ -141,19 +388,210 @@ impl ObjectStore for S3Client {
             request = request.max_keys(max);
         }
 
-        // Set continuation token
+        // Set pagination cursor; a continuation token takes precedence over start_after
         if let Some(token) = &options.continuation_token {
             request = request.continuation_token(token);
+        } else if let Some(start_after) = &options.start_after {
+            request = request.start_after(start_after);
         }
 
-        let response = request.send().await.map_err(|e| {
-            let err_str = Self::format_sdk_error(&e);
-            if err_str.contains("NotFound") || err_str.contains("NoSuchBucket") {
-                Error::NotFound(format!("Bucket not found: {}", path.bucket))
-            } else {
-                Error::Network(err_str)
-            }
-        })?;
+        request
+    }
+
+    /// Send one ListObjectsV2 request and return the raw response
+    ///
+    /// Debugging aid: the status, headers and XML body come back as the server
+    /// sent them, including fields the typed listing discards. Like
+    /// [`Self::head_object_headers`], error responses are returned too.
+    pub async fn list_objects_raw(
+        &self,
+        path: &RemotePath,
+        options: &ListOptions,
+    ) -> Result<ResponseHeaders> {
+        let capture = HeaderCapture::with_body();
+        let result = self
+            .list_objects_request(path, options)
+            .await
+            .customize()
+            .interceptor(capture.clone())
+            .send()
+            .await;
+
+        match (capture.take(), result) {
+            (Some(response), _) => Ok(response),
+            (None, Err(e)) => Err(map_sdk_error(e, || {
+                format!("Bucket not found: {}", path.bucket)
+            })),
+            (None, Ok(_)) => Err(Error::General("No response captured".into())),
+        }
+    }
+
+    /// Start a multipart upload and return its upload ID
+    pub async fn create_multipart_upload(
+        &self,
+        path: &RemotePath,
+        options: &PutOptions,
+    ) -> Result<String> {
+        let mut request = self
+            .bucket_client(&path.bucket)
+            .await
+            .create_multipart_upload()
+            .bucket(&path.bucket)
+            .key(&path.key);
+
+        if let Some(ct) = &options.content_type {
+            request = request.content_type(ct);
+        }
+
+        if let Some(ce) = &options.content_encoding {
+            request = request.content_encoding(ce);
+        }
+
+        if let Some(cc) = &options.cache_control {
+            request = request.cache_control(cc);
+        }
+
+        if let Some(cd) = &options.content_disposition {
+            request = request.content_disposition(cd);
+        }
+
+        if !options.metadata.is_empty() {
+            request = request.set_metadata(Some(options.metadata.clone()));
+        }
+
+        if let Some(sc) = &options.storage_class {
+            request = request.storage_class(aws_sdk_s3::types::StorageClass::from(sc.as_str()));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {}", path.bucket)))?;
+
+        response
+            .upload_id()
+            .map(str::to_string)
+            .ok_or_else(|| Error::General("Server returned no upload ID".into()))
+    }
+
+    /// Upload one part of a multipart upload and return its ETag
+    pub async fn upload_part(
+        &self,
+        path: &RemotePath,
+        upload_id: &str,
+        part_number: i32,
+        data: Vec<u8>,
+    ) -> Result<String> {
+        let response = self
+            .bucket_client(&path.bucket)
+            .await
+            .upload_part()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .body(aws_sdk_s3::primitives::ByteStream::from(data))
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Upload not found: {upload_id}")))?;
+
+        response.e_tag().map(str::to_string).ok_or_else(|| {
+            Error::General(format!("Server returned no ETag for part {part_number}"))
+        })
+    }
+
+    /// Complete a multipart upload from the parts recorded in `state`
+    pub async fn complete_multipart_upload(
+        &self,
+        path: &RemotePath,
+        state: &UploadState,
+    ) -> Result<ObjectInfo> {
+        use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
+
+        let mut parts: Vec<CompletedPart> = state
+            .completed_parts
+            .iter()
+            .map(|p| {
+                CompletedPart::builder()
+                    .part_number(p.part_number)
+                    .e_tag(&p.etag)
+                    .build()
+            })
+            .collect();
+        parts.sort_by_key(|p| p.part_number());
+
+        let response = self
+            .bucket_client(&path.bucket)
+            .await
+            .complete_multipart_upload()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .upload_id(&state.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Upload not found: {}", state.upload_id)))?;
+
+        let mut info = ObjectInfo::file(&path.key, state.total_size as i64);
+        if let Some(etag) = response.e_tag() {
+            info.etag = Some(etag.trim_matches('"').to_string());
+        }
+        info.last_modified = Some(Timestamp::now());
+
+        Ok(info)
+    }
+
+    /// Abort a multipart upload, discarding the parts uploaded so far
+    pub async fn abort_multipart_upload(&self, path: &RemotePath, upload_id: &str) -> Result<()> {
+        self.bucket_client(&path.bucket)
+            .await
+            .abort_multipart_upload()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .upload_id(upload_id)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Upload not found: {upload_id}")))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ObjectStore for S3Client {
+    async fn list_buckets(&self) -> Result<Vec<ObjectInfo>> {
+        let response = self
+            .inner
+            .list_buckets()
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || "buckets".to_string()))?;
+
+        let buckets = response
+            .buckets()
+            .iter()
+            .map(|b| {
+                let mut info = ObjectInfo::bucket(b.name().unwrap_or_default());
+                if let Some(creation_date) = b.creation_date() {
+                    info.last_modified = jiff::Timestamp::from_second(creation_date.secs()).ok();
+                }
+                info
+            })
+            .collect();
+
+        Ok(buckets)
+    }
+
+    async fn list_objects(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult> {
+        let request = self.list_objects_request(path, &options).await;
+        let response = request
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {}", path.bucket)))?;
 
         let mut items = Vec::new();
 
@@ -194,20 +632,14 @@ impl ObjectStore for S3Client {
 
     async fn head_object(&self, path: &RemotePath) -> Result<ObjectInfo> {
         let response = self
-            .inner
+            .bucket_client(&path.bucket)
+            .await
             .head_object()
             .bucket(&path.bucket)
             .key(&path.key)
             .send()
             .await
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                    Error::NotFound(path.to_string())
-                } else {
-                    Error::Network(err_str)
-                }
-            })?;
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
 
         let size = response.content_length().unwrap_or(0);
         let mut info = ObjectInfo::file(&path.key, size);
@@ -224,24 +656,87 @@ impl ObjectStore for S3Client {
             info.content_type = Some(ct.to_string());
         }
 
+        if let Some(ce) = response.content_encoding() {
+            info.content_encoding = Some(ce.to_string());
+        }
+
+        if let Some(cc) = response.cache_control() {
+            info.cache_control = Some(cc.to_string());
+        }
+
+        if let Some(cd) = response.content_disposition() {
+            info.content_disposition = Some(cd.to_string());
+        }
+
+        if let Some(metadata) = response.metadata() {
+            info.metadata = metadata
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect();
+        }
+
         if let Some(sc) = response.storage_class() {
             info.storage_class = Some(sc.as_str().to_string());
         }
 
+        info.version_id = response
+            .version_id()
+            .filter(|v| *v != "null")
+            .map(str::to_string);
+
         Ok(info)
     }
 
+    async fn object_checksum(&self, path: &RemotePath) -> Result<Option<ObjectChecksum>> {
+        let response = self
+            .bucket_client(&path.bucket)
+            .await
+            .head_object()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .checksum_mode(aws_sdk_s3::types::ChecksumMode::Enabled)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
+
+        let checksum = stored_checksum([
+            ("CRC32", response.checksum_crc32()),
+            ("CRC32C", response.checksum_crc32_c()),
+            ("CRC64NVME", response.checksum_crc64_nvme()),
+            ("SHA1", response.checksum_sha1()),
+            ("SHA256", response.checksum_sha256()),
+        ])
+        .map(|(algorithm, value)| ObjectChecksum {
+            algorithm: algorithm.to_string(),
+            value: value.to_string(),
+            checksum_type: response.checksum_type().map(|t| t.as_str().to_string()),
+        });
+
+        Ok(checksum)
+    }
+
+    async fn object_exists(&self, path: &RemotePath) -> Result<bool> {
+        match self.head_object(path).await {
+            Ok(_) => Ok(true),
+            Err(Error::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
     async fn bucket_exists(&self, bucket: &str) -> Result<bool> {
-        match self.inner.head_bucket().bucket(bucket).send().await {
+        match self
+            .bucket_client(bucket)
+            .await
+            .head_bucket()
+            .bucket(bucket)
+            .send()
+            .await
+        {
             Ok(_) => Ok(true),
-            Err(e) => {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchBucket") {
-                    Ok(false)
-                } else {
-                    Err(Error::Network(err_str))
-                }
-            }
+            Err(e) => match map_sdk_error(e, || format!("Bucket not found: {bucket}")) {
+                Error::NotFound(_) => Ok(false),
+                err => Err(err),
+            },
         }
     }
 
@@ -251,57 +746,75 @@ impl ObjectStore for S3Client {
             .bucket(bucket)
             .send()
             .await
-            .map_err(|e| Error::Network(e.to_string()))?;
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
 
         Ok(())
     }
 
     async fn delete_bucket(&self, bucket: &str) -> Result<()> {
-        self.inner
+        self.bucket_client(bucket)
+            .await
             .delete_bucket()
             .bucket(bucket)
             .send()
             .await
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchBucket") {
-                    Error::NotFound(format!("Bucket not found: {bucket}"))
-                } else {
-                    Error::Network(err_str)
-                }
-            })?;
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
 
         Ok(())
     }
 
-    async fn capabilities(&self) -> Result<Capabilities> {
-        // For now, return conservative defaults
-        // In Phase 5, we'll implement actual capability detection
-        Ok(Capabilities {
-            versioning: true,
-            object_lock: false,
-            tagging: true,
-            select: false,
-            notifications: false,
-        })
+    async fn capabilities(&self, bucket: &str) -> Result<Capabilities> {
+        detect_capabilities(&self.bucket_client(bucket).await, bucket).await
     }
 
     async fn get_object(&self, path: &RemotePath) -> Result<Vec<u8>> {
         let response = self
-            .inner
+            .bucket_client(&path.bucket)
+            .await
             .get_object()
             .bucket(&path.bucket)
             .key(&path.key)
             .send()
             .await
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                    Error::NotFound(path.to_string())
-                } else {
-                    Error::Network(err_str)
-                }
-            })?;
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
+
+        let data = response
+            .body
+            .collect()
+            .await
+            .map_err(|e| Error::Network(e.to_string()))?
+            .into_bytes()
+            .to_vec();
+
+        Ok(data)
+    }
+
+    async fn get_object_range(
+        &self,
+        path: &RemotePath,
+        offset: u64,
+        length: Option<u64>,
+    ) -> Result<Vec<u8>> {
+        if length == Some(0) {
+            return Ok(Vec::new());
+        }
+
+        let result = self
+            .bucket_client(&path.bucket)
+            .await
+            .get_object()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .range(range_header(offset, length)?)
+            .send()
+            .await;
+
+        // The range starts at or past the end of the object (including empty objects)
+        let response = match result {
+            Ok(response) => response,
+            Err(e) if is_invalid_range(&e) => return Ok(Vec::new()),
+            Err(e) => return Err(map_sdk_error(e, || path.to_string())),
+        };
 
         let data = response
             .body
@@ -319,25 +832,59 @@ impl ObjectStore for S3Client {
         path: &RemotePath,
         data: Vec<u8>,
         content_type: Option<&str>,
+    ) -> Result<ObjectInfo> {
+        let options = PutOptions {
+            content_type: content_type.map(str::to_string),
+            ..Default::default()
+        };
+        self.put_object_with_options(path, data, &options).await
+    }
+
+    async fn put_object_with_options(
+        &self,
+        path: &RemotePath,
+        data: Vec<u8>,
+        options: &PutOptions,
     ) -> Result<ObjectInfo> {
         let size = data.len() as i64;
         let body = aws_sdk_s3::primitives::ByteStream::from(data);
 
         let mut request = self
-            .inner
+            .bucket_client(&path.bucket)
+            .await
             .put_object()
             .bucket(&path.bucket)
             .key(&path.key)
             .body(body);
 
-        if let Some(ct) = content_type {
+        if let Some(ct) = &options.content_type {
             request = request.content_type(ct);
         }
 
+        if let Some(ce) = &options.content_encoding {
+            request = request.content_encoding(ce);
+        }
+
+        if let Some(cc) = &options.cache_control {
+            request = request.cache_control(cc);
+        }
+
+        if let Some(cd) = &options.content_disposition {
+            request = request.content_disposition(cd);
+        }
+
+        if !options.metadata.is_empty() {
+            request = request.set_metadata(Some(options.metadata.clone()));
+        }
+
+        if let Some(sc) = &options.storage_class {
+            request = request.storage_class(aws_sdk_s3::types::StorageClass::from(sc.as_str()));
+        }
+
         let response = request
             .send()
             .await
-            .map_err(|e| Error::Network(e.to_string()))?;
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {}", path.bucket)))?;
 
         let mut info = ObjectInfo::file(&path.key, size);
         if let Some(etag) = response.e_tag() {
@@ -349,113 +896,168 @@ impl ObjectStore for S3Client {
     }
 
     async fn delete_object(&self, path: &RemotePath) -> Result<()> {
-        self.inner
+        self.bucket_client(&path.bucket)
+            .await
             .delete_object()
             .bucket(&path.bucket)
             .key(&path.key)
             .send()
             .await
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                    Error::NotFound(path.to_string())
-                } else {
-                    Error::Network(err_str)
-                }
-            })?;
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
 
         Ok(())
     }
 
-    async fn delete_objects(&self, bucket: &str, keys: Vec<String>) -> Result<Vec<String>> {
-        use aws_sdk_s3::types::{Delete, ObjectIdentifier};
-
-        if keys.is_empty() {
-            return Ok(vec![]);
-        }
+    async fn delete_objects(&self, bucket: &str, keys: Vec<String>) -> Result<DeleteResult> {
+        use aws_sdk_s3::types::ObjectIdentifier;
 
-        let objects: Vec<ObjectIdentifier> = keys
+        let objects = keys
             .iter()
-            .map(|k| ObjectIdentifier::builder().key(k).build().unwrap())
-            .collect();
-
-        let delete = Delete::builder()
-            .set_objects(Some(objects))
-            .build()
+            .map(|k| ObjectIdentifier::builder().key(k).build())
+            .collect::<std::result::Result<Vec<_>, _>>()
             .map_err(|e| Error::General(e.to_string()))?;
+        self.delete_identifiers(bucket, objects).await
+    }
 
-        let response = self
-            .inner
-            .delete_objects()
-            .bucket(bucket)
-            .delete(delete)
+    async fn delete_object_version(&self, path: &RemotePath, version_id: &str) -> Result<()> {
+        self.bucket_client(&path.bucket)
+            .await
+            .delete_object()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .version_id(version_id)
             .send()
             .await
-            .map_err(|e| Error::Network(e.to_string()))?;
+            .map_err(|e| map_sdk_error(e, || format!("{path} (version {version_id})")))?;
 
-        // Collect deleted keys
-        let deleted: Vec<String> = response
-            .deleted()
-            .iter()
-            .filter_map(|d| d.key().map(|k| k.to_string()))
-            .collect();
+        Ok(())
+    }
 
-        // Check for errors
-        if !response.errors().is_empty() {
-            let error_keys: Vec<String> = response
-                .errors()
-                .iter()
-                .filter_map(|e| e.key().map(|k| k.to_string()))
-                .collect();
-            tracing::warn!("Failed to delete some objects: {:?}", error_keys);
-        }
+    async fn delete_object_versions(
+        &self,
+        bucket: &str,
+        versions: Vec<(String, String)>,
+    ) -> Result<DeleteResult> {
+        use aws_sdk_s3::types::ObjectIdentifier;
 
-        Ok(deleted)
+        let objects = versions
+            .iter()
+            .map(|(key, version_id)| {
+                ObjectIdentifier::builder()
+                    .key(key)
+                    .version_id(version_id)
+                    .build()
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Error::General(e.to_string()))?;
+        self.delete_identifiers(bucket, objects).await
     }
 
     async fn copy_object(&self, src: &RemotePath, dst: &RemotePath) -> Result<ObjectInfo> {
+        use aws_sdk_s3::types::{MetadataDirective, TaggingDirective};
+
         // Build copy source: bucket/key
         let copy_source = format!("{}/{}", src.bucket, src.key);
 
+        // Ask for headers, user metadata and tags to be carried over explicitly
         let response = self
-            .inner
+            .bucket_client(&dst.bucket)
+            .await
             .copy_object()
             .copy_source(&copy_source)
             .bucket(&dst.bucket)
             .key(&dst.key)
+            .metadata_directive(MetadataDirective::Copy)
+            .tagging_directive(TaggingDirective::Copy)
             .send()
             .await
-            .map_err(|e| {
-                let err_str = e.to_string();
-                if err_str.contains("NotFound") || err_str.contains("NoSuchKey") {
-                    Error::NotFound(src.to_string())
-                } else {
-                    Error::Network(err_str)
-                }
-            })?;
+            .map_err(|e| map_sdk_error(e, || src.to_string()))?;
 
         // Get size from head_object since copy doesn't return it
         let info = self.head_object(dst).await?;
 
-        // Update etag from copy response if available
-        let mut result = info;
+        // Update etag from copy response if available
+        let mut result = info;
+        if let Some(copy_result) = response.copy_object_result()
+            && let Some(etag) = copy_result.e_tag()
+        {
+            result.etag = Some(etag.trim_matches('"').to_string());
+        }
+
+        Ok(result)
+    }
+
+    async fn copy_object_with_options(
+        &self,
+        src: &RemotePath,
+        dst: &RemotePath,
+        options: &PutOptions,
+    ) -> Result<ObjectInfo> {
+        use aws_sdk_s3::types::{MetadataDirective, TaggingDirective};
+
+        // REPLACE drops every header that is not sent, so start from the
+        // source's current values
+        let current = self
+            .bucket_client(&src.bucket)
+            .await
+            .head_object()
+            .bucket(&src.bucket)
+            .key(&src.key)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || src.to_string()))?;
+
+        let pick =
+            |new: &Option<String>, old: Option<&str>| new.clone().or(old.map(str::to_string));
+        let metadata = if options.metadata.is_empty() {
+            current.metadata().cloned()
+        } else {
+            Some(options.metadata.clone())
+        };
+
+        let copy_source = format!("{}/{}", src.bucket, src.key);
+        let response = self
+            .bucket_client(&dst.bucket)
+            .await
+            .copy_object()
+            .copy_source(&copy_source)
+            .bucket(&dst.bucket)
+            .key(&dst.key)
+            .metadata_directive(MetadataDirective::Replace)
+            .tagging_directive(TaggingDirective::Copy)
+            .set_content_type(pick(&options.content_type, current.content_type()))
+            .set_content_encoding(pick(&options.content_encoding, current.content_encoding()))
+            .set_cache_control(pick(&options.cache_control, current.cache_control()))
+            .set_content_disposition(pick(
+                &options.content_disposition,
+                current.content_disposition(),
+            ))
+            .set_storage_class(current.storage_class().cloned())
+            .set_metadata(metadata)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || src.to_string()))?;
+
+        let mut info = self.head_object(dst).await?;
         if let Some(copy_result) = response.copy_object_result()
             && let Some(etag) = copy_result.e_tag()
         {
-            result.etag = Some(etag.trim_matches('"').to_string());
+            info.etag = Some(etag.trim_matches('"').to_string());
         }
 
-        Ok(result)
+        Ok(info)
     }
 
     async fn presign_get(&self, path: &RemotePath, expires_secs: u64) -> Result<String> {
+        self.check_presign_signature()?;
         let config = aws_sdk_s3::presigning::PresigningConfig::builder()
             .expires_in(std::time::Duration::from_secs(expires_secs))
             .build()
             .map_err(|e| Error::General(format!("presign_get config: {e}")))?;
 
         let request = self
-            .inner
+            .bucket_client(&path.bucket)
+            .await
             .get_object()
             .bucket(&path.bucket)
             .key(&path.key)
@@ -472,12 +1074,18 @@ impl ObjectStore for S3Client {
         expires_secs: u64,
         content_type: Option<&str>,
     ) -> Result<String> {
+        self.check_presign_signature()?;
         let config = aws_sdk_s3::presigning::PresigningConfig::builder()
             .expires_in(std::time::Duration::from_secs(expires_secs))
             .build()
             .map_err(|e| Error::General(format!("presign_put config: {e}")))?;
 
-        let mut builder = self.inner.put_object().bucket(&path.bucket).key(&path.key);
+        let mut builder = self
+            .bucket_client(&path.bucket)
+            .await
+            .put_object()
+            .bucket(&path.bucket)
+            .key(&path.key);
 
         if let Some(ct) = content_type {
             builder = builder.content_type(ct);
@@ -493,7 +1101,8 @@ impl ObjectStore for S3Client {
 
     async fn get_versioning(&self, bucket: &str) -> Result<Option<bool>> {
         let response = self
-            .inner
+            .bucket_client(bucket)
+            .await
             .get_bucket_versioning()
             .bucket(bucket)
             .send()
@@ -516,7 +1125,8 @@ impl ObjectStore for S3Client {
 
         let config = VersioningConfiguration::builder().status(status).build();
 
-        self.inner
+        self.bucket_client(bucket)
+            .await
             .put_bucket_versioning()
             .bucket(bucket)
             .versioning_configuration(config)
@@ -532,51 +1142,70 @@ impl ObjectStore for S3Client {
         path: &RemotePath,
         max_keys: Option<i32>,
     ) -> Result<Vec<ObjectVersion>> {
-        let mut builder = self.inner.list_object_versions().bucket(&path.bucket);
-
-        if !path.key.is_empty() {
-            builder = builder.prefix(&path.key);
-        }
+        let client = self.bucket_client(&path.bucket).await;
+        let mut versions = Vec::new();
+        let mut key_marker: Option<String> = None;
+        let mut version_id_marker: Option<String> = None;
+
+        loop {
+            let mut builder = client
+                .list_object_versions()
+                .bucket(&path.bucket)
+                .set_key_marker(key_marker.take())
+                .set_version_id_marker(version_id_marker.take());
+
+            if !path.key.is_empty() {
+                builder = builder.prefix(&path.key);
+            }
 
-        if let Some(max) = max_keys {
-            builder = builder.max_keys(max);
-        }
+            if let Some(max) = max_keys {
+                builder = builder.max_keys(max);
+            }
 
-        let response = builder
-            .send()
-            .await
-            .map_err(|e| Error::General(format!("list_object_versions: {e}")))?;
+            let response = builder
+                .send()
+                .await
+                .map_err(|e| Error::General(format!("list_object_versions: {e}")))?;
+
+            // Add regular versions
+            for v in response.versions() {
+                versions.push(ObjectVersion {
+                    key: v.key().unwrap_or_default().to_string(),
+                    version_id: v.version_id().unwrap_or("null").to_string(),
+                    is_latest: v.is_latest().unwrap_or(false),
+                    is_delete_marker: false,
+                    last_modified: v
+                        .last_modified()
+                        .and_then(|dt| Timestamp::from_second(dt.secs()).ok()),
+                    size_bytes: v.size(),
+                    etag: v.e_tag().map(|s| s.trim_matches('"').to_string()),
+                });
+            }
 
-        let mut versions = Vec::new();
+            // Add delete markers
+            for m in response.delete_markers() {
+                versions.push(ObjectVersion {
+                    key: m.key().unwrap_or_default().to_string(),
+                    version_id: m.version_id().unwrap_or("null").to_string(),
+                    is_latest: m.is_latest().unwrap_or(false),
+                    is_delete_marker: true,
+                    last_modified: m
+                        .last_modified()
+                        .and_then(|dt| Timestamp::from_second(dt.secs()).ok()),
+                    size_bytes: None,
+                    etag: None,
+                });
+            }
 
-        // Add regular versions
-        for v in response.versions() {
-            versions.push(ObjectVersion {
-                key: v.key().unwrap_or_default().to_string(),
-                version_id: v.version_id().unwrap_or("null").to_string(),
-                is_latest: v.is_latest().unwrap_or(false),
-                is_delete_marker: false,
-                last_modified: v
-                    .last_modified()
-                    .and_then(|dt| Timestamp::from_second(dt.secs()).ok()),
-                size_bytes: v.size(),
-                etag: v.e_tag().map(|s| s.trim_matches('"').to_string()),
-            });
-        }
-
-        // Add delete markers
-        for m in response.delete_markers() {
-            versions.push(ObjectVersion {
-                key: m.key().unwrap_or_default().to_string(),
-                version_id: m.version_id().unwrap_or("null").to_string(),
-                is_latest: m.is_latest().unwrap_or(false),
-                is_delete_marker: true,
-                last_modified: m
-                    .last_modified()
-                    .and_then(|dt| Timestamp::from_second(dt.secs()).ok()),
-                size_bytes: None,
-                etag: None,
-            });
+            // A limit means one page; otherwise follow the markers to the end
+            if max_keys.is_some() || !response.is_truncated().unwrap_or(false) {
+                break;
+            }
+            key_marker = response.next_key_marker().map(str::to_string);
+            version_id_marker = response.next_version_id_marker().map(str::to_string);
+            if key_marker.is_none() {
+                break;
+            }
         }
 
         // Sort by key and then by last_modified (descending)
@@ -594,7 +1223,8 @@ impl ObjectStore for S3Client {
         path: &RemotePath,
     ) -> Result<std::collections::HashMap<String, String>> {
         let response = self
-            .inner
+            .bucket_client(&path.bucket)
+            .await
             .get_object_tagging()
             .bucket(&path.bucket)
             .key(&path.key)
@@ -629,7 +1259,8 @@ impl ObjectStore for S3Client {
             .build()
             .expect("valid tagging");
 
-        self.inner
+        self.bucket_client(&path.bucket)
+            .await
             .put_object_tagging()
             .bucket(&path.bucket)
             .key(&path.key)
@@ -642,7 +1273,8 @@ impl ObjectStore for S3Client {
     }
 
     async fn delete_object_tags(&self, path: &RemotePath) -> Result<()> {
-        self.inner
+        self.bucket_client(&path.bucket)
+            .await
             .delete_object_tagging()
             .bucket(&path.bucket)
             .key(&path.key)
@@ -652,16 +1284,783 @@ impl ObjectStore for S3Client {
 
         Ok(())
     }
+
+    async fn get_object_acl(&self, path: &RemotePath) -> Result<Acl> {
+        let response = self
+            .bucket_client(&path.bucket)
+            .await
+            .get_object_acl()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
+
+        Ok(convert_acl(response.owner(), response.grants()))
+    }
+
+    async fn set_object_acl(&self, path: &RemotePath, acl: CannedAcl) -> Result<()> {
+        use aws_sdk_s3::types::ObjectCannedAcl;
+
+        self.bucket_client(&path.bucket)
+            .await
+            .put_object_acl()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .acl(ObjectCannedAcl::from(acl.to_string().as_str()))
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn put_object_acl(&self, path: &RemotePath, acl: &Acl) -> Result<()> {
+        self.bucket_client(&path.bucket)
+            .await
+            .put_object_acl()
+            .bucket(&path.bucket)
+            .key(&path.key)
+            .access_control_policy(access_control_policy(acl)?)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || path.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get_bucket_acl(&self, bucket: &str) -> Result<Acl> {
+        let response = self
+            .bucket_client(bucket)
+            .await
+            .get_bucket_acl()
+            .bucket(bucket)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
+
+        Ok(convert_acl(response.owner(), response.grants()))
+    }
+
+    async fn set_bucket_acl(&self, bucket: &str, acl: CannedAcl) -> Result<()> {
+        use aws_sdk_s3::types::BucketCannedAcl;
+
+        if !acl.applies_to_bucket() {
+            return Err(Error::General(format!(
+                "Canned ACL '{acl}' only applies to objects"
+            )));
+        }
+
+        self.bucket_client(bucket)
+            .await
+            .put_bucket_acl()
+            .bucket(bucket)
+            .acl(BucketCannedAcl::from(acl.to_string().as_str()))
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
+
+        Ok(())
+    }
+
+    async fn get_bucket_notification_configuration(
+        &self,
+        bucket: &str,
+    ) -> Result<Vec<NotificationRule>> {
+        let response = self
+            .bucket_client(bucket)
+            .await
+            .get_bucket_notification_configuration()
+            .bucket(bucket)
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
+
+        let queues = response.queue_configurations().iter().map(|c| {
+            notification_rule(
+                c.id(),
+                c.queue_arn(),
+                NotificationKind::Queue,
+                c.events(),
+                c.filter(),
+            )
+        });
+        let topics = response.topic_configurations().iter().map(|c| {
+            notification_rule(
+                c.id(),
+                c.topic_arn(),
+                NotificationKind::Topic,
+                c.events(),
+                c.filter(),
+            )
+        });
+        let lambdas = response.lambda_function_configurations().iter().map(|c| {
+            notification_rule(
+                c.id(),
+                c.lambda_function_arn(),
+                NotificationKind::Lambda,
+                c.events(),
+                c.filter(),
+            )
+        });
+        Ok(queues.chain(topics).chain(lambdas).collect())
+    }
+
+    async fn put_bucket_notification_configuration(
+        &self,
+        bucket: &str,
+        rules: Vec<NotificationRule>,
+    ) -> Result<()> {
+        use aws_sdk_s3::types::{
+            Event, LambdaFunctionConfiguration, NotificationConfiguration, QueueConfiguration,
+            TopicConfiguration,
+        };
+
+        let invalid = |e: aws_sdk_s3::error::BuildError| {
+            Error::General(format!("Invalid notification rule: {e}"))
+        };
+
+        let mut config = NotificationConfiguration::builder();
+        for rule in rules {
+            let events: Vec<Event> = rule
+                .events
+                .iter()
+                .map(|e| Event::from(e.as_str()))
+                .collect();
+            let filter = notification_filter(rule.prefix.as_deref(), rule.suffix.as_deref());
+            config = match rule.kind {
+                NotificationKind::Queue => config.queue_configurations(
+                    QueueConfiguration::builder()
+                        .set_id(rule.id)
+                        .queue_arn(rule.arn)
+                        .set_events(Some(events))
+                        .set_filter(filter)
+                        .build()
+                        .map_err(invalid)?,
+                ),
+                NotificationKind::Topic => config.topic_configurations(
+                    TopicConfiguration::builder()
+                        .set_id(rule.id)
+                        .topic_arn(rule.arn)
+                        .set_events(Some(events))
+                        .set_filter(filter)
+                        .build()
+                        .map_err(invalid)?,
+                ),
+                NotificationKind::Lambda => config.lambda_function_configurations(
+                    LambdaFunctionConfiguration::builder()
+                        .set_id(rule.id)
+                        .lambda_function_arn(rule.arn)
+                        .set_events(Some(events))
+                        .set_filter(filter)
+                        .build()
+                        .map_err(invalid)?,
+                ),
+            };
+        }
+
+        self.bucket_client(bucket)
+            .await
+            .put_bucket_notification_configuration()
+            .bucket(bucket)
+            .notification_configuration(config.build())
+            .send()
+            .await
+            .map_err(|e| map_sdk_error(e, || format!("Bucket not found: {bucket}")))?;
+
+        Ok(())
+    }
+
+    async fn get_object_lock_config(&self, bucket: &str) -> Result<Option<ObjectLockConfig>> {
+        use aws_sdk_s3::types::ObjectLockEnabled;
+
+        let response = match self
+            .bucket_client(bucket)
+            .await
+            .get_object_lock_configuration()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(e) if is_not_configured(&e) => return Ok(None),
+            Err(e) => return Err(map_sdk_error(e, || format!("Bucket not found: {bucket}"))),
+        };
+
+        let Some(config) = response.object_lock_configuration() else {
+            return Ok(None);
+        };
+        if config.object_lock_enabled() != Some(&ObjectLockEnabled::Enabled) {
+            return Ok(None);
+        }
+
+        let retention = config.rule().and_then(|r| r.default_retention());
+        Ok(Some(ObjectLockConfig {
+            mode: retention
+                .and_then(|r| r.mode())
+                .map(|m| m.as_str().to_string()),
+            days: retention.and_then(|r| r.days()),
+            years: retention.and_then(|r| r.years()),
+        }))
+    }
+
+    async fn get_bucket_tags(&self, bucket: &str) -> Result<HashMap<String, String>> {
+        let response = match self
+            .bucket_client(bucket)
+            .await
+            .get_bucket_tagging()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(e) if is_not_configured(&e) => return Ok(HashMap::new()),
+            Err(e) => return Err(map_sdk_error(e, || format!("Bucket not found: {bucket}"))),
+        };
+
+        Ok(response
+            .tag_set()
+            .iter()
+            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+            .collect())
+    }
+
+    async fn get_bucket_policy(&self, bucket: &str) -> Result<Option<String>> {
+        match self
+            .bucket_client(bucket)
+            .await
+            .get_bucket_policy()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response.policy().map(str::to_string)),
+            Err(e) if is_not_configured(&e) => Ok(None),
+            Err(e) => Err(map_sdk_error(e, || format!("Bucket not found: {bucket}"))),
+        }
+    }
+
+    async fn lifecycle_rule_count(&self, bucket: &str) -> Result<usize> {
+        match self
+            .bucket_client(bucket)
+            .await
+            .get_bucket_lifecycle_configuration()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response.rules().len()),
+            Err(e) if is_not_configured(&e) => Ok(0),
+            Err(e) => Err(map_sdk_error(e, || format!("Bucket not found: {bucket}"))),
+        }
+    }
+
+    async fn replication_rule_count(&self, bucket: &str) -> Result<usize> {
+        match self
+            .bucket_client(bucket)
+            .await
+            .get_bucket_replication()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response
+                .replication_configuration()
+                .map_or(0, |config| config.rules().len())),
+            Err(e) if is_not_configured(&e) => Ok(0),
+            Err(e) => Err(map_sdk_error(e, || format!("Bucket not found: {bucket}"))),
+        }
+    }
+}
+
+/// Convert one SDK notification configuration into a rule
+fn notification_rule(
+    id: Option<&str>,
+    arn: &str,
+    kind: NotificationKind,
+    events: &[aws_sdk_s3::types::Event],
+    filter: Option<&aws_sdk_s3::types::NotificationConfigurationFilter>,
+) -> NotificationRule {
+    use aws_sdk_s3::types::FilterRuleName;
+
+    let filter_rules = filter
+        .and_then(|f| f.key())
+        .map(|k| k.filter_rules())
+        .unwrap_or_default();
+    let filter_value = |wanted: FilterRuleName| {
+        filter_rules
+            .iter()
+            .find(|r| r.name() == Some(&wanted))
+            .and_then(|r| r.value())
+            .map(str::to_string)
+    };
+
+    NotificationRule {
+        id: id.map(str::to_string),
+        arn: arn.to_string(),
+        kind,
+        events: events.iter().map(|e| e.as_str().to_string()).collect(),
+        prefix: filter_value(FilterRuleName::Prefix),
+        suffix: filter_value(FilterRuleName::Suffix),
+    }
+}
+
+/// Build the key filter for a rule, or `None` when it has no prefix or suffix
+fn notification_filter(
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> Option<aws_sdk_s3::types::NotificationConfigurationFilter> {
+    use aws_sdk_s3::types::{
+        FilterRule, FilterRuleName, NotificationConfigurationFilter, S3KeyFilter,
+    };
+
+    let rules: Vec<FilterRule> = [
+        (FilterRuleName::Prefix, prefix),
+        (FilterRuleName::Suffix, suffix),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.map(|v| FilterRule::builder().name(name).value(v).build()))
+    .collect();
+    if rules.is_empty() {
+        return None;
+    }
+    Some(
+        NotificationConfigurationFilter::builder()
+            .key(S3KeyFilter::builder().set_filter_rules(Some(rules)).build())
+            .build(),
+    )
+}
+
+/// Reject endpoints the S3 client cannot talk to
+fn check_endpoint_scheme(endpoint: &str) -> Result<()> {
+    let scheme = endpoint
+        .split_once("://")
+        .map(|(scheme, _)| scheme.to_ascii_lowercase());
+    match scheme.as_deref() {
+        Some("http" | "https") => Ok(()),
+        _ => Err(Error::UnsupportedFeature(format!(
+            "Endpoint '{endpoint}' is not an http:// or https:// URL"
+        ))),
+    }
+}
+
+/// Whether requests for an alias use path-style addressing (`host/bucket/key`)
+///
+/// `path` and `dns` force one style. `auto` uses path style for endpoints
+/// that cannot serve `bucket.host` names (IP addresses, `localhost` and
+/// single-label hosts such as container names) and virtual-hosted style for
+/// other domains. `dns` with an IP address endpoint is rejected.
+fn path_style(alias: &Alias) -> Result<bool> {
+    let url = url::Url::parse(&alias.endpoint)?;
+    let (ip, local_name) = match url.host() {
+        Some(url::Host::Domain(host)) => {
+            let host = host.trim_end_matches('.');
+            let local = host.eq_ignore_ascii_case("localhost")
+                || host.to_ascii_lowercase().ends_with(".localhost")
+                || !host.contains('.');
+            (false, local)
+        }
+        Some(url::Host::Ipv4(_) | url::Host::Ipv6(_)) => (true, false),
+        None => (false, true),
+    };
+
+    match alias.bucket_lookup.as_str() {
+        "path" => Ok(true),
+        "dns" if ip => Err(Error::Config(format!(
+            "bucket_lookup \"dns\" needs a hostname endpoint, not an IP address ({}); \
+             use \"path\" or \"auto\"",
+            alias.endpoint
+        ))),
+        "dns" => Ok(false),
+        _ => Ok(ip || local_name),
+    }
+}
+
+/// Region named by a `GetBucketLocation` location constraint
+///
+/// An empty constraint means `us-east-1`, and `EU` is the legacy name of `eu-west-1`.
+fn location_region(constraint: Option<&str>) -> String {
+    match constraint {
+        None | Some("") => DEFAULT_REGION.to_string(),
+        Some("EU") => "eu-west-1".to_string(),
+        Some(region) => region.to_string(),
+    }
+}
+
+/// First checksum header the server returned, as `(algorithm, value)`
+///
+/// Objects carry at most one checksum algorithm; empty values are ignored.
+fn stored_checksum<'a>(
+    checksums: [(&'static str, Option<&'a str>); 5],
+) -> Option<(&'static str, &'a str)> {
+    checksums
+        .into_iter()
+        .find_map(|(algorithm, value)| value.filter(|v| !v.is_empty()).map(|v| (algorithm, v)))
+}
+
+/// Build an HTTP `Range` header value
+///
+/// Fails for an empty range, which HTTP cannot express, and for a range whose
+/// last byte lies beyond `u64::MAX`.
+fn range_header(offset: u64, length: Option<u64>) -> Result<String> {
+    let Some(len) = length else {
+        return Ok(format!("bytes={offset}-"));
+    };
+    if len == 0 {
+        return Err(Error::InvalidArgument(
+            "byte range length must be greater than zero".to_string(),
+        ));
+    }
+    let last = offset.checked_add(len - 1).ok_or_else(|| {
+        Error::InvalidArgument(format!(
+            "byte range {offset}+{len} extends beyond the largest possible offset"
+        ))
+    })?;
+    Ok(format!("bytes={offset}-{last}"))
+}
+
+/// Total object size from a `Content-Range` value such as `bytes 0-99/1234`
+fn content_range_total(value: &str) -> Option<u64> {
+    value.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+/// Convert an SDK owner and grant list into an `Acl`
+fn convert_acl(
+    owner: Option<&aws_sdk_s3::types::Owner>,
+    grants: &[aws_sdk_s3::types::Grant],
+) -> Acl {
+    let owner = owner.map(|o| Owner {
+        id: o.id().map(str::to_string),
+        display_name: o.display_name().map(str::to_string),
+    });
+
+    let grants = grants
+        .iter()
+        .filter_map(|g| {
+            let grantee = g.grantee()?;
+            let name = grantee
+                .id()
+                .or(grantee.uri())
+                .or(grantee.email_address())
+                .or(grantee.display_name())
+                .unwrap_or_default();
+            Some(AclGrant {
+                grantee_type: grantee.r#type().as_str().to_string(),
+                grantee: name.to_string(),
+                permission: g
+                    .permission()
+                    .map(|p| p.as_str().to_string())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    Acl { owner, grants }
+}
+
+/// Build the request body for an ACL, the reverse of [`convert_acl`]
+fn access_control_policy(acl: &Acl) -> Result<aws_sdk_s3::types::AccessControlPolicy> {
+    use aws_sdk_s3::types::{AccessControlPolicy, Grant, Grantee, Permission, Type};
+
+    let grants = acl
+        .grants
+        .iter()
+        .map(|g| {
+            let kind = Type::from(g.grantee_type.as_str());
+            let grantee = Grantee::builder().r#type(kind.clone());
+            let grantee = match kind {
+                Type::Group => grantee.uri(&g.grantee),
+                Type::AmazonCustomerByEmail => grantee.email_address(&g.grantee),
+                _ => grantee.id(&g.grantee),
+            }
+            .build()
+            .map_err(|e| Error::General(format!("Invalid ACL grant: {e}")))?;
+            Ok(Grant::builder()
+                .grantee(grantee)
+                .permission(Permission::from(g.permission.as_str()))
+                .build())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let owner = acl.owner.as_ref().map(|o| {
+        aws_sdk_s3::types::Owner::builder()
+            .set_id(o.id.clone())
+            .set_display_name(o.display_name.clone())
+            .build()
+    });
+
+    Ok(AccessControlPolicy::builder()
+        .set_grants(Some(grants))
+        .set_owner(owner)
+        .build())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_insecure_alias_builds_client() {
+        let mut alias = Alias::new("test", "https://s3.example.com", "access", "secret");
+        alias.insecure = true;
+        assert!(S3Client::new(alias).await.is_ok());
+    }
+
+    /// List `public/` through `alias` pointed at a local server that answers
+    /// every request with an empty page, returning the request heads it saw
+    async fn list_request_heads(mut alias: Alias) -> Vec<String> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        const EMPTY_PAGE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <ListBucketResult><Name>public</Name><Prefix></Prefix><KeyCount>0</KeyCount>\
+            <MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated></ListBucketResult>";
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        alias.endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let heads = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = heads.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut head = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !head.ends_with(b"\r\n\r\n") {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => head.extend_from_slice(&buf[..n]),
+                    }
+                }
+                seen.lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&head).to_ascii_lowercase());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/xml\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{EMPTY_PAGE}",
+                    EMPTY_PAGE.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let client = S3Client::new(alias).await.expect("client");
+        let path = RemotePath::new("public", "public", "");
+        let result = client
+            .list_objects(&path, ListOptions::default())
+            .await
+            .expect("list public bucket");
+        assert!(result.items.is_empty());
+        heads.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_alias_lists_without_signing() {
+        let mut alias = Alias::new("public", "http://placeholder", "", "");
+        alias.anonymous = true;
+        let heads = list_request_heads(alias).await;
+        assert!(!heads.is_empty());
+        for head in &heads {
+            assert!(head.starts_with("get /public"), "{head}");
+            assert!(!head.contains("\r\nauthorization:"), "{head}");
+            assert!(!head.contains("x-amz-security-token"), "{head}");
+        }
+
+        // The same request from a keyed alias is signed
+        let alias = Alias::new("private", "http://placeholder", "access", "secret");
+        let heads = list_request_heads(alias).await;
+        assert!(heads.iter().all(|head| head.contains("\r\nauthorization:")));
+    }
+
+    #[test]
+    fn test_access_control_policy_round_trip() {
+        let acl = Acl {
+            owner: Some(Owner {
+                id: Some("owner-id".to_string()),
+                display_name: None,
+            }),
+            grants: vec![
+                AclGrant {
+                    grantee_type: "CanonicalUser".to_string(),
+                    grantee: "owner-id".to_string(),
+                    permission: "FULL_CONTROL".to_string(),
+                },
+                AclGrant {
+                    grantee_type: "Group".to_string(),
+                    grantee: "http://acs.amazonaws.com/groups/global/AllUsers".to_string(),
+                    permission: "READ".to_string(),
+                },
+            ],
+        };
+
+        let policy = access_control_policy(&acl).unwrap();
+        let back = convert_acl(policy.owner(), policy.grants());
+        assert_eq!(back.owner, acl.owner);
+        assert_eq!(back.grants, acl.grants);
+    }
+
     #[test]
     fn test_object_info_creation() {
         let info = ObjectInfo::file("test.txt", 1024);
         assert_eq!(info.key, "test.txt");
         assert_eq!(info.size_bytes, Some(1024));
     }
+
+    #[tokio::test]
+    async fn test_resolve_with_custom_tls() {
+        let mut alias = Alias::new("test", "https://s3.example.com", "access", "secret");
+        alias.insecure = true;
+        alias.resolve = vec!["s3.example.com:10.0.0.5".to_string()];
+        assert!(S3Client::new(alias).await.is_ok());
+    }
+
+    #[test]
+    fn test_stored_checksum() {
+        assert_eq!(
+            stored_checksum([
+                ("CRC32", None),
+                ("CRC32C", Some("")),
+                ("CRC64NVME", None),
+                ("SHA1", None),
+                (
+                    "SHA256",
+                    Some("n4bQgYhMfWWaL+qgxVrQFaO/TxsrC4Is0V1sFbDwCgg=")
+                ),
+            ]),
+            Some(("SHA256", "n4bQgYhMfWWaL+qgxVrQFaO/TxsrC4Is0V1sFbDwCgg="))
+        );
+        assert_eq!(stored_checksum([("CRC32", None); 5]), None);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_http_endpoints() {
+        let alias = Alias::new("scratch", "file:///tmp/rc-scratch", "", "");
+        match S3Client::new(alias).await {
+            Err(Error::UnsupportedFeature(msg)) => assert!(msg.contains("file:///tmp/rc-scratch")),
+            other => panic!("expected UnsupportedFeature, got {:?}", other.err()),
+        }
+
+        let alias = Alias::new("odd", "ftp://s3.example.com", "a", "b");
+        assert!(matches!(
+            S3Client::new(alias).await,
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    fn lookup_alias(endpoint: &str, bucket_lookup: &str) -> Alias {
+        let mut alias = Alias::new("test", endpoint, "access", "secret");
+        alias.bucket_lookup = bucket_lookup.to_string();
+        alias
+    }
+
+    #[test]
+    fn test_path_style_auto() {
+        for endpoint in [
+            "http://localhost:9000",
+            "http://127.0.0.1:9000",
+            "http://[::1]:9000",
+            "http://minio:9000",
+            "http://rustfs.localhost",
+        ] {
+            assert!(
+                path_style(&lookup_alias(endpoint, "auto")).unwrap(),
+                "{endpoint} should use path style"
+            );
+        }
+        for endpoint in [
+            "https://s3.amazonaws.com",
+            "https://storage.example.com:9000",
+        ] {
+            assert!(
+                !path_style(&lookup_alias(endpoint, "auto")).unwrap(),
+                "{endpoint} should use virtual-hosted style"
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_style_explicit() {
+        assert!(path_style(&lookup_alias("https://s3.example.com", "path")).unwrap());
+        assert!(!path_style(&lookup_alias("http://localhost:9000", "dns")).unwrap());
+        assert!(matches!(
+            path_style(&lookup_alias("http://10.0.0.5:9000", "dns")),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bucket_lookup_shapes_request_host() {
+        let path = RemotePath::new("test", "photos", "cat.jpg");
+
+        let client = S3Client::new(lookup_alias("https://s3.example.com", "dns"))
+            .await
+            .unwrap();
+        let url = url::Url::parse(&client.presign_get(&path, 60).await.unwrap()).unwrap();
+        assert_eq!(url.host_str(), Some("photos.s3.example.com"));
+        assert_eq!(url.path(), "/cat.jpg");
+
+        let client = S3Client::new(lookup_alias("http://localhost:9000", "auto"))
+            .await
+            .unwrap();
+        let url = url::Url::parse(&client.presign_get(&path, 60).await.unwrap()).unwrap();
+        assert_eq!(url.host_str(), Some("localhost"));
+        assert_eq!(url.path(), "/photos/cat.jpg");
+    }
+
+    #[test]
+    fn test_location_region() {
+        assert_eq!(location_region(None), "us-east-1");
+        assert_eq!(location_region(Some("")), "us-east-1");
+        assert_eq!(location_region(Some("EU")), "eu-west-1");
+        assert_eq!(location_region(Some("ap-south-1")), "ap-south-1");
+    }
+
+    #[test]
+    fn test_range_header() {
+        assert_eq!(range_header(0, Some(100)).unwrap(), "bytes=0-99");
+        assert_eq!(range_header(1024, Some(1)).unwrap(), "bytes=1024-1024");
+        assert_eq!(range_header(512, None).unwrap(), "bytes=512-");
+        assert_eq!(
+            range_header(u64::MAX, Some(1)).unwrap(),
+            format!("bytes={0}-{0}", u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_content_range_total() {
+        assert_eq!(content_range_total("bytes 0-99/1234"), Some(1234));
+        assert_eq!(content_range_total("bytes 0-0/1"), Some(1));
+        assert_eq!(content_range_total("bytes */1234"), Some(1234));
+        assert_eq!(content_range_total("bytes 0-99/*"), None);
+        assert_eq!(content_range_total("garbage"), None);
+    }
+
+    #[test]
+    fn test_range_header_rejects_unrepresentable_ranges() {
+        assert!(matches!(
+            range_header(0, Some(0)),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            range_header(u64::MAX, Some(2)),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_notification_filter_round_trip() {
+        use aws_sdk_s3::types::Event;
+
+        assert!(notification_filter(None, None).is_none());
+
+        let filter = notification_filter(Some("logs/"), Some(".gz"));
+        let rule = notification_rule(
+            Some("rule-1"),
+            "arn:rustfs:sqs::primary:webhook",
+            NotificationKind::Queue,
+            &[Event::from("s3:ObjectCreated:*")],
+            filter.as_ref(),
+        );
+        assert_eq!(rule.id.as_deref(), Some("rule-1"));
+        assert_eq!(rule.events, vec!["s3:ObjectCreated:*"]);
+        assert_eq!(rule.prefix.as_deref(), Some("logs/"));
+        assert_eq!(rule.suffix.as_deref(), Some(".gz"));
+    }
 }
@@ -0,0 +1,408 @@
+//! S3 error classification
+//!
+//! Maps AWS SDK errors onto `rc_core::Error` variants using the S3 error code
+//! (or the HTTP status for bodiless responses such as HEAD), so callers can
+//! match on the variant instead of searching error messages.
+
+use aws_sdk_s3::error::{ProvideErrorMetadata, SdkError};
+use rc_core::Error;
+
+/// Error codes meaning the bucket, key or upload does not exist
+const NOT_FOUND_CODES: &[&str] = &[
+    "NoSuchKey",
+    "NoSuchBucket",
+    "NotFound",
+    "NoSuchVersion",
+    "NoSuchUpload",
+];
+
+/// Error codes meaning the credentials were rejected or lack permission
+const AUTH_CODES: &[&str] = &[
+    "AccessDenied",
+    "InvalidAccessKeyId",
+    "SignatureDoesNotMatch",
+    "ExpiredToken",
+    "InvalidToken",
+    "RequestTimeTooSkewed",
+];
+
+/// Error codes that a wrong local clock can cause
+pub(crate) const CLOCK_SKEW_CODES: &[&str] = &["SignatureDoesNotMatch", "RequestTimeTooSkewed"];
+
+/// Clock difference beyond which a signature failure is blamed on the clock
+const MAX_CLOCK_SKEW_SECS: i64 = 5 * 60;
+
+/// Error codes returned when a request is signed for the wrong region
+const REGION_MISMATCH_CODES: &[&str] = &["AuthorizationHeaderMalformed", "PermanentRedirect"];
+
+/// Error codes meaning the request conflicts with the current state
+const CONFLICT_CODES: &[&str] = &[
+    "BucketAlreadyExists",
+    "BucketAlreadyOwnedByYou",
+    "BucketNotEmpty",
+];
+
+/// Error codes meaning an optional bucket configuration is simply not set
+const NOT_CONFIGURED_CODES: &[&str] = &[
+    "NoSuchLifecycleConfiguration",
+    "NoSuchBucketPolicy",
+    "NoSuchTagSet",
+    "NoSuchTagSetError",
+    "ReplicationConfigurationNotFoundError",
+    "ObjectLockConfigurationNotFoundError",
+];
+
+/// Coarse category of an S3 error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum S3ErrorKind {
+    NotFound,
+    Auth,
+    Conflict,
+    Other,
+}
+
+impl S3ErrorKind {
+    /// Classify from the S3 error code, falling back to the HTTP status
+    pub(crate) fn from_parts(code: Option<&str>, status: Option<u16>) -> Self {
+        if let Some(code) = code {
+            if NOT_FOUND_CODES.contains(&code) {
+                return Self::NotFound;
+            }
+            if AUTH_CODES.contains(&code) {
+                return Self::Auth;
+            }
+            if CONFLICT_CODES.contains(&code) {
+                return Self::Conflict;
+            }
+        }
+
+        match status {
+            Some(404) => Self::NotFound,
+            Some(401) | Some(403) => Self::Auth,
+            Some(409) => Self::Conflict,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Classify an SDK error by its typed service error metadata
+pub(crate) fn classify<E: ProvideErrorMetadata>(err: &SdkError<E>) -> S3ErrorKind {
+    let code = err.as_service_error().and_then(|e| e.code());
+    let status = err.raw_response().map(|r| r.status().as_u16());
+    S3ErrorKind::from_parts(code, status)
+}
+
+/// Whether the requested byte range lies outside the object
+pub(crate) fn is_invalid_range<E: ProvideErrorMetadata>(err: &SdkError<E>) -> bool {
+    let code = err.as_service_error().and_then(|e| e.code());
+    let status = err.raw_response().map(|r| r.status().as_u16());
+    code == Some("InvalidRange") || status == Some(416)
+}
+
+/// Whether an `If-Match` or `If-None-Match` condition did not hold
+pub(crate) fn is_precondition_failed<E: ProvideErrorMetadata>(err: &SdkError<E>) -> bool {
+    let code = err.as_service_error().and_then(|e| e.code());
+    let status = err.raw_response().map(|r| r.status().as_u16());
+    code == Some("PreconditionFailed") || status == Some(412)
+}
+
+/// Whether the error means the requested bucket configuration is not set
+pub(crate) fn is_not_configured<E: ProvideErrorMetadata>(err: &SdkError<E>) -> bool {
+    not_configured_code(err.as_service_error().and_then(|e| e.code()))
+}
+
+fn not_configured_code(code: Option<&str>) -> bool {
+    code.is_some_and(|c| NOT_CONFIGURED_CODES.contains(&c))
+}
+
+/// Whether the server rejected the operation as not implemented
+pub(crate) fn not_implemented_parts(code: Option<&str>, status: Option<u16>) -> bool {
+    code == Some("NotImplemented") || status == Some(501)
+}
+
+/// Convert an SDK error into an `rc_core::Error`
+///
+/// `not_found` is used as the message when the error means the resource is missing.
+pub(crate) fn map_sdk_error<E>(err: SdkError<E>, not_found: impl FnOnce() -> String) -> Error
+where
+    E: ProvideErrorMetadata + std::fmt::Display,
+{
+    let kind = classify(&err);
+    if kind == S3ErrorKind::NotFound {
+        return Error::NotFound(not_found());
+    }
+
+    let mut msg = format_sdk_error(&err);
+    let code = err.as_service_error().and_then(|e| e.code());
+    let status = err.raw_response().map(|r| r.status().as_u16());
+    let headers = err.raw_response().map(|r| r.headers());
+    let date = headers.and_then(|h| h.get("date"));
+    let bucket_region = headers.and_then(|h| h.get("x-amz-bucket-region"));
+
+    let hints = [
+        clock_skew_hint(code, date, jiff::Timestamp::now()),
+        region_mismatch_hint(
+            code,
+            status,
+            bucket_region,
+            err.as_service_error().and_then(|e| e.message()),
+        ),
+    ];
+    for hint in hints.into_iter().flatten() {
+        msg.push_str("; ");
+        msg.push_str(&hint);
+    }
+
+    if not_implemented_parts(code, status) {
+        return Error::NotImplemented(msg);
+    }
+
+    match kind {
+        S3ErrorKind::Auth => Error::Auth(msg),
+        S3ErrorKind::Conflict => Error::Conflict(msg),
+        _ => Error::Network(msg),
+    }
+}
+
+/// Explain a request rejected because the bucket lives in another region
+pub(crate) fn region_mismatch_hint(
+    code: Option<&str>,
+    status: Option<u16>,
+    bucket_region: Option<&str>,
+    message: Option<&str>,
+) -> Option<String> {
+    let region = mismatched_region(code, status, bucket_region, message)?;
+    Some(format!(
+        "bucket is in region '{region}'; set the alias region to '{region}' or 'auto'"
+    ))
+}
+
+/// The region a request should have been signed for, if it was rejected for the wrong one
+pub(crate) fn region_of_mismatch<E: ProvideErrorMetadata>(err: &SdkError<E>) -> Option<String> {
+    let code = err.as_service_error().and_then(|e| e.code());
+    let message = err.as_service_error().and_then(|e| e.message());
+    let status = err.raw_response().map(|r| r.status().as_u16());
+    let bucket_region = err
+        .raw_response()
+        .and_then(|r| r.headers().get("x-amz-bucket-region"));
+    mismatched_region(code, status, bucket_region, message)
+}
+
+/// Find the expected region in a region-mismatch response
+///
+/// S3 names it in the `x-amz-bucket-region` header; servers that omit the
+/// header say "... expecting '<region>'" in the error message instead.
+fn mismatched_region(
+    code: Option<&str>,
+    status: Option<u16>,
+    bucket_region: Option<&str>,
+    message: Option<&str>,
+) -> Option<String> {
+    let mismatch = code.is_some_and(|c| REGION_MISMATCH_CODES.contains(&c)) || status == Some(301);
+    if !mismatch {
+        return None;
+    }
+
+    let from_message = || {
+        let (_, rest) = message?.split_once("expecting '")?;
+        rest.split_once('\'').map(|(region, _)| region)
+    };
+    bucket_region
+        .filter(|region| !region.is_empty())
+        .or_else(from_message)
+        .filter(|region| !region.is_empty())
+        .map(str::to_string)
+}
+
+/// Explain a signature failure caused by clock skew
+///
+/// Returns a hint when `code` is a clock-related error and the server's `Date`
+/// header differs from `now` by more than a few minutes.
+pub(crate) fn clock_skew_hint(
+    code: Option<&str>,
+    server_date: Option<&str>,
+    now: jiff::Timestamp,
+) -> Option<String> {
+    if !CLOCK_SKEW_CODES.contains(&code?) {
+        return None;
+    }
+
+    let server = jiff::fmt::rfc2822::DateTimeParser::new()
+        .parse_timestamp(server_date?)
+        .ok()?;
+    let skew = (now.as_second() - server.as_second()).abs();
+
+    (skew > MAX_CLOCK_SKEW_SECS).then(|| {
+        format!("clock skew detected: local vs server differ by {skew}s — sync your clock")
+    })
+}
+
+/// Format AWS SDK error into a detailed error message
+pub(crate) fn format_sdk_error<E: std::fmt::Display>(error: &SdkError<E>) -> String {
+    match error {
+        SdkError::ServiceError(service_err) => {
+            let err = service_err.err();
+            let meta = service_err.raw();
+            let mut msg = format!("Service error: {}", err);
+            // Try to extract additional error information from headers
+            if let Some(code) = meta.headers().get("x-amz-error-code")
+                && let Ok(code_str) = std::str::from_utf8(code.as_bytes())
+            {
+                msg.push_str(&format!(" (code: {})", code_str));
+            }
+            msg
+        }
+        SdkError::ConstructionFailure(err) => {
+            format!("Request construction failed: {:?}", err)
+        }
+        SdkError::TimeoutError(_) => "Request timeout".to_string(),
+        SdkError::DispatchFailure(err) => {
+            format!("Network dispatch error: {:?}", err)
+        }
+        SdkError::ResponseError(err) => {
+            format!("Response error: {:?}", err)
+        }
+        _ => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_by_code() {
+        assert_eq!(
+            S3ErrorKind::from_parts(Some("NoSuchKey"), Some(404)),
+            S3ErrorKind::NotFound
+        );
+        assert_eq!(
+            S3ErrorKind::from_parts(Some("NoSuchBucket"), None),
+            S3ErrorKind::NotFound
+        );
+        assert_eq!(
+            S3ErrorKind::from_parts(Some("AccessDenied"), Some(403)),
+            S3ErrorKind::Auth
+        );
+        assert_eq!(
+            S3ErrorKind::from_parts(Some("BucketNotEmpty"), Some(409)),
+            S3ErrorKind::Conflict
+        );
+        assert_eq!(
+            S3ErrorKind::from_parts(Some("SlowDown"), Some(503)),
+            S3ErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn test_clock_skew_hint() {
+        let server_date = Some("Tue, 01 Jul 2025 10:00:00 GMT");
+        let now: jiff::Timestamp = "2025-07-01T10:20:00Z".parse().unwrap();
+
+        let hint =
+            clock_skew_hint(Some("SignatureDoesNotMatch"), server_date, now).expect("skew hint");
+        assert!(hint.contains("differ by 1200s"));
+
+        // Small differences and unrelated errors are not blamed on the clock
+        let close: jiff::Timestamp = "2025-07-01T10:01:00Z".parse().unwrap();
+        assert!(clock_skew_hint(Some("SignatureDoesNotMatch"), server_date, close).is_none());
+        assert!(clock_skew_hint(Some("AccessDenied"), server_date, now).is_none());
+        assert!(clock_skew_hint(Some("RequestTimeTooSkewed"), None, now).is_none());
+    }
+
+    #[test]
+    fn test_region_mismatch_hint() {
+        let hint = region_mismatch_hint(
+            Some("AuthorizationHeaderMalformed"),
+            Some(400),
+            Some("eu-central-1"),
+            None,
+        )
+        .expect("region hint");
+        assert!(hint.contains("'eu-central-1'"));
+
+        assert!(region_mismatch_hint(None, Some(301), Some("us-west-2"), None).is_some());
+        assert!(region_mismatch_hint(Some("PermanentRedirect"), Some(301), None, None).is_none());
+        assert!(
+            region_mismatch_hint(Some("AccessDenied"), Some(403), Some("us-west-2"), None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_mismatched_region() {
+        // The header wins over the message
+        assert_eq!(
+            mismatched_region(
+                Some("AuthorizationHeaderMalformed"),
+                Some(400),
+                Some("eu-central-1"),
+                Some("the region 'us-east-1' is wrong; expecting 'eu-west-1'"),
+            )
+            .as_deref(),
+            Some("eu-central-1")
+        );
+        assert_eq!(
+            mismatched_region(
+                Some("AuthorizationHeaderMalformed"),
+                Some(400),
+                None,
+                Some("the region 'us-east-1' is wrong; expecting 'ap-south-1'"),
+            )
+            .as_deref(),
+            Some("ap-south-1")
+        );
+        assert_eq!(
+            mismatched_region(None, Some(301), Some("us-west-2"), None).as_deref(),
+            Some("us-west-2")
+        );
+
+        assert_eq!(
+            mismatched_region(Some("PermanentRedirect"), Some(301), None, None),
+            None
+        );
+        assert_eq!(
+            mismatched_region(Some("AccessDenied"), Some(403), Some("us-west-2"), None),
+            None
+        );
+        assert_eq!(
+            mismatched_region(
+                Some("AuthorizationHeaderMalformed"),
+                Some(400),
+                Some(""),
+                None
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_not_implemented() {
+        assert!(not_implemented_parts(Some("NotImplemented"), Some(501)));
+        assert!(not_implemented_parts(None, Some(501)));
+        assert!(!not_implemented_parts(Some("NoSuchBucket"), Some(404)));
+        assert!(!not_implemented_parts(Some("AccessDenied"), Some(403)));
+    }
+
+    #[test]
+    fn test_not_configured() {
+        assert!(not_configured_code(Some("NoSuchLifecycleConfiguration")));
+        assert!(not_configured_code(Some(
+            "ObjectLockConfigurationNotFoundError"
+        )));
+        assert!(!not_configured_code(Some("NoSuchBucket")));
+        assert!(!not_configured_code(None));
+    }
+
+    #[test]
+    fn test_classify_by_status_without_code() {
+        // HEAD responses carry no body, so only the status is available
+        assert_eq!(
+            S3ErrorKind::from_parts(None, Some(404)),
+            S3ErrorKind::NotFound
+        );
+        assert_eq!(S3ErrorKind::from_parts(None, Some(403)), S3ErrorKind::Auth);
+        assert_eq!(S3ErrorKind::from_parts(None, Some(500)), S3ErrorKind::Other);
+        assert_eq!(S3ErrorKind::from_parts(None, None), S3ErrorKind::Other);
+    }
+}
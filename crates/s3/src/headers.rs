@@ -0,0 +1,133 @@
+//! Raw response header capture
+//!
+//! The SDK only exposes typed output fields. For debugging proxies, CDNs and
+//! clock skew it helps to see every header the server sent, so this module
+//! provides an interceptor that records the raw HTTP response. It can also
+//! keep the response body, for fields the typed output drops altogether.
+
+use std::sync::{Arc, Mutex};
+
+use aws_sdk_s3::config::interceptors::{
+    AfterDeserializationInterceptorContextRef, BeforeDeserializationInterceptorContextRef,
+};
+use aws_sdk_s3::config::{ConfigBag, Intercept, RuntimeComponents};
+use aws_sdk_s3::error::BoxError;
+use serde::Serialize;
+
+/// Status and headers of an HTTP response, in the order the server sent them
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResponseHeaders {
+    /// HTTP status code
+    pub status: u16,
+    /// Header names and values
+    pub headers: Vec<(String, String)>,
+    /// Response body, when the request asked for it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl ResponseHeaders {
+    /// Get the first value of a header (case-insensitive)
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Interceptor that stores the last raw response it sees
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HeaderCapture {
+    captured: Arc<Mutex<Option<ResponseHeaders>>>,
+    body: bool,
+}
+
+impl HeaderCapture {
+    /// Capture that also keeps the response body
+    pub(crate) fn with_body() -> Self {
+        Self {
+            body: true,
+            ..Self::default()
+        }
+    }
+
+    /// Take the captured response, if one was received
+    pub(crate) fn take(&self) -> Option<ResponseHeaders> {
+        self.captured.lock().ok().and_then(|mut c| c.take())
+    }
+}
+
+impl Intercept for HeaderCapture {
+    fn name(&self) -> &'static str {
+        "HeaderCapture"
+    }
+
+    fn read_before_deserialization(
+        &self,
+        context: &BeforeDeserializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let response = context.response();
+        let captured = ResponseHeaders {
+            status: response.status().as_u16(),
+            headers: response
+                .headers()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: None,
+        };
+        if let Ok(mut slot) = self.captured.lock() {
+            *slot = Some(captured);
+        }
+        Ok(())
+    }
+
+    // Non-streaming bodies are only read into memory during deserialization
+    fn read_after_deserialization(
+        &self,
+        context: &AfterDeserializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if !self.body {
+            return Ok(());
+        }
+        let body = context
+            .response()
+            .body()
+            .bytes()
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned());
+        if let Ok(mut slot) = self.captured.lock()
+            && let Some(captured) = slot.as_mut()
+        {
+            captured.body = body;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_headers_get_is_case_insensitive() {
+        let headers = ResponseHeaders {
+            status: 200,
+            headers: vec![
+                (
+                    "date".to_string(),
+                    "Tue, 01 Jul 2025 10:00:00 GMT".to_string(),
+                ),
+                ("x-amz-request-id".to_string(), "ABC123".to_string()),
+            ],
+            body: None,
+        };
+        assert_eq!(headers.get("Date"), Some("Tue, 01 Jul 2025 10:00:00 GMT"));
+        assert_eq!(headers.get("X-Amz-Request-Id"), Some("ABC123"));
+        assert_eq!(headers.get("server"), None);
+    }
+}
@@ -7,8 +7,34 @@
 pub mod admin;
 pub mod capability;
 pub mod client;
+mod error;
+pub mod headers;
+pub mod listing;
 pub mod multipart;
+mod request_log;
+mod resolve;
+mod sigv2;
+mod tls;
+pub mod url_fetch;
 
 pub use admin::AdminClient;
 pub use client::S3Client;
+pub use headers::ResponseHeaders;
+pub use listing::{
+    DEFAULT_LIST_CONCURRENCY, MAX_PAGE_SIZE, PageLister, list_objects_parallel, list_objects_stream,
+};
 pub use multipart::{MultipartConfig, UploadState};
+pub use request_log::{REDACTED, is_secret_field};
+pub use url_fetch::UrlBody;
+
+use rc_core::{Alias, BackendFuture, BackendRegistry, ObjectStore};
+
+/// Register the S3 client for `http://` and `https://` endpoints
+pub fn register_backends(registry: &mut BackendRegistry) {
+    registry.register("http", connect);
+    registry.register("https", connect);
+}
+
+fn connect(alias: Alias) -> BackendFuture {
+    Box::pin(async move { Ok(Box::new(S3Client::new(alias).await?) as Box<dyn ObjectStore>) })
+}
@@ -0,0 +1,233 @@
+//! Concurrent recursive listing
+//!
+//! A recursive `ListObjectsV2` walk is a single chain of paginated requests.
+//! On wide buckets it is faster to list the top-level common prefixes first
+//! and then walk each of them concurrently.
+
+use async_trait::async_trait;
+use futures::future;
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use rc_core::{ListOptions, ListResult, ObjectInfo, ObjectStore, RemotePath, Result};
+
+/// Default number of prefixes listed at the same time
+pub const DEFAULT_LIST_CONCURRENCY: usize = 8;
+
+/// Most keys S3 returns in one listing page
+pub const MAX_PAGE_SIZE: i32 = 1000;
+
+/// The single listing call the concurrent walk is built on
+///
+/// Every [`ObjectStore`] implements it; the listing benchmark implements it
+/// directly to simulate request latency without a full store.
+#[async_trait]
+pub trait PageLister: Send + Sync {
+    /// Fetch one page of a `ListObjectsV2` listing
+    async fn list_page(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult>;
+}
+
+#[async_trait]
+impl<S> PageLister for S
+where
+    S: ObjectStore + ?Sized,
+{
+    async fn list_page(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult> {
+        self.list_objects(path, options).await
+    }
+}
+
+/// List every object under `path` recursively, walking top-level prefixes concurrently
+///
+/// At most `concurrency` prefixes are listed at once, each requesting
+/// `page_size` keys per page. Results are returned in
+/// no particular order; callers that need key order must sort them.
+pub async fn list_objects_parallel<S>(
+    store: &S,
+    path: &RemotePath,
+    concurrency: usize,
+    page_size: i32,
+) -> Result<Vec<ObjectInfo>>
+where
+    S: PageLister + ?Sized,
+{
+    list_objects_stream(store, path, concurrency, page_size)
+        .try_concat()
+        .await
+}
+
+/// Stream the objects under `path` page by page, walking top-level prefixes concurrently
+///
+/// Yields each listing page as soon as it arrives, so callers can filter or
+/// print results without holding the whole listing in memory. Pages from
+/// different prefixes interleave; directory placeholders are dropped.
+pub fn list_objects_stream<'a, S>(
+    store: &'a S,
+    path: &'a RemotePath,
+    concurrency: usize,
+    page_size: i32,
+) -> BoxStream<'a, Result<Vec<ObjectInfo>>>
+where
+    S: PageLister + ?Sized,
+{
+    // Delimited pages give the objects at this level and the prefixes to fan out over
+    pages(store, path.clone(), false, page_size)
+        .map_ok(move |page| {
+            let (objects, prefixes) = split_prefixes(page);
+            let nested = stream::iter(prefixes)
+                .map(move |prefix| {
+                    let sub_path = RemotePath::new(&path.alias, &path.bucket, prefix);
+                    pages(store, sub_path, true, page_size).boxed()
+                })
+                .flatten_unordered(concurrency.max(1));
+            stream::once(future::ready(Ok(objects))).chain(nested)
+        })
+        .try_flatten()
+        .map_ok(|items| items.into_iter().filter(|item| !item.is_dir).collect())
+        .boxed()
+}
+
+/// Follow continuation tokens, yielding one page per request
+fn pages<'a, S>(
+    store: &'a S,
+    path: RemotePath,
+    recursive: bool,
+    page_size: i32,
+) -> impl Stream<Item = Result<Vec<ObjectInfo>>> + Send + 'a
+where
+    S: PageLister + ?Sized,
+{
+    // `None` once the last page has been fetched; otherwise the token for the next page
+    stream::try_unfold(Some(None), move |cursor: Option<Option<String>>| {
+        let path = path.clone();
+        async move {
+            let Some(continuation_token) = cursor else {
+                return Ok(None);
+            };
+            let options = ListOptions {
+                recursive,
+                max_keys: Some(page_size),
+                continuation_token,
+                ..Default::default()
+            };
+
+            let result = store.list_page(&path, options).await?;
+            let next = match result.continuation_token {
+                Some(token) if result.truncated => Some(Some(token)),
+                _ => None,
+            };
+            Ok(Some((result.items, next)))
+        }
+    })
+}
+
+/// Separate objects from common prefixes in a delimited listing
+fn split_prefixes(items: Vec<ObjectInfo>) -> (Vec<ObjectInfo>, Vec<String>) {
+    let mut objects = Vec::new();
+    let mut prefixes = Vec::new();
+
+    for item in items {
+        if item.is_dir {
+            prefixes.push(item.key);
+        } else {
+            objects.push(item);
+        }
+    }
+
+    (objects, prefixes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A bucket with `prefixes` top-level prefixes of `keys` objects each, plus `root.txt`
+    struct WideBucket {
+        prefixes: usize,
+        keys: usize,
+    }
+
+    #[async_trait]
+    impl PageLister for WideBucket {
+        async fn list_page(&self, path: &RemotePath, options: ListOptions) -> Result<ListResult> {
+            let mut keys = vec!["root.txt".to_string()];
+            for p in 0..self.prefixes {
+                keys.extend((0..self.keys).map(|k| format!("p{p}/k{k}")));
+            }
+
+            let mut items: Vec<ObjectInfo> = Vec::new();
+            for key in keys.iter().filter(|key| key.starts_with(&path.key)) {
+                let rest = &key[path.key.len()..];
+                match rest.find('/') {
+                    Some(slash) if !options.recursive => {
+                        let prefix = format!("{}{}", path.key, &rest[..=slash]);
+                        if items.last().is_none_or(|last| last.key != prefix) {
+                            items.push(ObjectInfo::dir(&prefix));
+                        }
+                    }
+                    _ => items.push(ObjectInfo::file(key, 1)),
+                }
+            }
+
+            let start: usize = options.continuation_token.map_or(0, |t| t.parse().unwrap());
+            let end = (start + options.max_keys.unwrap() as usize).min(items.len());
+            Ok(ListResult {
+                truncated: end < items.len(),
+                continuation_token: (end < items.len()).then(|| end.to_string()),
+                items: items.drain(start..end).collect(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_parallel_walks_every_prefix() {
+        let bucket = WideBucket {
+            prefixes: 5,
+            keys: 7,
+        };
+        let path = RemotePath::new("test", "bucket", "");
+
+        let mut keys: Vec<String> = list_objects_parallel(&bucket, &path, 3, 2)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|item| item.key)
+            .collect();
+        keys.sort();
+
+        assert_eq!(keys.len(), 5 * 7 + 1);
+        assert_eq!(keys[0], "p0/k0");
+        assert_eq!(keys.last().unwrap(), "root.txt");
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_stream_yields_pages() {
+        let bucket = WideBucket {
+            prefixes: 2,
+            keys: 4,
+        };
+        let path = RemotePath::new("test", "bucket", "");
+
+        let pages: Vec<Vec<ObjectInfo>> = list_objects_stream(&bucket, &path, 2, 2)
+            .try_collect()
+            .await
+            .unwrap();
+
+        // Pages keep the requested size and prefix placeholders never leak through
+        assert!(pages.iter().all(|page| page.len() <= 2));
+        assert!(pages.iter().flatten().all(|item| !item.is_dir));
+        assert_eq!(pages.iter().map(Vec::len).sum::<usize>(), 2 * 4 + 1);
+    }
+
+    #[test]
+    fn test_split_prefixes() {
+        let items = vec![
+            ObjectInfo::dir("logs/"),
+            ObjectInfo::file("readme.txt", 10),
+            ObjectInfo::dir("images/"),
+        ];
+
+        let (objects, prefixes) = split_prefixes(items);
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].key, "readme.txt");
+        assert_eq!(prefixes, vec!["logs/", "images/"]);
+    }
+}
@@ -93,6 +93,10 @@ pub struct UploadState {
     /// Source file path (if local)
     pub source: Option<String>,
 
+    /// Modification time of the source file when the upload started
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_mtime: Option<jiff::Timestamp>,
+
     /// Total file size
     pub total_size: u64,
 
@@ -124,6 +128,7 @@ impl UploadState {
             upload_id: upload_id.into(),
             target: target.into(),
             source: None,
+            source_mtime: None,
             total_size,
             part_size,
             completed_parts: Vec::new(),
@@ -137,6 +142,30 @@ impl UploadState {
         self
     }
 
+    /// Set the source file's modification time
+    pub fn with_source_mtime(mut self, mtime: Option<jiff::Timestamp>) -> Self {
+        self.source_mtime = mtime;
+        self
+    }
+
+    /// Whether this saved upload was started from the same, unchanged source
+    ///
+    /// A file edited in place can keep its size, so the modification time must
+    /// match too; state saved without one is never reused.
+    pub fn matches_source(
+        &self,
+        source: &str,
+        mtime: Option<jiff::Timestamp>,
+        total_size: u64,
+        part_size: u64,
+    ) -> bool {
+        self.source.as_deref() == Some(source)
+            && self.source_mtime.is_some()
+            && self.source_mtime == mtime
+            && self.total_size == total_size
+            && self.part_size == part_size
+    }
+
     /// Add a completed part
     pub fn add_completed_part(&mut self, part_number: i32, etag: String) {
         self.completed_parts
@@ -299,6 +328,26 @@ mod tests {
         assert_eq!(state.next_part_number(), 3);
     }
 
+    #[test]
+    fn test_matches_source_checks_mtime() {
+        let started: jiff::Timestamp = "2026-01-15T10:00:00Z".parse().unwrap();
+        let edited: jiff::Timestamp = "2026-01-15T10:05:00Z".parse().unwrap();
+        let state = UploadState::new("upload-123", "bucket/key", 1000, 100)
+            .with_source("/data/big.bin")
+            .with_source_mtime(Some(started));
+
+        assert!(state.matches_source("/data/big.bin", Some(started), 1000, 100));
+        // Same path and size, but the file was edited in place
+        assert!(!state.matches_source("/data/big.bin", Some(edited), 1000, 100));
+        assert!(!state.matches_source("/data/big.bin", None, 1000, 100));
+        assert!(!state.matches_source("/data/other.bin", Some(started), 1000, 100));
+        assert!(!state.matches_source("/data/big.bin", Some(started), 1000, 200));
+
+        let unknown =
+            UploadState::new("upload-456", "bucket/key", 1000, 100).with_source("/data/big.bin");
+        assert!(!unknown.matches_source("/data/big.bin", None, 1000, 100));
+    }
+
     #[test]
     fn test_progress_percent() {
         let mut state = UploadState::new("upload-123", "bucket/key", 1000, 100);
@@ -0,0 +1,115 @@
+//! Static host resolution for aliases with `resolve` entries
+//!
+//! Hosts listed in an alias's `resolve` mappings connect to the given
+//! addresses; every other host falls back to the system resolver.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use aws_smithy_runtime_api::client::dns::{DnsFuture, ResolveDns, ResolveDnsError};
+use hyper_util::client::legacy::connect::dns::Name;
+
+/// Group host mappings by host, keeping every address given for it
+pub(crate) fn group_overrides(overrides: Vec<(String, IpAddr)>) -> HashMap<String, Vec<IpAddr>> {
+    let mut hosts: HashMap<String, Vec<IpAddr>> = HashMap::new();
+    for (host, ip) in overrides {
+        hosts.entry(host).or_default().push(ip);
+    }
+    hosts
+}
+
+/// Socket addresses for reqwest's resolver overrides
+///
+/// Port 0 keeps the port from the endpoint URL.
+pub(crate) fn socket_addrs(ips: &[IpAddr]) -> Vec<SocketAddr> {
+    ips.iter().map(|ip| SocketAddr::new(*ip, 0)).collect()
+}
+
+/// DNS resolver for the S3 SDK that answers mapped hosts from the alias
+///
+/// Also serves as the resolver of the hyper connector used with custom TLS.
+#[derive(Debug, Clone)]
+pub(crate) struct StaticResolver {
+    hosts: Arc<HashMap<String, Vec<IpAddr>>>,
+}
+
+impl StaticResolver {
+    pub(crate) fn new(hosts: HashMap<String, Vec<IpAddr>>) -> Self {
+        Self {
+            hosts: Arc::new(hosts),
+        }
+    }
+}
+
+impl ResolveDns for StaticResolver {
+    fn resolve_dns<'a>(&'a self, name: &'a str) -> DnsFuture<'a> {
+        if let Some(ips) = self.hosts.get(&name.to_ascii_lowercase()) {
+            return DnsFuture::ready(Ok(ips.clone()));
+        }
+
+        DnsFuture::new(async move {
+            let addrs = tokio::net::lookup_host((name, 0))
+                .await
+                .map_err(ResolveDnsError::new)?;
+            Ok(addrs.map(|addr| addr.ip()).collect())
+        })
+    }
+}
+
+impl tower_service::Service<Name> for StaticResolver {
+    type Response = std::vec::IntoIter<SocketAddr>;
+    type Error = ResolveDnsError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        let resolver = self.clone();
+        Box::pin(async move {
+            let ips = resolver.resolve_dns(name.as_str()).await?;
+            // hyper fills in the port from the request URL
+            Ok(socket_addrs(&ips).into_iter())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_overrides_keeps_every_address() {
+        let v4: IpAddr = "10.0.0.5".parse().unwrap();
+        let v6: IpAddr = "fd00::5".parse().unwrap();
+        let hosts = group_overrides(vec![
+            ("s3.local".to_string(), v4),
+            ("s3.local".to_string(), v6),
+        ]);
+        assert_eq!(hosts["s3.local"], vec![v4, v6]);
+        assert_eq!(socket_addrs(&hosts["s3.local"])[1].port(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_static_resolver_answers_mapped_host() {
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        let resolver = StaticResolver::new(group_overrides(vec![("s3.local".to_string(), ip)]));
+        assert_eq!(resolver.resolve_dns("S3.local").await.unwrap(), vec![ip]);
+    }
+
+    #[tokio::test]
+    async fn test_static_resolver_as_hyper_resolver() {
+        use tower_service::Service;
+
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        let mut resolver = StaticResolver::new(group_overrides(vec![("s3.local".to_string(), ip)]));
+        let name: Name = "s3.local".parse().unwrap();
+        let addrs: Vec<SocketAddr> = resolver.call(name).await.unwrap().collect();
+        assert_eq!(addrs, vec![SocketAddr::new(ip, 0)]);
+    }
+}
@@ -0,0 +1,326 @@
+//! TLS settings shared by the S3 and admin clients
+//!
+//! Aliases that keep the defaults use each client's built-in TLS setup. When
+//! an alias skips verification, adds a CA bundle, requires a minimum TLS
+//! version or presents a client certificate, one rustls configuration is built
+//! here and handed to both the S3 connector and the admin HTTP client.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use aws_smithy_runtime_api::client::http::{
+    HttpClient, HttpConnector as SdkHttpConnector, HttpConnectorFuture, HttpConnectorSettings,
+    SharedHttpClient, SharedHttpConnector,
+};
+use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, HttpResponse};
+use aws_smithy_runtime_api::client::result::ConnectorError;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::body::SdkBody;
+use hyper_util::client::legacy::Client;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioExecutor;
+use rc_core::{Alias, Error, Result};
+
+use crate::resolve::StaticResolver;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::WebPkiSupportedAlgorithms;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+
+/// Protocol versions for `tls_min_version = "1.3"`
+static TLS13_ONLY: &[&rustls::SupportedProtocolVersion] = &[&rustls::version::TLS13];
+
+/// Whether an alias needs a custom TLS configuration
+pub(crate) fn is_custom(alias: &Alias) -> bool {
+    alias.insecure
+        || alias.ca_bundle.is_some()
+        || alias.tls_min_version.is_some()
+        || alias.client_cert.is_some()
+        || alias.client_key.is_some()
+}
+
+/// Protocol versions allowed by a `tls_min_version` setting
+fn protocol_versions(
+    min: Option<&str>,
+) -> Result<&'static [&'static rustls::SupportedProtocolVersion]> {
+    match min {
+        None | Some("1.2") => Ok(rustls::ALL_VERSIONS),
+        Some("1.3") => Ok(TLS13_ONLY),
+        Some(other) => Err(Error::Config(format!(
+            "Unsupported minimum TLS version '{other}', expected 1.2 or 1.3"
+        ))),
+    }
+}
+
+fn read_pem(path: &str, what: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| Error::Config(format!("Failed to read {what} {path}: {e}")))
+}
+
+/// Trusted roots: the platform's certificates plus those in `ca_bundle`
+///
+/// When the platform store yields nothing (e.g. a minimal container), the
+/// bundled Mozilla roots are used as the base instead.
+fn root_store(ca_bundle: Option<&str>) -> Result<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+    let native = rustls_native_certs::load_native_certs();
+    for err in &native.errors {
+        tracing::debug!("Skipping platform certificate: {err}");
+    }
+    roots.add_parsable_certificates(native.certs);
+    if roots.is_empty() {
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    }
+
+    if let Some(ca_bundle) = ca_bundle {
+        let pem = read_pem(ca_bundle, "CA bundle")?;
+        for cert in CertificateDer::pem_slice_iter(&pem) {
+            let cert =
+                cert.map_err(|e| Error::Config(format!("Invalid CA bundle {ca_bundle}: {e}")))?;
+            roots
+                .add(cert)
+                .map_err(|e| Error::Config(format!("Invalid CA bundle {ca_bundle}: {e}")))?;
+        }
+    }
+    Ok(roots)
+}
+
+/// Build the rustls configuration for an alias, or `None` to keep the defaults
+pub(crate) fn client_config(alias: &Alias) -> Result<Option<rustls::ClientConfig>> {
+    if !is_custom(alias) {
+        return Ok(None);
+    }
+
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+    let builder = rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_protocol_versions(protocol_versions(alias.tls_min_version.as_deref())?)
+        .map_err(|e| Error::Config(format!("Invalid TLS settings: {e}")))?;
+
+    let builder = if alias.insecure {
+        builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(NoVerification(
+                provider.signature_verification_algorithms,
+            )))
+    } else {
+        builder.with_root_certificates(root_store(alias.ca_bundle.as_deref())?)
+    };
+
+    let config = match (&alias.client_cert, &alias.client_key) {
+        (Some(cert_path), Some(key_path)) => {
+            let certs = CertificateDer::pem_slice_iter(&read_pem(cert_path, "client certificate")?)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    Error::Config(format!("Invalid client certificate {cert_path}: {e}"))
+                })?;
+            let key = PrivateKeyDer::from_pem_slice(&read_pem(key_path, "client key")?)
+                .map_err(|e| Error::Config(format!("Invalid client key {key_path}: {e}")))?;
+            builder
+                .with_client_auth_cert(certs, key)
+                .map_err(|e| Error::Config(format!("Invalid client certificate or key: {e}")))?
+        }
+        (None, None) => builder.with_no_client_auth(),
+        _ => {
+            return Err(Error::Config(
+                "client_cert and client_key must be set together".to_string(),
+            ));
+        }
+    };
+
+    Ok(Some(config))
+}
+
+/// HTTP client for the S3 SDK that connects with `config`, resolving hosts through `resolver`
+pub(crate) fn s3_http_client(
+    config: rustls::ClientConfig,
+    resolver: StaticResolver,
+) -> SharedHttpClient {
+    SharedHttpClient::new(RustlsHttpClient {
+        config,
+        resolver,
+        connector: OnceLock::new(),
+    })
+}
+
+/// S3 SDK HTTP client over hyper with an alias's rustls configuration
+///
+/// The connector (and its connection pool) is built on first use and shared
+/// by every request after that.
+#[derive(Debug)]
+struct RustlsHttpClient {
+    config: rustls::ClientConfig,
+    resolver: StaticResolver,
+    connector: OnceLock<SharedHttpConnector>,
+}
+
+impl HttpClient for RustlsHttpClient {
+    fn http_connector(
+        &self,
+        settings: &HttpConnectorSettings,
+        _components: &RuntimeComponents,
+    ) -> SharedHttpConnector {
+        self.connector
+            .get_or_init(|| {
+                let mut http = HttpConnector::new_with_resolver(self.resolver.clone());
+                http.enforce_http(false);
+                http.set_connect_timeout(settings.connect_timeout());
+                let https = hyper_rustls::HttpsConnectorBuilder::new()
+                    .with_tls_config(self.config.clone())
+                    .https_or_http()
+                    .enable_http1()
+                    .wrap_connector(http);
+                SharedHttpConnector::new(RustlsConnector {
+                    client: Client::builder(TokioExecutor::new()).build(https),
+                    read_timeout: settings.read_timeout(),
+                })
+            })
+            .clone()
+    }
+}
+
+/// Sends SDK requests through a hyper client
+#[derive(Clone)]
+struct RustlsConnector {
+    client: Client<hyper_rustls::HttpsConnector<HttpConnector<StaticResolver>>, SdkBody>,
+    read_timeout: Option<Duration>,
+}
+
+impl std::fmt::Debug for RustlsConnector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RustlsConnector")
+            .field("read_timeout", &self.read_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SdkHttpConnector for RustlsConnector {
+    fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+        let request = match request.try_into_http1x() {
+            Ok(request) => request,
+            Err(e) => return HttpConnectorFuture::ready(Err(ConnectorError::user(e.into()))),
+        };
+        let response = self.client.request(request);
+        let read_timeout = self.read_timeout;
+        HttpConnectorFuture::new(async move {
+            let response = match read_timeout {
+                Some(limit) => tokio::time::timeout(limit, response)
+                    .await
+                    .map_err(|e| ConnectorError::timeout(e.into()))?,
+                None => response.await,
+            }
+            .map_err(|e| ConnectorError::io(e.into()))?;
+            HttpResponse::try_from(response.map(SdkBody::from_body_1_x))
+                .map_err(|e| ConnectorError::other(e.into(), None))
+        })
+    }
+}
+
+/// Accepts any server certificate, for aliases marked insecure
+#[derive(Debug)]
+struct NoVerification(WebPkiSupportedAlgorithms);
+
+impl ServerCertVerifier for NoVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.0)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.0)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_alias_keeps_builtin_tls() {
+        let alias = Alias::new("local", "https://s3.local", "a", "b");
+        assert!(!is_custom(&alias));
+        assert!(client_config(&alias).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_min_version() {
+        assert_eq!(protocol_versions(Some("1.3")).unwrap().len(), 1);
+        assert_eq!(
+            protocol_versions(None).unwrap().len(),
+            rustls::ALL_VERSIONS.len()
+        );
+        assert!(protocol_versions(Some("1.1")).is_err());
+
+        let mut alias = Alias::new("local", "https://s3.local", "a", "b");
+        alias.tls_min_version = Some("1.3".to_string());
+        assert!(client_config(&alias).unwrap().is_some());
+    }
+
+    /// Self-signed CA used only to check that bundles extend the trusted roots
+    const TEST_CA_PEM: &str = "\
+-----BEGIN CERTIFICATE-----
+MIIBgTCCASegAwIBAgIUBldUZODPpLvNusORDnNEXSrSyFUwCgYIKoZIzj0EAwIw
+FTETMBEGA1UEAwwKcmMgdGVzdCBDQTAgFw0yNjEwMTYwNDUyNDlaGA8yMTI2MDky
+MjA0NTI0OVowFTETMBEGA1UEAwwKcmMgdGVzdCBDQTBZMBMGByqGSM49AgEGCCqG
+SM49AwEHA0IABMQk6TweEOVXETQfTxT5NBBf1vypM2xJDNtpZnJ/KU5Qp7nGVssb
+lq1dIkDMGo0orvPDxfaAKBocN9LEs5QzFk6jUzBRMB0GA1UdDgQWBBRTZMVFyCRc
+Q+9uQePi2Avln3z8PTAfBgNVHSMEGDAWgBRTZMVFyCRcQ+9uQePi2Avln3z8PTAP
+BgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0gAMEUCIGo1S5TB7J+Is/EriPgD
+GJeC5Ce4jMzeNGsLrCb6PHB5AiEAmPn5A/d/PfG3Ts0TXeSyP/00ek3IhW/RORxx
+12embfw=
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_ca_bundle_adds_to_platform_roots() {
+        let base = root_store(None).unwrap();
+        assert!(!base.is_empty());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ca.pem");
+        std::fs::write(&path, TEST_CA_PEM).unwrap();
+
+        let roots = root_store(Some(path.to_str().unwrap())).unwrap();
+        assert_eq!(roots.len(), base.len() + 1);
+
+        std::fs::write(
+            &path,
+            "-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydA==\n-----END CERTIFICATE-----\n",
+        )
+        .unwrap();
+        assert!(root_store(Some(path.to_str().unwrap())).is_err());
+    }
+
+    #[test]
+    fn test_insecure_and_client_key_pairing() {
+        let mut alias = Alias::new("local", "https://s3.local", "a", "b");
+        alias.insecure = true;
+        assert!(client_config(&alias).unwrap().is_some());
+
+        alias.client_cert = Some("/nonexistent/cert.pem".to_string());
+        assert!(matches!(client_config(&alias), Err(Error::Config(_))));
+    }
+}
@@ -0,0 +1,160 @@
+//! Unsigned downloads from plain HTTP(S) URLs
+//!
+//! Presigned and public object URLs carry their own authorization (or need
+//! none), so they are fetched with a plain GET: no alias, no signing.
+
+use rc_core::{Error, Result};
+use reqwest::StatusCode;
+use reqwest::header::RANGE;
+
+/// Body of a GET response, read chunk by chunk
+pub struct UrlBody {
+    response: reqwest::Response,
+}
+
+impl UrlBody {
+    /// Send a GET for `url`, optionally limited to `length` bytes from `offset`
+    pub async fn get(url: &url::Url, offset: u64, length: Option<u64>) -> Result<Self> {
+        let client = reqwest::Client::builder().build().map_err(network_error)?;
+
+        let mut request = client.get(url.clone());
+        let range = byte_range(offset, length);
+        if let Some(range) = &range {
+            request = request.header(RANGE, range);
+        }
+
+        let response = request.send().await.map_err(network_error)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(status_error(status, url, &body));
+        }
+        if range.is_some() && status != StatusCode::PARTIAL_CONTENT {
+            return Err(Error::UnsupportedFeature(format!(
+                "{} does not support byte ranges",
+                url.host_str().unwrap_or_default()
+            )));
+        }
+
+        Ok(Self { response })
+    }
+
+    /// Body size announced by the server, if any
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Next chunk of the body, or `None` once it is complete
+    pub async fn chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        self.response
+            .chunk()
+            .await
+            .map(|chunk| chunk.map(|c| c.to_vec()))
+            .map_err(network_error)
+    }
+
+    /// Read the rest of the body
+    pub async fn bytes(mut self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        while let Some(chunk) = self.chunk().await? {
+            data.extend_from_slice(&chunk);
+        }
+        Ok(data)
+    }
+}
+
+/// `Range` header value for `length` bytes from `offset`; `None` for the whole body
+fn byte_range(offset: u64, length: Option<u64>) -> Option<String> {
+    match (offset, length) {
+        (0, None) => None,
+        (offset, None) => Some(format!("bytes={offset}-")),
+        (offset, Some(length)) => Some(format!(
+            "bytes={offset}-{}",
+            offset + length.saturating_sub(1)
+        )),
+    }
+}
+
+/// Map a transport error to [`Error::Network`] without the URL, whose query
+/// may hold presigned credentials
+fn network_error(e: reqwest::Error) -> Error {
+    let e = e.without_url();
+    let mut message = e.to_string();
+    let mut source = std::error::Error::source(&e);
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    Error::Network(message)
+}
+
+/// Map a failed response to an error; the URL's query (presigned credentials) is left out
+fn status_error(status: StatusCode, url: &url::Url, body: &str) -> Error {
+    let mut shown = url.clone();
+    shown.set_query(None);
+    match status {
+        StatusCode::NOT_FOUND => Error::NotFound(shown.to_string()),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Error::Auth(format!(
+            "{shown}: HTTP {} (the URL may have expired)",
+            status.as_u16()
+        )),
+        StatusCode::RANGE_NOT_SATISFIABLE => Error::General(format!(
+            "{shown}: requested range is past the end of the object"
+        )),
+        _ if status.is_server_error() => {
+            Error::Network(format!("HTTP {}: {}", status.as_u16(), body.trim()))
+        }
+        _ => Error::General(format!("HTTP {}: {}", status.as_u16(), body.trim())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_range() {
+        assert_eq!(byte_range(0, None), None);
+        assert_eq!(byte_range(10, None).as_deref(), Some("bytes=10-"));
+        assert_eq!(byte_range(0, Some(5)).as_deref(), Some("bytes=0-4"));
+        assert_eq!(byte_range(10, Some(1)).as_deref(), Some("bytes=10-10"));
+    }
+
+    #[test]
+    fn test_status_error_hides_query() {
+        let url = url::Url::parse("https://s3.example.com/b/k?X-Amz-Signature=secret").unwrap();
+        let err = status_error(StatusCode::FORBIDDEN, &url, "");
+        assert!(matches!(err, Error::Auth(_)));
+        assert!(!err.to_string().contains("secret"));
+        assert!(matches!(
+            status_error(StatusCode::NOT_FOUND, &url, ""),
+            Error::NotFound(_)
+        ));
+        assert!(matches!(
+            status_error(StatusCode::SERVICE_UNAVAILABLE, &url, "busy"),
+            Error::Network(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_network_error_hides_url() {
+        // A port nothing listens on, so the request fails to connect
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let url = url::Url::parse(&format!(
+            "http://127.0.0.1:{port}/b/k?X-Amz-Credential=AKIDEXAMPLE&X-Amz-Signature=secret"
+        ))
+        .unwrap();
+        let err = UrlBody::get(&url, 0, None)
+            .await
+            .err()
+            .expect("connection refused");
+        assert!(matches!(err, Error::Network(_)));
+        let message = err.to_string();
+        assert!(!message.contains("secret"), "{message}");
+        assert!(!message.contains("AKIDEXAMPLE"), "{message}");
+    }
+}